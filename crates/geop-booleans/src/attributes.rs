@@ -0,0 +1,137 @@
+use geop_geometry::surfaces::SurfaceLike;
+
+use geop_topology::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    topology::{attributes::Attributed, face::Face, shell::Shell, volume::Volume},
+};
+
+// The face of the sources that the face of a result was cut from, i.e. that lies on the same surface, possibly flipped like the
+// walls of a cut, and contains a point inside of the result face.
+fn source_face<'a>(face: &Face, sources: &'a [Volume]) -> Option<&'a Face> {
+    let p = face.inner_point();
+    let flipped = face.surface.neg();
+    sources
+        .iter()
+        .flat_map(|v| {
+            v.boundary
+                .faces
+                .iter()
+                .chain(v.cavities.iter().flat_map(|c| c.faces.iter()))
+        })
+        .filter(|s| *s.surface == *face.surface || *s.surface == flipped)
+        .find(|s| face_point_contains(s, p) != FacePointContains::Outside)
+}
+
+fn propagate_shell(shell: &Shell, sources: &[Volume]) -> Shell {
+    Shell::new(
+        shell
+            .faces
+            .iter()
+            .map(|face| {
+                let mut face = face.clone();
+                if let Some(source) = source_face(&face, sources) {
+                    face.attributes_mut().merge(source.attributes());
+                }
+                face
+            })
+            .collect(),
+    )
+}
+
+fn propagate(results: &[Volume], sources: &[Volume], volume_sources: &[Volume]) -> Vec<Volume> {
+    results
+        .iter()
+        .map(|volume| {
+            let mut result = Volume::new(
+                propagate_shell(&volume.boundary, sources),
+                volume
+                    .cavities
+                    .iter()
+                    .map(|c| propagate_shell(c, sources))
+                    .collect(),
+            );
+            result.attributes = volume.attributes.clone();
+            for source in volume_sources {
+                result.attributes.merge(source.attributes());
+            }
+            result
+        })
+        .collect()
+}
+
+// Booleans build new faces, which start without attributes. This passes the attributes of the faces of the operands on to the faces of
+// the results that were cut from them, and the attributes of the operands on to every result, where the first operand wins if
+// several have a value of the same type. It is a separate step, since it classifies every face of the results again. Use
+// propagate_cut_attributes for cuts.
+pub fn propagate_attributes(results: &[Volume], sources: &[Volume]) -> Vec<Volume> {
+    propagate(results, sources, sources)
+}
+
+// Like propagate_attributes, but the results of a cut are parts of the base, so only the base passes its attributes on to them. The
+// walls cut by the tools still get the attributes of the faces of the tools.
+pub fn propagate_cut_attributes(
+    results: &[Volume],
+    base: &Volume,
+    tools: &[Volume],
+) -> Vec<Volume> {
+    let sources: Vec<Volume> = std::iter::once(base).chain(tools).cloned().collect();
+    propagate(results, &sources, std::slice::from_ref(base))
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform};
+    use geop_topology::primitive_objects::volumes::cube::primitive_cube;
+
+    use crate::{difference::volume::cut, union::volume::union_all};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Material(&'static str);
+
+    #[derive(Debug, PartialEq)]
+    struct Finish(&'static str);
+
+    #[test]
+    fn test_propagate_attributes() {
+        let base = primitive_cube(2.0, 2.0, 2.0).with_attribute(Material("steel"));
+        let tool = primitive_cube(2.0, 2.0, 2.0)
+            .transform(Transform::from_translation(Point::new(1.0, 1.0, 1.0)))
+            .with_attribute(Material("rubber"))
+            .with_attribute(Finish("polished"));
+        let tool = Volume {
+            boundary: Shell::new(
+                tool.boundary
+                    .faces
+                    .iter()
+                    .map(|f| f.clone().with_attribute(Finish("milled")))
+                    .collect(),
+            ),
+            ..tool
+        };
+
+        let operands = [base.clone(), tool.clone()];
+        let union = propagate_attributes(&union_all(&operands), &operands);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].attribute(), Some(&Material("steel")));
+        assert_eq!(union[0].attribute(), Some(&Finish("polished")));
+
+        // The cut keeps the material of the base, and its walls are milled by the tool.
+        let parts = propagate_cut_attributes(
+            &cut(&base, std::slice::from_ref(&tool)),
+            &base,
+            std::slice::from_ref(&tool),
+        );
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].attribute(), Some(&Material("steel")));
+        assert_eq!(parts[0].attribute::<Finish>(), None);
+        let milled = parts[0]
+            .all_faces()
+            .iter()
+            .filter(|f| f.attribute() == Some(&Finish("milled")))
+            .count();
+        assert_eq!(milled, 3, "Expected the walls of the notch to be milled");
+        assert_eq!(parts[0].all_faces().len(), 9);
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    fmt::{Display, Formatter},
+    time::{Duration, Instant},
+};
+
+use geop_topology::{
+    progress::Cancelled,
+    topology::{
+        edge::Edge,
+        face::Face,
+        scene::{Color, Scene},
+    },
+};
+
+// Limits on the work of a boolean, so that pathological inputs fail with an error instead of hanging. No limits are set by default.
+#[derive(Debug, Clone, Default)]
+pub struct BooleanLimits {
+    // Number of pairs of faces that are intersected, checked before any work is done.
+    pub max_face_pairs: Option<usize>,
+    // Number of intersection curves and points found between the faces.
+    pub max_intersection_events: Option<usize>,
+    // Wall-clock time of the whole operation. It is checked between the steps, so a single slow step can overrun it.
+    pub time_budget: Option<Duration>,
+}
+
+impl BooleanLimits {
+    pub fn unlimited() -> BooleanLimits {
+        BooleanLimits::default()
+    }
+
+    pub fn with_max_face_pairs(mut self, max_face_pairs: usize) -> BooleanLimits {
+        self.max_face_pairs = Some(max_face_pairs);
+        self
+    }
+
+    pub fn with_max_intersection_events(mut self, max_intersection_events: usize) -> BooleanLimits {
+        self.max_intersection_events = Some(max_intersection_events);
+        self
+    }
+
+    pub fn with_time_budget(mut self, time_budget: Duration) -> BooleanLimits {
+        self.time_budget = Some(time_budget);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExceededLimit {
+    FacePairs(usize),
+    IntersectionEvents(usize),
+    Time(Duration),
+}
+
+// How far the boolean got before it was stopped.
+#[derive(Debug, Clone)]
+pub struct BooleanDiagnostics {
+    pub face_pairs: usize,
+    pub total_face_pairs: usize,
+    pub intersection_events: usize,
+    pub elapsed: Duration,
+    // The intersection curves found so far in black and the faces that were worked on when the operation stopped in red.
+    pub scene: Scene,
+}
+
+#[derive(Debug, Clone)]
+pub enum BooleanError {
+    Cancelled,
+    LimitExceeded(ExceededLimit, Box<BooleanDiagnostics>),
+    NotSingleVolume(usize), // The result of an operation that returns one volume is empty or falls apart into this many volumes
+}
+
+impl Display for BooleanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BooleanError::Cancelled => write!(f, "{}", Cancelled),
+            BooleanError::NotSingleVolume(n) => {
+                write!(f, "Result consists of {} volumes instead of one", n)
+            }
+            BooleanError::LimitExceeded(limit, diagnostics) => {
+                match limit {
+                    ExceededLimit::FacePairs(max) => {
+                        write!(f, "More than {} pairs of faces to intersect", max)?
+                    }
+                    ExceededLimit::IntersectionEvents(max) => {
+                        write!(f, "More than {} intersection curves and points", max)?
+                    }
+                    ExceededLimit::Time(budget) => write!(f, "Took longer than {:?}", budget)?,
+                }
+                write!(
+                    f,
+                    " after intersecting {} of {} pairs of faces in {:?}",
+                    diagnostics.face_pairs, diagnostics.total_face_pairs, diagnostics.elapsed
+                )
+            }
+        }
+    }
+}
+
+impl From<Cancelled> for BooleanError {
+    fn from(_: Cancelled) -> BooleanError {
+        BooleanError::Cancelled
+    }
+}
+
+// Keeps track of the work of a running boolean and checks it against the limits.
+pub struct Budget {
+    limits: BooleanLimits,
+    started: Instant,
+    face_pairs: usize,
+    total_face_pairs: usize,
+    intersection_events: usize,
+}
+
+impl Budget {
+    pub fn new(limits: &BooleanLimits) -> Budget {
+        Budget {
+            limits: limits.clone(),
+            started: Instant::now(),
+            face_pairs: 0,
+            total_face_pairs: 0,
+            intersection_events: 0,
+        }
+    }
+
+    // Sets the number of pairs of faces that the operation is about to intersect.
+    pub fn plan_face_pairs(&mut self, total: usize) -> Result<(), ExceededLimit> {
+        self.total_face_pairs = total;
+        match self.limits.max_face_pairs {
+            Some(max) if total > max => Err(ExceededLimit::FacePairs(max)),
+            _ => Ok(()),
+        }
+    }
+
+    // Counts a pair of faces that was intersected, with the number of curves and points it found.
+    pub fn add_face_pair(&mut self, events: usize) -> Result<(), ExceededLimit> {
+        self.face_pairs += 1;
+        self.intersection_events += events;
+        match self.limits.max_intersection_events {
+            Some(max) if self.intersection_events > max => {
+                Err(ExceededLimit::IntersectionEvents(max))
+            }
+            _ => self.check_time(),
+        }
+    }
+
+    pub fn check_time(&self) -> Result<(), ExceededLimit> {
+        match self.limits.time_budget {
+            Some(budget) if self.started.elapsed() > budget => Err(ExceededLimit::Time(budget)),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn exceeded(&self, limit: ExceededLimit, scene: Scene) -> BooleanError {
+        BooleanError::LimitExceeded(
+            limit,
+            Box::new(BooleanDiagnostics {
+                face_pairs: self.face_pairs,
+                total_face_pairs: self.total_face_pairs,
+                intersection_events: self.intersection_events,
+                elapsed: self.started.elapsed(),
+                scene,
+            }),
+        )
+    }
+}
+
+// The faces that were worked on when a boolean ran out of budget, with the intersection curves found so far.
+pub(crate) fn budget_scene<'a>(faces: &[&Face], edges: impl Iterator<Item = &'a Edge>) -> Scene {
+    Scene::new(
+        vec![],
+        faces.iter().map(|f| ((*f).clone(), Color::red())).collect(),
+        edges.map(|e| (e.clone(), Color::black())).collect(),
+        vec![],
+    )
+}
+
+// For the versions of the operations without limits, which can only be cancelled.
+pub(crate) fn without_limits<T>(result: Result<T, BooleanError>) -> Result<T, Cancelled> {
+    result.map_err(|e| match e {
+        BooleanError::Cancelled => Cancelled,
+        BooleanError::LimitExceeded(..) => {
+            unreachable!("Operations without limits do not exceed them")
+        }
+        BooleanError::NotSingleVolume(_) => {
+            unreachable!("Operations without limits return any number of volumes")
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform};
+    use geop_topology::{
+        primitive_objects::volumes::cube::primitive_cube,
+        progress::{CancellationToken, Progress},
+    };
+
+    use crate::union::volume::union_all_with_limits;
+
+    use super::*;
+
+    #[test]
+    fn test_boolean_limits() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let shifted = cube.transform(Transform::from_translation(Point::new(1.0, 0.5, 0.25)));
+        let volumes = [cube, shifted];
+        let union =
+            |limits: BooleanLimits| union_all_with_limits(&volumes, &Progress::none(), &limits);
+
+        assert!(union(BooleanLimits::unlimited()).is_ok());
+
+        // The pairs of faces are counted before any work is done.
+        match union(BooleanLimits::unlimited().with_max_face_pairs(10)) {
+            Err(BooleanError::LimitExceeded(ExceededLimit::FacePairs(10), diagnostics)) => {
+                assert_eq!(diagnostics.total_face_pairs, 36);
+                assert_eq!(diagnostics.face_pairs, 0);
+            }
+            result => panic!("Expected too many pairs of faces, got {:?}", result.err()),
+        }
+
+        // The faces that were worked on are kept for debugging.
+        let error = union(BooleanLimits::unlimited().with_max_intersection_events(1)).unwrap_err();
+        match &error {
+            BooleanError::LimitExceeded(ExceededLimit::IntersectionEvents(1), diagnostics) => {
+                assert!(diagnostics.intersection_events > 1);
+                assert!(diagnostics.face_pairs <= diagnostics.total_face_pairs);
+                assert_eq!(
+                    diagnostics.scene.faces.len(),
+                    2,
+                    "Expected the pair of faces"
+                );
+            }
+            error => panic!("Expected too many intersection events, got {:?}", error),
+        }
+        assert!(error
+            .to_string()
+            .starts_with("More than 1 intersection curves and points after intersecting"));
+
+        assert!(matches!(
+            union(BooleanLimits::unlimited().with_time_budget(Duration::ZERO)),
+            Err(BooleanError::LimitExceeded(ExceededLimit::Time(_), _))
+        ));
+
+        let token = CancellationToken::new();
+        token.cancel();
+        assert!(matches!(
+            union_all_with_limits(
+                &volumes,
+                &Progress::with_token(&token),
+                &BooleanLimits::unlimited()
+            ),
+            Err(BooleanError::Cancelled)
+        ));
+    }
+}
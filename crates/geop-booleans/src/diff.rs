@@ -0,0 +1,142 @@
+use geop_geometry::surfaces::surface::Surface;
+use geop_topology::{
+    measure::mass::measure_volume,
+    topology::{face::Face, volume::Volume},
+};
+
+use crate::xor::volume::volume_xor_parts;
+
+// The changes from one revision of a model to the next.
+// Faces of both revisions are matched by their geometry, not by their position, so a face keeps its match when faces are added,
+// removed or reordered. They are reported by their index in Volume::all_faces of their revision.
+#[derive(Debug)]
+pub struct ModelDiff {
+    pub added: Vec<Volume>,   // Material that is only in the new revision
+    pub removed: Vec<Volume>, // Material that is only in the old revision
+    pub unchanged_faces: Vec<(usize, usize)>, // Pairs of old and new faces with the same surface and edges
+    pub changed_faces: Vec<(usize, usize)>, // Pairs of old and new faces on the same surface, but with different edges
+    pub removed_faces: Vec<usize>,          // Old faces without a new face on their surface
+    pub added_faces: Vec<usize>,            // New faces without an old face on their surface
+    pub volume_delta: Option<f64>, // New volume minus old volume, if all faces are planar, since curved volumes are not measured
+}
+
+impl ModelDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed_faces.is_empty()
+            && self.removed_faces.is_empty()
+            && self.added_faces.is_empty()
+    }
+}
+
+// Two faces are equal if they lie on the same surface and have the same edges, regardless of where their contours start.
+fn face_eq(a: &Face, b: &Face) -> bool {
+    let edges_a = a.all_edges();
+    let edges_b = b.all_edges();
+    a.surface == b.surface
+        && edges_a.len() == edges_b.len()
+        && edges_a.iter().all(|e| edges_b.contains(e))
+}
+
+fn planar(volume: &Volume) -> bool {
+    volume
+        .all_faces()
+        .iter()
+        .all(|f| matches!(*f.surface, Surface::Plane(_)))
+}
+
+// Compares two revisions of a model. Equal faces are matched first, and the remaining faces are paired as changed if they lie on the
+// same surface. The volume delta is only measured for models with planar faces.
+pub fn diff(volume_old: &Volume, volume_new: &Volume) -> ModelDiff {
+    let faces_old = volume_old.all_faces();
+    let faces_new = volume_new.all_faces();
+
+    let mut unchanged_faces = Vec::<(usize, usize)>::new();
+    let mut unmatched = Vec::<usize>::new();
+    for (i, face) in faces_old.iter().enumerate() {
+        match (0..faces_new.len())
+            .find(|j| face_eq(face, &faces_new[*j]) && !unchanged_faces.iter().any(|(_, k)| k == j))
+        {
+            Some(j) => unchanged_faces.push((i, j)),
+            None => unmatched.push(i),
+        }
+    }
+    let paired = |pairs: &[(usize, usize)], j: usize| pairs.iter().any(|(_, k)| *k == j);
+    let mut changed_faces = Vec::<(usize, usize)>::new();
+    let mut removed_faces = Vec::<usize>::new();
+    for i in unmatched {
+        match (0..faces_new.len()).find(|j| {
+            faces_old[i].surface == faces_new[*j].surface
+                && !paired(&unchanged_faces, *j)
+                && !paired(&changed_faces, *j)
+        }) {
+            Some(j) => changed_faces.push((i, j)),
+            None => removed_faces.push(i),
+        }
+    }
+    let added_faces = (0..faces_new.len())
+        .filter(|j| !paired(&unchanged_faces, *j) && !paired(&changed_faces, *j))
+        .collect();
+
+    // Identical models would only produce empty shells in the boolean operation.
+    let (removed, added) =
+        match removed_faces.is_empty() && unchanged_faces.len() == faces_new.len() {
+            true => (Vec::new(), Vec::new()),
+            false => volume_xor_parts(volume_old, volume_new),
+        };
+
+    ModelDiff {
+        added,
+        removed,
+        unchanged_faces,
+        changed_faces,
+        removed_faces,
+        added_faces,
+        volume_delta: match planar(volume_old) && planar(volume_new) {
+            true => Some(measure_volume(volume_new) - measure_volume(volume_old)),
+            false => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform};
+    use geop_topology::{
+        primitive_objects::{faces::sphere::primitive_sphere, volumes::cube::primitive_cube},
+        topology::shell::Shell,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        // The box is lengthened in x, so the face at x = -1 stays, the one at x = 1 moves and the four faces around grow.
+        let old = primitive_cube(2.0, 2.0, 2.0);
+        let new = primitive_cube(3.0, 2.0, 2.0)
+            .transform(Transform::from_translation(Point::new(0.5, 0.0, 0.0)));
+        let d = diff(&old, &new);
+        assert_eq!(d.unchanged_faces.len(), 1);
+        assert_eq!(d.changed_faces.len(), 4);
+        assert_eq!(d.removed_faces.len(), 1);
+        assert_eq!(d.added_faces.len(), 1);
+        let moved = &old.all_faces()[d.removed_faces[0]];
+        assert!(moved.inner_point().x > 0.99);
+        assert_eq!(d.added.len(), 1);
+        assert!(d.removed.is_empty());
+        assert!((d.volume_delta.unwrap() - 4.0).abs() < 1e-9);
+        assert!(!d.is_empty());
+
+        assert!(diff(&old, &old).is_empty());
+
+        // Curved models are compared, but not measured.
+        let sphere = Volume::new(
+            Shell::new(vec![primitive_sphere(Point::zero(), 1.0)]),
+            vec![],
+        );
+        let d = diff(&sphere, &sphere);
+        assert!(d.is_empty());
+        assert_eq!(d.volume_delta, None);
+    }
+}
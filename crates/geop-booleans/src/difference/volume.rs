@@ -0,0 +1,298 @@
+use geop_topology::{
+    progress::{Cancelled, Progress},
+    topology::{face::Face, non_manifold_shell::NonManifoldShell, volume::Volume},
+};
+
+use crate::{
+    budget::{budget_scene, without_limits, BooleanError, BooleanLimits, Budget},
+    recorder::{recorded, RecordedOperation},
+    remesh::volume::{
+        face_volume_classify, faces_split_at_vertices, volumes_split_faces_with_limits,
+        FaceVolumeClass, SPLIT_PROGRESS,
+    },
+    union::face::face_face_union,
+};
+
+// Subtracts all tools from the base volume at once. This shares the intersections between all operands instead of folding pairwise differences.
+// The faces of the tools that are inside of the base are flipped and become the walls of the cut. The result may consist of several volumes.
+pub fn cut(base: &Volume, tools: &[Volume]) -> Vec<Volume> {
+    cut_with_progress(base, tools, &Progress::none())
+        .expect("Operations without cancellation token are not cancelled")
+}
+
+// Like cut, reporting the progress and stopping early when the operation is cancelled.
+pub fn cut_with_progress(
+    base: &Volume,
+    tools: &[Volume],
+    progress: &Progress,
+) -> Result<Vec<Volume>, Cancelled> {
+    without_limits(cut_with_limits(
+        base,
+        tools,
+        progress,
+        &BooleanLimits::unlimited(),
+    ))
+}
+
+// Like cut_with_progress, stopping with an error that holds partial diagnostics when one of the limits is exceeded.
+pub fn cut_with_limits(
+    base: &Volume,
+    tools: &[Volume],
+    progress: &Progress,
+    limits: &BooleanLimits,
+) -> Result<Vec<Volume>, BooleanError> {
+    let mut volumes = vec![base.clone()];
+    volumes.extend(tools.iter().cloned());
+    recorded(RecordedOperation::Cut, &volumes, limits, || {
+        cut_faces(&volumes, progress, limits)
+            .map(|faces| NonManifoldShell::new(faces_split_at_vertices(faces)).to_volumes())
+    })
+}
+
+// The faces of the result of cutting the tools volumes[1..] from the base volumes[0], which are the faces of the base outside of all
+// tools and the flipped faces of the tools inside of the base.
+fn cut_faces(
+    volumes: &[Volume],
+    progress: &Progress,
+    limits: &BooleanLimits,
+) -> Result<Vec<Face>, BooleanError> {
+    let (base, tools) = (&volumes[0], &volumes[1..]);
+    let mut budget = Budget::new(limits);
+    let splits =
+        volumes_split_faces_with_limits(volumes, progress, &mut budget, 0.0, SPLIT_PROGRESS)?;
+    let total: usize = splits.iter().map(|s| s.len()).sum();
+    let mut done = 0;
+
+    let mut faces = Vec::<Face>::new();
+    for (k, split) in splits.into_iter().enumerate() {
+        for face in split {
+            progress.report_step(SPLIT_PROGRESS, 1.0, done, total)?;
+            done += 1;
+            budget
+                .check_time()
+                .map_err(|limit| budget.exceeded(limit, budget_scene(&[&face], [].iter())))?;
+            if k == 0 {
+                let keep = tools.iter().all(|tool| {
+                    matches!(
+                        face_volume_classify(&face, tool),
+                        FaceVolumeClass::Outside | FaceVolumeClass::OnOppositeSide
+                    )
+                });
+                if keep {
+                    faces.push(face);
+                }
+                continue;
+            }
+            let keep = face_volume_classify(&face, base) == FaceVolumeClass::Inside
+                && volumes.iter().enumerate().skip(1).all(|(m, tool)| {
+                    m == k
+                        || match face_volume_classify(&face, tool) {
+                            FaceVolumeClass::Outside => true,
+                            FaceVolumeClass::OnSameSide => k < m,
+                            FaceVolumeClass::Inside => false,
+                            FaceVolumeClass::OnOppositeSide => false,
+                        }
+                });
+            if keep {
+                faces.push(face.flip());
+            }
+        }
+    }
+    progress.report(1.0)?;
+    Ok(faces)
+}
+
+// Whether the faces share an edge, which runs in opposite directions in both.
+fn share_edge(a: &Face, b: &Face) -> bool {
+    a.all_edges().iter().any(|e| {
+        b.all_edges().iter().any(|f| {
+            e.start.is_some()
+                && e.start == f.end
+                && e.end == f.start
+                && e.get_midpoint() == f.get_midpoint()
+        })
+    })
+}
+
+// Merges faces on the same surface that share an edge into one face. Where a tool only touches self, the faces of self are split along
+// the contact, but the pieces on both sides are kept.
+fn merge_coplanar_faces(mut faces: Vec<Face>) -> Vec<Face> {
+    let mut i = 0;
+    while i < faces.len() {
+        let other = (i + 1..faces.len())
+            .find(|j| faces[i].surface == faces[*j].surface && share_edge(&faces[i], &faces[*j]));
+        match other {
+            Some(j) => {
+                let b = faces.remove(j);
+                let a = faces.remove(i);
+                faces.splice(i..i, face_face_union(&a, &b));
+            }
+            None => i += 1,
+        }
+    }
+    faces
+}
+
+// Subtracts the other volume from self, e.g. to drill a hole with a cylinder through a box. Faces of self outside of the other volume
+// are kept, and faces of the other volume inside of self are flipped to become the walls of the cut, like in cut. Of the coplanar
+// faces only those of self that touch the other volume from outside are kept, so faces that both share on the same side are
+// removed, and the pieces of faces of self that were split where the other volume touches them are merged again.
+// The result has to be a single volume, use cut for tools that split self apart or remove all of it.
+pub fn volume_difference(volume_self: &Volume, volume_other: &Volume) -> Volume {
+    match volume_difference_with_progress(volume_self, volume_other, &Progress::none()) {
+        Ok(volume) => volume,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+// Like volume_difference, reporting the progress and stopping early when the operation is cancelled. Results that are not a single
+// volume are an error instead of a panic.
+pub fn volume_difference_with_progress(
+    volume_self: &Volume,
+    volume_other: &Volume,
+    progress: &Progress,
+) -> Result<Volume, BooleanError> {
+    volume_difference_with_limits(
+        volume_self,
+        volume_other,
+        progress,
+        &BooleanLimits::unlimited(),
+    )
+}
+
+// Like volume_difference_with_progress, stopping with an error that holds partial diagnostics when one of the limits is exceeded.
+pub fn volume_difference_with_limits(
+    volume_self: &Volume,
+    volume_other: &Volume,
+    progress: &Progress,
+    limits: &BooleanLimits,
+) -> Result<Volume, BooleanError> {
+    let volumes = [volume_self.clone(), volume_other.clone()];
+    let mut result = recorded(RecordedOperation::Difference, &volumes, limits, || {
+        cut_faces(&volumes, progress, limits).map(|faces| match faces.is_empty() {
+            true => Vec::new(),
+            false => {
+                let faces = merge_coplanar_faces(faces_split_at_vertices(faces));
+                NonManifoldShell::new(faces_split_at_vertices(faces)).to_volumes()
+            }
+        })
+    })?;
+    match result.len() {
+        1 => Ok(result.remove(0)),
+        n => Err(BooleanError::NotSingleVolume(n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use geop_geometry::{
+        points::point::Point,
+        surfaces::{plane::Plane, surface::Surface},
+        transforms::Transform,
+    };
+    use geop_topology::{
+        measure::mass::measure_volume,
+        operations::extrude::extrude,
+        primitive_objects::{edges::circle::primitive_circle, volumes::cube::primitive_cube},
+        topology::contour::Contour,
+    };
+
+    use crate::verify::verify_boolean;
+
+    use super::*;
+
+    #[test]
+    fn test_cut() {
+        let base = primitive_cube(4.0, 4.0, 4.0);
+        let bar = primitive_cube(1.0, 1.0, 6.0);
+        let moved =
+            |v: &Volume, x: f64| v.transform(Transform::from_translation(Point::new(x, 0.0, 0.0)));
+
+        // Two bars drill two holes through the base at once.
+        let volumes = cut(&base, &[moved(&bar, -1.0), moved(&bar, 1.0)]);
+        assert_eq!(volumes.len(), 1, "Expected the base with two holes");
+        assert!((measure_volume(&volumes[0]) - 56.0).abs() < 1e-6);
+
+        // A slab through the middle splits the base in two.
+        let slab = primitive_cube(6.0, 6.0, 1.0);
+        let volumes = cut(&base, &[slab]);
+        assert_eq!(volumes.len(), 2, "Expected the base to fall apart");
+        for volume in volumes.iter() {
+            assert!(
+                (measure_volume(volume) - 24.0).abs() < 1e-6,
+                "Expected half of the rest"
+            );
+        }
+
+        // Tools that miss the base leave it as it is.
+        let volumes = cut(&base, &[moved(&bar, 10.0)]);
+        assert_eq!(volumes.len(), 1);
+        assert!((measure_volume(&volumes[0]) - 64.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_volume_difference() {
+        let base = primitive_cube(4.0, 4.0, 4.0);
+        let small = primitive_cube(2.0, 2.0, 2.0);
+        let moved = |v: &Volume, x: f64, z: f64| {
+            v.transform(Transform::from_translation(Point::new(x, 0.0, z)))
+        };
+
+        // A cylinder drills a hole through a box.
+        let disk = Face::new(
+            Some(Contour::new(vec![primitive_circle(
+                Point::new(0.0, 0.0, -3.0),
+                -Point::unit_z(),
+                0.5,
+            )])),
+            vec![],
+            Rc::new(Surface::Plane(Plane::new(
+                Point::new(0.0, 0.0, -3.0),
+                Point::unit_y(),
+                Point::unit_x(),
+            ))),
+        );
+        let cylinder = extrude(disk, Point::new(0.0, 0.0, 6.0));
+        let drilled = volume_difference(&base, &cylinder);
+        assert_eq!(drilled.all_faces().len(), 7, "Expected the box with a hole");
+        let operands = [base.clone(), cylinder];
+        verify_boolean(RecordedOperation::Difference, &operands, &[drilled], 0).unwrap();
+
+        // A volume inside leaves a cavity, and a volume apart leaves the base as it is.
+        let hollow = volume_difference(&base, &small);
+        assert_eq!(hollow.cavities.len(), 1);
+        assert!((measure_volume(&hollow) - 56.0).abs() < 1e-6);
+        let unchanged = volume_difference(&base, &moved(&small, 10.0, 0.0));
+        assert_eq!(unchanged.all_faces().len(), 6);
+        assert!((measure_volume(&unchanged) - 64.0).abs() < 1e-6);
+
+        // A pocket flush with the top removes the shared part of the top face.
+        let pocket = volume_difference(&base, &moved(&small, 0.0, 1.0));
+        assert_eq!(pocket.all_faces().len(), 11);
+        assert!((measure_volume(&pocket) - 56.0).abs() < 1e-6);
+
+        // A volume that only touches the top splits it along the contact, and both pieces are merged again.
+        let touched = volume_difference(&base, &moved(&small, 0.0, 3.0));
+        assert_eq!(
+            touched.all_faces().len(),
+            6,
+            "Expected the top face in one piece"
+        );
+        assert!((measure_volume(&touched) - 64.0).abs() < 1e-6);
+
+        // Results that are empty or fall apart are errors.
+        let progress = Progress::none();
+        let limits = BooleanLimits::unlimited();
+        assert!(matches!(
+            volume_difference_with_limits(&small, &base, &progress, &limits),
+            Err(BooleanError::NotSingleVolume(0))
+        ));
+        let slab = primitive_cube(6.0, 6.0, 1.0);
+        assert!(matches!(
+            volume_difference_with_limits(&base, &slab, &progress, &limits),
+            Err(BooleanError::NotSingleVolume(2))
+        ));
+    }
+}
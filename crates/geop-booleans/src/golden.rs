@@ -0,0 +1,290 @@
+use std::path::{Path, PathBuf};
+
+use geop_geometry::{
+    curves::{curve::Curve, CurveLike},
+    points::point::Point,
+    surfaces::surface::Surface,
+    EQ_THRESHOLD,
+};
+use geop_rasterize::volume::rasterize_volume_into_triangle_list;
+use geop_topology::topology::{
+    contour::Contour, edge::Edge, face::Face, scene::Color, shell::Shell, volume::Volume,
+};
+
+// Golden file tests for booleans. The result of an operation is written in a canonical text form, which does not depend on the
+// order of faces or on where contours start, and compared to a checked in file. Set GEOP_UPDATE_GOLDEN=1 to write the files
+// instead, e.g. after an intended change of the algorithms. The tessellated area and volume are checked in addition, since they
+// stay stable when the topology of a correct result changes, e.g. when faces are split differently.
+
+fn number(x: f64) -> String {
+    // Rounding hides numerical noise, and -0.0 is printed as 0.0.
+    let x = (x * 1e6).round() / 1e6;
+    format!("{:.6}", x + 0.0)
+}
+
+fn point(p: Point) -> String {
+    format!("({}, {}, {})", number(p.x), number(p.y), number(p.z))
+}
+
+// Directions of axes are only defined up to their sign, so the sign is chosen such that the first non zero coordinate is positive.
+fn axis(d: Point) -> Point {
+    let d = d.normalize();
+    let first = [d.x, d.y, d.z]
+        .into_iter()
+        .find(|c| c.abs() > EQ_THRESHOLD)
+        .unwrap_or(1.0);
+    match first > 0.0 {
+        true => d,
+        false => -d,
+    }
+}
+
+fn canonical_edge(edge: &Edge) -> String {
+    match (&edge.curve, edge.start, edge.end) {
+        (Curve::Line(_), Some(start), Some(end)) => {
+            format!("Line {} -> {}", point(start), point(end))
+        }
+        (Curve::Circle(circle), None, None) => format!(
+            "Circle at {} around {} with radius {}",
+            point(circle.basis),
+            point(circle.normal),
+            number(circle.radius.norm())
+        ),
+        (curve, Some(start), Some(end)) => format!(
+            "{} {} -> {} -> {}",
+            curve_name(curve),
+            point(start),
+            point(edge.get_midpoint()),
+            point(end)
+        ),
+        (curve, start, end) => format!(
+            "{} {:?} -> {:?} through {}",
+            curve_name(curve),
+            start.map(point),
+            end.map(point),
+            point(curve.interpolate(start, end, 0.5))
+        ),
+    }
+}
+
+fn curve_name(curve: &Curve) -> &'static str {
+    match curve {
+        Curve::Line(_) => "Line",
+        Curve::Circle(_) => "Circle",
+        Curve::Ellipse(_) => "Ellipse",
+        Curve::Helix(_) => "Helix",
+        Curve::Bezier(_) => "Bezier",
+        Curve::Composite(_) => "Composite",
+    }
+}
+
+fn canonical_surface(surface: &Surface) -> String {
+    match surface {
+        Surface::Plane(plane) => {
+            let normal = plane.u_slope.cross(plane.v_slope).normalize();
+            format!(
+                "Plane with normal {} at offset {}",
+                point(normal),
+                number(plane.basis.dot(normal))
+            )
+        }
+        Surface::Sphere(sphere) => format!(
+            "Sphere at {} with radius {}, outwards = {}",
+            point(sphere.basis),
+            number(sphere.radius),
+            sphere.normal_outwards
+        ),
+        Surface::Cylinder(cylinder) => {
+            let direction = axis(cylinder.extend_dir);
+            // The point of the axis closest to the origin.
+            let basis = cylinder.basis - direction * cylinder.basis.dot(direction);
+            format!(
+                "Cylinder through {} along {} with radius {}, outwards = {}",
+                point(basis),
+                point(direction),
+                number(cylinder.radius.norm()),
+                cylinder.normal_outwards
+            )
+        }
+    }
+}
+
+// The edges of the contour, rotated such that the smallest edge comes first.
+fn canonical_contour(contour: &Contour) -> String {
+    let edges: Vec<String> = contour.edges.iter().map(canonical_edge).collect();
+    let first = (0..edges.len())
+        .min_by(|a, b| edges[*a].cmp(&edges[*b]))
+        .expect("Contours have edges");
+    (0..edges.len())
+        .map(|i| format!("    {}\n", edges[(first + i) % edges.len()]))
+        .collect()
+}
+
+pub fn canonical_face(face: &Face) -> String {
+    let mut out = format!("  Face on {}\n", canonical_surface(&face.surface));
+    if let Some(boundary) = &face.boundary {
+        out += "   Boundary\n";
+        out += &canonical_contour(boundary);
+    }
+    let mut holes: Vec<String> = face.holes.iter().map(canonical_contour).collect();
+    holes.sort();
+    for hole in holes {
+        out += "   Hole\n";
+        out += &hole;
+    }
+    out
+}
+
+fn canonical_shell(shell: &Shell) -> String {
+    let mut faces: Vec<String> = shell.faces.iter().map(canonical_face).collect();
+    faces.sort();
+    faces.concat()
+}
+
+// Canonical text form of a volume. Equal volumes give the same text, regardless of the order of their faces, shells and edges.
+pub fn canonical_volume(volume: &Volume) -> String {
+    let mut out = String::from(" Boundary shell\n");
+    out += &canonical_shell(&volume.boundary);
+    let mut cavities: Vec<String> = volume.cavities.iter().map(canonical_shell).collect();
+    cavities.sort();
+    for cavity in cavities {
+        out += " Cavity shell\n";
+        out += &cavity;
+    }
+    out
+}
+
+pub fn canonical_volumes(volumes: &[Volume]) -> String {
+    let mut parts: Vec<String> = volumes.iter().map(canonical_volume).collect();
+    parts.sort();
+    parts.iter().map(|p| format!("Volume\n{}", p)).collect()
+}
+
+// The directory of the golden files of this crate.
+pub fn golden_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.txt", name))
+}
+
+// Compares the canonical form of the volumes with the golden file. The file is written instead if GEOP_UPDATE_GOLDEN is set.
+// A missing file is an error, so that a test without its checked in file does not pass silently. The error names the first line that differs.
+pub fn check_golden(path: &Path, volumes: &[Volume]) -> Result<(), String> {
+    let actual = canonical_volumes(volumes);
+    if std::env::var("GEOP_UPDATE_GOLDEN").is_ok() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        return std::fs::write(path, actual).map_err(|e| e.to_string());
+    }
+    if !path.exists() {
+        return Err(format!(
+            "{} does not exist, run the test with GEOP_UPDATE_GOLDEN=1 to write it",
+            path.display()
+        ));
+    }
+    let expected = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    if expected == actual {
+        return Ok(());
+    }
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (e, a) if e != a => {
+                return Err(format!(
+                    "{} differs in line {}:\n  expected: {}\n  actual:   {}",
+                    path.display(),
+                    line,
+                    e.unwrap_or("<end of file>"),
+                    a.unwrap_or("<end of file>")
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct MeshProperties {
+    pub area: f64,
+    pub volume: f64,
+}
+
+// Area and enclosed volume of the tessellation of the volumes.
+pub fn mesh_properties(volumes: &[Volume]) -> MeshProperties {
+    let mut area = 0.0;
+    let mut volume = 0.0;
+    for v in volumes {
+        for triangle in rasterize_volume_into_triangle_list(v, Color::white()).triangles {
+            let (a, b, c) = (triangle.a.point(), triangle.b.point(), triangle.c.point());
+            area += (b - a).cross(c - a).norm() / 2.0;
+            volume += a.dot(b.cross(c)) / 6.0;
+        }
+    }
+    MeshProperties { area, volume }
+}
+
+// Checks the tessellated area and volume against the expected values. The tolerance is relative, since the tessellation is
+// only an approximation of curved faces and is stored in single precision.
+pub fn check_mesh_properties(
+    volumes: &[Volume],
+    expected: &MeshProperties,
+    tolerance: f64,
+) -> Result<(), String> {
+    let actual = mesh_properties(volumes);
+    let close = |a: f64, e: f64| (a - e).abs() <= tolerance * e.abs().max(1.0);
+    match close(actual.area, expected.area) && close(actual.volume, expected.volume) {
+        true => Ok(()),
+        false => Err(format!(
+            "Mesh properties differ: expected {:?}, actual {:?}",
+            expected, actual
+        )),
+    }
+}
+
+// Runs both checks on the result of a boolean operation and panics with a description of the differences.
+pub fn assert_golden(name: &str, volumes: &[Volume], expected: &MeshProperties, tolerance: f64) {
+    let golden = check_golden(&golden_path(name), volumes);
+    let mesh = check_mesh_properties(volumes, expected, tolerance);
+    if let Err(e) = golden.and(mesh) {
+        panic!("Golden test {} failed: {}", name, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::transforms::Transform;
+    use geop_topology::primitive_objects::volumes::cube::primitive_cube;
+
+    use crate::union::volume::union_all;
+
+    use super::*;
+
+    #[test]
+    fn test_golden_union_of_cubes() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let shifted = cube.transform(Transform::from_translation(Point::new(1.0, 0.0, 0.0)));
+        let volumes = union_all(&[cube.clone(), shifted.clone()]);
+        let expected = MeshProperties {
+            area: 32.0,
+            volume: 12.0,
+        };
+        assert_golden("union_of_cubes", &volumes, &expected, 1e-5);
+
+        // The canonical form does not depend on the order of the operands.
+        assert_eq!(
+            canonical_volumes(&union_all(&[shifted, cube])),
+            canonical_volumes(&volumes)
+        );
+
+        // A golden file that was not written yet is an error.
+        if std::env::var("GEOP_UPDATE_GOLDEN").is_err() {
+            let missing = check_golden(&golden_path("does_not_exist"), &volumes);
+            assert!(missing.is_err(), "Expected a missing golden file to fail");
+        }
+    }
+}
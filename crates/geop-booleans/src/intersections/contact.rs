@@ -0,0 +1,112 @@
+use geop_geometry::{
+    surfaces::{surface::Surface, SurfaceLike},
+    transforms::{rotation::Rotation, Transform},
+};
+use geop_topology::topology::{face::Face, volume::Volume};
+
+use super::face_face::face_face_same_surface_intersection;
+
+// A face of each volume and the regions where they touch.
+#[derive(Debug, Clone)]
+pub struct ContactFaces {
+    pub face_a: Face,
+    pub face_b: Face,
+    // The overlap lies on the surface of face_a, with its normal pointing out of a and into b.
+    pub overlap: Vec<Face>,
+}
+
+// Moves face_b onto the surface of face_a, if both surfaces coincide within the tolerance with opposite normals.
+// Planes are aligned by a small rotation and translation. Other surfaces have to coincide exactly.
+fn snap_to_contact(face_a: &Face, face_b: &Face, tolerance: f64) -> Option<Face> {
+    match (&*face_a.surface, &*face_b.surface) {
+        (Surface::Plane(plane_a), Surface::Plane(plane_b)) => {
+            let normal_a = face_a.surface.normal(plane_a.basis);
+            let normal_b = face_b.surface.normal(plane_b.basis);
+            if normal_b.angle(-normal_a) > tolerance {
+                return None;
+            }
+            let offset = (plane_a.basis - plane_b.basis).dot(normal_a);
+            if offset.abs() > tolerance {
+                return None;
+            }
+            let rotation = Rotation::between(normal_b, -normal_a).to_transform();
+            let snap = Transform::from_translation(plane_b.basis + normal_a * offset)
+                * rotation
+                * Transform::from_translation(-plane_b.basis);
+            Some(face_b.transform(snap))
+        }
+        (surface_a, surface_b) if surface_a == &surface_b.neg() => Some(face_b.clone()),
+        _ => None,
+    }
+}
+
+// Finds the regions where the boundaries of both volumes touch, e.g. to set up contacts of an assembly for a simulation.
+// Only faces with opposite normals are in contact, faces that overlap with the same normal belong to overlapping volumes.
+pub fn contact_faces(volume_a: &Volume, volume_b: &Volume, tolerance: f64) -> Vec<ContactFaces> {
+    let faces_b = volume_b.all_faces();
+    let mut contacts = Vec::<ContactFaces>::new();
+    for face_a in volume_a.all_faces() {
+        for face_b in faces_b.iter() {
+            if !face_a
+                .bounds()
+                .bounds
+                .may_intersect(&face_b.bounds().bounds, tolerance)
+            {
+                continue;
+            }
+            let snapped = match snap_to_contact(&face_a, face_b, tolerance) {
+                Some(snapped) => snapped,
+                None => continue,
+            };
+            let overlap = face_face_same_surface_intersection(&face_a, &snapped.flip());
+            if !overlap.is_empty() {
+                contacts.push(ContactFaces {
+                    face_a: face_a.clone(),
+                    face_b: face_b.clone(),
+                    overlap,
+                });
+            }
+        }
+    }
+    contacts
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::points::point::Point;
+    use geop_topology::{
+        measure::mass::measure_area, primitive_objects::volumes::cube::primitive_cube,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_contact_faces() {
+        let a = primitive_cube(2.0, 2.0, 2.0);
+        let b = primitive_cube(1.0, 1.0, 1.0);
+        let placed = |z: f64| b.transform(Transform::from_translation(Point::new(0.5, 0.5, z)));
+
+        // The small cube stands on the top of the large one.
+        let contacts = contact_faces(&a, &placed(1.5), 1e-6);
+        assert_eq!(
+            contacts.len(),
+            1,
+            "Expected the top of a touching the bottom of b"
+        );
+        let overlap = &contacts[0].overlap;
+        assert_eq!(overlap.len(), 1);
+        assert!((measure_area(&overlap[0]) - 1.0).abs() < 1e-6);
+        let normal = overlap[0].normal(overlap[0].inner_point());
+        assert!(
+            (normal - Point::unit_z()).norm() < 1e-9,
+            "Expected the normal out of a"
+        );
+
+        // A gap within the tolerance is closed, a larger one is not.
+        assert_eq!(contact_faces(&a, &placed(1.5 + 1e-4), 1e-3).len(), 1);
+        assert!(contact_faces(&a, &placed(1.5 + 1e-2), 1e-3).is_empty());
+
+        // Overlapping volumes do not touch.
+        assert!(contact_faces(&a, &placed(1.0), 1e-6).is_empty());
+    }
+}
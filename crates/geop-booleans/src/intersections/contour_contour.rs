@@ -0,0 +1,218 @@
+use std::{cmp::Ordering, rc::Rc};
+
+use geop_geometry::{curves::CurveLike, points::point::Point, surfaces::surface::Surface};
+use geop_topology::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    topology::{contour::Contour, edge::Edge, face::Face},
+};
+
+use super::edge_edge::{edge_edge_intersection, EdgeEdgeIntersection};
+
+// Relative step along an edge to probe on which side of the other contour it runs.
+const PROBE_STEP: f64 = 1e-3;
+
+#[derive(Debug, Clone)]
+pub enum ContourEvent {
+    Point(Point, bool), // Isolated point where the contours meet, and whether b crosses from one side of a to the other
+    Overlap(Edge, bool), // Part of an edge of a that b runs along, oriented like a, and whether b runs in the same direction
+}
+
+#[derive(Debug, Clone)]
+pub struct ContourIntersectionEvent {
+    pub edge_index: usize, // Index of the edge of a on which the event starts
+    pub event: ContourEvent,
+}
+
+// Orders points on the same edge along its direction.
+fn compare_on_edge(edge: &Edge, p: Point, q: Point) -> Ordering {
+    if p == q {
+        Ordering::Equal
+    } else if edge.curve.between(p, edge.start, Some(q)) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+fn event_start(event: &ContourEvent) -> Point {
+    match event {
+        ContourEvent::Point(p, _) => *p,
+        ContourEvent::Overlap(edge, _) => edge.start.expect("Overlaps of contours are bounded"),
+    }
+}
+
+// Points on b shortly before and after p.
+fn probes(b: &Contour, p: Point) -> (Point, Point) {
+    let n = b.edges.len();
+    for (i, edge) in b.edges.iter().enumerate() {
+        if edge.end == Some(p) {
+            let next = &b.edges[(i + 1) % n];
+            return (
+                edge.curve
+                    .interpolate(edge.start, Some(p), 1.0 - PROBE_STEP),
+                next.curve.interpolate(Some(p), next.end, PROBE_STEP),
+            );
+        }
+        if edge.start != Some(p)
+            && edge.curve.on_curve(p)
+            && edge.curve.between(p, edge.start, edge.end)
+        {
+            return (
+                edge.curve
+                    .interpolate(edge.start, Some(p), 1.0 - PROBE_STEP),
+                edge.curve.interpolate(Some(p), edge.end, PROBE_STEP),
+            );
+        }
+    }
+    panic!("Point is not on the contour");
+}
+
+// The events where the contour b meets the contour a, both on the given surface, in the order in which they occur along a.
+// Guarantees:
+// - Events are sorted along a, starting at the start of its first edge. Full circles start at an arbitrary point.
+// - Every point appears once, even if it is a vertex of one or both contours.
+// - Overlaps are reported per pair of edges and are not merged across vertices. Points at the ends of overlaps are not
+//   reported as separate point events.
+// - A point counts as a crossing if b runs from the inside of a to the outside or vice versa. Points where b only touches a
+//   from one side, or where it continues along a, are not crossings.
+pub fn contour_contour_events(
+    a: &Contour,
+    b: &Contour,
+    surface: Rc<Surface>,
+) -> Vec<ContourIntersectionEvent> {
+    let mut events = Vec::<ContourIntersectionEvent>::new();
+    for (i, edge_a) in a.edges.iter().enumerate() {
+        for edge_b in b.edges.iter() {
+            match edge_edge_intersection(edge_a, edge_b) {
+                EdgeEdgeIntersection::Points(points) => {
+                    events.extend(points.into_iter().map(|p| ContourIntersectionEvent {
+                        edge_index: i,
+                        event: ContourEvent::Point(p, false),
+                    }))
+                }
+                EdgeEdgeIntersection::Edges(edges) => events.extend(edges.into_iter().map(|e| {
+                    let m = e.get_midpoint();
+                    let same_dir = edge_a.curve.tangent(m).dot(edge_b.curve.tangent(m)) > 0.0;
+                    ContourIntersectionEvent {
+                        edge_index: i,
+                        event: ContourEvent::Overlap(e, same_dir),
+                    }
+                })),
+                EdgeEdgeIntersection::None => {}
+            }
+        }
+    }
+    events.sort_by(|x, y| {
+        x.edge_index.cmp(&y.edge_index).then_with(|| {
+            compare_on_edge(
+                &a.edges[x.edge_index],
+                event_start(&x.event),
+                event_start(&y.event),
+            )
+        })
+    });
+
+    let overlap_ends: Vec<Point> = events
+        .iter()
+        .filter_map(|e| match &e.event {
+            ContourEvent::Overlap(edge, _) => Some([edge.start, edge.end]),
+            ContourEvent::Point(_, _) => None,
+        })
+        .flatten()
+        .flatten()
+        .collect();
+    let inside_a = Face::new(Some(a.clone()), vec![], surface);
+    let mut seen = Vec::<Point>::new();
+    let mut result = Vec::<ContourIntersectionEvent>::new();
+    for e in events {
+        match e.event {
+            ContourEvent::Point(p, _) => {
+                if seen.contains(&p) || overlap_ends.contains(&p) {
+                    continue;
+                }
+                seen.push(p);
+                let (before, after) = probes(b, p);
+                let crossing = matches!(
+                    (
+                        face_point_contains(&inside_a, before),
+                        face_point_contains(&inside_a, after)
+                    ),
+                    (FacePointContains::Inside, FacePointContains::Outside)
+                        | (FacePointContains::Outside, FacePointContains::Inside)
+                );
+                result.push(ContourIntersectionEvent {
+                    edge_index: e.edge_index,
+                    event: ContourEvent::Point(p, crossing),
+                });
+            }
+            ContourEvent::Overlap(_, _) => result.push(e),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::surfaces::plane::Plane;
+    use geop_topology::primitive_objects::edges::line::primitive_line;
+
+    use super::*;
+
+    fn polygon(corners: &[(f64, f64)]) -> Contour {
+        let n = corners.len();
+        Contour::new(
+            (0..n)
+                .map(|i| {
+                    let (x0, y0) = corners[i];
+                    let (x1, y1) = corners[(i + 1) % n];
+                    primitive_line(Point::new(x0, y0, 0.0), Point::new(x1, y1, 0.0))
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_contour_contour_events() {
+        let surface = Rc::new(Surface::Plane(Plane::new(
+            Point::zero(),
+            Point::unit_x(),
+            Point::unit_y(),
+        )));
+        let square = polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+
+        // A diamond crosses every edge of the square twice, and the events follow the square.
+        let diamond = polygon(&[(0.0, -1.2), (1.2, 0.0), (0.0, 1.2), (-1.2, 0.0)]);
+        let events = contour_contour_events(&square, &diamond, surface.clone());
+        let indices: Vec<usize> = events.iter().map(|e| e.edge_index).collect();
+        assert_eq!(indices, vec![0, 0, 1, 1, 2, 2, 3, 3]);
+        assert!(events
+            .iter()
+            .all(|e| matches!(e.event, ContourEvent::Point(_, true))));
+        assert!(matches!(
+            events[0].event,
+            ContourEvent::Point(p, _) if p == Point::new(-0.2, -1.0, 0.0)
+        ));
+        assert!(matches!(
+            events[1].event,
+            ContourEvent::Point(p, _) if p == Point::new(0.2, -1.0, 0.0)
+        ));
+
+        // A triangle that touches the square at one of its corners does not cross it.
+        let triangle = polygon(&[(0.0, -1.0), (-1.0, -2.0), (1.0, -2.0)]);
+        let events = contour_contour_events(&square, &triangle, surface.clone());
+        assert_eq!(events.len(), 1, "Expected the touching point once");
+        assert!(matches!(
+            events[0].event,
+            ContourEvent::Point(p, false) if p == Point::new(0.0, -1.0, 0.0)
+        ));
+
+        // A shifted square runs along the bottom and top edges in the same direction.
+        let shifted = polygon(&[(0.0, -1.0), (2.0, -1.0), (2.0, 1.0), (0.0, 1.0)]);
+        let events = contour_contour_events(&square, &shifted, surface);
+        assert_eq!(events.len(), 2, "Expected the overlaps only");
+        for (event, index) in events.iter().zip([0, 2]) {
+            assert_eq!(event.edge_index, index);
+            assert!(matches!(event.event, ContourEvent::Overlap(_, true)));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use geop_geometry::{
+    curve_surface_intersection::curve_surface::{
+        curve_surface_intersection, CurveSurfaceIntersection,
+    },
+    curves::CurveLike,
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+use geop_topology::topology::{contour::Contour, edge::Edge};
+
+// Relative step along an edge to probe on which side of the plane the contour runs.
+const PROBE_STEP: f64 = 1e-3;
+
+// How the contour passes the plane, seen along the contour.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlaneCrossing {
+    Positive, // From behind the plane to the side its normal points to
+    Negative, // From the side the normal points to to behind the plane
+    Touching, // Stays on one side, or lies in the plane entirely
+}
+
+#[derive(Debug, Clone)]
+pub enum ContourPlaneEvent {
+    Point(Point),
+    Overlap(Vec<Edge>), // Consecutive edges of the contour that lie in the plane
+}
+
+#[derive(Debug, Clone)]
+pub struct ContourPlaneCrossing {
+    pub edge_index: usize, // Index of the edge of the contour on which the event starts
+    pub event: ContourPlaneEvent,
+    pub crossing: PlaneCrossing,
+}
+
+// -1 behind the plane, 1 in front of it and 0 on it.
+fn side(plane: &Plane, p: Point) -> i32 {
+    let distance = (p - plane.basis).dot(plane.normal(plane.basis));
+    match distance {
+        d if d > EQ_THRESHOLD => 1,
+        d if d < -EQ_THRESHOLD => -1,
+        _ => 0,
+    }
+}
+
+fn crossing(before: i32, after: i32) -> PlaneCrossing {
+    match (before, after) {
+        (b, a) if b < 0 && a > 0 => PlaneCrossing::Positive,
+        (b, a) if b > 0 && a < 0 => PlaneCrossing::Negative,
+        _ => PlaneCrossing::Touching,
+    }
+}
+
+// Points where the edge crosses or touches the plane, without its end points, in the direction of the edge.
+fn edge_points(edge: &Edge, surface: &Surface) -> Vec<Point> {
+    let mut points: Vec<Point> = match curve_surface_intersection(&edge.curve, surface) {
+        CurveSurfaceIntersection::Points(points) => points
+            .into_iter()
+            .map(|p| p.point)
+            .filter(|p| {
+                Some(*p) != edge.start
+                    && Some(*p) != edge.end
+                    && edge.curve.between(*p, edge.start, edge.end)
+            })
+            .collect(),
+        CurveSurfaceIntersection::None | CurveSurfaceIntersection::Curve(_) => vec![],
+    };
+    // Closed edges keep the order of the curve parameter.
+    if edge.start.is_some() {
+        points.sort_by(|p, q| match edge.curve.between(*p, edge.start, Some(*q)) {
+            true => std::cmp::Ordering::Less,
+            false => std::cmp::Ordering::Greater,
+        });
+    }
+    points
+}
+
+// The points where the contour crosses or touches the plane, in the order in which they occur along the contour, starting at the
+// start of its first edge. Edges that lie in the plane are reported together with their neighbors in the plane as one overlap, and
+// whether the contour crosses the plane there depends on the edges before and after them. Vertices on the plane are reported once.
+// Positive and negative crossings alternate along a closed contour, so they pair up into the intervals of a slice, and the
+// number of crossings tells on which side of the plane a point on the contour is.
+pub fn contour_plane_intersection(contour: &Contour, plane: &Plane) -> Vec<ContourPlaneCrossing> {
+    let surface = Surface::Plane(plane.clone());
+    let edges = &contour.edges;
+    let n = edges.len();
+    let in_plane: Vec<bool> = edges
+        .iter()
+        .map(|e| curve_surface_intersection(&e.curve, &surface).is_curve())
+        .collect();
+    let first = match in_plane.iter().position(|p| !p) {
+        Some(first) => first,
+        None => {
+            return vec![ContourPlaneCrossing {
+                edge_index: 0,
+                event: ContourPlaneEvent::Overlap(edges.clone()),
+                crossing: PlaneCrossing::Touching,
+            }]
+        }
+    };
+    let before_end = |e: &Edge| e.curve.interpolate(e.start, e.end, 1.0 - PROBE_STEP);
+    let after_start = |e: &Edge| e.curve.interpolate(e.start, e.end, PROBE_STEP);
+
+    // The loop starts at an edge outside the plane, so that every overlap is found at its first edge.
+    let mut result = Vec::<ContourPlaneCrossing>::new();
+    let mut i = first;
+    while i < first + n {
+        let index = i % n;
+        let edge = &edges[index];
+        if in_plane[index] {
+            let mut run = vec![edge.clone()];
+            while in_plane[(i + 1) % n] {
+                i += 1;
+                run.push(edges[i % n].clone());
+            }
+            let before = side(plane, before_end(&edges[(index + n - 1) % n]));
+            let after = side(plane, after_start(&edges[(i + 1) % n]));
+            result.push(ContourPlaneCrossing {
+                edge_index: index,
+                event: ContourPlaneEvent::Overlap(run),
+                crossing: crossing(before, after),
+            });
+            i += 1;
+            continue;
+        }
+        let previous = (index + n - 1) % n;
+        if let Some(start) = edge.start {
+            if !in_plane[previous] && side(plane, start) == 0 {
+                result.push(ContourPlaneCrossing {
+                    edge_index: index,
+                    event: ContourPlaneEvent::Point(start),
+                    crossing: crossing(
+                        side(plane, before_end(&edges[previous])),
+                        side(plane, after_start(edge)),
+                    ),
+                });
+            }
+        }
+        for p in edge_points(edge, &surface) {
+            let before = edge
+                .curve
+                .interpolate(edge.start, Some(p), 1.0 - PROBE_STEP);
+            let after = edge.curve.interpolate(Some(p), edge.end, PROBE_STEP);
+            result.push(ContourPlaneCrossing {
+                edge_index: index,
+                event: ContourPlaneEvent::Point(p),
+                crossing: crossing(side(plane, before), side(plane, after)),
+            });
+        }
+        i += 1;
+    }
+    result.sort_by_key(|c| c.edge_index);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_topology::primitive_objects::edges::{circle::primitive_circle, line::primitive_line};
+
+    use super::*;
+
+    fn polygon(points: &[(f64, f64)]) -> Contour {
+        let points: Vec<Point> = points
+            .iter()
+            .map(|(x, y)| Point::new(*x, *y, 0.0))
+            .collect();
+        Contour::new(
+            (0..points.len())
+                .map(|i| primitive_line(points[i], points[(i + 1) % points.len()]))
+                .collect(),
+        )
+    }
+
+    fn summary(crossings: &[ContourPlaneCrossing]) -> Vec<(usize, bool, PlaneCrossing)> {
+        crossings
+            .iter()
+            .map(|c| {
+                let overlap = matches!(c.event, ContourPlaneEvent::Overlap(_));
+                (c.edge_index, overlap, c.crossing)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_contour_plane_intersection() {
+        use PlaneCrossing::*;
+
+        // The plane x = 0, with its normal along x.
+        let plane = Plane::new(Point::zero(), Point::unit_y(), Point::unit_z());
+
+        let square = polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+        let crossings = contour_plane_intersection(&square, &plane);
+        assert_eq!(
+            summary(&crossings),
+            vec![(0, false, Positive), (2, false, Negative)]
+        );
+        assert!(matches!(
+            crossings[0].event,
+            ContourPlaneEvent::Point(p) if p == Point::new(0.0, -1.0, 0.0)
+        ));
+
+        // Vertices on the plane are reported once, by the edge that starts there.
+        let diamond = polygon(&[(0.0, -1.0), (1.0, 0.0), (0.0, 1.0), (-1.0, 0.0)]);
+        assert_eq!(
+            summary(&contour_plane_intersection(&diamond, &plane)),
+            vec![(0, false, Positive), (2, false, Negative)]
+        );
+        let triangle = polygon(&[(0.0, 0.0), (1.0, -1.0), (1.0, 1.0)]);
+        assert_eq!(
+            summary(&contour_plane_intersection(&triangle, &plane)),
+            vec![(0, false, Touching)]
+        );
+
+        // An edge in the plane crosses it if the contour continues on the other side.
+        let touching = polygon(&[(0.0, -1.0), (1.0, -1.0), (1.0, 1.0), (0.0, 1.0)]);
+        assert_eq!(
+            summary(&contour_plane_intersection(&touching, &plane)),
+            vec![(3, true, Touching)]
+        );
+        let step = polygon(&[
+            (-1.0, -1.0),
+            (0.0, -1.0),
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (-1.0, 2.0),
+        ]);
+        let crossings = contour_plane_intersection(&step, &plane);
+        assert_eq!(
+            summary(&crossings),
+            vec![(1, true, Positive), (4, false, Negative)]
+        );
+        assert!(matches!(&crossings[0].event, ContourPlaneEvent::Overlap(run) if run.len() == 1));
+
+        // A circle crosses the plane twice, once in each direction.
+        let circle = Contour::new(vec![primitive_circle(Point::zero(), Point::unit_z(), 1.0)]);
+        let crossings = contour_plane_intersection(&circle, &plane);
+        assert_eq!(crossings.len(), 2);
+        assert!(crossings.iter().any(|c| c.crossing == Positive));
+        assert!(crossings.iter().any(|c| c.crossing == Negative));
+
+        // A contour away from the plane does not meet it.
+        let away = polygon(&[(1.0, -1.0), (2.0, -1.0), (2.0, 1.0)]);
+        assert!(contour_plane_intersection(&away, &plane).is_empty());
+    }
+}
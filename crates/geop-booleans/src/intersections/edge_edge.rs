@@ -1,7 +1,6 @@
 use geop_geometry::{
-    curve_curve_intersection::curve_curve::{curve_curve_intersection, CurveCurveIntersection},
-    curves::CurveLike,
-    points::point::Point,
+    curve_curve_intersection::curve_curve::CurveCurveIntersection, curves::CurveLike,
+    intersection_cache::IntersectionCache, points::point::Point,
 };
 
 use crate::remesh::edge::{edge_remesh, edge_split, EdgeRemesh};
@@ -20,7 +19,16 @@ pub enum EdgeEdgeIntersection {
 }
 
 pub fn edge_edge_intersection(edge_self: &Edge, edge_other: &Edge) -> EdgeEdgeIntersection {
-    match curve_curve_intersection(&edge_self.curve, &edge_other.curve) {
+    edge_edge_intersection_with_cache(edge_self, edge_other, &mut IntersectionCache::new())
+}
+
+// Same as edge_edge_intersection, but looks up the intersection of the curves in the cache.
+pub fn edge_edge_intersection_with_cache(
+    edge_self: &Edge,
+    edge_other: &Edge,
+    cache: &mut IntersectionCache,
+) -> EdgeEdgeIntersection {
+    match cache.curve_curve(&edge_self.curve, &edge_other.curve) {
         CurveCurveIntersection::Curve(_) => {
             let same_dir = edge_self
                 .curve
@@ -45,9 +53,10 @@ pub fn edge_edge_intersection(edge_self: &Edge, edge_other: &Edge) -> EdgeEdgeIn
 
             EdgeEdgeIntersection::Edges(edge_remesh(&edge_self.curve, intervals))
         }
-        CurveCurveIntersection::FinitePoints(mut points) => {
+        CurveCurveIntersection::FinitePoints(points) => {
             let intersections = points
-                .drain(..)
+                .into_iter()
+                .map(|p| p.point)
                 .filter(|p| {
                     edge_point_contains(edge_self, *p) != EdgePointContains::Outside
                         && edge_point_contains(edge_other, *p) != EdgePointContains::Outside
@@ -1,15 +1,76 @@
 use geop_geometry::{
-    curve_surface_intersection::curve_surface::{
-        curve_surface_intersection, CurveSurfaceIntersection,
-    },
-    points::point::Point,
+    curve_surface_intersection::curve_surface::CurveSurfaceIntersection,
+    intersection_cache::IntersectionCache, points::point::Point,
 };
 
 use geop_topology::{
-    contains::face_point::{face_point_contains, FacePointContains},
+    contains::{
+        edge_point::{edge_point_contains, EdgePointContains},
+        face_edge::{face_edge_contains, FaceEdgeContains},
+        face_point::{face_point_contains, FacePointContains},
+    },
     topology::{edge::Edge, face::Face},
 };
 
+use crate::split_if_necessary::point_split_edge::split_edge_by_points_if_necessary;
+
+use super::edge_edge::{edge_edge_intersection_with_cache, EdgeEdgeIntersection};
+
+// Splits an edge on the surface of the face where it crosses or touches the boundary of the face, so that every piece is either
+// inside, outside or on the boundary.
+pub fn split_edge_at_face_boundary(face: &Face, edge: &Edge) -> Vec<Edge> {
+    split_edge_at_face_boundary_with_cache(face, edge, &mut IntersectionCache::new())
+}
+
+pub fn split_edge_at_face_boundary_with_cache(
+    face: &Face,
+    edge: &Edge,
+    cache: &mut IntersectionCache,
+) -> Vec<Edge> {
+    let mut points = Vec::<Point>::new();
+    for boundary_edge in face.all_edges() {
+        match edge_edge_intersection_with_cache(edge, &boundary_edge, cache) {
+            EdgeEdgeIntersection::Points(ps) => points.extend(ps),
+            EdgeEdgeIntersection::Edges(es) => {
+                points.extend(es.iter().flat_map(|e| [e.start, e.end]).flatten())
+            }
+            EdgeEdgeIntersection::None => {}
+        }
+        // Vertices are not part of the edges, so crossings exactly at a vertex have to be found separately.
+        for p in [boundary_edge.start, boundary_edge.end]
+            .into_iter()
+            .flatten()
+        {
+            if edge_point_contains(edge, p) == EdgePointContains::Inside {
+                points.push(p);
+            }
+        }
+    }
+    split_edge_by_points_if_necessary(edge, &points)
+}
+
+// Returns the parts of an edge on the surface of the face that are inside of the face or on its boundary.
+pub fn face_edge_clip(face: &Face, edge: &Edge) -> Vec<Edge> {
+    face_edge_clip_with_cache(face, edge, &mut IntersectionCache::new())
+}
+
+pub fn face_edge_clip_with_cache(
+    face: &Face,
+    edge: &Edge,
+    cache: &mut IntersectionCache,
+) -> Vec<Edge> {
+    split_edge_at_face_boundary_with_cache(face, edge, cache)
+        .into_iter()
+        .filter(|e| match face_edge_contains(face, e) {
+            FaceEdgeContains::Inside => true,
+            FaceEdgeContains::OnBorderSameDir => true,
+            FaceEdgeContains::OnBorderOppositeDir => true,
+            FaceEdgeContains::Outside => false,
+            FaceEdgeContains::NotSameSurface => false,
+        })
+        .collect()
+}
+
 pub enum FaceEdgeIntersection {
     None,
     Points(Vec<Point>),
@@ -17,16 +78,27 @@ pub enum FaceEdgeIntersection {
 }
 
 pub fn face_edge_intersection(face: &Face, edge: &Edge) -> FaceEdgeIntersection {
-    match curve_surface_intersection(&edge.curve, &face.surface) {
-        CurveSurfaceIntersection::Points(mut points) => FaceEdgeIntersection::Points(
+    face_edge_intersection_with_cache(face, edge, &mut IntersectionCache::new())
+}
+
+// Same as face_edge_intersection, but looks up the intersections of the curves and surfaces in the cache.
+pub fn face_edge_intersection_with_cache(
+    face: &Face,
+    edge: &Edge,
+    cache: &mut IntersectionCache,
+) -> FaceEdgeIntersection {
+    match cache.curve_surface(&edge.curve, &face.surface) {
+        CurveSurfaceIntersection::Points(points) => FaceEdgeIntersection::Points(
             points
-                .drain(..)
+                .into_iter()
+                .map(|p| p.point)
                 .filter(|p| face_point_contains(face, *p) == FacePointContains::Inside)
                 .collect(),
         ),
-        CurveSurfaceIntersection::Curve(_e) => {
-            todo!("Split edges by intersections")
-        }
+        CurveSurfaceIntersection::Curve(_) => match face_edge_clip_with_cache(face, edge, cache) {
+            edges if edges.is_empty() => FaceEdgeIntersection::None,
+            edges => FaceEdgeIntersection::Edges(edges),
+        },
         CurveSurfaceIntersection::None => FaceEdgeIntersection::None,
     }
 }
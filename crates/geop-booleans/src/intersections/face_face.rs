@@ -1,5 +1,6 @@
 use geop_geometry::{
     curves::curve::Curve,
+    intersection_cache::IntersectionCache,
     points::point::Point,
     surface_surface_intersection::surface_surface::{
         surface_surface_intersection, FaceSurfaceIntersection,
@@ -13,7 +14,9 @@ use geop_topology::{
 
 use crate::remesh::face::{face_remesh, face_split, normalize_faces, FaceSplit};
 
-use super::face_edge::{face_edge_intersection, FaceEdgeIntersection};
+use super::face_edge::{
+    face_edge_clip_with_cache, face_edge_intersection_with_cache, FaceEdgeIntersection,
+};
 
 pub fn face_face_same_surface_intersection(face_self: &Face, face_other: &Face) -> Vec<Face> {
     assert!(
@@ -35,7 +38,7 @@ pub fn face_face_same_surface_intersection(face_self: &Face, face_other: &Face)
         })
         .collect::<Vec<FaceSplit>>();
 
-    let contours = face_remesh(edges);
+    let contours = face_remesh(edges, &face_self.surface);
     return normalize_faces(contours, face_self.surface.clone());
 }
 
@@ -45,11 +48,24 @@ pub enum FaceFaceIntersection {
     Faces(Vec<Face>),
 }
 
-fn curve_face_intersection_same_surface(_curve: Curve, _face: Face) -> Vec<Edge> {
-    todo!()
+fn curve_face_intersection_same_surface(
+    curve: Curve,
+    face: Face,
+    cache: &mut IntersectionCache,
+) -> Vec<Edge> {
+    face_edge_clip_with_cache(&face, &Edge::new(None, None, curve), cache)
 }
 
 pub fn face_face_intersection(face_self: &Face, face_other: &Face) -> FaceFaceIntersection {
+    face_face_intersection_with_cache(face_self, face_other, &mut IntersectionCache::new())
+}
+
+// Same as face_face_intersection, but shares the intersections of the curves and surfaces with other pairs of faces through the cache.
+pub fn face_face_intersection_with_cache(
+    face_self: &Face,
+    face_other: &Face,
+    cache: &mut IntersectionCache,
+) -> FaceFaceIntersection {
     match surface_surface_intersection(&face_self.surface, &face_other.surface) {
         FaceSurfaceIntersection::None => FaceFaceIntersection::None,
         FaceSurfaceIntersection::CurvesAndPoints(curves, points) => {
@@ -64,9 +80,13 @@ pub fn face_face_intersection(face_self: &Face, face_other: &Face) -> FaceFaceIn
 
             let curves = curves
                 .iter()
-                .map(|curve| curve_face_intersection_same_surface(curve.clone(), face_self.clone()))
+                .map(|curve| {
+                    curve_face_intersection_same_surface(curve.clone(), face_self.clone(), cache)
+                })
+                .collect::<Vec<Vec<Edge>>>()
+                .into_iter()
                 .flatten()
-                .map(|edge| face_edge_intersection(face_other, &edge))
+                .map(|edge| face_edge_intersection_with_cache(face_other, &edge, cache))
                 .collect::<Vec<FaceEdgeIntersection>>();
 
             let mut edges = Vec::<Edge>::new();
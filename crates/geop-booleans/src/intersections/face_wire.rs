@@ -0,0 +1,118 @@
+use geop_topology::{
+    contains::face_edge::{face_edge_contains, FaceEdgeContains},
+    topology::{edge::Edge, face::Face, wire::Wire},
+};
+
+use super::face_edge::split_edge_at_face_boundary;
+
+// Which parts of a wire are kept when it is clipped to a face. Parts on the boundary of the face count as inside.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WireClip {
+    Inside,
+    Outside,
+}
+
+// Returns the parts of a wire on the surface of the face that are inside of the face or outside of it, e.g. to trim construction
+// curves to a face or to keep a toolpath within a pocket. Consecutive parts stay connected in one wire, so the result has one wire
+// per stretch of the wire inside or outside. If a closed wire starts in the middle of such a stretch, the last and the first part
+// are joined.
+pub fn clip_wire_to_face(wire: &Wire, face: &Face, keep: WireClip) -> Vec<Wire> {
+    let mut chains = Vec::<Vec<Edge>>::new();
+    let mut current = Vec::<Edge>::new();
+    for edge in wire.edges.iter() {
+        for piece in split_edge_at_face_boundary(face, edge) {
+            let inside = match face_edge_contains(face, &piece) {
+                FaceEdgeContains::Inside
+                | FaceEdgeContains::OnBorderSameDir
+                | FaceEdgeContains::OnBorderOppositeDir => true,
+                FaceEdgeContains::Outside => false,
+                FaceEdgeContains::NotSameSurface => {
+                    panic!("Wire is not on the surface of the face")
+                }
+            };
+            match inside == (keep == WireClip::Inside) {
+                true => current.push(piece),
+                false => {
+                    if !current.is_empty() {
+                        chains.push(std::mem::take(&mut current));
+                    }
+                }
+            }
+        }
+    }
+    if !current.is_empty() {
+        match chains.first_mut() {
+            Some(first) if wire.is_closed() && first[0].start == wire.edges[0].start => {
+                current.append(first);
+                *first = current;
+            }
+            _ => chains.push(current),
+        }
+    }
+    chains.into_iter().map(Wire::new).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, surfaces::plane::Plane};
+    use geop_topology::primitive_objects::edges::line::primitive_line;
+
+    use super::*;
+
+    fn polyline(points: &[(f64, f64)]) -> Wire {
+        let points: Vec<Point> = points
+            .iter()
+            .map(|(x, y)| Point::new(*x, *y, 0.0))
+            .collect();
+        Wire::new(
+            points
+                .windows(2)
+                .map(|w| primitive_line(w[0], w[1]))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_clip_wire_to_face() {
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let face = Face::rectangle(&plane, 2.0, 2.0);
+
+        // A line through the face is trimmed to the face, and the rest is left on both sides.
+        let line = polyline(&[(-2.0, 0.0), (2.0, 0.0)]);
+        let inside = clip_wire_to_face(&line, &face, WireClip::Inside);
+        assert_eq!(inside.len(), 1);
+        assert_eq!(inside[0].start(), Point::new(-1.0, 0.0, 0.0));
+        assert_eq!(inside[0].end(), Point::new(1.0, 0.0, 0.0));
+        assert_eq!(clip_wire_to_face(&line, &face, WireClip::Outside).len(), 2);
+
+        // Consecutive parts inside stay connected around a corner of the wire.
+        let bent = polyline(&[(-2.0, 0.5), (0.0, 0.5), (0.0, -2.0)]);
+        let inside = clip_wire_to_face(&bent, &face, WireClip::Inside);
+        assert_eq!(inside.len(), 1);
+        assert_eq!(inside[0].edges.len(), 2);
+        assert_eq!(inside[0].start(), Point::new(-1.0, 0.5, 0.0));
+        assert_eq!(inside[0].end(), Point::new(0.0, -1.0, 0.0));
+
+        // A closed wire that starts outside is joined across its start.
+        let closed = polyline(&[
+            (-2.0, -0.5),
+            (2.0, -0.5),
+            (2.0, 0.5),
+            (-2.0, 0.5),
+            (-2.0, -0.5),
+        ]);
+        assert!(closed.is_closed());
+        let outside = clip_wire_to_face(&closed, &face, WireClip::Outside);
+        assert_eq!(outside.len(), 2);
+        for wire in outside.iter() {
+            assert_eq!(wire.edges.len(), 3, "Expected the parts around both ends");
+            assert!((wire.start().x.abs() - 1.0).abs() < 1e-9);
+        }
+        assert_eq!(clip_wire_to_face(&closed, &face, WireClip::Inside).len(), 2);
+
+        // A wire along the boundary counts as inside.
+        let edge = polyline(&[(-1.0, -1.0), (1.0, -1.0)]);
+        assert_eq!(clip_wire_to_face(&edge, &face, WireClip::Inside).len(), 1);
+        assert!(clip_wire_to_face(&edge, &face, WireClip::Outside).is_empty());
+    }
+}
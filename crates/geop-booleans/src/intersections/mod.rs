@@ -1,3 +1,7 @@
+pub mod contact;
+pub mod contour_contour;
+pub mod contour_plane;
 pub mod edge_edge;
 pub mod face_edge;
 pub mod face_face;
+pub mod face_wire;
@@ -0,0 +1,258 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    points::point::Point,
+    transforms::{rotation::Rotation, Transform},
+};
+use geop_topology::{
+    contains::{
+        face_point::{face_point_contains, FacePointContains},
+        volume_point::{volume_point_contains, VolumePointContains},
+    },
+    rng::Rng,
+    topology::{contour::Contour, face::Face, volume::Volume},
+};
+
+use crate::intersections::face_face::{face_face_intersection, FaceFaceIntersection};
+
+// Checks that operations do not depend on the coordinate system: moving the inputs by a rigid transform has to move the result by
+// the same transform, i.e. op(T(a), T(b)) == T(op(a, b)). Comparisons that prefer an axis, like searching the "lowest" vertex,
+// break this and show up as failures for some of the random transforms.
+
+// A random rotation followed by a random translation of up to 10 units along each axis.
+pub fn rigid_transform(rng: &mut Rng) -> Transform {
+    let rotation = Rotation::from_axis_angle(rng.unit_vector(), rng.range(0.0, 2.0 * PI));
+    let translation = Point::new(
+        rng.range(-10.0, 10.0),
+        rng.range(-10.0, 10.0),
+        rng.range(-10.0, 10.0),
+    );
+    Transform::from_translation(translation) * rotation.to_transform()
+}
+
+// Runs the operation on inputs moved by random transforms and compares with the moved result of the untransformed inputs.
+// `op` receives the transform to apply to its inputs. The error names the trial and the transform, so that it can be reproduced.
+pub fn check_transform_invariance<R, Op, Move, Equal>(
+    trials: usize,
+    seed: u64,
+    op: Op,
+    move_result: Move,
+    equal: Equal,
+) -> Result<(), String>
+where
+    Op: Fn(Transform) -> R,
+    Move: Fn(&R, Transform) -> R,
+    Equal: Fn(&R, &R) -> bool,
+{
+    let reference = op(Transform::from_translation(Point::zero()));
+    let mut rng = Rng::new(seed);
+    for trial in 0..trials {
+        let transform = rigid_transform(&mut rng);
+        let actual = op(transform);
+        let expected = move_result(&reference, transform);
+        if !equal(&actual, &expected) {
+            return Err(format!(
+                "Result is not invariant in trial {} with seed {} under {:?}",
+                trial, seed, transform
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Matches the elements of both lists one to one, in any order.
+fn match_all<T, F: Fn(&T, &T) -> bool>(a: &[T], b: &[T], equal: F) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut used = vec![false; b.len()];
+    a.iter().all(
+        |x| match (0..b.len()).find(|i| !used[*i] && equal(x, &b[*i])) {
+            Some(i) => {
+                used[i] = true;
+                true
+            }
+            None => false,
+        },
+    )
+}
+
+// Contours are equal if they have the same edges in the same cyclic order, regardless of the edge they start with.
+pub fn contours_equal(a: &Contour, b: &Contour) -> bool {
+    let n = a.edges.len();
+    n == b.edges.len() && (0..n).any(|k| (0..n).all(|i| a.edges[i] == b.edges[(i + k) % n]))
+}
+
+pub fn faces_equal(a: &Face, b: &Face) -> bool {
+    let boundaries_equal = match (&a.boundary, &b.boundary) {
+        (Some(a), Some(b)) => contours_equal(a, b),
+        (None, None) => true,
+        _ => false,
+    };
+    a.surface == b.surface && boundaries_equal && match_all(&a.holes, &b.holes, contours_equal)
+}
+
+pub fn volumes_equal(a: &Volume, b: &Volume) -> bool {
+    match_all(&a.all_faces(), &b.all_faces(), faces_equal)
+}
+
+pub fn check_boolean_invariance<F: Fn(&Volume, &Volume) -> Vec<Volume>>(
+    a: &Volume,
+    b: &Volume,
+    op: F,
+    trials: usize,
+    seed: u64,
+) -> Result<(), String> {
+    check_transform_invariance(
+        trials,
+        seed,
+        |t| op(&a.transform(t), &b.transform(t)),
+        |volumes, t| volumes.iter().map(|v| v.transform(t)).collect(),
+        |x, y| match_all(x, y, volumes_equal),
+    )
+}
+
+pub fn check_face_face_intersection_invariance(
+    a: &Face,
+    b: &Face,
+    trials: usize,
+    seed: u64,
+) -> Result<(), String> {
+    check_transform_invariance(
+        trials,
+        seed,
+        |t| face_face_intersection(&a.transform(t), &b.transform(t)),
+        |result, t| match result {
+            FaceFaceIntersection::None => FaceFaceIntersection::None,
+            FaceFaceIntersection::EdgesAndPoints(points, edges) => {
+                FaceFaceIntersection::EdgesAndPoints(
+                    points.iter().map(|p| t * *p).collect(),
+                    edges.iter().map(|e| e.transform(t)).collect(),
+                )
+            }
+            FaceFaceIntersection::Faces(faces) => {
+                FaceFaceIntersection::Faces(faces.iter().map(|f| f.transform(t)).collect())
+            }
+        },
+        |x, y| match (x, y) {
+            (FaceFaceIntersection::None, FaceFaceIntersection::None) => true,
+            (
+                FaceFaceIntersection::EdgesAndPoints(pa, ea),
+                FaceFaceIntersection::EdgesAndPoints(pb, eb),
+            ) => match_all(pa, pb, |p, q| p == q) && match_all(ea, eb, |e, f| e == f),
+            (FaceFaceIntersection::Faces(fa), FaceFaceIntersection::Faces(fb)) => {
+                match_all(fa, fb, faces_equal)
+            }
+            _ => false,
+        },
+    )
+}
+
+pub fn check_face_point_invariance(
+    face: &Face,
+    points: &[Point],
+    trials: usize,
+    seed: u64,
+) -> Result<(), String> {
+    check_transform_invariance(
+        trials,
+        seed,
+        |t| {
+            let face = face.transform(t);
+            points
+                .iter()
+                .map(|p| face_point_contains(&face, t * *p))
+                .collect::<Vec<_>>()
+        },
+        |results, t| {
+            results
+                .iter()
+                .map(|r| match r {
+                    FacePointContains::OnEdge(edge) => FacePointContains::OnEdge(edge.transform(t)),
+                    FacePointContains::OnPoint(p) => FacePointContains::OnPoint(t * *p),
+                    r => r.clone(),
+                })
+                .collect::<Vec<_>>()
+        },
+        |x, y| x == y,
+    )
+}
+
+// Only the kind of the containment is compared, the faces and edges that a point is on are checked by the face containment.
+pub fn check_volume_point_invariance(
+    volume: &Volume,
+    points: &[Point],
+    trials: usize,
+    seed: u64,
+) -> Result<(), String> {
+    check_transform_invariance(
+        trials,
+        seed,
+        |t| {
+            let volume = volume.transform(t);
+            points
+                .iter()
+                .map(|p| std::mem::discriminant(&volume_point_contains(&volume, t * *p)))
+                .collect::<Vec<std::mem::Discriminant<VolumePointContains>>>()
+        },
+        |results, _| results.clone(),
+        |x, y| x == y,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::surfaces::plane::Plane;
+    use geop_topology::primitive_objects::volumes::cube::primitive_cube;
+
+    use crate::{difference::volume::cut, union::volume::union_all, xor::volume::volume_xor};
+
+    use super::*;
+
+    #[test]
+    fn test_invariance() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let shifted = cube.transform(Transform::from_translation(Point::new(1.0, 0.5, 0.25)));
+        let bar = primitive_cube(1.0, 1.0, 4.0);
+
+        check_boolean_invariance(
+            &cube,
+            &shifted,
+            |a, b| union_all(&[a.clone(), b.clone()]),
+            3,
+            1,
+        )
+        .unwrap();
+        check_boolean_invariance(&cube, &bar, |a, b| cut(a, std::slice::from_ref(b)), 3, 2)
+            .unwrap();
+        check_boolean_invariance(&cube, &shifted, volume_xor, 3, 3).unwrap();
+
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let square = Face::rectangle(&plane, 2.0, 2.0);
+        let disk = Face::disk(&plane, Point::new(1.0, 0.0, 0.0), 0.5);
+        check_face_face_intersection_invariance(&square, &disk, 3, 4).unwrap();
+        let upright = Face::rectangle(
+            &Plane::new(Point::zero(), Point::unit_x(), Point::unit_z()),
+            1.0,
+            1.0,
+        );
+        check_face_face_intersection_invariance(&square, &upright, 3, 5).unwrap();
+
+        let points = [
+            Point::new(0.5, 0.5, 0.0),
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+        ];
+        check_face_point_invariance(&square, &points, 3, 6).unwrap();
+
+        let points = [
+            Point::new(0.5, 0.5, 0.5),
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(1.0, 1.0, 1.0),
+        ];
+        check_volume_point_invariance(&cube, &points, 3, 7).unwrap();
+    }
+}
@@ -1,8 +1,17 @@
+pub mod attributes;
+pub mod budget;
+pub mod diff;
 pub mod difference;
+pub mod golden;
 pub mod intersections;
+pub mod invariance;
+pub mod recorder;
 pub mod remesh;
+pub mod slice;
 pub mod split_if_necessary;
 pub mod union;
+pub mod verify;
+pub mod xor;
 
 // use topology::scene::Scene;
 
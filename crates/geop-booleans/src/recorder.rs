@@ -0,0 +1,377 @@
+use std::{
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use geop_geometry::EQ_THRESHOLD;
+use geop_topology::{
+    progress::Progress,
+    topology::{
+        binary::{
+            check_header, header, volume_from_bytes, volume_to_bytes, DecodeError, HEADER_LEN,
+        },
+        volume::Volume,
+    },
+};
+
+use crate::{
+    budget::{BooleanError, BooleanLimits},
+    difference::volume::{cut_with_limits, volume_difference_with_limits},
+    union::volume::union_all_with_limits,
+    verify::{verify_boolean, BooleanResult},
+    xor::volume::volume_xor_parts_with_limits,
+};
+
+// Opt-in capture of failing booleans. While recording is on, every boolean that panics or fails with an error other than
+// cancellation writes its exact inputs into a file in the recording directory. The file can be replayed with `geop-cli replay`,
+// so that a bug report only needs that one file.
+// Only the booleans of this crate are recorded: union, cut, xor and difference. The operations of geop-topology, like extrude,
+// shell and draft, are not, since their inputs are more than volumes and they do not run through recorded().
+
+pub const KIND_RECORDING: u8 = 24;
+pub const RECORDING_EXTENSION: &str = "geoprec";
+
+static RECORDING_DIRECTORY: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+pub fn start_recording(directory: &Path) {
+    *RECORDING_DIRECTORY.lock().unwrap() = Some(directory.to_path_buf());
+}
+
+pub fn stop_recording() {
+    *RECORDING_DIRECTORY.lock().unwrap() = None;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordedOperation {
+    Union,
+    Cut, // The first operand is the base, the others are the tools
+    Xor,
+    Difference, // Like a cut with a single tool, whose result has to be a single volume
+}
+
+impl RecordedOperation {
+    fn name(&self) -> &'static str {
+        match self {
+            RecordedOperation::Union => "union",
+            RecordedOperation::Cut => "cut",
+            RecordedOperation::Xor => "xor",
+            RecordedOperation::Difference => "difference",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Recording {
+    pub operation: RecordedOperation,
+    pub operands: Vec<Volume>,
+    pub limits: BooleanLimits,
+    // EQ_THRESHOLD of the build that recorded the failure, since a different tolerance can hide or cause it.
+    pub tolerance: f64,
+    // Booleans are deterministic and record 0. Kept so that the format does not change if randomised checks are recorded.
+    pub seed: u64,
+    // The panic message or the error.
+    pub message: String,
+}
+
+// What happened when a recording was replayed.
+pub enum ReplayOutcome {
+    Succeeded(Vec<Volume>),
+    Failed(BooleanError),
+    Panicked(String),
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "Unknown panic".to_string(),
+    }
+}
+
+// Writes the recording into the directory and returns its path.
+fn save_in(directory: &Path, recording: &Recording) -> Option<PathBuf> {
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let path = directory.join(format!(
+        "{}-{}.{}",
+        recording.operation.name(),
+        time,
+        RECORDING_EXTENSION
+    ));
+    // Failing to write the recording must not hide the original failure.
+    match std::fs::write(&path, recording_to_bytes(recording)) {
+        Ok(()) => Some(path),
+        Err(error) => {
+            eprintln!(
+                "Could not record failing {}: {}",
+                recording.operation.name(),
+                error
+            );
+            None
+        }
+    }
+}
+
+fn save(recording: &Recording) {
+    if let Some(directory) = RECORDING_DIRECTORY.lock().unwrap().clone() {
+        save_in(&directory, recording);
+    }
+}
+
+// Runs a boolean and records its inputs if it fails while recording is on. With the `verify` feature, successful results are also
+// checked by verify_boolean, and results that fail the check are recorded, in the temporary directory if recording is off, and
+// panic with the path of the recording.
+pub(crate) fn recorded<T: BooleanResult>(
+    operation: RecordedOperation,
+    operands: &[Volume],
+    limits: &BooleanLimits,
+    run: impl FnOnce() -> Result<T, BooleanError>,
+) -> Result<T, BooleanError> {
+    let verify = cfg!(feature = "verify");
+    if RECORDING_DIRECTORY.lock().unwrap().is_none() && !verify {
+        return run();
+    }
+    let recording = |message: String| Recording {
+        operation,
+        operands: operands.to_vec(),
+        limits: limits.clone(),
+        tolerance: EQ_THRESHOLD,
+        seed: 0,
+        message,
+    };
+    match catch_unwind(AssertUnwindSafe(run)) {
+        Ok(Err(BooleanError::Cancelled)) => Err(BooleanError::Cancelled),
+        Ok(Err(error)) => {
+            save(&recording(error.to_string()));
+            Err(error)
+        }
+        Ok(Ok(result)) if verify => {
+            if let Err(failure) = verify_boolean(operation, operands, &result.result_volumes(), 0) {
+                let directory = RECORDING_DIRECTORY
+                    .lock()
+                    .unwrap()
+                    .clone()
+                    .unwrap_or_else(std::env::temp_dir);
+                let message = format!(
+                    "Result of {} fails verification: {}",
+                    operation.name(),
+                    failure
+                );
+                let path = save_in(&directory, &recording(message.clone()));
+                panic!("{}, recorded in {:?}", message, path);
+            }
+            Ok(result)
+        }
+        Ok(result) => result,
+        Err(payload) => {
+            save(&recording(panic_message(&*payload)));
+            resume_unwind(payload)
+        }
+    }
+}
+
+// Runs the recorded operation again on the recorded inputs.
+pub fn replay(recording: &Recording) -> ReplayOutcome {
+    let progress = Progress::none();
+    let operands = &recording.operands;
+    let limits = &recording.limits;
+    let result = catch_unwind(AssertUnwindSafe(|| match recording.operation {
+        RecordedOperation::Union => union_all_with_limits(operands, &progress, limits),
+        RecordedOperation::Cut => cut_with_limits(&operands[0], &operands[1..], &progress, limits),
+        RecordedOperation::Xor => {
+            volume_xor_parts_with_limits(&operands[0], &operands[1], &progress, limits).map(
+                |(mut self_minus_other, other_minus_self)| {
+                    self_minus_other.extend(other_minus_self);
+                    self_minus_other
+                },
+            )
+        }
+        RecordedOperation::Difference => {
+            volume_difference_with_limits(&operands[0], &operands[1], &progress, limits)
+                .map(|volume| vec![volume])
+        }
+    }));
+    match result {
+        Ok(Ok(volumes)) => ReplayOutcome::Succeeded(volumes),
+        Ok(Err(error)) => ReplayOutcome::Failed(error),
+        Err(payload) => ReplayOutcome::Panicked(panic_message(&*payload)),
+    }
+}
+
+// After the header: the operation, the tolerance, the seed, the limits, the message and the operands. Limits are a presence byte
+// followed by the value, with the time budget in nanoseconds. The message and every operand are prefixed with their length in bytes,
+// and the operands are stored in the volume format of geop_topology::topology::binary.
+pub fn recording_to_bytes(recording: &Recording) -> Vec<u8> {
+    let mut bytes = header(KIND_RECORDING);
+    bytes.push(recording.operation as u8);
+    bytes.extend(recording.tolerance.to_le_bytes());
+    bytes.extend(recording.seed.to_le_bytes());
+    let limits = [
+        recording.limits.max_face_pairs.map(|v| v as u64),
+        recording.limits.max_intersection_events.map(|v| v as u64),
+        recording.limits.time_budget.map(|v| v.as_nanos() as u64),
+    ];
+    for limit in limits {
+        bytes.push(limit.is_some() as u8);
+        bytes.extend(limit.unwrap_or(0).to_le_bytes());
+    }
+    bytes.extend((recording.message.len() as u32).to_le_bytes());
+    bytes.extend(recording.message.as_bytes());
+    bytes.extend((recording.operands.len() as u32).to_le_bytes());
+    for operand in recording.operands.iter() {
+        let operand = volume_to_bytes(operand);
+        bytes.extend((operand.len() as u32).to_le_bytes());
+        bytes.extend(operand);
+    }
+    bytes
+}
+
+fn take<'a>(bytes: &'a [u8], position: &mut usize, len: usize) -> Result<&'a [u8], DecodeError> {
+    let slice = bytes
+        .get(*position..*position + len)
+        .ok_or(DecodeError::UnexpectedEnd)?;
+    *position += len;
+    Ok(slice)
+}
+
+fn take_u64(bytes: &[u8], position: &mut usize) -> Result<u64, DecodeError> {
+    Ok(u64::from_le_bytes(
+        take(bytes, position, 8)?.try_into().unwrap(),
+    ))
+}
+
+fn take_len(bytes: &[u8], position: &mut usize) -> Result<usize, DecodeError> {
+    Ok(u32::from_le_bytes(take(bytes, position, 4)?.try_into().unwrap()) as usize)
+}
+
+pub fn recording_from_bytes(bytes: &[u8]) -> Result<Recording, DecodeError> {
+    check_header(bytes, KIND_RECORDING)?;
+    let mut position = HEADER_LEN;
+    let operation = match take(bytes, &mut position, 1)?[0] {
+        0 => RecordedOperation::Union,
+        1 => RecordedOperation::Cut,
+        2 => RecordedOperation::Xor,
+        3 => RecordedOperation::Difference,
+        tag => return Err(DecodeError::InvalidTag(tag)),
+    };
+    let tolerance = f64::from_bits(take_u64(bytes, &mut position)?);
+    let seed = take_u64(bytes, &mut position)?;
+    let mut limits = [None; 3];
+    for limit in limits.iter_mut() {
+        let present = take(bytes, &mut position, 1)?[0];
+        let value = take_u64(bytes, &mut position)?;
+        *limit = match present {
+            0 => None,
+            1 => Some(value),
+            tag => return Err(DecodeError::InvalidTag(tag)),
+        };
+    }
+    let len = take_len(bytes, &mut position)?;
+    let message = String::from_utf8(take(bytes, &mut position, len)?.to_vec())
+        .map_err(|_| DecodeError::Invalid("Message is not UTF-8".to_string()))?;
+    let mut operands = Vec::<Volume>::new();
+    for _ in 0..take_len(bytes, &mut position)? {
+        let len = take_len(bytes, &mut position)?;
+        operands.push(volume_from_bytes(take(bytes, &mut position, len)?)?);
+    }
+    let required = match operation {
+        RecordedOperation::Union => 0,
+        RecordedOperation::Cut => 1,
+        RecordedOperation::Xor => 2,
+        RecordedOperation::Difference => 2,
+    };
+    if operands.len() < required {
+        return Err(DecodeError::Invalid("Missing operands".to_string()));
+    }
+    if position != bytes.len() {
+        return Err(DecodeError::Invalid("Trailing data".to_string()));
+    }
+    Ok(Recording {
+        operation,
+        operands,
+        limits: BooleanLimits {
+            max_face_pairs: limits[0].map(|v| v as usize),
+            max_intersection_events: limits[1].map(|v| v as usize),
+            time_budget: limits[2].map(Duration::from_nanos),
+        },
+        tolerance,
+        seed,
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform};
+    use geop_topology::primitive_objects::volumes::cube::primitive_cube;
+
+    use crate::{budget::ExceededLimit, union::volume::union_all_with_limits};
+
+    use super::*;
+
+    #[test]
+    fn test_record_and_replay() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let shifted = cube.transform(Transform::from_translation(Point::new(1.0, 0.5, 0.25)));
+        let operands = [cube, shifted];
+        // Other tests may record their own failures while recording is on, so this one is found by its limit.
+        let limits = BooleanLimits::unlimited().with_max_face_pairs(3);
+        let directory = std::env::temp_dir().join(format!("geop-recorder-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        start_recording(&directory);
+        let result = union_all_with_limits(&operands, &Progress::none(), &limits);
+        stop_recording();
+        assert!(matches!(
+            result,
+            Err(BooleanError::LimitExceeded(ExceededLimit::FacePairs(3), _))
+        ));
+
+        let recordings: Vec<Recording> = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| {
+                recording_from_bytes(&std::fs::read(entry.unwrap().path()).unwrap()).unwrap()
+            })
+            .filter(|r| r.limits.max_face_pairs == Some(3))
+            .collect();
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(
+            recordings.len(),
+            1,
+            "Expected the failure to be recorded once"
+        );
+        let recording = &recordings[0];
+        assert_eq!(recording.operation, RecordedOperation::Union);
+        assert_eq!(recording.operands.len(), 2);
+        assert_eq!(recording.tolerance, EQ_THRESHOLD);
+        assert!(recording.message.starts_with("More than 3 pairs"));
+
+        // Replaying fails the same way, and succeeds without the limit.
+        assert!(matches!(
+            replay(recording),
+            ReplayOutcome::Failed(BooleanError::LimitExceeded(ExceededLimit::FacePairs(3), _))
+        ));
+        let unlimited = Recording {
+            limits: BooleanLimits::unlimited(),
+            ..recording.clone()
+        };
+        match replay(&unlimited) {
+            ReplayOutcome::Succeeded(volumes) => assert_eq!(volumes.len(), 1),
+            _ => panic!("Expected the union to succeed without limits"),
+        }
+
+        // Truncated files are rejected.
+        let bytes = recording_to_bytes(recording);
+        assert!(matches!(
+            recording_from_bytes(&bytes[..bytes.len() - 1]),
+            Err(DecodeError::UnexpectedEnd)
+        ));
+    }
+}
@@ -3,13 +3,24 @@ use std::{
     rc::Rc,
 };
 
-use geop_geometry::surfaces::surface::Surface;
+use geop_geometry::{
+    curves::{curve::Curve, CurveLike},
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
 use geop_topology::{
     contains::{
+        edge_point::{edge_point_contains, EdgePointContains},
         face_contour::{face_contour_contains, FaceContourContains},
         face_edge::{face_edge_contains, FaceEdgeContains},
+        face_point::{face_point_contains, FacePointContains},
+    },
+    measure::mass::{contour_polygon, contour_signed_area},
+    topology::{
+        contour::{Contour, ContourTangent},
+        edge::Edge,
+        face::Face,
     },
-    topology::{contour::Contour, edge::Edge, face::Face},
 };
 
 use crate::{
@@ -45,6 +56,72 @@ pub fn face_split_points(face_self: &Face, face_other: &Face) -> Vec<Point> {
     intersections
 }
 
+// Step along the tangent for estimating the curvature of the boundaries where they touch.
+const TANGENCY_STEP: f64 = 1e-3;
+// Sine of the angle between the boundaries, and difference of their curvatures, below which they are considered equal.
+const TANGENCY_THRESHOLD: f64 = 1e-4;
+
+// The curvature vector of the curve at p, which points towards the center of curvature and has the curvature as its length.
+// Points at a small step along the tangent are pulled back onto the curve by the curvature, so the second difference is used.
+fn curvature_vector(curve: &Curve, p: Point) -> Point {
+    let step = curve.tangent(p).normalize() * TANGENCY_STEP;
+    let (before, after) = (curve.project(p - step).point, curve.project(p + step).point);
+    (before + after - p * 2.0) / (TANGENCY_STEP * TANGENCY_STEP)
+}
+
+fn boundary_edge_at(face: &Face, p: Point) -> Option<Edge> {
+    face.all_edges()
+        .into_iter()
+        .find(|e| edge_point_contains(e, p) == EdgePointContains::Inside)
+}
+
+// Whether the boundaries of both faces touch at p without crossing, like two circles that touch from the outside or the inside.
+// Then both boundaries are smooth at p with parallel tangents, and bend by a different amount, so that one stays on one side of the
+// other. Boundaries that bend by the same amount overlap, and are split at the ends of the overlap instead.
+pub fn is_tangential_contact(face_self: &Face, face_other: &Face, p: Point) -> bool {
+    let (tangent_self, tangent_other) = match (
+        face_self.boundary_tangent(p),
+        face_other.boundary_tangent(p),
+    ) {
+        (ContourTangent::OnEdge(a), ContourTangent::OnEdge(b)) => (a, b),
+        _ => return false,
+    };
+    if tangent_self.cross(tangent_other).norm() > TANGENCY_THRESHOLD {
+        return false;
+    }
+    let (edge_self, edge_other) = match (
+        boundary_edge_at(face_self, p),
+        boundary_edge_at(face_other, p),
+    ) {
+        (Some(edge_self), Some(edge_other)) => (edge_self, edge_other),
+        _ => return false,
+    };
+    let sideways = face_self.surface.normal(p).cross(tangent_self);
+    let bend_self = curvature_vector(&edge_self.curve, p).dot(sideways);
+    let bend_other = curvature_vector(&edge_other.curve, p).dot(sideways);
+    (bend_self - bend_other).abs() > TANGENCY_THRESHOLD
+}
+
+// Like face_edge_contains, but away from the points where the boundaries touch. An edge that is not split there can have its
+// midpoint exactly on the other boundary, e.g. a full circle, although it does not run along it.
+fn face_edge_contains_apart(face: &Face, edge: &Edge, contacts: &[Point]) -> FaceEdgeContains {
+    if !contacts.contains(&edge.get_midpoint()) {
+        return face_edge_contains(face, edge);
+    }
+    for t in [0.3, 0.7, 0.1, 0.9] {
+        let p = edge.interpolate(t);
+        if contacts.contains(&p) {
+            continue;
+        }
+        match face_point_contains(face, p) {
+            FacePointContains::Inside => return FaceEdgeContains::Inside,
+            FacePointContains::Outside => return FaceEdgeContains::Outside,
+            _ => {}
+        }
+    }
+    face_edge_contains(face, edge)
+}
+
 #[derive(Debug)]
 pub enum FaceSplit {
     AinB(Edge),
@@ -65,7 +142,12 @@ pub fn face_split(face_self: &Face, face_other: &Face) -> Vec<FaceSplit> {
     // debug_data::add_face(face_self.clone(), DebugColor::Red);
     // debug_data::add_face(face_other.clone(), DebugColor::Blue);
 
-    let intersections = face_split_points(face_self, face_other);
+    // Boundaries that only touch stay on the same side of each other, so they are not split there. Splitting a closed edge at a
+    // single point would also leave two open ends that do not meet.
+    let (contacts, intersections): (Vec<Point>, Vec<Point>) =
+        face_split_points(face_self, face_other)
+            .into_iter()
+            .partition(|p| is_tangential_contact(face_self, face_other, *p));
 
     // println!("intersections: {:}", intersections.len());
     // for point in intersections.iter() {
@@ -77,24 +159,24 @@ pub fn face_split(face_self: &Face, face_other: &Face) -> Vec<FaceSplit> {
 
     let res: Vec<FaceSplit> = edges_self
         .into_iter()
-        .map(|edge| match face_edge_contains(face_other, &edge) {
-            FaceEdgeContains::Inside => FaceSplit::AinB(edge),
-            FaceEdgeContains::OnBorderSameDir => FaceSplit::AonBSameSide(edge),
-            FaceEdgeContains::OnBorderOppositeDir => FaceSplit::AonBOpSide(edge),
-            FaceEdgeContains::Outside => FaceSplit::AoutB(edge),
-            FaceEdgeContains::NotSameSurface => panic!("Not same surface"),
-        })
-        .chain(
-            edges_other
-                .into_iter()
-                .map(|edge| match face_edge_contains(face_self, &edge) {
-                    FaceEdgeContains::Inside => FaceSplit::BinA(edge),
-                    FaceEdgeContains::OnBorderSameDir => FaceSplit::BonASameSide(edge),
-                    FaceEdgeContains::OnBorderOppositeDir => FaceSplit::BonAOpSide(edge),
-                    FaceEdgeContains::Outside => FaceSplit::BoutA(edge),
-                    FaceEdgeContains::NotSameSurface => panic!("Not same surface"),
-                }),
+        .map(
+            |edge| match face_edge_contains_apart(face_other, &edge, &contacts) {
+                FaceEdgeContains::Inside => FaceSplit::AinB(edge),
+                FaceEdgeContains::OnBorderSameDir => FaceSplit::AonBSameSide(edge),
+                FaceEdgeContains::OnBorderOppositeDir => FaceSplit::AonBOpSide(edge),
+                FaceEdgeContains::Outside => FaceSplit::AoutB(edge),
+                FaceEdgeContains::NotSameSurface => panic!("Not same surface"),
+            },
         )
+        .chain(edges_other.into_iter().map(|edge| {
+            match face_edge_contains_apart(face_self, &edge, &contacts) {
+                FaceEdgeContains::Inside => FaceSplit::BinA(edge),
+                FaceEdgeContains::OnBorderSameDir => FaceSplit::BonASameSide(edge),
+                FaceEdgeContains::OnBorderOppositeDir => FaceSplit::BonAOpSide(edge),
+                FaceEdgeContains::Outside => FaceSplit::BoutA(edge),
+                FaceEdgeContains::NotSameSurface => panic!("Not same surface"),
+            }
+        }))
         .collect();
 
     // for edge in res.iter() {
@@ -120,7 +202,7 @@ pub fn face_split(face_self: &Face, face_other: &Face) -> Vec<FaceSplit> {
     res
 }
 
-pub fn face_remesh(mut edges_intermediate: Vec<FaceSplit>) -> Vec<Contour> {
+pub fn face_remesh(mut edges_intermediate: Vec<FaceSplit>, surface: &Surface) -> Vec<Contour> {
     println!("face_remesh");
     for edge in edges_intermediate.iter() {
         println!("Edge: {:?}", edge);
@@ -151,17 +233,42 @@ pub fn face_remesh(mut edges_intermediate: Vec<FaceSplit>) -> Vec<Contour> {
             for edge in edges.iter() {
                 println!("Edge: {:?}", edge);
             }
-            let next_i = edges.iter().position(|edge| {
-                edge.start == new_contour[new_contour.len() - 1].end
-                    || edge.end == new_contour[new_contour.len() - 1].end
+            let last = &new_contour[new_contour.len() - 1];
+            // Closed edges, like full circles, are contours of their own. Their missing end points must not chain them to each other.
+            if last.start.is_none() && last.end.is_none() {
+                contours.push(Contour::new(new_contour));
+                break;
+            }
+            // Where the faces share boundary segments, several edges can meet at a vertex. The kept edges are already oriented,
+            // so an edge that starts at the vertex is preferred, and of those the one with the sharpest left turn.
+            let next_i = match last.end {
+                Some(v) => {
+                    let normal = surface.normal(v);
+                    let t_in = last.tangent(v);
+                    edges
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, edge)| edge.start == Some(v))
+                        .max_by(|(_, a), (_, b)| {
+                            t_in.angle2(a.tangent(v), normal)
+                                .total_cmp(&t_in.angle2(b.tangent(v), normal))
+                        })
+                        .map(|(i, _)| (i, false))
+                }
+                None => None,
+            }
+            .or_else(|| {
+                edges
+                    .iter()
+                    .position(|edge| edge.end == last.end)
+                    .map(|i| (i, true))
             });
             match next_i {
-                Some(i) => {
-                    if edges[i].start == new_contour[new_contour.len() - 1].end {
-                        new_contour.push(edges.remove(i));
-                    } else {
-                        new_contour.push(edges.remove(i).flip());
-                    }
+                Some((i, false)) => {
+                    new_contour.push(edges.remove(i));
+                }
+                Some((i, true)) => {
+                    new_contour.push(edges.remove(i).flip());
                 }
                 None => {
                     assert!(new_contour[0].start == new_contour[new_contour.len() - 1].end);
@@ -175,6 +282,27 @@ pub fn face_remesh(mut edges_intermediate: Vec<FaceSplit>) -> Vec<Contour> {
     return contours;
 }
 
+// Signed area enclosed by the contour, positive if it runs counterclockwise around the normal of the surface. The area of the
+// polygon of the contour is measured around the normal at its center. This is exact on planes, and on curved surfaces good enough
+// to tell the orientation of contours that enclose a region, like the loops of face booleans. Contours that go around the surface,
+// like a circle around a cylinder, enclose nothing and have an area close to 0.
+pub fn contour_signed_area_on(contour: &Contour, surface: &Surface) -> f64 {
+    let points = contour_polygon(contour);
+    let center = points.iter().fold(Point::zero(), |a, p| a + *p) / points.len() as f64;
+    contour_signed_area(contour, surface.normal(surface.project(center)))
+}
+
+// The contour running counterclockwise around the normal of the surface, i.e. as the boundary of the region it encloses. Contours
+// that enclose nothing stay as they are.
+fn counterclockwise(contour: Contour, surface: &Surface) -> Contour {
+    match contour_signed_area_on(&contour, surface) < -EQ_THRESHOLD {
+        true => contour.flip(),
+        false => contour,
+    }
+}
+
+// Contours nested into each other. All contours are stored counterclockwise, as the boundary of the region they enclose, and get
+// their orientation as boundary or hole when the faces are built.
 pub struct ContourHierarchy {
     pub contour: Contour,
     pub children: Vec<ContourHierarchy>,
@@ -187,9 +315,16 @@ impl ContourHierarchy {
         contour: Contour,
         surface: Rc<Surface>,
     ) -> Option<Contour> {
+        if face_contour_contains(
+            &Face::new(Some(self.contour.clone()), vec![], surface.clone()),
+            &contour,
+        ) != FaceContourContains::Inside
+        {
+            return Some(contour);
+        }
         for child in self.children.iter_mut() {
             match face_contour_contains(
-                &Face::new(Some(child.contour.flip()), vec![], surface.clone()),
+                &Face::new(Some(child.contour.clone()), vec![], surface.clone()),
                 &contour,
             ) {
                 FaceContourContains::Inside => {
@@ -204,30 +339,28 @@ impl ContourHierarchy {
                 FaceContourContains::NotSameSurface => panic!("should not happen"),
             }
         }
-        if face_contour_contains(
-            &Face::new(Some(self.contour.clone()), vec![], surface.clone()),
-            &contour,
-        ) == FaceContourContains::Inside
-        {
-            self.children.push(ContourHierarchy {
-                contour,
-                children: Vec::new(),
-            });
-            return None;
-        }
-        return Some(contour);
+        self.children.push(ContourHierarchy {
+            contour,
+            children: Vec::new(),
+        });
+        None
     }
 
+    // The contours at even depth bound faces, and the ones at odd depth are their holes, running clockwise.
     pub fn as_faces(&self, surface: Rc<Surface>) -> Vec<Face> {
         let mut faces = Vec::<Face>::new();
-        let mut face = Face::new(Some(self.contour.clone()), vec![], surface.clone());
+        let mut holes = Vec::<Contour>::new();
         for child in self.children.iter() {
-            face.holes.push(child.contour.clone());
+            holes.push(child.contour.flip());
             for child2 in child.children.iter() {
                 faces.extend(child2.as_faces(surface.clone()));
             }
         }
-        faces.push(face);
+        faces.push(Face::new(
+            Some(self.contour.clone()),
+            holes,
+            surface.clone(),
+        ));
         faces
     }
 }
@@ -243,36 +376,89 @@ impl Display for ContourHierarchy {
     }
 }
 
+// Points at which contours are sampled to tell on which side of another contour they are, away from where they touch.
+const CONTOUR_SAMPLES: [f64; 4] = [0.3, 0.7, 0.1, 0.9];
+
+// Whether the contour is inside of the outer contour, where it may touch the outer contour, but does not cross it.
+fn contour_inside_touching(outer: &Contour, contour: &Contour, surface: Rc<Surface>) -> bool {
+    let face = Face::new(Some(outer.clone()), vec![], surface);
+    let mut inside = false;
+    for edge in contour.edges.iter() {
+        for t in CONTOUR_SAMPLES {
+            match face_point_contains(&face, edge.interpolate(t)) {
+                FacePointContains::Inside => inside = true,
+                FacePointContains::Outside => return false,
+                _ => {}
+            }
+        }
+    }
+    inside
+}
+
+// Builds faces from the contours of face_remesh. Which contours are boundaries and which are holes follows from how they are nested,
+// so the orientation of strictly nested contours does not matter. Larger contours are placed first, so that they are there to take
+// the contours inside of them. Holes that touch the boundary around them, like a circle cut out of a disk at its rim, are not
+// strictly nested, so for those the clockwise orientation from face_remesh decides that they are holes.
 pub fn normalize_faces(contours: Vec<Contour>, surface: Rc<Surface>) -> Vec<Face> {
     println!("normalize_faces");
     println!("Contours: {}", contours.len());
-    let mut hierarchies = Vec::<ContourHierarchy>::new();
-    'outer_loop: for contour in contours.iter() {
-        for h in hierarchies.iter_mut() {
+    let mut contours: Vec<(f64, bool, Contour)> = contours
+        .into_iter()
+        .map(|c| {
+            let is_hole = contour_signed_area_on(&c, &surface) < -EQ_THRESHOLD;
+            let c = counterclockwise(c, &surface);
+            (contour_signed_area_on(&c, &surface), is_hole, c)
+        })
+        .collect();
+    contours.sort_by(|(a, _, _), (b, _, _)| b.total_cmp(a));
+
+    let mut hierarchies = Vec::<(bool, ContourHierarchy)>::new();
+    'outer_loop: for (_, is_hole, contour) in contours.into_iter() {
+        for (_, h) in hierarchies.iter_mut() {
             if h.consume_contour_if_inside(contour.clone(), surface.clone())
                 .is_none()
             {
                 continue 'outer_loop;
             }
         }
-        hierarchies.push(ContourHierarchy {
-            contour: contour.clone(),
-            children: Vec::new(),
-        });
+        hierarchies.push((
+            is_hole,
+            ContourHierarchy {
+                contour,
+                children: Vec::new(),
+            },
+        ));
     }
-    // Now build a hierarchy of Contours
-    let mut faces = Vec::<Face>::new();
-    for h in hierarchies.iter() {
-        faces.extend(h.as_faces(surface.clone()));
+
+    // Holes that are not strictly inside of any contour go into the smallest boundary that they touch from the inside.
+    let mut i = hierarchies.len();
+    while i > 0 {
+        i -= 1;
+        if !hierarchies[i].0 {
+            continue;
+        }
+        let target = (0..hierarchies.len()).rev().find(|j| {
+            *j != i
+                && contour_inside_touching(
+                    &hierarchies[*j].1.contour,
+                    &hierarchies[i].1.contour,
+                    surface.clone(),
+                )
+        });
+        if let Some(j) = target {
+            let (_, hole) = hierarchies.remove(i);
+            let j = if j > i { j - 1 } else { j };
+            hierarchies[j].1.children.push(hole);
+        }
     }
 
     println!("Hierarchies: {}", hierarchies.len());
-    for h in hierarchies.iter() {
+    for (_, h) in hierarchies.iter() {
         println!("Hierarchy: {}", h);
     }
     // Now build a hierarchy of Contours
     let mut faces = Vec::<Face>::new();
-    for h in hierarchies.iter() {
+    for (_, h) in hierarchies.iter() {
         faces.extend(h.as_faces(surface.clone()));
     }
 
@@ -282,3 +468,95 @@ pub fn normalize_faces(contours: Vec<Contour>, surface: Rc<Surface>) -> Vec<Face
     }
     faces
 }
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::surfaces::plane::Plane;
+    use geop_topology::measure::mass::measure_area;
+
+    use crate::{difference::face_face::face_face_difference, union::face::face_face_union};
+
+    use super::*;
+
+    #[test]
+    fn test_normalize_faces() {
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let surface = Surface::Plane(plane.clone());
+
+        // Cutting out the middle of a square leaves a hole, and the outer contour stays the boundary.
+        let square = Face::rectangle(&plane, 4.0, 4.0);
+        let faces = face_face_difference(&square, &Face::rectangle(&plane, 2.0, 2.0));
+        assert_eq!(faces.len(), 1);
+        let boundary = faces[0].boundary.as_ref().unwrap();
+        assert!((contour_signed_area_on(boundary, &surface) - 16.0).abs() < 1e-9);
+        assert_eq!(faces[0].holes.len(), 1);
+        assert!((contour_signed_area_on(&faces[0].holes[0], &surface) + 4.0).abs() < 1e-9);
+        assert!((measure_area(&faces[0]) - 12.0).abs() < 1e-9);
+
+        // Same for circles, which are single closed edges.
+        let disk = Face::disk(&plane, Point::zero(), 2.0);
+        let faces = face_face_difference(&disk, &Face::disk(&plane, Point::zero(), 1.0));
+        assert_eq!(faces.len(), 1);
+        assert_eq!(faces[0].holes.len(), 1);
+        assert!(contour_signed_area_on(&faces[0].holes[0], &surface) < 0.0);
+        assert!((measure_area(&faces[0]) - 3.0 * std::f64::consts::PI).abs() < 1e-3);
+
+        // An island in a hole bounds a face of its own, whatever the orientation of the contours.
+        let contours = [4.0, 3.0, 2.0]
+            .iter()
+            .map(|s| Face::rectangle(&plane, *s, *s).boundary.unwrap().flip())
+            .collect();
+        let mut faces = normalize_faces(contours, Rc::new(surface.clone()));
+        faces.sort_by(|a, b| measure_area(a).total_cmp(&measure_area(b)));
+        assert_eq!(faces.len(), 2);
+        assert!((measure_area(&faces[0]) - 4.0).abs() < 1e-9);
+        assert!((measure_area(&faces[1]) - 7.0).abs() < 1e-9);
+
+        // A hole that touches the boundary at the rim keeps its orientation from the remesh and leaves a crescent.
+        let small = Face::disk(&plane, Point::new(0.5, 0.0, 0.0), 0.5);
+        let faces = face_face_difference(&Face::disk(&plane, Point::zero(), 1.0), &small);
+        assert_eq!(faces.len(), 1);
+        assert_eq!(faces[0].holes.len(), 1);
+        assert!((measure_area(&faces[0]) - 0.75 * std::f64::consts::PI).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_tangential_contact() {
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let total_area = |faces: &[Face]| faces.iter().map(measure_area).sum::<f64>();
+        let pi = std::f64::consts::PI;
+        let disk = Face::disk(&plane, Point::zero(), 1.0);
+
+        // Circles touching from the outside stay apart.
+        let outside = Face::disk(&plane, Point::new(2.0, 0.0, 0.0), 1.0);
+        assert!(is_tangential_contact(&disk, &outside, Point::unit_x()));
+        let union = face_face_union(&disk, &outside);
+        assert_eq!(union.len(), 2);
+        assert!((total_area(&union) - 2.0 * pi).abs() < 1e-3);
+        let difference = face_face_difference(&disk, &outside);
+        assert_eq!(difference.len(), 1);
+        assert!((total_area(&difference) - pi).abs() < 1e-3);
+
+        // A circle touching from the inside is swallowed by the union, and leaves a crescent in the difference.
+        let inside = Face::disk(&plane, Point::new(0.5, 0.0, 0.0), 0.5);
+        assert!(is_tangential_contact(&disk, &inside, Point::unit_x()));
+        let union = face_face_union(&disk, &inside);
+        assert_eq!(union.len(), 1);
+        assert!((total_area(&union) - pi).abs() < 1e-3);
+        let difference = face_face_difference(&disk, &inside);
+        assert_eq!(difference.len(), 1);
+        assert!((total_area(&difference) - 0.75 * pi).abs() < 1e-3);
+
+        // A square whose side is tangent to the circle, and equal circles that overlap instead of touching.
+        let above = Plane::new(Point::unit_y() * 2.0, Point::unit_x(), Point::unit_y());
+        let square = Face::rectangle(&above, 2.0, 2.0);
+        assert!(is_tangential_contact(&disk, &square, Point::unit_y()));
+        assert!(!is_tangential_contact(&disk, &disk, Point::unit_y()));
+        let union = face_face_union(&disk, &square);
+        assert_eq!(union.len(), 2);
+        assert!((total_area(&union) - (pi + 4.0)).abs() < 1e-3);
+        let difference = face_face_difference(&square, &disk);
+        assert_eq!(difference.len(), 1);
+        assert!((total_area(&difference) - 4.0).abs() < 1e-3);
+    }
+}
@@ -1,12 +1,30 @@
-use crate::intersections::face_face::{face_face_intersection, FaceFaceIntersection};
-use geop_topology::topology::{edge::Edge, face::Face, volume::Volume};
+use crate::{
+    budget::{budget_scene, without_limits, BooleanError, BooleanLimits, Budget},
+    intersections::face_face::{face_face_intersection_with_cache, FaceFaceIntersection},
+    split_if_necessary::{
+        face_regions::split_face_by_edges,
+        point_split_edge::split_contour_by_points_if_necessary,
+        silhouette::{silhouette_direction, silhouette_edges},
+    },
+};
+use geop_geometry::intersection_cache::IntersectionCache;
+use geop_geometry::points::point::Point;
+use geop_geometry::surface_surface_intersection::surface_surface::{
+    surface_surface_intersection, FaceSurfaceIntersection,
+};
+use geop_topology::{
+    contains::volume_point::{volume_point_contains, VolumePointContains},
+    progress::{Cancelled, Progress},
+    topology::{edge::Edge, face::Face, volume::Volume},
+};
 
 // Points are ignored for now.
 pub fn volume_split_edges(volume_self: &Volume, volume_other: &Volume) -> Vec<Edge> {
     let mut edges = Vec::<Edge>::new();
+    let mut cache = IntersectionCache::new();
     for face_self in volume_self.all_faces().iter() {
         for face_other in volume_other.all_faces().iter() {
-            match face_face_intersection(face_self, face_other) {
+            match face_face_intersection_with_cache(face_self, face_other, &mut cache) {
                 FaceFaceIntersection::EdgesAndPoints(_points, new_edges) => {
                     edges.extend(new_edges);
                 }
@@ -22,6 +40,9 @@ pub fn volume_split_edges(volume_self: &Volume, volume_other: &Volume) -> Vec<Ed
     edges
 }
 
+// Fraction of the progress of a boolean that is spent on imprinting the operands. The rest is spent on classifying the split faces.
+pub const SPLIT_PROGRESS: f64 = 0.8;
+
 #[derive(Debug)]
 pub enum VolumeSplit {
     AinB(Face),
@@ -34,31 +55,242 @@ pub enum VolumeSplit {
     BoutA(Face),
 }
 
-pub fn volume_split(_volume_self: &Volume, _volume_other: &Volume) -> Vec<VolumeSplit> {
-    todo!("Implement volume split");
-    // let mut intersections = volume_split_edges(volume_self, volume_other);
-
-    // let mut faces_self = split_faces_by_edges_if_necessary(volume_self.all_faces(), &intersections);
-    // let mut faces_other =
-    //     split_faces_by_edges_if_necessary(volume_other.all_faces(), &intersections);
-
-    // faces_self
-    //     .into_iter()
-    //     .map(|edge| match volume_contains_face(face_other, &edge) {
-    //         FaceContainsEdge::Inside => VolumeSplit::AinB(edge),
-    //         FaceContainsEdge::OnBorderSameDir => VolumeSplit::AonBSameSide(edge),
-    //         FaceContainsEdge::OnBorderOppositeDir => VolumeSplit::AonBOpSide(edge),
-    //         FaceContainsEdge::Outside => VolumeSplit::AoutB(edge),
-    //     })
-    //     .chain(
-    //         faces_other
-    //             .into_iter()
-    //             .map(|face| match volume_contains_face(face_self, &edge) {
-    //                 FaceContainsEdge::Inside => VolumeSplit::BinA(edge),
-    //                 FaceContainsEdge::OnBorderSameDir => VolumeSplit::BonASameSide(edge),
-    //                 FaceContainsEdge::OnBorderOppositeDir => VolumeSplit::BonAOpSide(edge),
-    //                 FaceContainsEdge::Outside => VolumeSplit::BoutA(edge),
-    //             }),
-    //     )
-    //     .collect()
+// Imprints the operands onto each other: every face is split along its intersections with the faces of all other operands.
+// Each pair of faces is intersected only once and the result is used for both faces. Coplanar faces imprint their boundaries onto each other.
+// Returns the split faces for every operand.
+pub fn volumes_split_faces(volumes: &[Volume]) -> Vec<Vec<Face>> {
+    volumes_split_faces_with_progress(volumes, &Progress::none(), 0.0, 1.0)
+        .expect("Operations without cancellation token are not cancelled")
+}
+
+// Like volumes_split_faces, reporting the progress within the range from start to end.
+pub fn volumes_split_faces_with_progress(
+    volumes: &[Volume],
+    progress: &Progress,
+    start: f64,
+    end: f64,
+) -> Result<Vec<Vec<Face>>, Cancelled> {
+    let mut budget = Budget::new(&BooleanLimits::unlimited());
+    without_limits(volumes_split_faces_with_limits(
+        volumes,
+        progress,
+        &mut budget,
+        start,
+        end,
+    ))
+}
+
+// Curved faces that cross a plane are also cut along their silhouettes seen along its normal, so that every piece faces the same way
+// along it.
+fn add_silhouettes(cuts: &mut Vec<Edge>, face: &Face, other: &Face) {
+    if let Some(direction) = silhouette_direction(&other.surface) {
+        for edge in silhouette_edges(&face.surface, direction) {
+            if !cuts.contains(&edge) {
+                cuts.push(edge);
+            }
+        }
+    }
+}
+
+// Like volumes_split_faces_with_progress, stopping with partial diagnostics when the budget runs out.
+pub fn volumes_split_faces_with_limits(
+    volumes: &[Volume],
+    progress: &Progress,
+    budget: &mut Budget,
+    start: f64,
+    end: f64,
+) -> Result<Vec<Vec<Face>>, BooleanError> {
+    let faces = volumes
+        .iter()
+        .map(|v| v.all_faces())
+        .collect::<Vec<Vec<Face>>>();
+    let mut cuts = faces
+        .iter()
+        .map(|f| vec![Vec::<Edge>::new(); f.len()])
+        .collect::<Vec<Vec<Vec<Edge>>>>();
+
+    let total_pairs: usize = (0..faces.len())
+        .map(|a| faces[a].len() * faces[a + 1..].iter().map(|f| f.len()).sum::<usize>())
+        .sum();
+    budget
+        .plan_face_pairs(total_pairs)
+        .map_err(|limit| budget.exceeded(limit, budget_scene(&[], [].iter())))?;
+
+    // The faces of one volume share their boundary curves and often their surfaces, so the same curves are intersected again for many pairs.
+    let mut cache = IntersectionCache::new();
+    // Intersecting the pairs of faces takes most of the time, splitting the faces the rest.
+    let middle = start + (end - start) * 0.75;
+    let total_faces: usize = faces.iter().map(|f| f.len()).sum();
+    let mut done = 0;
+    for a in 0..volumes.len() {
+        for (i, face_a) in faces[a].iter().enumerate() {
+            progress.report_step(start, middle, done, total_faces)?;
+            done += 1;
+            for b in a + 1..volumes.len() {
+                for (j, face_b) in faces[b].iter().enumerate() {
+                    let events =
+                        match surface_surface_intersection(&face_a.surface, &face_b.surface) {
+                            FaceSurfaceIntersection::Surface(_) => {
+                                cuts[a][i].extend(face_b.all_edges());
+                                cuts[b][j].extend(face_a.all_edges());
+                                1
+                            }
+                            _ => {
+                                match face_face_intersection_with_cache(face_a, face_b, &mut cache)
+                                {
+                                    FaceFaceIntersection::EdgesAndPoints(points, edges) => {
+                                        let events = points.len() + edges.len();
+                                        if !edges.is_empty() {
+                                            add_silhouettes(&mut cuts[a][i], face_a, face_b);
+                                            add_silhouettes(&mut cuts[b][j], face_b, face_a);
+                                        }
+                                        cuts[a][i].extend(edges.iter().cloned());
+                                        cuts[b][j].extend(edges);
+                                        events
+                                    }
+                                    _ => 0,
+                                }
+                            }
+                        };
+                    budget.add_face_pair(events).map_err(|limit| {
+                        budget.exceeded(
+                            limit,
+                            budget_scene(&[face_a, face_b], cuts.iter().flatten().flatten()),
+                        )
+                    })?;
+                }
+            }
+        }
+    }
+
+    let mut done = 0;
+    let mut result = Vec::<Vec<Face>>::new();
+    for (faces, cuts) in faces.iter().zip(cuts.iter()) {
+        let mut split = Vec::<Face>::new();
+        for (face, face_cuts) in faces.iter().zip(cuts.iter()) {
+            progress.report_step(middle, end, done, total_faces)?;
+            done += 1;
+            budget
+                .check_time()
+                .map_err(|limit| budget.exceeded(limit, budget_scene(&[face], face_cuts.iter())))?;
+            split.extend(split_face_by_edges(face, face_cuts));
+        }
+        result.push(split);
+    }
+    progress.report(end)?;
+    Ok(result)
+}
+
+// Splits the boundary edges of the faces at the vertices of all other faces, so that faces that touch along an edge share exactly the same edges.
+// This is needed where operands only touch, since then only one side of the contact gets imprinted.
+pub fn faces_split_at_vertices(faces: Vec<Face>) -> Vec<Face> {
+    let points = faces
+        .iter()
+        .flat_map(|f| f.all_points())
+        .collect::<Vec<Point>>();
+    faces
+        .into_iter()
+        .map(|face| {
+            Face::new(
+                face.boundary
+                    .map(|c| split_contour_by_points_if_necessary(c, &points)),
+                face.holes
+                    .into_iter()
+                    .map(|c| split_contour_by_points_if_necessary(c, &points))
+                    .collect(),
+                face.surface,
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FaceVolumeClass {
+    Inside,
+    Outside,
+    OnSameSide,
+    OnOppositeSide,
+}
+
+// Classifies a face that was split by volumes_split_faces against a volume, i.e. the face is either completely inside, outside, or on the boundary of the volume.
+pub fn face_volume_classify(face: &Face, volume: &Volume) -> FaceVolumeClass {
+    let p = face.inner_point();
+    match volume_point_contains(volume, p) {
+        VolumePointContains::Inside => FaceVolumeClass::Inside,
+        VolumePointContains::Outside => FaceVolumeClass::Outside,
+        VolumePointContains::OnFace(other) => match other.normal(p).dot(face.normal(p)) > 0.0 {
+            true => FaceVolumeClass::OnSameSide,
+            false => FaceVolumeClass::OnOppositeSide,
+        },
+        VolumePointContains::OnEdge(_) | VolumePointContains::OnPoint(_) => {
+            panic!("Inner point of a split face is on an edge of the other volume")
+        }
+    }
+}
+
+pub fn volume_split(volume_self: &Volume, volume_other: &Volume) -> Vec<VolumeSplit> {
+    volume_split_with_progress(volume_self, volume_other, &Progress::none())
+        .expect("Operations without cancellation token are not cancelled")
+}
+
+// Like volume_split, reporting the progress and stopping early when the operation is cancelled.
+pub fn volume_split_with_progress(
+    volume_self: &Volume,
+    volume_other: &Volume,
+    progress: &Progress,
+) -> Result<Vec<VolumeSplit>, Cancelled> {
+    without_limits(volume_split_with_limits(
+        volume_self,
+        volume_other,
+        progress,
+        &BooleanLimits::unlimited(),
+    ))
+}
+
+// Like volume_split_with_progress, stopping with an error that holds partial diagnostics when one of the limits is exceeded.
+pub fn volume_split_with_limits(
+    volume_self: &Volume,
+    volume_other: &Volume,
+    progress: &Progress,
+    limits: &BooleanLimits,
+) -> Result<Vec<VolumeSplit>, BooleanError> {
+    let mut budget = Budget::new(limits);
+    let mut splits = volumes_split_faces_with_limits(
+        &[volume_self.clone(), volume_other.clone()],
+        progress,
+        &mut budget,
+        0.0,
+        SPLIT_PROGRESS,
+    )?;
+    let faces_other = splits.pop().unwrap();
+    let faces_self = splits.pop().unwrap();
+    let total = faces_self.len() + faces_other.len();
+
+    let mut result = Vec::<VolumeSplit>::with_capacity(total);
+    for face in faces_self {
+        progress.report_step(SPLIT_PROGRESS, 1.0, result.len(), total)?;
+        budget
+            .check_time()
+            .map_err(|limit| budget.exceeded(limit, budget_scene(&[&face], [].iter())))?;
+        result.push(match face_volume_classify(&face, volume_other) {
+            FaceVolumeClass::Inside => VolumeSplit::AinB(face),
+            FaceVolumeClass::OnSameSide => VolumeSplit::AonBSameSide(face),
+            FaceVolumeClass::OnOppositeSide => VolumeSplit::AonBOpSide(face),
+            FaceVolumeClass::Outside => VolumeSplit::AoutB(face),
+        });
+    }
+    for face in faces_other {
+        progress.report_step(SPLIT_PROGRESS, 1.0, result.len(), total)?;
+        budget
+            .check_time()
+            .map_err(|limit| budget.exceeded(limit, budget_scene(&[&face], [].iter())))?;
+        result.push(match face_volume_classify(&face, volume_self) {
+            FaceVolumeClass::Inside => VolumeSplit::BinA(face),
+            FaceVolumeClass::OnSameSide => VolumeSplit::BonASameSide(face),
+            FaceVolumeClass::OnOppositeSide => VolumeSplit::BonAOpSide(face),
+            FaceVolumeClass::Outside => VolumeSplit::BoutA(face),
+        });
+    }
+    progress.report(1.0)?;
+    Ok(result)
 }
@@ -0,0 +1,187 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    curves::{curve::Curve, line::Line},
+    points::point::Point,
+    surfaces::surface::Surface,
+};
+use geop_topology::topology::{contour::Contour, edge::Edge, face::Face, volume::Volume};
+
+use crate::intersections::face_edge::face_edge_clip;
+
+use super::section::slice_layers;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InfillPattern {
+    // Parallel lines, rotated by 90 degrees from layer to layer.
+    Rectilinear { spacing: f64, angle: f64 },
+    // Lines in both directions on every layer.
+    Grid { spacing: f64, angle: f64 },
+}
+
+// The paths of one layer, which a toolpath generator can turn into moves.
+#[derive(Debug, Clone)]
+pub struct LayerPaths {
+    pub height: f64,
+    // Outer boundaries run counterclockwise and holes clockwise around the slicing direction.
+    pub perimeters: Vec<Contour>,
+    // Infill segments inside of the perimeters, ordered such that consecutive lines run in alternating directions.
+    pub infill: Vec<Edge>,
+}
+
+// Parallel lines with the given spacing and angle relative to the u direction of the plane of the face, clipped to the face.
+fn hatch_face(face: &Face, spacing: f64, angle: f64) -> Vec<Edge> {
+    let plane = match &*face.surface {
+        Surface::Plane(plane) => plane,
+        _ => panic!("Infill is only defined for planar faces"),
+    };
+    let direction = plane.u_slope * angle.cos() + plane.v_slope * angle.sin();
+    let offset_dir = plane.u_slope * -angle.sin() + plane.v_slope * angle.cos();
+    let offsets: Vec<f64> = face
+        .all_points()
+        .iter()
+        .map(|p| (*p - plane.basis).dot(offset_dir))
+        .collect();
+    let min = offsets.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = offsets.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    // The lines are placed halfway between multiples of the spacing, so that they do not run along edges of rectangular sections.
+    let first = (min / spacing - 0.5).floor() as i64;
+    let last = (max / spacing - 0.5).ceil() as i64;
+    let mut paths = Vec::<Edge>::new();
+    for (i, k) in (first..=last).enumerate() {
+        let basis = plane.basis + offset_dir * ((k as f64 + 0.5) * spacing);
+        let line = Edge::new(None, None, Curve::Line(Line::new(basis, direction)));
+        let mut segments = face_edge_clip(face, &line);
+        segments.sort_by(|a, b| {
+            let a = (a.get_midpoint() - basis).dot(direction);
+            let b = (b.get_midpoint() - basis).dot(direction);
+            a.total_cmp(&b)
+        });
+        for segment in segments.iter_mut() {
+            if (segment.end.unwrap() - segment.start.unwrap()).dot(direction) < 0.0 {
+                *segment = segment.flip();
+            }
+        }
+        if i % 2 == 1 {
+            segments = segments.into_iter().rev().map(|s| s.flip()).collect();
+        }
+        paths.extend(segments);
+    }
+    paths
+}
+
+// Infill segments of a planar face, e.g. of a section. The layer index alternates the direction of rectilinear infill.
+pub fn infill_face(face: &Face, pattern: &InfillPattern, layer_index: usize) -> Vec<Edge> {
+    match pattern {
+        InfillPattern::Rectilinear { spacing, angle } => {
+            let angle = angle + (layer_index % 2) as f64 * PI / 2.0;
+            hatch_face(face, *spacing, angle)
+        }
+        InfillPattern::Grid { spacing, angle } => {
+            let mut paths = hatch_face(face, *spacing, *angle);
+            paths.extend(hatch_face(face, *spacing, angle + PI / 2.0));
+            paths
+        }
+    }
+}
+
+// Slices the volume into layers along the direction and fills each layer with the pattern.
+pub fn slice_with_infill(
+    volume: &Volume,
+    direction: Point,
+    layer_height: f64,
+    pattern: &InfillPattern,
+) -> Vec<LayerPaths> {
+    slice_layers(volume, direction, layer_height)
+        .into_iter()
+        .enumerate()
+        .map(|(i, layer)| LayerPaths {
+            height: layer.height,
+            perimeters: layer
+                .faces
+                .iter()
+                .flat_map(|f| f.boundary.iter().chain(f.holes.iter()).cloned())
+                .collect(),
+            infill: layer
+                .faces
+                .iter()
+                .flat_map(|f| infill_face(f, pattern, i))
+                .collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::curves::CurveLike;
+    use geop_topology::primitive_objects::volumes::cube::primitive_cube;
+
+    use super::*;
+
+    fn direction(edge: &Edge) -> Point {
+        (edge.end.unwrap() - edge.start.unwrap()).normalize()
+    }
+
+    #[test]
+    fn test_slice_with_infill() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let pattern = InfillPattern::Rectilinear {
+            spacing: 0.5,
+            angle: 0.0,
+        };
+        let layers = slice_with_infill(&cube, Point::unit_z(), 1.0, &pattern);
+        assert_eq!(layers.len(), 2, "Expected two layers");
+        for layer in layers.iter() {
+            assert_eq!(layer.perimeters.len(), 1, "Expected the square perimeter");
+            assert_eq!(
+                layer.infill.len(),
+                4,
+                "Expected four lines across the square"
+            );
+            for line in layer.infill.iter() {
+                let length = (line.end.unwrap() - line.start.unwrap()).norm();
+                assert!(
+                    (length - 2.0).abs() < 1e-6,
+                    "Expected lines across the square"
+                );
+            }
+            // Consecutive lines run in opposite directions.
+            for pair in layer.infill.windows(2) {
+                assert!(direction(&pair[0]).dot(direction(&pair[1])) < -1.0 + 1e-9);
+            }
+        }
+        // The direction of rectilinear infill turns by 90 degrees from layer to layer.
+        let first = direction(&layers[0].infill[0]);
+        let second = direction(&layers[1].infill[0]);
+        assert!(
+            first.dot(second).abs() < 1e-9,
+            "Expected perpendicular layers"
+        );
+
+        // Grid infill has lines in both directions on every layer.
+        let pattern = InfillPattern::Grid {
+            spacing: 0.5,
+            angle: 0.0,
+        };
+        let layers = slice_with_infill(&cube, Point::unit_z(), 1.0, &pattern);
+        for layer in layers.iter() {
+            assert_eq!(
+                layer.infill.len(),
+                8,
+                "Expected four lines in each direction"
+            );
+            let first = direction(&layer.infill[0]);
+            let parallel = layer
+                .infill
+                .iter()
+                .filter(|e| direction(e).dot(first).abs() > 1.0 - 1e-9)
+                .count();
+            assert_eq!(parallel, 4);
+            assert!(layer
+                .infill
+                .iter()
+                .all(|e| e.curve.on_curve(e.get_midpoint())));
+        }
+    }
+}
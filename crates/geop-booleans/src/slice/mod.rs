@@ -0,0 +1,2 @@
+pub mod infill;
+pub mod section;
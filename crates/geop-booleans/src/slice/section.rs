@@ -0,0 +1,220 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    points::point::Point,
+    surface_surface_intersection::surface_surface::{
+        surface_surface_intersection, FaceSurfaceIntersection,
+    },
+    surfaces::{plane::Plane, surface::Surface, SurfaceLike},
+};
+use geop_topology::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    measure::mass::measure_area,
+    topology::{
+        contour::Contour,
+        edge::Edge,
+        face::{bounds::face_sample_points, Face},
+        volume::Volume,
+    },
+};
+
+use crate::intersections::face_edge::face_edge_clip;
+
+// A plane with the given normal through the origin. The basis of the plane is chosen such that its normal is exactly the given normal.
+pub fn section_plane(origin: Point, normal: Point) -> Plane {
+    let normal = normal.normalize();
+    let u = match Point::unit_x().cross(normal).norm_sq() > Point::unit_y().cross(normal).norm_sq()
+    {
+        true => Point::unit_x().cross(normal).normalize(),
+        false => Point::unit_y().cross(normal).normalize(),
+    };
+    Plane::new(origin, u, normal.cross(u))
+}
+
+// The segments where the faces of the volume cross the plane. They are oriented such that the material is on their left,
+// when looking against the normal of the plane.
+fn section_segments(volume: &Volume, plane: &Plane) -> Vec<Edge> {
+    let plane_surface = Surface::Plane(plane.clone());
+    let normal = plane_surface.normal(plane.basis);
+    let mut segments = Vec::<Edge>::new();
+    for face in volume.all_faces() {
+        let face_normal = match &*face.surface {
+            Surface::Plane(face_plane) => face.surface.normal(face_plane.basis),
+            _ => todo!("Sections of curved faces"),
+        };
+        let curves = match surface_surface_intersection(&face.surface, &plane_surface) {
+            FaceSurfaceIntersection::CurvesAndPoints(curves, _) => curves,
+            // Faces in the plane are bounded by the segments of their neighbors.
+            FaceSurfaceIntersection::Surface(_) | FaceSurfaceIntersection::None => continue,
+        };
+        let direction = normal.cross(face_normal);
+        for curve in curves {
+            for segment in face_edge_clip(&face, &Edge::new(None, None, curve)) {
+                let (start, end) = (segment.start.unwrap(), segment.end.unwrap());
+                match (end - start).dot(direction) > 0.0 {
+                    true => segments.push(segment),
+                    false => segments.push(segment.flip()),
+                }
+            }
+        }
+    }
+    segments
+}
+
+// Chains oriented segments into closed loops.
+fn chain_loops(mut segments: Vec<Edge>) -> Vec<Contour> {
+    let mut loops = Vec::<Contour>::new();
+    while let Some(first) = segments.pop() {
+        let start = first.start.unwrap();
+        let mut edges = vec![first];
+        loop {
+            let end = edges[edges.len() - 1].end.unwrap();
+            if end == start {
+                break;
+            }
+            let next = segments
+                .iter()
+                .position(|s| s.start == Some(end))
+                .expect("Section of a closed volume has to consist of closed loops");
+            edges.push(segments.remove(next));
+        }
+        loops.push(Contour::new(edges));
+    }
+    loops
+}
+
+// Merges consecutive segments on the same line, which appear where the plane crosses an edge between two faces that are split.
+fn merge_collinear(contour: Contour) -> Contour {
+    let mut edges = Vec::<Edge>::new();
+    for edge in contour.edges {
+        if let Some(last) = edges.last() {
+            if last.curve == edge.curve {
+                let merged = Edge::new(last.start, edge.end, last.curve.clone());
+                edges.pop();
+                edges.push(merged);
+                continue;
+            }
+        }
+        edges.push(edge);
+    }
+    if edges.len() > 1 && edges[0].curve == edges[edges.len() - 1].curve {
+        let last = edges.pop().unwrap();
+        edges[0] = Edge::new(last.start, edges[0].end, last.curve);
+    }
+    Contour::new(edges)
+}
+
+// Cross section of a volume with a plane, as faces on the plane. Holes of the section, e.g. from through holes, are holes of the faces.
+// The plane should not contain faces of the volume. Only planar faces are supported for now.
+pub fn volume_plane_section(volume: &Volume, plane: &Plane) -> Vec<Face> {
+    let surface = Rc::new(Surface::Plane(plane.clone()));
+    let loops: Vec<Contour> = chain_loops(section_segments(volume, plane))
+        .into_iter()
+        .map(merge_collinear)
+        .collect();
+
+    // Outer loops run counterclockwise around the normal, holes clockwise.
+    let (outers, holes): (Vec<_>, Vec<_>) = loops
+        .into_iter()
+        .map(|c| {
+            let area = measure_area(&Face::new(Some(c.clone()), vec![], surface.clone()));
+            (c, area)
+        })
+        .partition(|(_, area)| *area > 0.0);
+
+    let mut faces_holes = vec![Vec::<Contour>::new(); outers.len()];
+    for (hole, _) in holes {
+        let p = hole.edges[0].get_midpoint();
+        let outer = outers
+            .iter()
+            .enumerate()
+            .filter(|(_, (outer, _))| {
+                face_point_contains(&Face::new(Some(outer.clone()), vec![], surface.clone()), p)
+                    == FacePointContains::Inside
+            })
+            .min_by(|a, b| a.1 .1.total_cmp(&b.1 .1))
+            .expect("Hole is not inside of any section loop");
+        faces_holes[outer.0].push(hole);
+    }
+    outers
+        .into_iter()
+        .zip(faces_holes)
+        .map(|((outer, _), holes)| Face::new(Some(outer), holes, surface.clone()))
+        .collect()
+}
+
+// A cross section at a given height along the slicing direction.
+#[derive(Debug, Clone)]
+pub struct Layer {
+    pub height: f64,
+    pub faces: Vec<Face>,
+}
+
+// Slices the volume into layers of the given thickness along the direction. Each layer is sectioned in its middle,
+// so that faces perpendicular to the direction at multiples of the layer height are never hit exactly.
+pub fn slice_layers(volume: &Volume, direction: Point, layer_height: f64) -> Vec<Layer> {
+    let direction = direction.normalize();
+    let heights: Vec<f64> = volume
+        .all_faces()
+        .iter()
+        .flat_map(face_sample_points)
+        .map(|p| p.dot(direction))
+        .collect();
+    let min = heights.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = heights.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let count = ((max - min) / layer_height).ceil() as usize;
+    (0..count)
+        .map(|i| {
+            let height = min + (i as f64 + 0.5) * layer_height;
+            let plane = section_plane(direction * height, direction);
+            Layer {
+                height,
+                faces: volume_plane_section(volume, &plane),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_topology::{
+        measure::mass::measure_area, primitive_objects::volumes::cube::primitive_cube,
+    };
+
+    use crate::difference::volume::cut;
+
+    use super::*;
+
+    #[test]
+    fn test_volume_plane_section() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let drilled = cut(&cube, &[primitive_cube(1.0, 1.0, 4.0)]).remove(0);
+
+        // The section through the drilled cube is a square with a square hole.
+        let plane = section_plane(Point::new(0.0, 0.0, 0.25), Point::unit_z());
+        let faces = volume_plane_section(&drilled, &plane);
+        assert_eq!(faces.len(), 1, "Expected one section");
+        assert_eq!(faces[0].holes.len(), 1, "Expected the hole of the drill");
+        assert!((measure_area(&faces[0]) - 3.0).abs() < 1e-6);
+        assert!(
+            (faces[0].normal(faces[0].inner_point()) - Point::unit_z()).norm() < 1e-9,
+            "Expected the section to face along the normal of the plane"
+        );
+
+        // A plane that misses the volume has no section.
+        let plane = section_plane(Point::new(0.0, 0.0, 5.0), Point::unit_z());
+        assert!(volume_plane_section(&cube, &plane).is_empty());
+
+        // Layers are sectioned in their middle.
+        let layers = slice_layers(&cube, Point::unit_z(), 0.5);
+        assert_eq!(layers.len(), 4, "Expected four layers");
+        for (layer, height) in layers.iter().zip([-0.75, -0.25, 0.25, 0.75]) {
+            assert!(
+                (layer.height - height).abs() < 1e-9,
+                "Expected layer at {height}"
+            );
+            assert_eq!(layer.faces.len(), 1);
+            assert!((measure_area(&layer.faces[0]) - 4.0).abs() < 1e-6);
+        }
+    }
+}
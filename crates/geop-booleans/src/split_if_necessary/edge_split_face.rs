@@ -3,6 +3,8 @@
 //     split_if_necessary::point_split_edge::split_contours_by_points_if_necessary,
 // };
 
+use super::face_regions::split_face_by_edges;
+
 use geop_topology::{
     contains::{
         // contour_point::contour_point_contains,
@@ -14,82 +16,7 @@ use geop_topology::{
 
 pub fn split_face_by_edge_if_necessary(face: &Face, edge: &Edge) -> Vec<Face> {
     match face_edge_contains(face, edge) {
-        FaceEdgeContains::Inside => {
-            todo!()
-            // let split_points = vec![edge.start.clone(), edge.end.clone()]
-            //     .drain(..)
-            //     .filter_map(|p| p)
-            //     .collect();
-
-            // let mut contours = face.holes.clone();
-            // contours.push(face.boundary.clone());
-            // let contours = split_contours_by_points_if_necessary(contours, &split_points);
-            // let start_contour =
-            //     contours
-            //         .iter()
-            //         .find(|&c| match contour_point_contains(c, edge.start) {
-            //             EdgePointContains::OnPoint(_) => true,
-            //             _ => false,
-            //         });
-            // let end_contour =
-            //     contours
-            //         .iter()
-            //         .find(|&c| match contour_point_contains(c, edge.end) {
-            //             EdgePointContains::OnPoint(_) => true,
-            //             _ => false,
-            //         });
-            // let mut new_contours = Vec::<Contour>::new();
-            // match start_contour {
-            //     Option::Some(start_contour) => match end_contour {
-            //         Option::Some(end_contour) => {
-            //             if std::ptr::eq(start_contour, end_contour) {
-            //                 // Make it 2 contours
-            //                 let mut edges = start_contour.get_subcurve(edge.end, edge.start);
-            //                 edges.push(edge.clone());
-            //                 new_contours.push(Contour::new(edges));
-            //                 let mut edges = start_contour.get_subcurve(edge.start, edge.end);
-            //                 edges.push(edge.flip());
-            //                 new_contours.push(Contour::new(edges));
-            //                 // Push the rest of the contours
-            //                 for contour in contours.iter() {
-            //                     if !std::ptr::eq(contour, start_contour) {
-            //                         new_contours.push(contour.clone());
-            //                     }
-            //                 }
-            //             } else {
-            //                 // Make it 1 contour
-            //                 let mut edges = start_contour.get_subcurve_single_point(edge.start);
-            //                 edges.push(edge.clone());
-            //                 edges.extend(end_contour.get_subcurve_single_point(edge.end));
-            //                 edges.push(edge.flip());
-            //                 new_contours.push(Contour::new(edges));
-            //             }
-            //         }
-            //         Option::None => {
-            //             // Make it 1 contour
-            //             let mut edges = start_contour.get_subcurve_single_point(edge.start);
-            //             edges.push(edge.clone());
-            //             edges.push(edge.flip());
-            //             new_contours.push(Contour::new(edges));
-            //         }
-            //     },
-            //     Option::None => match end_contour {
-            //         Option::Some(end_contour) => {
-            //             // Make it 1 contour
-            //             let mut edges = end_contour.get_subcurve_single_point(edge.end);
-            //             edges.push(edge.clone());
-            //             edges.push(edge.flip());
-            //             new_contours.push(Contour::new(edges));
-            //         }
-            //         Option::None => {
-            //             // Make it 1 contour
-            //             let edges = vec![edge.clone(), edge.flip()];
-            //             new_contours.push(Contour::new(edges));
-            //         }
-            //     },
-            // }
-            // return normalize_faces(new_contours, face.surface.clone());
-        }
+        FaceEdgeContains::Inside => split_face_by_edges(face, std::slice::from_ref(edge)),
         FaceEdgeContains::Outside => {
             vec![face.clone()]
         }
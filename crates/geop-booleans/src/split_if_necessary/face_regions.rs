@@ -0,0 +1,311 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{points::point::Point, surfaces::SurfaceLike, EQ_THRESHOLD};
+use geop_topology::{
+    contains::{
+        face_edge::{face_edge_contains, FaceEdgeContains},
+        face_point::{face_point_contains, FacePointContains},
+    },
+    topology::{contour::Contour, edge::Edge, face::Face},
+};
+
+use crate::{
+    intersections::edge_edge::{edge_edge_intersection, EdgeEdgeIntersection},
+    split_if_necessary::point_split_edge::split_edges_by_points_if_necessary,
+};
+
+fn edge_edge_points(a: &Edge, b: &Edge) -> Vec<Point> {
+    match edge_edge_intersection(a, b) {
+        EdgeEdgeIntersection::Points(points) => points,
+        EdgeEdgeIntersection::Edges(edges) => edges
+            .iter()
+            .flat_map(|e| [e.start, e.end])
+            .flatten()
+            .collect(),
+        EdgeEdgeIntersection::None => Vec::new(),
+    }
+}
+
+fn same_edge(a: &Edge, b: &Edge) -> bool {
+    a.curve == b.curve && a.start == b.start && a.end == b.end
+}
+
+// Removes cut edges that end in the middle of the face, since they do not separate any regions.
+fn remove_dangling_edges(boundary: &[Edge], mut cuts: Vec<Edge>) -> Vec<Edge> {
+    loop {
+        let degree = |p: Point| {
+            boundary
+                .iter()
+                .chain(cuts.iter())
+                .flat_map(|e| [e.start, e.end])
+                .filter(|q| *q == Some(p))
+                .count()
+        };
+        let dangling = cuts.iter().position(|e| {
+            [e.start, e.end]
+                .into_iter()
+                .flatten()
+                .any(|p| degree(p) < 2)
+        });
+        match dangling {
+            Some(i) => {
+                cuts.remove(i);
+            }
+            None => return cuts,
+        }
+    }
+}
+
+// Walks along the half edges and always takes the sharpest left turn, which traces the boundaries of the regions on the left of the edges.
+fn trace_cycles(face: &Face, half_edges: &[Edge]) -> Vec<Contour> {
+    let mut used = vec![false; half_edges.len()];
+    let mut cycles = Vec::<Contour>::new();
+    for first in 0..half_edges.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut cycle = vec![half_edges[first].clone()];
+        if half_edges[first].start.is_none() {
+            // Closed edges without vertices form a cycle on their own.
+            cycles.push(Contour::new(cycle));
+            continue;
+        }
+        loop {
+            let incoming = &cycle[cycle.len() - 1];
+            let v = incoming.end.unwrap();
+            let normal = face.surface.normal(v);
+            let t_in = incoming.tangent(v);
+            let next = (0..half_edges.len())
+                .filter(|i| half_edges[*i].start == Some(v))
+                .map(|i| {
+                    let outgoing = &half_edges[i];
+                    let mut turn = t_in.angle2(outgoing.tangent(v), normal);
+                    // Going back the same way is the last resort.
+                    if turn > PI - EQ_THRESHOLD || same_edge(outgoing, &incoming.flip()) {
+                        turn = -PI;
+                    }
+                    (i, turn)
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .expect("Half edges have to form closed cycles")
+                .0;
+            if next == first {
+                break;
+            }
+            assert!(!used[next], "Half edge is used by two regions");
+            used[next] = true;
+            cycle.push(half_edges[next].clone());
+        }
+        cycles.push(Contour::new(cycle));
+    }
+    cycles
+}
+
+fn contour_point(contour: &Contour) -> Point {
+    contour.edges[0].get_midpoint()
+}
+
+fn contour_face(face: &Face, contour: &Contour) -> Face {
+    Face::new(Some(contour.clone()), vec![], face.surface.clone())
+}
+
+// Points just outside of the boundary of the face. The face is on the left of its boundary, so they are found by stepping to the right
+// of the boundary edges. They are only needed if the point grid of the surface does not reach beyond the face, e.g. for large faces.
+fn points_outside_boundary(face: &Face) -> Vec<Point> {
+    let mut points = Vec::<Point>::new();
+    for edge in face.boundary.iter().flat_map(|c| c.edges.iter()) {
+        let m = edge.get_midpoint();
+        let right = edge.tangent(m).cross(face.surface.normal(m)).normalize();
+        for step in [1e-1, 1e-3, 1e-5] {
+            points.push(face.surface.exp(m, right * step));
+        }
+    }
+    points
+}
+
+// Partitions a face into regions by a set of edges on its surface. This is a generalization of imprinting a single edge.
+// The edges may intersect each other and the boundary of the face. Parts of the edges outside of the face or on its boundary are ignored,
+// as are edges that end in the middle of the face, since they do not separate anything.
+pub fn split_face_by_edges(face: &Face, edges: &[Edge]) -> Vec<Face> {
+    let boundary = face.all_edges();
+
+    let mut points = Vec::<Point>::new();
+    for (i, edge) in edges.iter().enumerate() {
+        points.extend(edge.start);
+        points.extend(edge.end);
+        for other in boundary.iter().chain(edges[i + 1..].iter()) {
+            points.extend(edge_edge_points(edge, other));
+        }
+    }
+
+    let boundary = split_edges_by_points_if_necessary(boundary, &points);
+    let mut cuts = Vec::<Edge>::new();
+    for edge in split_edges_by_points_if_necessary(edges.to_vec(), &points) {
+        if face_edge_contains(face, &edge) != FaceEdgeContains::Inside {
+            continue;
+        }
+        if cuts
+            .iter()
+            .any(|c| same_edge(c, &edge) || same_edge(c, &edge.flip()))
+        {
+            continue;
+        }
+        cuts.push(edge);
+    }
+    let cuts = remove_dangling_edges(&boundary, cuts);
+    if cuts.is_empty() {
+        return vec![face.clone()];
+    }
+
+    let mut half_edges = boundary;
+    for cut in cuts {
+        half_edges.push(cut.flip());
+        half_edges.push(cut);
+    }
+    let cycles = trace_cycles(face, &half_edges);
+
+    // Every cycle either bounds a region, or it runs clockwise around an island of edges and is a hole of the region around it.
+    // A hole contour as a face boundary describes everything outside of it, so it contains the points outside of the original boundary.
+    // A face without boundary, like a whole sphere, has no outside. A point of it that is not on any cycle takes its place, and the
+    // region around it is bounded only by cycles that are holes in this sense.
+    let outer = Face::new(face.boundary.clone(), vec![], face.surface.clone());
+    let reference = face
+        .surface
+        .point_grid(1.0)
+        .into_iter()
+        .chain(points_outside_boundary(face))
+        .find(|p| match face.boundary {
+            Some(_) => face_point_contains(&outer, *p) == FacePointContains::Outside,
+            None => cycles.iter().all(|c| {
+                !matches!(
+                    face_point_contains(&contour_face(face, c), *p),
+                    FacePointContains::OnEdge(_) | FacePointContains::OnPoint(_)
+                )
+            }),
+        });
+    let (holes, regions): (Vec<Contour>, Vec<Contour>) = cycles.into_iter().partition(|c| {
+        reference.is_some_and(|r| {
+            face_point_contains(&contour_face(face, c), r) == FacePointContains::Inside
+        })
+    });
+
+    let mut region_holes = vec![Vec::<Contour>::new(); regions.len()];
+    let mut around_reference = Vec::<Contour>::new();
+    for hole in holes {
+        let p = contour_point(&hole);
+        let candidates: Vec<usize> = (0..regions.len())
+            .filter(|i| {
+                face_point_contains(&contour_face(face, &regions[*i]), p)
+                    == FacePointContains::Inside
+            })
+            .collect();
+        // The innermost candidate is inside of all other candidates.
+        let innermost = candidates.iter().find(|i| {
+            let q = contour_point(&regions[**i]);
+            candidates.iter().all(|j| {
+                j == *i
+                    || face_point_contains(&contour_face(face, &regions[*j]), q)
+                        == FacePointContains::Inside
+            })
+        });
+        match (innermost, &face.boundary) {
+            (Some(i), _) => region_holes[*i].push(hole),
+            (None, None) => around_reference.push(hole),
+            (None, Some(_)) => panic!("Hole is not inside of any region"),
+        }
+    }
+
+    let mut result: Vec<Face> = regions
+        .into_iter()
+        .zip(region_holes)
+        .map(|(boundary, holes)| Face::new(Some(boundary), holes, face.surface.clone()))
+        .collect();
+    if !around_reference.is_empty() {
+        let boundary = around_reference.remove(0);
+        result.push(Face::new(
+            Some(boundary),
+            around_reference,
+            face.surface.clone(),
+        ));
+    }
+    result
+}
+
+// Imprints a face that lies on the surface of another face, like a sketch wrapped onto it with geop_topology::operations::wrap,
+// such that the region it covers becomes a face of its own.
+pub fn imprint_face(face: &Face, imprint: &Face) -> Vec<Face> {
+    split_face_by_edges(face, &imprint.all_edges())
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::surfaces::plane::Plane;
+    use geop_topology::{
+        measure::mass::measure_area, primitive_objects::edges::line::primitive_line,
+    };
+
+    use super::*;
+
+    fn square(center: Point, size: f64) -> Vec<Edge> {
+        let h = size / 2.0;
+        let corners = [
+            center + Point::new(-h, -h, 0.0),
+            center + Point::new(h, -h, 0.0),
+            center + Point::new(h, h, 0.0),
+            center + Point::new(-h, h, 0.0),
+        ];
+        (0..4)
+            .map(|i| primitive_line(corners[i], corners[(i + 1) % 4]))
+            .collect()
+    }
+
+    #[test]
+    fn test_split_face_by_edges() {
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let face = Face::rectangle(&plane, 4.0, 4.0);
+
+        // A line across the face splits it in two halves.
+        let line = primitive_line(Point::new(-3.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0));
+        let halves = split_face_by_edges(&face, std::slice::from_ref(&line));
+        assert_eq!(halves.len(), 2, "Expected two halves");
+        for half in halves.iter() {
+            assert!(
+                (measure_area(half) - 8.0).abs() < 1e-6,
+                "Expected half of the area"
+            );
+            assert!(half.holes.is_empty(), "Halves have no holes");
+        }
+
+        // A dangling edge does not split anything.
+        let dangling = primitive_line(Point::new(-3.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0));
+        assert_eq!(split_face_by_edges(&face, &[dangling]).len(), 1);
+
+        // An island inside of one half becomes a face of its own and a hole of that half.
+        let mut edges = square(Point::new(0.0, 1.0, 0.0), 1.0);
+        edges.push(line);
+        let faces = split_face_by_edges(&face, &edges);
+        assert_eq!(faces.len(), 3, "Expected two halves and the island");
+        let mut areas: Vec<f64> = faces.iter().map(measure_area).collect();
+        areas.sort_by(f64::total_cmp);
+        for (area, expected) in areas.iter().zip([1.0, 7.0, 8.0]) {
+            assert!(
+                (area - expected).abs() < 1e-6,
+                "Expected area {expected}, got {area}"
+            );
+        }
+        assert_eq!(faces.iter().map(|f| f.holes.len()).sum::<usize>(), 1);
+
+        // A face larger than the point grid of the plane still finds a point outside of its boundary.
+        let large = Face::rectangle(&plane, 300.0, 300.0);
+        let faces = split_face_by_edges(&large, &square(Point::zero(), 1.0));
+        assert_eq!(faces.len(), 2, "Expected the island and the face around it");
+        let mut areas: Vec<f64> = faces.iter().map(measure_area).collect();
+        areas.sort_by(f64::total_cmp);
+        assert!((areas[0] - 1.0).abs() < 1e-6, "Expected the island");
+        assert!(
+            (areas[1] - 89999.0).abs() < 1e-6,
+            "Expected the face with a hole"
+        );
+    }
+}
@@ -1,2 +1,4 @@
 pub mod edge_split_face;
+pub mod face_regions;
 pub mod point_split_edge;
+pub mod silhouette;
@@ -8,6 +8,9 @@ use geop_topology::{
 // This operation splits an edge by a list of points.
 // If one part goes to infinity, it will be removed.
 pub fn split_edge_by_points_if_necessary(edge: &Edge, points: &[Point]) -> Vec<Edge> {
+    if edge.start.is_some() && edge.end.is_some() {
+        return edge.split_at_points(points);
+    }
     let mut result = vec![edge.clone()];
     for p in points {
         let mut new_result = Vec::<Edge>::new();
@@ -0,0 +1,93 @@
+use geop_geometry::{
+    curves::{circle::Circle, curve::Curve},
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+use geop_topology::topology::edge::Edge;
+
+// The curves on the surface where its normal is perpendicular to the direction, i.e. its outline seen along the direction. On both
+// sides of them the surface faces the other way along the direction. Only spheres are supported for now, whose silhouette is the
+// great circle around the direction.
+pub fn silhouette_edges(surface: &Surface, direction: Point) -> Vec<Edge> {
+    match surface {
+        Surface::Sphere(sphere) => vec![Edge::new(
+            None,
+            None,
+            Curve::Circle(Circle::new(sphere.basis, direction, sphere.radius)),
+        )],
+        Surface::Plane(_) | Surface::Cylinder(_) => vec![],
+    }
+}
+
+// The direction along which the silhouettes of curved faces are cut when they meet a face on the surface, which is the normal of
+// planes. A sphere face that bulges over a plane is otherwise on both sides of it, and then a single inner point does not classify
+// it.
+pub fn silhouette_direction(surface: &Surface) -> Option<Point> {
+    match surface {
+        Surface::Plane(plane) => Some(plane.normal(plane.basis)),
+        Surface::Sphere(_) | Surface::Cylinder(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::transforms::Transform;
+    use geop_topology::{
+        primitive_objects::{faces::sphere::primitive_sphere, volumes::cube::primitive_cube},
+        topology::{face::Face, shell::Shell, volume::Volume},
+    };
+
+    use crate::{
+        difference::volume::cut, recorder::RecordedOperation, union::volume::union_all,
+        verify::verify_boolean,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_silhouette_booleans() {
+        // A sphere that pokes out of the top of a box. The equator seen from above runs inside of the box.
+        let sphere = Volume::new(
+            Shell::new(vec![primitive_sphere(Point::zero(), 1.0)]),
+            vec![],
+        );
+        let cube = primitive_cube(4.0, 4.0, 4.0)
+            .transform(Transform::from_translation(Point::new(0.0, 0.0, -1.5)));
+        let operands = [cube.clone(), sphere.clone()];
+
+        let union = union_all(&operands);
+        assert_eq!(union.len(), 1);
+        let faces = union[0].all_faces();
+        assert_eq!(
+            faces.len(),
+            7,
+            "Expected the box with a hole in its top and the cap"
+        );
+        let caps: Vec<&Face> = faces
+            .iter()
+            .filter(|f| matches!(*f.surface, Surface::Sphere(_)))
+            .collect();
+        assert_eq!(caps.len(), 1);
+        let p = caps[0].inner_point();
+        assert!(p.z > 0.5);
+        assert!(caps[0].normal(p).dot(p) > 0.0);
+        verify_boolean(RecordedOperation::Union, &operands, &union, 0).unwrap();
+
+        // The dent left by the sphere is split at the equator, and both pieces face into the sphere.
+        let parts = cut(&cube, std::slice::from_ref(&sphere));
+        assert_eq!(parts.len(), 1);
+        let faces = parts[0].all_faces();
+        assert_eq!(faces.len(), 8);
+        let dents: Vec<&Face> = faces
+            .iter()
+            .filter(|f| matches!(*f.surface, Surface::Sphere(_)))
+            .collect();
+        assert_eq!(dents.len(), 2);
+        for dent in dents {
+            let p = dent.inner_point();
+            assert!(p.z < 0.5);
+            assert!(dent.normal(p).dot(p) < 0.0);
+        }
+        verify_boolean(RecordedOperation::Cut, &operands, &parts, 0).unwrap();
+    }
+}
@@ -21,6 +21,6 @@ pub fn face_face_union(face_self: &Face, face_other: &Face) -> Vec<Face> {
         })
         .collect::<Vec<FaceSplit>>();
 
-    let contours = face_remesh(edges);
+    let contours = face_remesh(edges, &face_self.surface);
     return normalize_faces(contours, face_self.surface.clone());
 }
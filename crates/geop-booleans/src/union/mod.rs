@@ -1 +1,3 @@
 pub mod face;
+pub mod shell;
+pub mod volume;
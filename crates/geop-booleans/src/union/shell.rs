@@ -0,0 +1,106 @@
+use std::fmt::{Display, Formatter};
+
+use geop_geometry::surfaces::SurfaceLike;
+use geop_topology::topology::{edge::Edge, face::Face, shell::Shell};
+
+use crate::{
+    intersections::face_face::{face_face_intersection, FaceFaceIntersection},
+    split_if_necessary::edge_split_face::split_face_by_edges_if_necessary,
+};
+
+#[derive(Debug, Clone)]
+pub enum ShellUnionError {
+    NoIntersection,       // The faces do not intersect along an edge
+    NotCutThrough(usize), // The intersection ends inside of the face, 0 for face_a and 1 for face_b, so it does not split the face
+}
+
+impl Display for ShellUnionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShellUnionError::NoIntersection => write!(f, "Faces do not intersect along an edge"),
+            ShellUnionError::NotCutThrough(i) => {
+                write!(f, "Intersection does not cut through face {}", i)
+            }
+        }
+    }
+}
+
+// Whether the face is on the side of the surface of the other face that its normal points to.
+fn in_front_of(face: &Face, other: &Face) -> bool {
+    let p = face.inner_point();
+    let q = other.surface.project(p);
+    (p - q).dot(other.surface.normal(q)) > 0.0
+}
+
+// Union of two faces on different surfaces that cross each other, like the walls of two solids meeting along an edge. Both faces
+// are split along their intersection, and of each face the pieces in front of the surface of the other face are kept, i.e. the
+// boundary of the union of the regions behind the faces. The kept pieces run along the intersection edges in opposite directions, so
+// they form a connected shell, which is open where the faces end. The intersection has to cut through both faces, since edges that
+// end in the middle of a face do not split it, and the union is an error otherwise.
+pub fn shell_union(face_a: &Face, face_b: &Face) -> Result<Shell, ShellUnionError> {
+    assert!(
+        face_a.surface != face_b.surface,
+        "Faces on the same surface are merged by face_face_union"
+    );
+    let edges: Vec<Edge> = match face_face_intersection(face_a, face_b) {
+        FaceFaceIntersection::EdgesAndPoints(_, edges) => edges,
+        FaceFaceIntersection::None | FaceFaceIntersection::Faces(_) => vec![],
+    };
+    if edges.is_empty() {
+        return Err(ShellUnionError::NoIntersection);
+    }
+
+    let mut faces = Vec::<Face>::new();
+    for (i, (face, other)) in [(face_a, face_b), (face_b, face_a)].into_iter().enumerate() {
+        let pieces = split_face_by_edges_if_necessary(face, &edges);
+        if pieces.len() < 2 {
+            return Err(ShellUnionError::NotCutThrough(i));
+        }
+        faces.extend(pieces.into_iter().filter(|piece| in_front_of(piece, other)));
+    }
+    Ok(Shell::new(faces))
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, surfaces::plane::Plane};
+
+    use super::*;
+
+    #[test]
+    fn test_shell_union() {
+        let floor = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let wall = Plane::new(Point::zero(), Point::unit_y(), Point::unit_z());
+        let face_a = Face::rectangle(&floor, 2.0, 2.0);
+        let face_b = Face::rectangle(&wall, 2.0, 2.0);
+
+        // Of both faces, the halves in front of the other face are kept, and they meet along the intersection.
+        let shell = shell_union(&face_a, &face_b).unwrap();
+        assert_eq!(shell.faces.len(), 2);
+        for face in shell.faces.iter() {
+            let p = face.inner_point();
+            assert!(
+                p.x > -1e-9 && p.z > -1e-9,
+                "Expected {:?} in front of both faces",
+                p
+            );
+            assert!(face.all_points().contains(&Point::new(0.0, 1.0, 0.0)));
+            assert!(face.all_points().contains(&Point::new(0.0, -1.0, 0.0)));
+        }
+
+        // The intersection ends inside of the larger face, so it cannot be split.
+        let raised = Plane::new(Point::new(0.0, 0.0, 0.5), Point::unit_x(), Point::unit_y());
+        let large = Face::rectangle(&raised, 4.0, 4.0);
+        assert!(matches!(
+            shell_union(&large, &face_b),
+            Err(ShellUnionError::NotCutThrough(0))
+        ));
+
+        // Faces apart from each other do not intersect.
+        let far = Plane::new(Point::new(0.0, 0.0, 5.0), Point::unit_x(), Point::unit_y());
+        assert!(matches!(
+            shell_union(&Face::rectangle(&far, 2.0, 2.0), &face_b),
+            Err(ShellUnionError::NoIntersection)
+        ));
+    }
+}
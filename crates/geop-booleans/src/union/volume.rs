@@ -0,0 +1,121 @@
+use geop_topology::{
+    operations::pattern::pattern_along_curve,
+    progress::{Cancelled, Progress},
+    topology::{face::Face, non_manifold_shell::NonManifoldShell, volume::Volume, wire::Wire},
+};
+
+use crate::{
+    budget::{budget_scene, without_limits, BooleanError, BooleanLimits, Budget},
+    recorder::{recorded, RecordedOperation},
+    remesh::volume::{
+        face_volume_classify, faces_split_at_vertices, volumes_split_faces_with_limits,
+        FaceVolumeClass, SPLIT_PROGRESS,
+    },
+};
+
+// Union of any number of volumes. All operands are imprinted onto each other at once, which is faster and more robust than folding pairwise unions,
+// since intermediate results never have to be intersected again.
+// Coplanar faces that face the same way are kept only once. The result may consist of several disconnected volumes.
+pub fn union_all(volumes: &[Volume]) -> Vec<Volume> {
+    union_all_with_progress(volumes, &Progress::none())
+        .expect("Operations without cancellation token are not cancelled")
+}
+
+// The copies of pattern_along_curve, merged where they overlap.
+pub fn union_pattern_along_curve(
+    volume: &Volume,
+    wire: &Wire,
+    count: usize,
+    align_to_tangent: bool,
+) -> Vec<Volume> {
+    union_all(&pattern_along_curve(volume, wire, count, align_to_tangent))
+}
+
+// Like union_all, reporting the progress and stopping early when the operation is cancelled.
+pub fn union_all_with_progress(
+    volumes: &[Volume],
+    progress: &Progress,
+) -> Result<Vec<Volume>, Cancelled> {
+    without_limits(union_all_with_limits(
+        volumes,
+        progress,
+        &BooleanLimits::unlimited(),
+    ))
+}
+
+// Like union_all_with_progress, stopping with an error that holds partial diagnostics when one of the limits is exceeded.
+pub fn union_all_with_limits(
+    volumes: &[Volume],
+    progress: &Progress,
+    limits: &BooleanLimits,
+) -> Result<Vec<Volume>, BooleanError> {
+    recorded(RecordedOperation::Union, volumes, limits, || {
+        let mut budget = Budget::new(limits);
+        let splits =
+            volumes_split_faces_with_limits(volumes, progress, &mut budget, 0.0, SPLIT_PROGRESS)?;
+        let total: usize = splits.iter().map(|s| s.len()).sum();
+        let mut done = 0;
+        let mut faces = Vec::<Face>::new();
+        for (k, split) in splits.into_iter().enumerate() {
+            for face in split {
+                progress.report_step(SPLIT_PROGRESS, 1.0, done, total)?;
+                done += 1;
+                budget
+                    .check_time()
+                    .map_err(|limit| budget.exceeded(limit, budget_scene(&[&face], [].iter())))?;
+                let keep = volumes.iter().enumerate().all(|(m, volume)| {
+                    m == k
+                        || match face_volume_classify(&face, volume) {
+                            FaceVolumeClass::Outside => true,
+                            FaceVolumeClass::OnSameSide => k < m,
+                            FaceVolumeClass::Inside => false,
+                            FaceVolumeClass::OnOppositeSide => false,
+                        }
+                });
+                if keep {
+                    faces.push(face);
+                }
+            }
+        }
+        let volumes = NonManifoldShell::new(faces_split_at_vertices(faces)).to_volumes();
+        progress.report(1.0)?;
+        Ok(volumes)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform};
+    use geop_topology::{
+        measure::mass::measure_volume, primitive_objects::volumes::cube::primitive_cube,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_union_all() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let moved = |x: f64| cube.transform(Transform::from_translation(Point::new(x, 0.0, 0.0)));
+
+        // A chain of overlapping cubes becomes one box, a cube far away stays a volume of its own.
+        let volumes = union_all(&[cube.clone(), moved(1.0), moved(2.0), moved(10.0)]);
+        assert_eq!(volumes.len(), 2, "Expected the chain and the separate cube");
+        let mut sizes: Vec<f64> = volumes.iter().map(measure_volume).collect();
+        sizes.sort_by(f64::total_cmp);
+        assert!(
+            (sizes[0] - 8.0).abs() < 1e-6,
+            "Expected the separate cube, got {}",
+            sizes[0]
+        );
+        assert!(
+            (sizes[1] - 16.0).abs() < 1e-6,
+            "Expected the chain, got {}",
+            sizes[1]
+        );
+
+        // The union of a single volume is the volume itself.
+        let volumes = union_all(std::slice::from_ref(&cube));
+        assert_eq!(volumes.len(), 1);
+        assert!((measure_volume(&volumes[0]) - 8.0).abs() < 1e-6);
+    }
+}
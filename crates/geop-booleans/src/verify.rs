@@ -0,0 +1,219 @@
+use std::fmt::Display;
+
+use geop_geometry::points::point::Point;
+use geop_topology::{
+    contains::volume_point::{volume_point_contains, VolumePointContains},
+    rng::Rng,
+    topology::{bounded::Bounded, volume::Volume},
+};
+
+use crate::recorder::RecordedOperation;
+
+// Cross-check of boolean results against the containment of sampled points in the operands, i.e. a Monte Carlo estimate of the
+// inclusion-exclusion of the operand volumes. Every sample that is inside of the result has to be inside of the operands as the
+// operation says, e.g. inside of any operand for a union. The check runs after every boolean when the crate is built with the
+// `verify` feature, and a violation panics with a recording of the inputs.
+
+// Number of points sampled in the bounding box of the operands.
+pub const VERIFY_SAMPLES: usize = 512;
+
+// Fraction of the samples that may be classified differently, for points within the tolerance of faces that containment puts on
+// different sides.
+pub const VERIFY_TOLERANCE: f64 = 0.005;
+
+// Results of booleans as the volumes they cover.
+pub trait BooleanResult {
+    fn result_volumes(&self) -> Vec<Volume>;
+}
+
+impl BooleanResult for Vec<Volume> {
+    fn result_volumes(&self) -> Vec<Volume> {
+        self.clone()
+    }
+}
+
+// The parts of a xor on both sides.
+impl BooleanResult for (Vec<Volume>, Vec<Volume>) {
+    fn result_volumes(&self) -> Vec<Volume> {
+        self.0.iter().chain(self.1.iter()).cloned().collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VerificationFailure {
+    pub samples: usize, // Samples that are not on the boundary of any volume
+    pub expected_inside: usize,
+    pub result_inside: usize,
+    pub mismatches: Vec<Point>,
+}
+
+impl Display for VerificationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} samples are misclassified, {} are inside of the result instead of {}, e.g. {:?}",
+            self.mismatches.len(),
+            self.samples,
+            self.result_inside,
+            self.expected_inside,
+            self.mismatches.first()
+        )
+    }
+}
+
+// Whether the point is inside, or None if it is on the boundary.
+fn inside(volumes: &[Volume], p: Point) -> Option<bool> {
+    let mut inside = false;
+    for volume in volumes {
+        match volume_point_contains(volume, p) {
+            VolumePointContains::Inside => inside = true,
+            VolumePointContains::Outside => {}
+            _ => return None,
+        }
+    }
+    Some(inside)
+}
+
+fn expected(operation: RecordedOperation, operands: &[bool]) -> bool {
+    match operation {
+        RecordedOperation::Union => operands.iter().any(|i| *i),
+        RecordedOperation::Cut | RecordedOperation::Difference => {
+            operands[0] && !operands[1..].iter().any(|i| *i)
+        }
+        RecordedOperation::Xor => operands[0] != operands[1],
+    }
+}
+
+// Samples points uniformly in the bounding box of the operands, and compares the containment in the result with the one the
+// operation gives from the containment in the operands. Samples on the boundary of any of the volumes are skipped.
+pub fn verify_boolean(
+    operation: RecordedOperation,
+    operands: &[Volume],
+    result: &[Volume],
+    seed: u64,
+) -> Result<(), VerificationFailure> {
+    if operands.is_empty() {
+        return Ok(());
+    }
+    let mut bounding_box = operands[0].bounding_box();
+    for operand in operands[1..].iter() {
+        let other = operand.bounding_box();
+        bounding_box.add_point(other.min);
+        bounding_box.add_point(other.max);
+    }
+
+    let mut rng = Rng::new(seed);
+    let mut failure = VerificationFailure {
+        samples: 0,
+        expected_inside: 0,
+        result_inside: 0,
+        mismatches: Vec::new(),
+    };
+    for _ in 0..VERIFY_SAMPLES {
+        let (min, max) = (bounding_box.min, bounding_box.max);
+        let p = Point::new(
+            rng.range(min.x, max.x),
+            rng.range(min.y, max.y),
+            rng.range(min.z, max.z),
+        );
+        let in_operands: Option<Vec<bool>> = operands
+            .iter()
+            .map(|o| inside(std::slice::from_ref(o), p))
+            .collect();
+        let (in_operands, in_result) = match (in_operands, inside(result, p)) {
+            (Some(in_operands), Some(in_result)) => (in_operands, in_result),
+            _ => continue,
+        };
+        let should = expected(operation, &in_operands);
+        failure.samples += 1;
+        failure.expected_inside += should as usize;
+        failure.result_inside += in_result as usize;
+        if should != in_result {
+            failure.mismatches.push(p);
+        }
+    }
+    match failure.mismatches.len() as f64 > VERIFY_TOLERANCE * failure.samples as f64 {
+        true => Err(failure),
+        false => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::transforms::Transform;
+    use geop_topology::primitive_objects::volumes::cube::primitive_cube;
+
+    use crate::{difference::volume::cut, union::volume::union_all};
+
+    use super::*;
+
+    fn operands() -> [Volume; 2] {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let shifted = cube.transform(Transform::from_translation(Point::new(1.0, 0.5, 0.25)));
+        [cube, shifted]
+    }
+
+    #[test]
+    fn test_verify_boolean() {
+        let operands = operands();
+        let union = union_all(&operands);
+        let parts = cut(&operands[0], &operands[1..]);
+        assert!(verify_boolean(RecordedOperation::Union, &operands, &union, 0).is_ok());
+        assert!(verify_boolean(RecordedOperation::Cut, &operands, &parts, 0).is_ok());
+
+        // The union is not a valid result of the cut, since it also covers the tool.
+        let failure = verify_boolean(RecordedOperation::Cut, &operands, &union, 0).unwrap_err();
+        assert!(failure.mismatches.len() as f64 > VERIFY_TOLERANCE * failure.samples as f64);
+        assert!(failure.result_inside > failure.expected_inside);
+        assert!(
+            failure.samples > VERIFY_SAMPLES / 2,
+            "Expected few samples on boundaries"
+        );
+    }
+
+    // With the feature, a boolean whose result fails the check panics with the path of a recording of its inputs.
+    #[cfg(feature = "verify")]
+    #[test]
+    fn test_verify_recording() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        use geop_topology::topology::binary::volume_to_bytes;
+
+        use crate::{
+            budget::BooleanLimits,
+            recorder::{recorded, recording_from_bytes},
+        };
+
+        let operands = operands();
+        let limits = BooleanLimits::unlimited();
+        let payload = catch_unwind(AssertUnwindSafe(|| {
+            recorded(RecordedOperation::Union, &operands, &limits, || {
+                Ok(vec![operands[0].clone()])
+            })
+        }))
+        .unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("Result of union fails verification"));
+
+        // The message ends with the path as Some("...").
+        let path = message
+            .split("Some(\"")
+            .nth(1)
+            .and_then(|rest| rest.strip_suffix("\")"))
+            .expect("Expected the path of the recording");
+        let recording = recording_from_bytes(&std::fs::read(path).unwrap()).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(recording.operation, RecordedOperation::Union);
+        assert_eq!(recording.operands.len(), 2);
+        assert_eq!(
+            volume_to_bytes(&recording.operands[1]),
+            volume_to_bytes(&operands[1])
+        );
+
+        // Correct results pass through.
+        let union = recorded(RecordedOperation::Union, &operands, &limits, || {
+            Ok(union_all(&operands))
+        });
+        assert_eq!(union.unwrap().len(), 1);
+    }
+}
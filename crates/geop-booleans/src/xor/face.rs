@@ -0,0 +1,76 @@
+use crate::remesh::face::{face_remesh, face_split, normalize_faces, FaceSplit};
+use geop_topology::topology::face::Face;
+
+// Symmetric difference of two faces on the same surface, i.e. the parts that are covered by exactly one of the faces.
+// Both differences are selected from the same split, and remeshed separately, since they only touch at the points where the boundaries cross.
+pub fn face_xor(face_self: &Face, face_other: &Face) -> Vec<Face> {
+    assert!(
+        face_self.surface == face_other.surface,
+        "Faces must have the same surface",
+    );
+
+    let mut self_minus_other = Vec::<FaceSplit>::new();
+    let mut other_minus_self = Vec::<FaceSplit>::new();
+    for mode in face_split(face_self, face_other) {
+        match mode {
+            FaceSplit::AinB(edge) => other_minus_self.push(FaceSplit::AinB(edge.flip())),
+            FaceSplit::AonBSameSide(_) => {}
+            FaceSplit::AonBOpSide(edge) => self_minus_other.push(FaceSplit::AonBOpSide(edge)),
+            FaceSplit::AoutB(edge) => self_minus_other.push(FaceSplit::AoutB(edge)),
+            FaceSplit::BinA(edge) => self_minus_other.push(FaceSplit::BinA(edge.flip())),
+            FaceSplit::BonASameSide(_) => {}
+            FaceSplit::BonAOpSide(edge) => other_minus_self.push(FaceSplit::BonAOpSide(edge)),
+            FaceSplit::BoutA(edge) => other_minus_self.push(FaceSplit::BoutA(edge)),
+        }
+    }
+
+    let mut faces = normalize_faces(
+        face_remesh(self_minus_other, &face_self.surface),
+        face_self.surface.clone(),
+    );
+    faces.extend(normalize_faces(
+        face_remesh(other_minus_self, &face_self.surface),
+        face_self.surface.clone(),
+    ));
+    faces
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, surfaces::plane::Plane};
+    use geop_topology::measure::mass::measure_area;
+
+    use super::*;
+
+    #[test]
+    fn test_face_xor() {
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let square = Face::rectangle(&plane, 2.0, 2.0);
+        let shifted = Face::polygon(
+            &plane,
+            &[
+                Point::new(0.0, -1.0, 0.0),
+                Point::new(2.0, -1.0, 0.0),
+                Point::new(2.0, 1.0, 0.0),
+                Point::new(0.0, 1.0, 0.0),
+            ],
+        );
+
+        // The overlap of half of each square is removed from both.
+        let faces = face_xor(&square, &shifted);
+        assert_eq!(faces.len(), 2, "Expected one part of each square");
+        for face in faces.iter() {
+            assert!(
+                (measure_area(face) - 2.0).abs() < 1e-6,
+                "Expected half of a square"
+            );
+        }
+
+        // A square inside of another leaves a ring.
+        let small = Face::rectangle(&plane, 1.0, 1.0);
+        let faces = face_xor(&square, &small);
+        assert_eq!(faces.len(), 1, "Expected the ring");
+        assert_eq!(faces[0].holes.len(), 1, "Expected the hole of the ring");
+        assert!((measure_area(&faces[0]) - 3.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,122 @@
+use geop_topology::{
+    progress::{Cancelled, Progress},
+    topology::{face::Face, non_manifold_shell::NonManifoldShell, volume::Volume},
+};
+
+use crate::{
+    budget::{without_limits, BooleanError, BooleanLimits},
+    recorder::{recorded, RecordedOperation},
+    remesh::volume::{faces_split_at_vertices, volume_split_with_limits, VolumeSplit},
+};
+
+fn faces_to_volumes(faces: Vec<Face>) -> Vec<Volume> {
+    if faces.is_empty() {
+        return Vec::new();
+    }
+    NonManifoldShell::new(faces_split_at_vertices(faces)).to_volumes()
+}
+
+// Returns the two halves of the symmetric difference, self - other and other - self. They are kept apart, since they touch along the intersection curves.
+pub fn volume_xor_parts(volume_self: &Volume, volume_other: &Volume) -> (Vec<Volume>, Vec<Volume>) {
+    volume_xor_parts_with_progress(volume_self, volume_other, &Progress::none())
+        .expect("Operations without cancellation token are not cancelled")
+}
+
+// Like volume_xor_parts, reporting the progress and stopping early when the operation is cancelled.
+pub fn volume_xor_parts_with_progress(
+    volume_self: &Volume,
+    volume_other: &Volume,
+    progress: &Progress,
+) -> Result<(Vec<Volume>, Vec<Volume>), Cancelled> {
+    without_limits(volume_xor_parts_with_limits(
+        volume_self,
+        volume_other,
+        progress,
+        &BooleanLimits::unlimited(),
+    ))
+}
+
+// Like volume_xor_parts_with_progress, stopping with an error that holds partial diagnostics when one of the limits is exceeded.
+pub fn volume_xor_parts_with_limits(
+    volume_self: &Volume,
+    volume_other: &Volume,
+    progress: &Progress,
+    limits: &BooleanLimits,
+) -> Result<(Vec<Volume>, Vec<Volume>), BooleanError> {
+    recorded(
+        RecordedOperation::Xor,
+        &[volume_self.clone(), volume_other.clone()],
+        limits,
+        || {
+            let mut self_minus_other = Vec::<Face>::new();
+            let mut other_minus_self = Vec::<Face>::new();
+            for mode in volume_split_with_limits(volume_self, volume_other, progress, limits)? {
+                match mode {
+                    VolumeSplit::AinB(face) => other_minus_self.push(face.flip()),
+                    VolumeSplit::AonBSameSide(_) => {}
+                    VolumeSplit::AonBOpSide(face) => self_minus_other.push(face),
+                    VolumeSplit::AoutB(face) => self_minus_other.push(face),
+                    VolumeSplit::BinA(face) => self_minus_other.push(face.flip()),
+                    VolumeSplit::BonASameSide(_) => {}
+                    VolumeSplit::BonAOpSide(face) => other_minus_self.push(face),
+                    VolumeSplit::BoutA(face) => other_minus_self.push(face),
+                }
+            }
+
+            Ok((
+                faces_to_volumes(self_minus_other),
+                faces_to_volumes(other_minus_self),
+            ))
+        },
+    )
+}
+
+// Symmetric difference of two volumes, i.e. the material that is in exactly one of them, e.g. to find the regions that changed between two revisions of a model.
+// The volumes of self - other come first, followed by the volumes of other - self.
+pub fn volume_xor(volume_self: &Volume, volume_other: &Volume) -> Vec<Volume> {
+    let (mut volumes, other_minus_self) = volume_xor_parts(volume_self, volume_other);
+    volumes.extend(other_minus_self);
+    volumes
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform, EQ_THRESHOLD};
+    use geop_topology::{
+        measure::mass::measure_volume, primitive_objects::volumes::cube::primitive_cube,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_volume_xor() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let shifted = cube.transform(Transform::from_translation(Point::new(1.0, 0.0, 0.0)));
+
+        let (self_minus_other, other_minus_self) = volume_xor_parts(&cube, &shifted);
+        assert_eq!(
+            self_minus_other.len(),
+            1,
+            "Expected the part of the first cube"
+        );
+        assert_eq!(
+            other_minus_self.len(),
+            1,
+            "Expected the part of the second cube"
+        );
+        assert!((measure_volume(&self_minus_other[0]) - 4.0).abs() < 1e-6);
+        assert!((measure_volume(&other_minus_self[0]) - 4.0).abs() < 1e-6);
+        // The part of the first cube is the one at negative x.
+        assert!(self_minus_other[0]
+            .all_faces()
+            .iter()
+            .flat_map(|f| f.all_edges())
+            .all(|e| e.start.is_none_or(|p| p.x < EQ_THRESHOLD)));
+
+        let volumes = volume_xor(&cube, &shifted);
+        assert_eq!(volumes.len(), 2);
+
+        // Equal volumes have no symmetric difference.
+        assert!(volume_xor(&cube, &cube).is_empty(), "Expected nothing left");
+    }
+}
@@ -0,0 +1,56 @@
+use std::process::ExitCode;
+
+use geop_booleans::recorder::{recording_from_bytes, replay, ReplayOutcome};
+
+const USAGE: &str = "Usage: geop-cli replay <recording>";
+
+// Replays a failing boolean that was captured with geop_booleans::recorder, and exits with 1 if it still fails.
+fn replay_command(path: &str) -> ExitCode {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            eprintln!("Could not read {}: {}", path, error);
+            return ExitCode::from(2);
+        }
+    };
+    let recording = match recording_from_bytes(&bytes) {
+        Ok(recording) => recording,
+        Err(error) => {
+            eprintln!("Could not decode {}: {}", path, error);
+            return ExitCode::from(2);
+        }
+    };
+    println!(
+        "{:?} of {} operands, recorded with tolerance {:e} and seed {}",
+        recording.operation,
+        recording.operands.len(),
+        recording.tolerance,
+        recording.seed
+    );
+    println!("Recorded failure: {}", recording.message);
+    match replay(&recording) {
+        ReplayOutcome::Succeeded(volumes) => {
+            println!("Succeeded with {} volumes", volumes.len());
+            ExitCode::SUCCESS
+        }
+        ReplayOutcome::Failed(error) => {
+            println!("Failed: {}", error);
+            ExitCode::from(1)
+        }
+        ReplayOutcome::Panicked(message) => {
+            println!("Panicked: {}", message);
+            ExitCode::from(1)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.iter().map(|a| a.as_str()).collect::<Vec<&str>>()[..] {
+        ["replay", path] => replay_command(path),
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
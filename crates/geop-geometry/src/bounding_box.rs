@@ -43,6 +43,15 @@ impl BoundingBox {
         self.max.z = self.max.z.max(p.z);
     }
 
+    pub fn from_points(points: &[Point]) -> BoundingBox {
+        assert!(!points.is_empty());
+        let mut bounding_box = BoundingBox::new(points[0], points[0]);
+        for p in points.iter() {
+            bounding_box.add_point(*p);
+        }
+        bounding_box
+    }
+
     pub fn with_2_points(interval_self_1: Point, interval_self_2: Point) -> BoundingBox {
         let mut bounding_box = BoundingBox::new(interval_self_1, interval_self_1);
         bounding_box.add_point(interval_self_2);
@@ -0,0 +1,60 @@
+use crate::{bounding_box::BoundingBox, points::point::Point, transforms::Transform};
+
+// A sphere around a set of points. It is the cheapest bound to test against another one, but loose for elongated parts, where a
+// BoundingBox or a Kdop fits much tighter.
+#[derive(Debug, Clone)]
+pub struct BoundingSphere {
+    pub center: Point,
+    pub radius: f64,
+}
+
+impl BoundingSphere {
+    pub fn new(center: Point, radius: f64) -> BoundingSphere {
+        assert!(radius >= 0.0);
+        BoundingSphere { center, radius }
+    }
+
+    // Sphere around the center of the bounding box of the points. It is not minimal, but at most sqrt(3) times larger than the
+    // box, and cheap to compute.
+    pub fn from_points(points: &[Point]) -> BoundingSphere {
+        assert!(!points.is_empty());
+        let bounding_box = BoundingBox::from_points(points);
+        let center = (bounding_box.min + bounding_box.max) / 2.0;
+        let radius = points
+            .iter()
+            .map(|p| (*p - center).norm())
+            .fold(0.0, f64::max);
+        BoundingSphere::new(center, radius)
+    }
+
+    pub fn intersects(&self, other: &BoundingSphere, tolerance: f64) -> bool {
+        (self.center - other.center).norm() <= self.radius + other.radius + tolerance
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (p - self.center).norm() <= self.radius
+    }
+
+    // Only for rigid transforms, which keep the radius.
+    pub fn transform(&self, transform: Transform) -> BoundingSphere {
+        BoundingSphere::new(transform * self.center, self.radius)
+    }
+
+    // Lower bound of the distance between anything inside of both spheres.
+    pub fn distance(&self, other: &BoundingSphere) -> f64 {
+        ((self.center - other.center).norm() - self.radius - other.radius).max(0.0)
+    }
+
+    pub fn merge(&self, other: &BoundingSphere) -> BoundingSphere {
+        let d = (other.center - self.center).norm();
+        if d + other.radius <= self.radius {
+            return self.clone();
+        }
+        if d + self.radius <= other.radius {
+            return other.clone();
+        }
+        let radius = (d + self.radius + other.radius) / 2.0;
+        let center = self.center + (other.center - self.center) * ((radius - self.radius) / d);
+        BoundingSphere::new(center, radius)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    curves::{bezier::Bezier, curve::Curve, CurveLike},
+    points::point::Point,
+};
+
+pub enum BezierCurveIntersection {
+    None,
+    Points(Vec<Point>),
+    Bezier(Bezier),
+}
+
+// Intersects a Bezier curve with any other curve, by finding the points on the Bezier curve that have zero distance to the other curve.
+pub fn bezier_curve_intersection(bezier: &Bezier, other: &Curve) -> BezierCurveIntersection {
+    let distance = |p: Point| other.project(p).distance;
+    match bezier.distance_roots(&distance) {
+        None => BezierCurveIntersection::Bezier(bezier.clone()),
+        Some(roots) if roots.is_empty() => BezierCurveIntersection::None,
+        Some(roots) => {
+            BezierCurveIntersection::Points(roots.into_iter().map(|t| bezier.point(t)).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::line::Line;
+
+    #[test]
+    fn test_bezier_line_intersection() {
+        let bezier = Bezier::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+        );
+        let line = Curve::Line(Line::new(Point::new(0.0, 0.5, 0.0), Point::unit_x()));
+        match bezier_curve_intersection(&bezier, &line) {
+            BezierCurveIntersection::Points(points) => {
+                assert_eq!(points.len(), 2);
+                for p in points {
+                    assert!(bezier.on_curve(p));
+                    assert!(line.on_curve(p));
+                }
+            }
+            _ => panic!("Expected two points"),
+        }
+
+        let line = Curve::Line(Line::new(Point::new(0.0, 2.0, 0.0), Point::unit_x()));
+        assert!(matches!(
+            bezier_curve_intersection(&bezier, &line),
+            BezierCurveIntersection::None
+        ));
+    }
+}
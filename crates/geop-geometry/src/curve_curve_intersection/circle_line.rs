@@ -22,10 +22,15 @@ pub fn circle_line_intersection(circle: &Circle, line: &Line) -> CircleLineInter
         return CircleLineIntersection::None;
     }
 
-    assert!(
-        circle.normal.is_perpendicular(line.direction),
-        "3D circle-line intersection is not implemented yet"
-    );
+    if !circle.normal.is_perpendicular(line.direction) {
+        // The line crosses the plane of the circle in a single point, which is an intersection if it is on the circle.
+        let t = (circle.basis - line.basis).dot(circle.normal) / line.direction.dot(circle.normal);
+        let point = line.basis + line.direction * t;
+        if ((point - circle.basis).norm() - circle.radius.norm()).abs() < EQ_THRESHOLD {
+            return CircleLineIntersection::OnePoint(point);
+        }
+        return CircleLineIntersection::None;
+    }
 
     let v = circle.basis - line.basis;
     let dir = line.direction.normalize();
@@ -62,5 +67,17 @@ mod tests {
             }
             _ => panic!("Expected two point intersection"),
         }
+
+        // Oblique lines cross the plane of the circle once.
+        let l = Line::new(Point::new(0.0, 0.0, -1.0), Point::new(1.0, 0.0, 1.0));
+        match circle_line_intersection(&c, &l) {
+            CircleLineIntersection::OnePoint(p) => assert_eq!(p, Point::new(1.0, 0.0, 0.0)),
+            _ => panic!("Expected one point intersection"),
+        }
+        let l = Line::new(Point::new(0.0, 0.5, -1.0), Point::new(1.0, 0.0, 1.0));
+        assert!(matches!(
+            circle_line_intersection(&c, &l),
+            CircleLineIntersection::None
+        ));
     }
 }
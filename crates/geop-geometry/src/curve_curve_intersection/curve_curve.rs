@@ -1,9 +1,19 @@
-use crate::{curves::curve::Curve, points::point::Point};
+use crate::{
+    curves::{
+        bezier::Bezier, circle::Circle, composite::CompositeCurve, curve::Curve, ellipse::Ellipse,
+        line::Line, CurveLike,
+    },
+    points::point::Point,
+    EQ_THRESHOLD,
+};
 
 use super::{
+    bezier_curve::{bezier_curve_intersection, BezierCurveIntersection},
     circle_circle::{circle_circle_intersection, CircleCircleIntersection},
     circle_line::{circle_line_intersection, CircleLineIntersection},
+    ellipse_circle::{ellipse_circle_intersection, EllipseCircleIntersection},
     ellipse_ellipse::{ellipse_ellipse_intersection, EllipseEllipseIntersection},
+    ellipse_line::{ellipse_line_intersection, EllipseLineIntersection},
     helix_circle::{helix_circle_intersection, HelixCircleIntersection},
     helix_line::{helix_line_intersection, HelixLineIntersection},
     line_line::{line_line_intersection, LineLineIntersection},
@@ -16,99 +26,305 @@ pub struct PointArray {
     pub extend_dir: Point,
 }
 
-#[derive(Debug, PartialEq)]
+// A point where two curves meet, with its parameters on both curves as returned by their projections.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveCurvePoint {
+    pub point: Point,
+    pub parameter_self: f64,
+    pub parameter_other: f64,
+    // 1 where the curves cross and 2 where they touch, or where several intersections fall together within tolerance.
+    pub multiplicity: usize,
+}
+
+impl CurveCurvePoint {
+    pub fn is_tangent(&self) -> bool {
+        self.multiplicity > 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum CurveCurveIntersection {
     None,
-    FinitePoints(Vec<Point>),
+    // Sorted by the parameter on the first curve, without duplicates.
+    FinitePoints(Vec<CurveCurvePoint>),
     InfiniteDiscretePoints(PointArray),
     Curve(Curve),
 }
 
+// The intersections as they are found by the individual routines, unordered and possibly with duplicates near tangencies.
+enum RawIntersection {
+    None,
+    Points(Vec<Point>),
+    PointArray(PointArray),
+    Curve(Curve),
+}
+
 pub fn curve_curve_intersection(edge_self: &Curve, edge_other: &Curve) -> CurveCurveIntersection {
+    match raw_intersection(edge_self, edge_other) {
+        RawIntersection::None => CurveCurveIntersection::None,
+        RawIntersection::Points(points) => {
+            CurveCurveIntersection::FinitePoints(sorted_points(edge_self, edge_other, points))
+        }
+        RawIntersection::PointArray(point_array) => {
+            CurveCurveIntersection::InfiniteDiscretePoints(point_array)
+        }
+        RawIntersection::Curve(curve) => CurveCurveIntersection::Curve(curve),
+    }
+}
+
+// Sine of the angle below which curves are considered tangent. It is larger than EQ_THRESHOLD, since the tangents at a touching
+// point found with tolerance are only accurate up to the square root of the tolerance.
+pub(crate) const TANGENT_THRESHOLD: f64 = 1e-4;
+
+// Whether two tangents are parallel, i.e. the curves touch instead of crossing.
+pub fn is_tangential(a: Point, b: Point) -> bool {
+    if a.norm() < EQ_THRESHOLD || b.norm() < EQ_THRESHOLD {
+        return false;
+    }
+    a.normalize().cross(b.normalize()).norm() < TANGENT_THRESHOLD
+}
+
+fn sorted_points(
+    edge_self: &Curve,
+    edge_other: &Curve,
+    points: Vec<Point>,
+) -> Vec<CurveCurvePoint> {
+    let mut result = Vec::<CurveCurvePoint>::new();
+    for point in points {
+        let multiplicity = match is_tangential(edge_self.tangent(point), edge_other.tangent(point))
+        {
+            true => 2,
+            false => 1,
+        };
+        match result.iter_mut().find(|r| r.point == point) {
+            Some(existing) => {
+                existing.multiplicity = existing.multiplicity.max(multiplicity).max(2)
+            }
+            None => result.push(CurveCurvePoint {
+                point,
+                parameter_self: edge_self.project(point).parameter,
+                parameter_other: edge_other.project(point).parameter,
+                multiplicity,
+            }),
+        }
+    }
+    result.sort_by(|a, b| a.parameter_self.total_cmp(&b.parameter_self));
+    result
+}
+
+fn raw_intersection(edge_self: &Curve, edge_other: &Curve) -> RawIntersection {
     match edge_self {
         Curve::Line(line) => match edge_other {
             Curve::Line(other_line) => match line_line_intersection(line, other_line) {
-                LineLineIntersection::None => CurveCurveIntersection::None,
-                LineLineIntersection::Point(p) => CurveCurveIntersection::FinitePoints(vec![p]),
-                LineLineIntersection::Line(l) => CurveCurveIntersection::Curve(Curve::Line(l)),
+                LineLineIntersection::None => RawIntersection::None,
+                LineLineIntersection::Point(p) => RawIntersection::Points(vec![p]),
+                LineLineIntersection::Line(l) => RawIntersection::Curve(Curve::Line(l)),
             },
             Curve::Circle(other_circle) => match circle_line_intersection(other_circle, line) {
-                CircleLineIntersection::None => CurveCurveIntersection::None,
-                CircleLineIntersection::OnePoint(p) => {
-                    CurveCurveIntersection::FinitePoints(vec![p])
-                }
-                CircleLineIntersection::TwoPoint(p1, p2) => {
-                    CurveCurveIntersection::FinitePoints(vec![p1, p2])
-                }
+                CircleLineIntersection::None => RawIntersection::None,
+                CircleLineIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+                CircleLineIntersection::TwoPoint(p1, p2) => RawIntersection::Points(vec![p1, p2]),
             },
-            Curve::Ellipse(_) => todo!("Implement this"),
+            Curve::Ellipse(ellipse) => ellipse_line(ellipse, line),
             Curve::Helix(helix) => match helix_line_intersection(helix, line) {
                 HelixLineIntersection::PointArray(point_array) => {
-                    CurveCurveIntersection::InfiniteDiscretePoints(point_array)
+                    RawIntersection::PointArray(point_array)
                 }
-                HelixLineIntersection::TwoPoint(p) => CurveCurveIntersection::FinitePoints(vec![p]),
-                HelixLineIntersection::OnePoint(p) => CurveCurveIntersection::FinitePoints(vec![p]),
-                HelixLineIntersection::None => CurveCurveIntersection::None,
+                HelixLineIntersection::TwoPoint(p) => RawIntersection::Points(vec![p]),
+                HelixLineIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+                HelixLineIntersection::None => RawIntersection::None,
             },
+            Curve::Bezier(bezier) => bezier_intersection(bezier, edge_self),
+            Curve::Composite(composite) => composite_curve_intersection(composite, edge_self),
         },
         Curve::Circle(circle) => match edge_other {
             Curve::Line(other_line) => match circle_line_intersection(circle, other_line) {
-                CircleLineIntersection::None => CurveCurveIntersection::None,
-                CircleLineIntersection::OnePoint(p) => {
-                    CurveCurveIntersection::FinitePoints(vec![p])
-                }
-                CircleLineIntersection::TwoPoint(p1, p2) => {
-                    CurveCurveIntersection::FinitePoints(vec![p1, p2])
-                }
+                CircleLineIntersection::None => RawIntersection::None,
+                CircleLineIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+                CircleLineIntersection::TwoPoint(p1, p2) => RawIntersection::Points(vec![p1, p2]),
             },
             Curve::Circle(other_circle) => match circle_circle_intersection(circle, other_circle) {
-                CircleCircleIntersection::None => CurveCurveIntersection::None,
-                CircleCircleIntersection::OnePoint(p) => {
-                    CurveCurveIntersection::FinitePoints(vec![p])
-                }
-                CircleCircleIntersection::TwoPoint(p1, p2) => {
-                    CurveCurveIntersection::FinitePoints(vec![p1, p2])
-                }
-                CircleCircleIntersection::Circle(c) => {
-                    CurveCurveIntersection::Curve(Curve::Circle(c))
-                }
+                CircleCircleIntersection::None => RawIntersection::None,
+                CircleCircleIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+                CircleCircleIntersection::TwoPoint(p1, p2) => RawIntersection::Points(vec![p1, p2]),
+                CircleCircleIntersection::Circle(c) => RawIntersection::Curve(Curve::Circle(c)),
             },
-            Curve::Ellipse(_) => todo!("Implement this"),
+            Curve::Ellipse(ellipse) => ellipse_circle(ellipse, circle),
             Curve::Helix(helix) => match helix_circle_intersection(helix, circle) {
-                HelixCircleIntersection::TwoPoints(p1, p2) => {
-                    CurveCurveIntersection::FinitePoints(vec![p1, p2])
-                }
-                HelixCircleIntersection::OnePoint(p) => {
-                    CurveCurveIntersection::FinitePoints(vec![p])
-                }
-                HelixCircleIntersection::None => CurveCurveIntersection::None,
+                HelixCircleIntersection::TwoPoints(p1, p2) => RawIntersection::Points(vec![p1, p2]),
+                HelixCircleIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+                HelixCircleIntersection::None => RawIntersection::None,
             },
+            Curve::Bezier(bezier) => bezier_intersection(bezier, edge_self),
+            Curve::Composite(composite) => composite_curve_intersection(composite, edge_self),
         },
         Curve::Ellipse(ellipse) => match edge_other {
-            Curve::Line(_) => todo!("Implement this"),
-            Curve::Circle(_) => todo!("Implement this"),
+            Curve::Line(line) => ellipse_line(ellipse, line),
+            Curve::Circle(circle) => ellipse_circle(ellipse, circle),
             Curve::Ellipse(other_ellipse) => {
                 match ellipse_ellipse_intersection(ellipse, other_ellipse) {
                     EllipseEllipseIntersection::Ellipse(ellipse) => {
-                        CurveCurveIntersection::Curve(Curve::Ellipse(ellipse))
-                    }
-                    EllipseEllipseIntersection::OnePoint(p0) => {
-                        CurveCurveIntersection::FinitePoints(vec![p0])
+                        RawIntersection::Curve(Curve::Ellipse(ellipse))
                     }
+                    EllipseEllipseIntersection::OnePoint(p0) => RawIntersection::Points(vec![p0]),
                     EllipseEllipseIntersection::TwoPoint(p0, p1) => {
-                        CurveCurveIntersection::FinitePoints(vec![p0, p1])
+                        RawIntersection::Points(vec![p0, p1])
                     }
                     EllipseEllipseIntersection::ThreePoint(p0, p1, p2) => {
-                        CurveCurveIntersection::FinitePoints(vec![p0, p1, p2])
+                        RawIntersection::Points(vec![p0, p1, p2])
                     }
                     EllipseEllipseIntersection::FourPoint(p0, p1, p2, p3) => {
-                        CurveCurveIntersection::FinitePoints(vec![p0, p1, p2, p3])
+                        RawIntersection::Points(vec![p0, p1, p2, p3])
                     }
-                    EllipseEllipseIntersection::None => CurveCurveIntersection::None,
+                    EllipseEllipseIntersection::None => RawIntersection::None,
                 }
             }
             Curve::Helix(_) => todo!("Implement this"),
+            Curve::Bezier(bezier) => bezier_intersection(bezier, edge_self),
+            Curve::Composite(composite) => composite_curve_intersection(composite, edge_self),
+        },
+        Curve::Helix(helix) => match edge_other {
+            Curve::Line(line) => match helix_line_intersection(helix, line) {
+                HelixLineIntersection::PointArray(point_array) => {
+                    RawIntersection::PointArray(point_array)
+                }
+                HelixLineIntersection::TwoPoint(p) => RawIntersection::Points(vec![p]),
+                HelixLineIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+                HelixLineIntersection::None => RawIntersection::None,
+            },
+            Curve::Circle(circle) => match helix_circle_intersection(helix, circle) {
+                HelixCircleIntersection::TwoPoints(p1, p2) => RawIntersection::Points(vec![p1, p2]),
+                HelixCircleIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+                HelixCircleIntersection::None => RawIntersection::None,
+            },
+            _ => todo!("Implement this"),
+        },
+        Curve::Bezier(bezier) => match edge_other {
+            Curve::Composite(composite) => composite_curve_intersection(composite, edge_self),
+            _ => bezier_intersection(bezier, edge_other),
         },
-        Curve::Helix(_) => todo!("Implement this"),
+        Curve::Composite(composite) => composite_curve_intersection(composite, edge_other),
+    }
+}
+
+fn ellipse_line(ellipse: &Ellipse, line: &Line) -> RawIntersection {
+    match ellipse_line_intersection(ellipse, line) {
+        EllipseLineIntersection::None => RawIntersection::None,
+        EllipseLineIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
+        EllipseLineIntersection::TwoPoint(p1, p2) => RawIntersection::Points(vec![p1, p2]),
+    }
+}
+
+fn ellipse_circle(ellipse: &Ellipse, circle: &Circle) -> RawIntersection {
+    match ellipse_circle_intersection(ellipse, circle) {
+        EllipseCircleIntersection::Circle(circle) => RawIntersection::Curve(Curve::Circle(circle)),
+        EllipseCircleIntersection::Points(points) if points.is_empty() => RawIntersection::None,
+        EllipseCircleIntersection::Points(points) => RawIntersection::Points(points),
+    }
+}
+
+fn bezier_intersection(bezier: &Bezier, other: &Curve) -> RawIntersection {
+    match bezier_curve_intersection(bezier, other) {
+        BezierCurveIntersection::None => RawIntersection::None,
+        BezierCurveIntersection::Points(points) => RawIntersection::Points(points),
+        BezierCurveIntersection::Bezier(bezier) => RawIntersection::Curve(Curve::Bezier(bezier)),
+    }
+}
+
+// Composite curves are intersected segment by segment. Only intersections within the segments are kept.
+fn composite_curve_intersection(composite: &CompositeCurve, other: &Curve) -> RawIntersection {
+    if let Curve::Composite(other_composite) = other {
+        if composite == other_composite {
+            return RawIntersection::Curve(other.clone());
+        }
+    }
+
+    let mut points = Vec::<Point>::new();
+    for segment in composite.segments.iter() {
+        match raw_intersection(&segment.curve, other) {
+            RawIntersection::None => {}
+            RawIntersection::Points(segment_points) => {
+                for p in segment_points {
+                    if segment
+                        .curve
+                        .between(p, Some(segment.start), Some(segment.end))
+                        && !points.contains(&p)
+                    {
+                        points.push(p);
+                    }
+                }
+            }
+            RawIntersection::PointArray(_) => {
+                todo!("Intersection of composite curves with point arrays")
+            }
+            RawIntersection::Curve(_) => {
+                todo!("Partial overlaps of composite curves")
+            }
+        }
+    }
+    match points.is_empty() {
+        true => RawIntersection::None,
+        false => RawIntersection::Points(points),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::helix::Helix;
+
+    use super::*;
+
+    #[test]
+    fn test_curve_curve_points() {
+        // A line crossing a circle meets it twice, sorted along the line.
+        let circle = Curve::Circle(Circle::new(Point::zero(), Point::unit_z(), 1.0));
+        let line = Curve::Line(Line::new(Point::new(2.0, 0.0, 0.0), -Point::unit_x()));
+        let points = match curve_curve_intersection(&line, &circle) {
+            CurveCurveIntersection::FinitePoints(points) => points,
+            _ => panic!("Expected points"),
+        };
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].point, Point::new(1.0, 0.0, 0.0));
+        assert!((points[0].parameter_self - 1.0).abs() < 1e-9);
+        assert!((points[1].parameter_self - 3.0).abs() < 1e-9);
+        assert!((points[1].parameter_other - std::f64::consts::PI).abs() < 1e-9);
+        assert!(points.iter().all(|p| !p.is_tangent()));
+
+        // A tangent line touches it once.
+        let tangent = Curve::Line(Line::new(Point::new(0.0, 1.0, 0.0), Point::unit_x()));
+        let points = match curve_curve_intersection(&circle, &tangent) {
+            CurveCurveIntersection::FinitePoints(points) => points,
+            _ => panic!("Expected points"),
+        };
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].point, Point::new(0.0, 1.0, 0.0));
+        assert!(points[0].is_tangent());
+    }
+
+    #[test]
+    fn test_curve_curve_helix_circle() {
+        // A helix meets a coaxial circle of its radius once, with the number of turns as its parameter.
+        let helix = Curve::Helix(Helix::new(
+            Point::zero(),
+            Point::unit_z(),
+            Point::unit_x(),
+            true,
+        ));
+        let circle = Curve::Circle(Circle::new(Point::unit_z() * 1.25, Point::unit_z(), 1.0));
+        for (a, b) in [(&circle, &helix), (&helix, &circle)] {
+            let points = match curve_curve_intersection(a, b) {
+                CurveCurveIntersection::FinitePoints(points) => points,
+                _ => panic!("Expected points"),
+            };
+            assert_eq!(points.len(), 1);
+            assert_eq!(points[0].point, Point::new(0.0, 1.0, 1.25));
+        }
+        match curve_curve_intersection(&helix, &circle) {
+            CurveCurveIntersection::FinitePoints(points) => {
+                assert!((points[0].parameter_self - 1.25).abs() < 1e-9);
+                assert!((points[0].parameter_other - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+            }
+            _ => panic!("Expected points"),
+        }
     }
 }
@@ -0,0 +1,74 @@
+use crate::{
+    curves::{circle::Circle, ellipse::Ellipse},
+    points::point::Point,
+    EQ_THRESHOLD,
+};
+
+use super::curve_curve_intersection_numerical;
+
+pub enum EllipseCircleIntersection {
+    Circle(Circle), // The ellipse is the circle
+    Points(Vec<Point>),
+}
+
+// A circle is an ellipse with equal radii, so it is intersected like two ellipses.
+pub fn ellipse_circle_intersection(
+    ellipse: &Ellipse,
+    circle: &Circle,
+) -> EllipseCircleIntersection {
+    let as_ellipse = Ellipse::new(
+        circle.basis,
+        circle.normal,
+        circle.radius,
+        circle.normal.cross(circle.radius),
+    );
+    let radius = circle.radius.norm();
+    let same_ellipse = ellipse.basis == circle.basis
+        && ellipse.normal.is_parallel(circle.normal)
+        && (ellipse.major_radius.norm() - radius).abs() < EQ_THRESHOLD
+        && (ellipse.minor_radius.norm() - radius).abs() < EQ_THRESHOLD;
+    if same_ellipse {
+        return EllipseCircleIntersection::Circle(circle.clone());
+    }
+    EllipseCircleIntersection::Points(curve_curve_intersection_numerical(ellipse, &as_ellipse))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::CurveLike;
+
+    use super::*;
+
+    #[test]
+    fn test_ellipse_circle_intersection() {
+        // An ellipse crossing the unit circle four times
+        let ellipse = Ellipse::new(
+            Point::zero(),
+            Point::unit_z(),
+            Point::unit_x() * 2.0,
+            Point::unit_y() * 0.5,
+        );
+        let circle = Circle::new(Point::zero(), Point::unit_z(), 1.0);
+        match ellipse_circle_intersection(&ellipse, &circle) {
+            EllipseCircleIntersection::Points(points) => {
+                assert_eq!(points.len(), 4);
+                for p in points {
+                    assert!(ellipse.on_curve(p) && circle.on_curve(p));
+                }
+            }
+            _ => panic!("Intersection should be points"),
+        }
+
+        // An ellipse with equal radii is the circle
+        let round = Ellipse::new(
+            Point::zero(),
+            -Point::unit_z(),
+            Point::unit_y(),
+            Point::unit_x(),
+        );
+        assert!(matches!(
+            ellipse_circle_intersection(&round, &circle),
+            EllipseCircleIntersection::Circle(_)
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    curves::{ellipse::Ellipse, line::Line, CurveLike},
+    points::point::Point,
+    EQ_THRESHOLD,
+};
+
+#[derive(Debug)]
+pub enum EllipseLineIntersection {
+    TwoPoint(Point, Point),
+    OnePoint(Point),
+    None,
+}
+
+pub fn ellipse_line_intersection(ellipse: &Ellipse, line: &Line) -> EllipseLineIntersection {
+    let dir = line.direction.normalize();
+
+    // A line through the plane of the ellipse meets it at most where it passes the plane.
+    let across = dir.dot(ellipse.normal);
+    if across.abs() > EQ_THRESHOLD {
+        let s = (ellipse.basis - line.basis).dot(ellipse.normal) / across;
+        let point = line.basis + dir * s;
+        return match ellipse.on_curve(point) {
+            true => EllipseLineIntersection::OnePoint(point),
+            false => EllipseLineIntersection::None,
+        };
+    }
+    if (line.basis - ellipse.basis).dot(ellipse.normal).abs() > EQ_THRESHOLD {
+        return EllipseLineIntersection::None;
+    }
+
+    // In the plane, the coordinates along the radii are linear in the line parameter, and the ellipse is where they are on the unit
+    // circle, i.e. (x0 + s * x1)^2 + (y0 + s * y1)^2 = 1.
+    let v = line.basis - ellipse.basis;
+    let (a, b) = (ellipse.major_radius, ellipse.minor_radius);
+    let (x0, x1) = (a.dot(v) / a.norm_sq(), a.dot(dir) / a.norm_sq());
+    let (y0, y1) = (b.dot(v) / b.norm_sq(), b.dot(dir) / b.norm_sq());
+    let qa = x1 * x1 + y1 * y1;
+    let qb = 2.0 * (x0 * x1 + y0 * y1);
+    let qc = x0 * x0 + y0 * y0 - 1.0;
+    let discriminant = qb * qb - 4.0 * qa * qc;
+    let center = -qb / (2.0 * qa);
+    if discriminant.abs() < EQ_THRESHOLD {
+        let point = line.basis + dir * center;
+        return match ellipse.on_curve(point) {
+            true => EllipseLineIntersection::OnePoint(point),
+            false => EllipseLineIntersection::None,
+        };
+    }
+    if discriminant < 0.0 {
+        return EllipseLineIntersection::None;
+    }
+    let offset = discriminant.sqrt() / (2.0 * qa);
+    EllipseLineIntersection::TwoPoint(
+        line.basis + dir * (center - offset),
+        line.basis + dir * (center + offset),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellipse_line_intersection() {
+        let ellipse = Ellipse::new(
+            Point::zero(),
+            Point::unit_z(),
+            Point::unit_x() * 2.0,
+            Point::unit_y(),
+        );
+
+        // Along the major axis, sorted along the line
+        let line = Line::new(Point::new(5.0, 0.0, 0.0), -Point::unit_x());
+        match ellipse_line_intersection(&ellipse, &line) {
+            EllipseLineIntersection::TwoPoint(p1, p2) => {
+                assert_eq!(p1, Point::new(2.0, 0.0, 0.0));
+                assert_eq!(p2, Point::new(-2.0, 0.0, 0.0));
+            }
+            _ => panic!("Intersection should be two points"),
+        }
+
+        // Touching at the end of the minor axis, and missing it above
+        let line = Line::new(Point::unit_y(), Point::unit_x());
+        match ellipse_line_intersection(&ellipse, &line) {
+            EllipseLineIntersection::OnePoint(p) => assert_eq!(p, Point::unit_y()),
+            _ => panic!("Intersection should be one point"),
+        }
+        let line = Line::new(Point::unit_y() * 1.5, Point::unit_x());
+        assert!(matches!(
+            ellipse_line_intersection(&ellipse, &line),
+            EllipseLineIntersection::None
+        ));
+
+        // Through the plane, on the ellipse and inside of it
+        let line = Line::new(Point::new(0.0, -1.0, 3.0), Point::unit_z());
+        match ellipse_line_intersection(&ellipse, &line) {
+            EllipseLineIntersection::OnePoint(p) => assert_eq!(p, -Point::unit_y()),
+            _ => panic!("Intersection should be one point"),
+        }
+        let line = Line::new(Point::zero(), Point::new(1.0, 0.0, 1.0));
+        assert!(matches!(
+            ellipse_line_intersection(&ellipse, &line),
+            EllipseLineIntersection::None
+        ));
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{bounding_box::BoundingBox, curves::CurveLike, points::point::Point, EQ_THRESHOLD};
 
 // Alphabetical order
+pub mod bezier_curve;
 pub mod circle_circle;
 pub mod circle_line;
 pub mod curve_curve;
+pub mod ellipse_circle;
 pub mod ellipse_ellipse;
+pub mod ellipse_line;
 pub mod helix_circle;
 pub mod helix_line;
 pub mod line_line;
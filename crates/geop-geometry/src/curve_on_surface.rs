@@ -0,0 +1,193 @@
+use crate::{
+    curves::{
+        composite::{CompositeCurve, CompositeSegment},
+        curve::Curve,
+        line::Line,
+        CurveLike,
+    },
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+// Number of segments the first fit starts with. It is doubled until the fit is within tolerance.
+const MIN_SEGMENTS: usize = 4;
+const MAX_SEGMENTS: usize = 4096;
+
+// A curve in the (u, v) parameter space of a surface (z = 0), which is bound to the surface and maps to 3d by evaluating the surface.
+// It is used to trim curved faces in parameter space, where containment and intersection are 2d problems.
+// The parameter space curve is a polyline, which is fitted to a 3d curve within a tolerance. Periodic parameters are unwrapped,
+// so that the polyline is continuous even if the 3d curve crosses the seam of the surface.
+#[derive(Debug, Clone)]
+pub struct CurveOnSurface {
+    pub surface: Surface,
+    pub pcurve: CompositeCurve,
+}
+
+// Shifts u by a multiple of the period, so that it is as close as possible to the reference.
+fn unwrap(u: f64, reference: f64, period: Option<f64>) -> f64 {
+    match period {
+        Some(period) => u - ((u - reference) / period).round() * period,
+        None => u,
+    }
+}
+
+// Removes the interior points that lie on a straight line between their neighbors.
+fn merge_collinear(points: Vec<Point>) -> Vec<Point> {
+    let mut result = Vec::<Point>::with_capacity(points.len());
+    for p in points {
+        if result.len() >= 2 {
+            let a = result[result.len() - 2];
+            let b = result[result.len() - 1];
+            let (ab, bp) = (b - a, p - b);
+            if ab.cross(bp).norm() < EQ_THRESHOLD * ab.norm() * bp.norm() && ab.dot(bp) > 0.0 {
+                result.pop();
+            }
+        }
+        if result.last() != Some(&p) {
+            result.push(p);
+        }
+    }
+    result
+}
+
+fn polyline(points: &[Point]) -> CompositeCurve {
+    CompositeCurve::new(
+        points
+            .windows(2)
+            .map(|w| CompositeSegment::new(Curve::Line(Line::new(w[0], w[1] - w[0])), w[0], w[1]))
+            .collect(),
+    )
+}
+
+impl CurveOnSurface {
+    pub fn new(surface: Surface, pcurve: CompositeCurve) -> CurveOnSurface {
+        CurveOnSurface { surface, pcurve }
+    }
+
+    // Fits a parameter space curve to the part of the 3d curve between start and end, which has to lie on the surface.
+    pub fn from_curve(
+        surface: Surface,
+        curve: &Curve,
+        start: Option<Point>,
+        end: Option<Point>,
+        tolerance: f64,
+    ) -> CurveOnSurface {
+        let period = surface.u_period();
+        let mut n = MIN_SEGMENTS;
+        loop {
+            let mut points = Vec::<Point>::with_capacity(n + 1);
+            for i in 0..=n {
+                let p = curve.interpolate(start, end, i as f64 / n as f64);
+                assert!(surface.on_surface(p), "Curve is not on surface");
+                let mut uv = surface.parameters(p);
+                if let Some(last) = points.last() {
+                    uv.x = unwrap(uv.x, last.x, period);
+                }
+                points.push(uv);
+            }
+            let result = CurveOnSurface::new(surface.clone(), polyline(&merge_collinear(points)));
+            if n >= MAX_SEGMENTS || result.deviation(curve, 2 * n) <= tolerance {
+                return result;
+            }
+            n *= 2;
+        }
+    }
+
+    // Returns the parameters at t in [0, 1] along the curve.
+    pub fn uv(&self, t: f64) -> Point {
+        self.pcurve.interpolate(None, None, t)
+    }
+
+    // Returns the 3d point at t in [0, 1] along the curve.
+    pub fn point(&self, t: f64) -> Point {
+        self.surface.point_at(self.uv(t))
+    }
+
+    pub fn start(&self) -> Point {
+        self.point(0.0)
+    }
+
+    pub fn end(&self) -> Point {
+        self.point(1.0)
+    }
+
+    // Maximum distance of the mapped curve to the 3d curve, measured at the given number of samples.
+    pub fn deviation(&self, curve: &Curve, samples: usize) -> f64 {
+        (0..=samples)
+            .map(|i| {
+                let p = self.point(i as f64 / samples as f64);
+                curve.project(p).distance
+            })
+            .fold(0.0, f64::max)
+    }
+
+    // Refits the parameter space curve if it is not within tolerance of the 3d curve anymore, e.g. after the 3d curve was modified.
+    // Returns whether the curve was refitted.
+    pub fn synchronize(
+        &mut self,
+        curve: &Curve,
+        start: Option<Point>,
+        end: Option<Point>,
+        tolerance: f64,
+    ) -> bool {
+        let in_sync = self.deviation(curve, 4 * MIN_SEGMENTS) <= tolerance
+            && start.is_none_or(|s| (self.start() - s).norm() <= tolerance)
+            && end.is_none_or(|e| (self.end() - e).norm() <= tolerance);
+        if in_sync {
+            return false;
+        }
+        *self = CurveOnSurface::from_curve(self.surface.clone(), curve, start, end, tolerance);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        curves::circle::Circle,
+        surfaces::{cylinder::Cylinder, plane::Plane},
+    };
+
+    #[test]
+    fn test_curve_on_surface() {
+        // A line on a plane is a line in parameter space.
+        let plane = Surface::Plane(Plane::new(Point::zero(), Point::unit_x(), Point::unit_y()));
+        let line = Curve::Line(Line::new(Point::zero(), Point::new(1.0, 1.0, 0.0)));
+        let start = Point::new(1.0, 1.0, 0.0);
+        let end = Point::new(3.0, 3.0, 0.0);
+        let cos = CurveOnSurface::from_curve(plane, &line, Some(start), Some(end), 1e-6);
+        assert_eq!(cos.pcurve.segments.len(), 1);
+        assert_eq!(cos.start(), start);
+        assert_eq!(cos.end(), end);
+
+        // A full circle around a cylinder crosses the seam, but stays a straight line in parameter space.
+        let cylinder = Surface::Cylinder(Cylinder::new(Point::zero(), Point::unit_z(), 2.0, true));
+        let circle = Curve::Circle(Circle::new(Point::new(0.0, 0.0, 1.0), Point::unit_z(), 2.0));
+        let cos = CurveOnSurface::from_curve(cylinder.clone(), &circle, None, None, 1e-6);
+        assert_eq!(cos.pcurve.segments.len(), 1);
+        let (uv0, uv1) = (cos.uv(0.0), cos.uv(1.0));
+        assert!(((uv1.x - uv0.x).abs() - 2.0 * std::f64::consts::PI).abs() < 1e-6);
+        assert!((uv0.y - 1.0).abs() < 1e-9);
+        assert!(cos.deviation(&circle, 100) < 1e-6);
+
+        // A circle that is tilted is fitted by a polyline, and refitted when the circle moves.
+        let tilted = Curve::Circle(Circle::new(Point::zero(), Point::new(0.0, 1.0, 1.0), 1.0));
+        let plane = Surface::Plane(Plane::new(
+            Point::zero(),
+            Point::unit_x(),
+            Point::new(0.0, 1.0, -1.0),
+        ));
+        let mut cos = CurveOnSurface::from_curve(plane, &tilted, None, None, 1e-3);
+        assert!(cos.deviation(&tilted, 1000) < 1e-3);
+        assert!(!cos.synchronize(&tilted, None, None, 1e-3));
+        let moved = Curve::Circle(Circle::new(
+            Point::new(0.5, 0.0, 0.0),
+            Point::new(0.0, 1.0, 1.0),
+            1.0,
+        ));
+        assert!(cos.synchronize(&moved, None, None, 1e-3));
+        assert!(cos.deviation(&moved, 1000) < 1e-3);
+    }
+}
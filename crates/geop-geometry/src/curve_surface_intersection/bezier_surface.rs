@@ -0,0 +1,26 @@
+use crate::{
+    curves::bezier::Bezier,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+pub enum BezierSurfaceIntersection {
+    None,
+    Points(Vec<Point>),
+    Bezier(Bezier),
+}
+
+// Intersects a Bezier curve with a surface, by finding the points on the Bezier curve that have zero distance to the surface.
+pub fn bezier_surface_intersection(
+    bezier: &Bezier,
+    surface: &Surface,
+) -> BezierSurfaceIntersection {
+    let distance = |p: Point| (surface.project(p) - p).norm();
+    match bezier.distance_roots(&distance) {
+        None => BezierSurfaceIntersection::Bezier(bezier.clone()),
+        Some(roots) if roots.is_empty() => BezierSurfaceIntersection::None,
+        Some(roots) => {
+            BezierSurfaceIntersection::Points(roots.into_iter().map(|t| bezier.point(t)).collect())
+        }
+    }
+}
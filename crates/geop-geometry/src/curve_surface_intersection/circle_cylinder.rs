@@ -17,7 +17,7 @@ pub fn circle_cylinder_intersection(
         let distance = circle.basis - cylinder.basis;
         let distance = distance - distance.dot(cylinder.extend_dir) * cylinder.extend_dir;
         if distance.norm() < EQ_THRESHOLD {
-            if (circle.radius.norm() - cylinder.radius.norm()).abs() < f64::EPSILON {
+            if (circle.radius.norm() - cylinder.radius.norm()).abs() < EQ_THRESHOLD {
                 return CircleCylinderIntersection::Circle(circle.clone());
             }
         }
@@ -1,6 +1,13 @@
-use crate::{curves::curve::Curve, points::point::Point, surfaces::surface::Surface};
+use crate::{
+    curve_curve_intersection::curve_curve::TANGENT_THRESHOLD,
+    curves::{composite::CompositeCurve, curve::Curve, CurveLike},
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
 
 use super::{
+    bezier_surface::{bezier_surface_intersection, BezierSurfaceIntersection},
     circle_cylinder::{circle_cylinder_intersection, CircleCylinderIntersection},
     circle_plane::{circle_plane_intersection, CirclePlaneIntersection},
     circle_sphere::{circle_sphere_intersection, CircleSphereIntersection},
@@ -9,7 +16,32 @@ use super::{
     line_sphere::{line_sphere_intersection, LineSphereIntersection},
 };
 
+// A point where a curve meets a surface, with its parameter on the curve as returned by its projection and the parameters on the surface.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveSurfacePoint {
+    pub point: Point,
+    pub parameter: f64,
+    pub uv: Point,
+    // 1 where the curve crosses the surface and 2 where it touches it, or where several intersections fall together within tolerance.
+    pub multiplicity: usize,
+}
+
+impl CurveSurfacePoint {
+    pub fn is_tangent(&self) -> bool {
+        self.multiplicity > 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum CurveSurfaceIntersection {
+    None,
+    // Sorted by the parameter on the curve, without duplicates.
+    Points(Vec<CurveSurfacePoint>),
+    Curve(Curve),
+}
+
+// The intersections as they are found by the individual routines, unordered and possibly with duplicates near tangencies.
+enum RawIntersection {
     None,
     Points(Vec<Point>),
     Curve(Curve),
@@ -39,76 +71,150 @@ impl CurveSurfaceIntersection {
 }
 
 pub fn curve_surface_intersection(curve: &Curve, surface: &Surface) -> CurveSurfaceIntersection {
+    match raw_intersection(curve, surface) {
+        RawIntersection::None => CurveSurfaceIntersection::None,
+        RawIntersection::Points(points) => {
+            CurveSurfaceIntersection::Points(sorted_points(curve, surface, points))
+        }
+        RawIntersection::Curve(curve) => CurveSurfaceIntersection::Curve(curve),
+    }
+}
+
+fn sorted_points(curve: &Curve, surface: &Surface, points: Vec<Point>) -> Vec<CurveSurfacePoint> {
+    let mut result = Vec::<CurveSurfacePoint>::new();
+    for point in points {
+        // The curve touches the surface where its tangent lies in the tangent plane.
+        let tangent = curve.tangent(point);
+        let multiplicity = match tangent.norm() > EQ_THRESHOLD
+            && tangent
+                .normalize()
+                .dot(surface.normal(surface.project(point)))
+                .abs()
+                < TANGENT_THRESHOLD
+        {
+            true => 2,
+            false => 1,
+        };
+        match result.iter_mut().find(|r| r.point == point) {
+            Some(existing) => {
+                existing.multiplicity = existing.multiplicity.max(multiplicity).max(2)
+            }
+            None => result.push(CurveSurfacePoint {
+                point,
+                parameter: curve.project(point).parameter,
+                uv: surface.parameters(point),
+                multiplicity,
+            }),
+        }
+    }
+    result.sort_by(|a, b| a.parameter.total_cmp(&b.parameter));
+    result
+}
+
+fn raw_intersection(curve: &Curve, surface: &Surface) -> RawIntersection {
     match curve {
         Curve::Line(line) => match surface {
             Surface::Plane(plane) => match line_plane_intersection(line, plane) {
-                LinePlaneIntersection::Line(line) => {
-                    CurveSurfaceIntersection::Curve(Curve::Line(line))
-                }
-                LinePlaneIntersection::Point(point) => {
-                    CurveSurfaceIntersection::Points(vec![point])
-                }
-                LinePlaneIntersection::None => CurveSurfaceIntersection::None,
+                LinePlaneIntersection::Line(line) => RawIntersection::Curve(Curve::Line(line)),
+                LinePlaneIntersection::Point(point) => RawIntersection::Points(vec![point]),
+                LinePlaneIntersection::None => RawIntersection::None,
             },
             Surface::Sphere(sphere) => match line_sphere_intersection(line, sphere) {
                 LineSphereIntersection::TwoPoints(point1, point2) => {
-                    CurveSurfaceIntersection::Points(vec![point1, point2])
-                }
-                LineSphereIntersection::OnePoint(point) => {
-                    CurveSurfaceIntersection::Points(vec![point])
+                    RawIntersection::Points(vec![point1, point2])
                 }
-                LineSphereIntersection::None => CurveSurfaceIntersection::None,
+                LineSphereIntersection::OnePoint(point) => RawIntersection::Points(vec![point]),
+                LineSphereIntersection::None => RawIntersection::None,
             },
             Surface::Cylinder(cylinder) => match line_cylinder_intersection(line, cylinder) {
-                CylinderLineIntersection::Line(line) => {
-                    CurveSurfaceIntersection::Curve(Curve::Line(line))
-                }
+                CylinderLineIntersection::Line(line) => RawIntersection::Curve(Curve::Line(line)),
                 CylinderLineIntersection::TwoPoints(point1, point2) => {
-                    CurveSurfaceIntersection::Points(vec![point1, point2])
-                }
-                CylinderLineIntersection::Point(point) => {
-                    CurveSurfaceIntersection::Points(vec![point])
+                    RawIntersection::Points(vec![point1, point2])
                 }
-                CylinderLineIntersection::None => CurveSurfaceIntersection::None,
+                CylinderLineIntersection::Point(point) => RawIntersection::Points(vec![point]),
+                CylinderLineIntersection::None => RawIntersection::None,
             },
         },
         Curve::Circle(circle) => match surface {
             Surface::Plane(plane) => match circle_plane_intersection(circle, plane) {
-                CirclePlaneIntersection::None => CurveSurfaceIntersection::None,
-                CirclePlaneIntersection::TwoPoints(p1, p2) => {
-                    CurveSurfaceIntersection::Points(vec![p1, p2])
-                }
-                CirclePlaneIntersection::OnePoint(p) => CurveSurfaceIntersection::Points(vec![p]),
+                CirclePlaneIntersection::None => RawIntersection::None,
+                CirclePlaneIntersection::TwoPoints(p1, p2) => RawIntersection::Points(vec![p1, p2]),
+                CirclePlaneIntersection::OnePoint(p) => RawIntersection::Points(vec![p]),
                 CirclePlaneIntersection::Circle(circle) => {
-                    CurveSurfaceIntersection::Curve(Curve::Circle(circle))
+                    RawIntersection::Curve(Curve::Circle(circle))
                 }
             },
             Surface::Sphere(sphere) => match circle_sphere_intersection(circle, sphere) {
-                CircleSphereIntersection::None => CurveSurfaceIntersection::None,
-                CircleSphereIntersection::OnePoint(point) => {
-                    CurveSurfaceIntersection::Points(vec![point])
-                }
+                CircleSphereIntersection::None => RawIntersection::None,
+                CircleSphereIntersection::OnePoint(point) => RawIntersection::Points(vec![point]),
                 CircleSphereIntersection::TwoPoints(point1, point2) => {
-                    CurveSurfaceIntersection::Points(vec![point1, point2])
+                    RawIntersection::Points(vec![point1, point2])
                 }
                 CircleSphereIntersection::Circle(circle) => {
-                    CurveSurfaceIntersection::Curve(Curve::Circle(circle))
+                    RawIntersection::Curve(Curve::Circle(circle))
                 }
             },
             Surface::Cylinder(cylinder) => match circle_cylinder_intersection(circle, cylinder) {
                 CircleCylinderIntersection::Circle(circle) => {
-                    CurveSurfaceIntersection::Curve(Curve::Circle(circle))
+                    RawIntersection::Curve(Curve::Circle(circle))
                 }
                 CircleCylinderIntersection::TwoPoints(point1, point2) => {
-                    CurveSurfaceIntersection::Points(vec![point1, point2])
+                    RawIntersection::Points(vec![point1, point2])
                 }
-                CircleCylinderIntersection::OnePoint(point) => {
-                    CurveSurfaceIntersection::Points(vec![point])
-                }
-                CircleCylinderIntersection::None => CurveSurfaceIntersection::None,
+                CircleCylinderIntersection::OnePoint(point) => RawIntersection::Points(vec![point]),
+                CircleCylinderIntersection::None => RawIntersection::None,
             },
         },
         Curve::Ellipse(_) => todo!("Implement this"),
         Curve::Helix(_) => todo!("Implement this"),
+        Curve::Bezier(bezier) => match bezier_surface_intersection(bezier, surface) {
+            BezierSurfaceIntersection::None => RawIntersection::None,
+            BezierSurfaceIntersection::Points(points) => RawIntersection::Points(points),
+            BezierSurfaceIntersection::Bezier(bezier) => {
+                RawIntersection::Curve(Curve::Bezier(bezier))
+            }
+        },
+        Curve::Composite(composite) => composite_surface_intersection(composite, surface),
+    }
+}
+
+// Composite curves are intersected segment by segment. The composite curve is only returned if all segments lie on the surface.
+// If only some of the segments lie on the surface, their end points are returned.
+fn composite_surface_intersection(
+    composite: &CompositeCurve,
+    surface: &Surface,
+) -> RawIntersection {
+    let mut points = Vec::<Point>::new();
+    let mut all_on_surface = true;
+    for segment in composite.segments.iter() {
+        match raw_intersection(&segment.curve, surface) {
+            RawIntersection::None => all_on_surface = false,
+            RawIntersection::Points(segment_points) => {
+                all_on_surface = false;
+                for p in segment_points {
+                    if segment
+                        .curve
+                        .between(p, Some(segment.start), Some(segment.end))
+                        && !points.contains(&p)
+                    {
+                        points.push(p);
+                    }
+                }
+            }
+            RawIntersection::Curve(_) => {
+                for p in [segment.start, segment.end] {
+                    if !points.contains(&p) {
+                        points.push(p);
+                    }
+                }
+            }
+        }
+    }
+    if all_on_surface {
+        return RawIntersection::Curve(Curve::Composite(composite.clone()));
+    }
+    match points.is_empty() {
+        true => RawIntersection::None,
+        false => RawIntersection::Points(points),
     }
 }
@@ -12,7 +12,7 @@ pub fn line_cylinder_intersection(line: &Line, cylinder: &Cylinder) -> CylinderL
         let distance = line.basis - cylinder.basis;
         let distance = distance - distance.dot(cylinder.extend_dir) * cylinder.extend_dir;
         let radius = distance.norm();
-        if (radius - cylinder.radius.norm()).abs() < f64::EPSILON {
+        if (radius - cylinder.radius.norm()).abs() < EQ_THRESHOLD {
             return CylinderLineIntersection::Line(line.clone());
         }
         return CylinderLineIntersection::None;
@@ -1,5 +1,6 @@
 use crate::{curves::CurveLike, points::point::Point, surfaces::SurfaceLike};
 
+pub mod bezier_surface;
 pub mod circle_cylinder;
 pub mod circle_plane;
 pub mod circle_sphere;
@@ -22,7 +23,7 @@ pub fn curve_surface_intersection_numerical(
             let grad = surface.unsigned_l2_squared_distance_gradient(initial_guess);
             let tangent = curve.tangent(initial_guess);
             initial_guess = initial_guess + tangent * step_size;
-            initial_guess = curve.project(initial_guess);
+            initial_guess = curve.project(initial_guess).point;
 
             if grad.is_none() {
                 break;
@@ -0,0 +1,403 @@
+use crate::{bounding_box::BoundingBox, points::point::Point, transforms::Transform, EQ_THRESHOLD};
+
+use super::{curve::Curve, ClosestPoint, CurveLike};
+
+// Number of samples used to find good starting points for the numerical projection.
+const PROJECTION_SAMPLES: usize = 32;
+// Number of intervals for the numerical integration of the arc length.
+const ARC_LENGTH_INTERVALS: usize = 64;
+
+// A cubic Bezier curve with control points p0, p1, p2, p3. The curve starts at p0 and ends at p3.
+// B(t) = (1-t)^3 p0 + 3 (1-t)^2 t p1 + 3 (1-t) t^2 p2 + t^3 p3, t in [0, 1].
+// p0 and p3 have to differ, since points on the curve are located by their parameter and a closed curve would have two at its
+// end point. Closed loops are built from several Bezier edges.
+#[derive(Debug, Clone)]
+pub struct Bezier {
+    pub p0: Point,
+    pub p1: Point,
+    pub p2: Point,
+    pub p3: Point,
+}
+
+// A clamped B-spline, e.g. for the export to other CAD formats.
+#[derive(Debug, Clone)]
+pub struct BSplineData {
+    pub degree: usize,
+    pub knots: Vec<f64>,
+    pub control_points: Vec<Point>,
+}
+
+impl Bezier {
+    pub fn new(p0: Point, p1: Point, p2: Point, p3: Point) -> Bezier {
+        assert!(p0 != p3, "Closed Bezier curves are not supported");
+        Bezier { p0, p1, p2, p3 }
+    }
+
+    pub fn transform(&self, transform: Transform) -> Bezier {
+        Bezier::new(
+            transform * self.p0,
+            transform * self.p1,
+            transform * self.p2,
+            transform * self.p3,
+        )
+    }
+
+    pub fn neg(&self) -> Bezier {
+        Bezier::new(self.p3, self.p2, self.p1, self.p0)
+    }
+
+    pub fn point(&self, t: f64) -> Point {
+        let s = 1.0 - t;
+        self.p0 * (s * s * s)
+            + self.p1 * (3.0 * s * s * t)
+            + self.p2 * (3.0 * s * t * t)
+            + self.p3 * (t * t * t)
+    }
+
+    // First derivative with respect to the curve parameter.
+    pub fn derivative(&self, t: f64) -> Point {
+        let s = 1.0 - t;
+        (self.p1 - self.p0) * (3.0 * s * s)
+            + (self.p2 - self.p1) * (6.0 * s * t)
+            + (self.p3 - self.p2) * (3.0 * t * t)
+    }
+
+    pub fn second_derivative(&self, t: f64) -> Point {
+        (self.p2 - self.p1 * 2.0 + self.p0) * (6.0 * (1.0 - t))
+            + (self.p3 - self.p2 * 2.0 + self.p1) * (6.0 * t)
+    }
+
+    // Finds the parameter of the point on the curve that is closest to p.
+    pub fn parameter(&self, p: Point) -> f64 {
+        let mut best = 0.0;
+        for i in 0..=PROJECTION_SAMPLES {
+            let t = i as f64 / PROJECTION_SAMPLES as f64;
+            if (self.point(t) - p).norm_sq() < (self.point(best) - p).norm_sq() {
+                best = t;
+            }
+        }
+        // Newton iteration on (B(t) - p) . B'(t) = 0
+        let mut t: f64 = best;
+        for _ in 0..32 {
+            let diff = self.point(t) - p;
+            let d1 = self.derivative(t);
+            let d2 = self.second_derivative(t);
+            let f = diff.dot(d1);
+            let df = d1.dot(d1) + diff.dot(d2);
+            if df.abs() < EQ_THRESHOLD * EQ_THRESHOLD {
+                break;
+            }
+            let next = (t - f / df).clamp(0.0, 1.0);
+            if (next - t).abs() < EQ_THRESHOLD * EQ_THRESHOLD {
+                t = next;
+                break;
+            }
+            t = next;
+        }
+        match (self.point(t) - p).norm_sq() < (self.point(best) - p).norm_sq() {
+            true => t,
+            false => best,
+        }
+    }
+
+    // Splits the curve at t into two Bezier curves, using de Casteljau's algorithm. This split is exact.
+    pub fn split(&self, t: f64) -> (Bezier, Bezier) {
+        assert!(t > 0.0 && t < 1.0);
+        let lerp = |a: Point, b: Point| a + (b - a) * t;
+        let p01 = lerp(self.p0, self.p1);
+        let p12 = lerp(self.p1, self.p2);
+        let p23 = lerp(self.p2, self.p3);
+        let p012 = lerp(p01, p12);
+        let p123 = lerp(p12, p23);
+        let p0123 = lerp(p012, p123);
+        (
+            Bezier::new(self.p0, p01, p012, p0123),
+            Bezier::new(p0123, p123, p23, self.p3),
+        )
+    }
+
+    // Splits the curve at a point on the curve.
+    pub fn split_at(&self, p: Point) -> (Bezier, Bezier) {
+        assert!(self.on_curve(p));
+        self.split(self.parameter(p))
+    }
+
+    // Returns the control points of the same curve, represented as a Bezier curve of degree 4.
+    pub fn elevate_degree(&self) -> Vec<Point> {
+        vec![
+            self.p0,
+            self.p0 * 0.25 + self.p1 * 0.75,
+            (self.p1 + self.p2) * 0.5,
+            self.p2 * 0.75 + self.p3 * 0.25,
+            self.p3,
+        ]
+    }
+
+    pub fn to_bspline(&self) -> BSplineData {
+        BSplineData {
+            degree: 3,
+            knots: vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0],
+            control_points: vec![self.p0, self.p1, self.p2, self.p3],
+        }
+    }
+
+    fn arc_length(&self, t0: f64, t1: f64) -> f64 {
+        // Simpson's rule
+        let h = (t1 - t0) / ARC_LENGTH_INTERVALS as f64;
+        let mut sum = self.derivative(t0).norm() + self.derivative(t1).norm();
+        for i in 1..ARC_LENGTH_INTERVALS {
+            let weight = match i % 2 {
+                0 => 2.0,
+                _ => 4.0,
+            };
+            sum += weight * self.derivative(t0 + i as f64 * h).norm();
+        }
+        (sum * h / 3.0).abs()
+    }
+
+    // Finds the parameters where the given distance function vanishes, e.g. the distance to another curve or surface.
+    // Local minima of the sampled distance are refined by golden section search. Returns None if the whole curve has zero distance.
+    pub(crate) fn distance_roots(&self, distance: &dyn Fn(Point) -> f64) -> Option<Vec<f64>> {
+        let ts = (0..=PROJECTION_SAMPLES)
+            .map(|i| i as f64 / PROJECTION_SAMPLES as f64)
+            .collect::<Vec<f64>>();
+        let ds = ts
+            .iter()
+            .map(|t| distance(self.point(*t)))
+            .collect::<Vec<f64>>();
+        if ds.iter().all(|d| *d < EQ_THRESHOLD) {
+            return None;
+        }
+
+        let golden = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let mut roots = Vec::<f64>::new();
+        for i in 0..ts.len() {
+            let left = i == 0 || ds[i] <= ds[i - 1];
+            let right = i == ts.len() - 1 || ds[i] <= ds[i + 1];
+            if !(left && right) {
+                continue;
+            }
+            let (mut a, mut b) = (ts[i.saturating_sub(1)], ts[(i + 1).min(ts.len() - 1)]);
+            while b - a > EQ_THRESHOLD * EQ_THRESHOLD {
+                let c = b - golden * (b - a);
+                let d = a + golden * (b - a);
+                match distance(self.point(c)) < distance(self.point(d)) {
+                    true => b = d,
+                    false => a = c,
+                }
+            }
+            let t = (a + b) / 2.0;
+            if distance(self.point(t)) < EQ_THRESHOLD
+                && !roots.iter().any(|r| self.point(*r) == self.point(t))
+            {
+                roots.push(t);
+            }
+        }
+        Some(roots)
+    }
+
+    fn interval(&self, start: Option<Point>, end: Option<Point>) -> (f64, f64) {
+        (
+            start.map_or(0.0, |p| self.parameter(p)),
+            end.map_or(1.0, |p| self.parameter(p)),
+        )
+    }
+}
+
+impl CurveLike for Bezier {
+    fn transform(&self, transform: Transform) -> Curve {
+        Curve::Bezier(self.transform(transform))
+    }
+
+    fn neg(&self) -> Curve {
+        Curve::Bezier(self.neg())
+    }
+
+    fn tangent(&self, p: Point) -> Point {
+        assert!(self.on_curve(p));
+        let t = self.parameter(p);
+        let d = self.derivative(t);
+        if d.norm() > EQ_THRESHOLD {
+            return d.normalize();
+        }
+        // Coinciding control points at the ends. The tangent is then given by the second derivative.
+        match t < 0.5 {
+            true => self.second_derivative(t).normalize(),
+            false => -self.second_derivative(t).normalize(),
+        }
+    }
+
+    fn on_curve(&self, p: Point) -> bool {
+        self.project(p).distance < EQ_THRESHOLD
+    }
+
+    fn distance(&self, x: Point, y: Point) -> f64 {
+        assert!(self.on_curve(x));
+        assert!(self.on_curve(y));
+        self.arc_length(self.parameter(x), self.parameter(y))
+    }
+
+    fn interpolate(&self, start: Option<Point>, end: Option<Point>, t: f64) -> Point {
+        let (t0, t1) = self.interval(start, end);
+        self.point(t0 + t * (t1 - t0))
+    }
+
+    fn between(&self, m: Point, start: Option<Point>, end: Option<Point>) -> bool {
+        assert!(self.on_curve(m));
+        if Some(m) == start || Some(m) == end {
+            return true;
+        }
+        let (t0, t1) = self.interval(start, end);
+        let tm = self.parameter(m);
+        t0 <= tm && tm <= t1
+    }
+
+    fn get_midpoint(&self, start: Option<Point>, end: Option<Point>) -> Point {
+        self.interpolate(start, end, 0.5)
+    }
+
+    fn project(&self, p: Point) -> ClosestPoint {
+        let t = self.parameter(p);
+        ClosestPoint::new(t, self.point(t), p)
+    }
+
+    fn get_bounding_box(&self, start: Option<Point>, end: Option<Point>) -> BoundingBox {
+        let (t0, t1) = self.interval(start, end);
+        let mut bounding_box = BoundingBox::with_2_points(self.point(t0), self.point(t1));
+        // The extrema are at the roots of the derivative, which is a quadratic polynomial per coordinate.
+        let a = (self.p3 - self.p2 * 3.0 + self.p1 * 3.0 - self.p0) * 3.0;
+        let b = (self.p2 - self.p1 * 2.0 + self.p0) * 6.0;
+        let c = (self.p1 - self.p0) * 3.0;
+        for (a, b, c) in [(a.x, b.x, c.x), (a.y, b.y, c.y), (a.z, b.z, c.z)] {
+            let mut roots = Vec::<f64>::new();
+            if a.abs() < EQ_THRESHOLD {
+                if b.abs() > EQ_THRESHOLD {
+                    roots.push(-c / b);
+                }
+            } else {
+                let discriminant = b * b - 4.0 * a * c;
+                if discriminant >= 0.0 {
+                    roots.push((-b + discriminant.sqrt()) / (2.0 * a));
+                    roots.push((-b - discriminant.sqrt()) / (2.0 * a));
+                }
+            }
+            for t in roots {
+                if t > t0 && t < t1 {
+                    bounding_box.add_point(self.point(t));
+                }
+            }
+        }
+        bounding_box
+    }
+}
+
+impl PartialEq for Bezier {
+    fn eq(&self, other: &Bezier) -> bool {
+        self.p0 == other.p0 && self.p1 == other.p1 && self.p2 == other.p2 && self.p3 == other.p3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bezier() {
+        let bezier = Bezier::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+        );
+        let mid = bezier.point(0.5);
+        assert_eq!(mid, Point::new(0.5, 0.75, 0.0));
+        assert!(bezier.on_curve(mid));
+        assert!(!bezier.on_curve(Point::new(0.5, 0.5, 0.0)));
+        assert_eq!(bezier.tangent(mid), Point::unit_x());
+        assert_eq!(bezier.project(Point::new(0.5, 2.0, 0.0)).point, mid);
+
+        let (left, right) = bezier.split_at(mid);
+        assert_eq!(left.p3, mid);
+        assert_eq!(right.p0, mid);
+        assert_eq!(left.point(0.5), bezier.point(0.25));
+        assert_eq!(right.point(0.5), bezier.point(0.75));
+
+        let bounding_box = bezier.get_bounding_box(None, None);
+        assert_eq!(bounding_box.max, Point::new(1.0, 0.75, 0.0));
+
+        let length = bezier.distance(bezier.p0, bezier.p3);
+        let split_length = left.distance(left.p0, left.p3) + right.distance(right.p0, right.p3);
+        assert!((length - split_length).abs() < EQ_THRESHOLD);
+    }
+
+    // Point of the Bezier curve with any number of control points, by de Casteljau's algorithm.
+    fn de_casteljau(points: &[Point], t: f64) -> Point {
+        let mut points = points.to_vec();
+        while points.len() > 1 {
+            points = points
+                .windows(2)
+                .map(|w| w[0] * (1.0 - t) + w[1] * t)
+                .collect();
+        }
+        points[0]
+    }
+
+    // Point of the B-spline, as the sum of its control points weighted by the Cox-de Boor basis functions.
+    fn b_spline_point(spline: &BSplineData, t: f64) -> Point {
+        fn basis(knots: &[f64], i: usize, degree: usize, t: f64) -> f64 {
+            if degree == 0 {
+                // The last non-empty span includes its end, so that t = 1 is on the curve.
+                let last = knots[i + 1] == knots[knots.len() - 1] && t == knots[i + 1];
+                return match (knots[i] <= t && t < knots[i + 1])
+                    || (last && knots[i] < knots[i + 1])
+                {
+                    true => 1.0,
+                    false => 0.0,
+                };
+            }
+            let ratio = |a: f64, b: f64| if b == 0.0 { 0.0 } else { a / b };
+            ratio(t - knots[i], knots[i + degree] - knots[i]) * basis(knots, i, degree - 1, t)
+                + ratio(
+                    knots[i + degree + 1] - t,
+                    knots[i + degree + 1] - knots[i + 1],
+                ) * basis(knots, i + 1, degree - 1, t)
+        }
+        spline
+            .control_points
+            .iter()
+            .enumerate()
+            .fold(Point::zero(), |sum, (i, p)| {
+                sum + *p * basis(&spline.knots, i, spline.degree, t)
+            })
+    }
+
+    #[test]
+    fn test_bezier_conversions() {
+        let bezier = Bezier::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 2.0, 0.0),
+            Point::new(3.0, -1.0, 1.0),
+            Point::new(4.0, 0.0, 2.0),
+        );
+        let elevated = bezier.elevate_degree();
+        assert_eq!(elevated.len(), 5);
+        let spline = bezier.to_bspline();
+        assert_eq!(
+            spline.knots.len(),
+            spline.control_points.len() + spline.degree + 1
+        );
+        for i in 0..=16 {
+            let t = i as f64 / 16.0;
+            assert!(
+                (de_casteljau(&elevated, t) - bezier.point(t)).norm() < EQ_THRESHOLD,
+                "Elevated curve differs at {}",
+                t
+            );
+            assert!(
+                (b_spline_point(&spline, t) - bezier.point(t)).norm() < EQ_THRESHOLD,
+                "B-spline differs at {}",
+                t
+            );
+        }
+    }
+}
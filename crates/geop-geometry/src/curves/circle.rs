@@ -1,6 +1,13 @@
-use crate::{points::point::Point, transforms::Transform, EQ_THRESHOLD};
+use std::f64::consts::PI;
 
-use super::{curve::Curve, CurveLike};
+use crate::{
+    periodic::{periodic_between, wrap},
+    points::point::Point,
+    transforms::Transform,
+    EQ_THRESHOLD,
+};
+
+use super::{curve::Curve, ellipse::Ellipse, ClosestPoint, CurveLike};
 
 #[derive(Debug, Clone)]
 pub struct Circle {
@@ -12,7 +19,7 @@ pub struct Circle {
 
 pub enum CircleTransform {
     Circle(Circle),
-    Ellipse(), // TODO: Implement this
+    Ellipse(Ellipse), // Under non-uniform scaling
 }
 
 impl Circle {
@@ -40,21 +47,42 @@ impl Circle {
         let basis = transform * self.basis;
         let normal = transform * (self.normal + self.basis) - basis;
         let radius = transform * (self.radius + self.basis) - basis;
-        let scale_factor = radius.norm() / self.radius.norm();
-        assert!((normal.norm() - scale_factor * self.normal.norm()) < EQ_THRESHOLD, "Circle can only be transformed with uniform scaling. An extension of this method is planned to return ellipsis.");
+        let dir_cross = transform * (self.dir_cross + self.basis) - basis;
+        if (radius.norm() - dir_cross.norm()).abs() > EQ_THRESHOLD
+            || radius.dot(dir_cross).abs() > EQ_THRESHOLD
+        {
+            return CircleTransform::Ellipse(Ellipse::from_conjugate_radii(
+                basis, radius, dir_cross,
+            ));
+        }
         CircleTransform::Circle(Circle::new(basis, normal.normalize(), radius.norm()))
     }
 
     pub fn neg(&self) -> Circle {
         Circle::new(self.basis, -self.normal, self.radius.norm())
     }
+
+    // Angle of p around the normal in [0, 2pi), measured from the radius vector.
+    pub fn angle(&self, p: Point) -> f64 {
+        let p = p - self.basis;
+        wrap(self.dir_cross.dot(p).atan2(self.radius.dot(p)), 2.0 * PI)
+    }
+
+    fn point_at_angle(&self, angle: f64) -> Point {
+        angle.cos() * self.radius + angle.sin() * self.dir_cross + self.basis
+    }
+
+    // Tolerance for angles, such that points within EQ_THRESHOLD on the circle are equal.
+    fn angle_tolerance(&self) -> f64 {
+        EQ_THRESHOLD / self.radius.norm()
+    }
 }
 
 impl CurveLike for Circle {
     fn transform(&self, transform: Transform) -> Curve {
         match self.transform(transform) {
             CircleTransform::Circle(circle) => Curve::Circle(circle),
-            CircleTransform::Ellipse() => todo!("Implement this"),
+            CircleTransform::Ellipse(ellipse) => Curve::Ellipse(ellipse),
         }
     }
 
@@ -75,7 +103,8 @@ impl CurveLike for Circle {
     fn distance(&self, x: Point, y: Point) -> f64 {
         assert!(self.on_curve(x));
         assert!(self.on_curve(y));
-        let angle = (x - self.basis).angle(y - self.basis);
+        // Along the circle in its direction, so that arcs over more than half of the circle have the right length.
+        let angle = wrap(self.angle(y) - self.angle(x), 2.0 * PI);
         self.radius.norm() * angle
     }
 
@@ -84,42 +113,20 @@ impl CurveLike for Circle {
             (Some(start), Some(end)) => {
                 assert!(self.on_curve(start));
                 assert!(self.on_curve(end));
-                let start = start - self.basis;
-                let end = end - self.basis;
-                let x_start = self.radius.dot(start);
-                let x_end = self.radius.dot(end);
-                let y_start = self.dir_cross.dot(start);
-                let y_end = self.dir_cross.dot(end);
-                let angle1 = y_start.atan2(x_start);
-                let mut angle2 = y_end.atan2(x_end);
-                if angle2 < angle1 {
-                    angle2 += 2.0 * std::f64::consts::PI;
-                }
-                let angle = angle1 + t * (angle2 - angle1);
-                angle.cos() * self.radius + angle.sin() * self.dir_cross + self.basis
+                let angle1 = self.angle(start);
+                // The sweep is measured modulo 2pi, so that arcs across the seam at angle 0 do not go the long way around.
+                let sweep = wrap(self.angle(end) - angle1, 2.0 * PI);
+                self.point_at_angle(angle1 + t * sweep)
             }
             (Some(start), None) => {
                 assert!(self.on_curve(start));
-                let start = start - self.basis;
-                let x_start = self.radius.dot(start);
-                let y_start = self.dir_cross.dot(start);
-                let angle1 = y_start.atan2(x_start);
-                let angle = angle1 + t * std::f64::consts::PI * 2.0;
-                angle.cos() * self.radius + angle.sin() * self.dir_cross + self.basis
+                self.point_at_angle(self.angle(start) + t * PI * 2.0)
             }
             (None, Some(end)) => {
                 assert!(self.on_curve(end));
-                let end = end - self.basis;
-                let x_end = self.radius.dot(end);
-                let y_end = self.dir_cross.dot(end);
-                let angle2 = y_end.atan2(x_end);
-                let angle = angle2 + t * std::f64::consts::PI * 2.0;
-                angle.cos() * self.radius + angle.sin() * self.dir_cross + self.basis
-            }
-            (None, None) => {
-                let angle = t * std::f64::consts::PI * 2.0;
-                angle.cos() * self.radius + angle.sin() * self.dir_cross + self.basis
+                self.point_at_angle(self.angle(end) + t * PI * 2.0)
             }
+            (None, None) => self.point_at_angle(t * PI * 2.0),
         }
     }
 
@@ -130,19 +137,13 @@ impl CurveLike for Circle {
             (Some(start), Some(end)) => {
                 assert!(self.on_curve(start));
                 assert!(self.on_curve(end));
-                let start = start - self.basis;
-                let end = end - self.basis;
-                let m = m - self.basis;
-                let angle_start = self.dir_cross.dot(start).atan2(self.radius.dot(start));
-                let mut angle_end = self.dir_cross.dot(end).atan2(self.radius.dot(end));
-                let mut angle_m = self.dir_cross.dot(m).atan2(self.radius.dot(m));
-                if angle_end < angle_start {
-                    angle_end += 2.0 * std::f64::consts::PI;
-                }
-                if angle_m < angle_start {
-                    angle_m += 2.0 * std::f64::consts::PI;
-                }
-                angle_start <= angle_m && angle_m <= angle_end
+                periodic_between(
+                    self.angle(m),
+                    self.angle(start),
+                    self.angle(end),
+                    2.0 * PI,
+                    self.angle_tolerance(),
+                )
             }
             (Some(start), None) => {
                 assert!(self.on_curve(start));
@@ -190,10 +191,9 @@ impl CurveLike for Circle {
         }
     }
 
-    fn project(&self, p: Point) -> Point {
-        let v = p - self.basis;
-        let v = v - self.normal * (v.dot(self.normal));
-        v.normalize() * self.radius.norm() + self.basis
+    fn project(&self, p: Point) -> ClosestPoint {
+        let angle = self.angle(p);
+        ClosestPoint::new(angle, self.point_at_angle(angle), p)
     }
 
     fn get_bounding_box(
@@ -205,9 +205,12 @@ impl CurveLike for Circle {
     }
 }
 
-// Implement partial eqality for Circle
+// Implement partial eqality for Circle. The radius vector only sets where the parameter starts, so circles that were flipped or
+// transformed back are still equal.
 impl PartialEq for Circle {
     fn eq(&self, other: &Circle) -> bool {
-        self.basis == other.basis && self.normal == other.normal && self.radius == other.radius
+        self.basis == other.basis
+            && self.normal == other.normal
+            && (self.radius.norm() - other.radius.norm()).abs() < EQ_THRESHOLD
     }
 }
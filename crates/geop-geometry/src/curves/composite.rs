@@ -0,0 +1,303 @@
+use crate::{bounding_box::BoundingBox, points::point::Point, transforms::Transform, EQ_THRESHOLD};
+
+use super::{curve::Curve, ClosestPoint, CurveLike};
+
+// A bounded piece of a curve, going from start to end in the direction of the curve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeSegment {
+    pub curve: Curve,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl CompositeSegment {
+    pub fn new(curve: Curve, start: Point, end: Point) -> CompositeSegment {
+        assert!(curve.on_curve(start));
+        assert!(curve.on_curve(end));
+        assert!(
+            start != end,
+            "Segments have to be shorter than a full period"
+        );
+        CompositeSegment { curve, start, end }
+    }
+
+    // Length of the segment from start to p, measured along the curve.
+    fn length_to(&self, p: Point) -> f64 {
+        match &self.curve {
+            Curve::Circle(circle) => {
+                let mut angle = (self.start - circle.basis).angle2(p - circle.basis, circle.normal);
+                if angle < 0.0 {
+                    angle += 2.0 * std::f64::consts::PI;
+                }
+                angle * circle.radius.norm()
+            }
+            curve => curve.distance(self.start, p),
+        }
+    }
+
+    fn length(&self) -> f64 {
+        self.length_to(self.end)
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        self.curve.on_curve(p) && self.curve.between(p, Some(self.start), Some(self.end))
+    }
+}
+
+// A chain of curve segments that behaves like a single curve, e.g. a profile of straight lines and fillet arcs.
+// Internally, points are located by their arc length from the start, which gives a continuous parameterization across the segments.
+#[derive(Debug, Clone)]
+pub struct CompositeCurve {
+    pub segments: Vec<CompositeSegment>,
+    offsets: Vec<f64>, // Arc length at the start of each segment
+    length: f64,
+}
+
+impl CompositeCurve {
+    pub fn new(segments: Vec<CompositeSegment>) -> CompositeCurve {
+        assert!(!segments.is_empty());
+        for i in 0..segments.len() - 1 {
+            assert!(
+                segments[i].end == segments[i + 1].start,
+                "Segments have to be connected"
+            );
+        }
+        let mut offsets = Vec::with_capacity(segments.len());
+        let mut length = 0.0;
+        for segment in segments.iter() {
+            offsets.push(length);
+            length += segment.length();
+        }
+        CompositeCurve {
+            segments,
+            offsets,
+            length,
+        }
+    }
+
+    pub fn start(&self) -> Point {
+        self.segments[0].start
+    }
+
+    pub fn end(&self) -> Point {
+        self.segments[self.segments.len() - 1].end
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.start() == self.end()
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    pub fn transform(&self, transform: Transform) -> CompositeCurve {
+        CompositeCurve::new(
+            self.segments
+                .iter()
+                .map(|s| {
+                    CompositeSegment::new(
+                        s.curve.transform(transform),
+                        transform * s.start,
+                        transform * s.end,
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    pub fn neg(&self) -> CompositeCurve {
+        CompositeCurve::new(
+            self.segments
+                .iter()
+                .rev()
+                .map(|s| CompositeSegment::new(s.curve.neg(), s.end, s.start))
+                .collect(),
+        )
+    }
+
+    // Arc length from the start of the curve to p. For closed curves, the start point has position 0.
+    fn position(&self, p: Point) -> f64 {
+        for (segment, offset) in self.segments.iter().zip(self.offsets.iter()) {
+            if segment.contains(p) {
+                return offset + segment.length_to(p);
+            }
+        }
+        panic!("Point is not on composite curve");
+    }
+
+    fn point_at(&self, position: f64) -> Point {
+        let position = match self.is_closed() {
+            true => position.rem_euclid(self.length),
+            false => position.clamp(0.0, self.length),
+        };
+        for (segment, offset) in self.segments.iter().zip(self.offsets.iter()) {
+            let length = segment.length();
+            if position <= offset + length {
+                let t = ((position - offset) / length).clamp(0.0, 1.0);
+                return segment
+                    .curve
+                    .interpolate(Some(segment.start), Some(segment.end), t);
+            }
+        }
+        self.end()
+    }
+
+    // Positions of start and end, such that start <= end. None means the start or end of the whole curve.
+    fn interval(&self, start: Option<Point>, end: Option<Point>) -> (f64, f64) {
+        let s0 = start.map_or(0.0, |p| self.position(p));
+        let mut s1 = end.map_or(self.length, |p| self.position(p));
+        if self.is_closed() && s1 <= s0 + EQ_THRESHOLD && end.is_some() {
+            s1 += self.length;
+        }
+        assert!(s0 <= s1 + EQ_THRESHOLD, "End comes before start");
+        (s0, s1)
+    }
+}
+
+impl CurveLike for CompositeCurve {
+    fn transform(&self, transform: Transform) -> Curve {
+        Curve::Composite(self.transform(transform))
+    }
+
+    fn neg(&self) -> Curve {
+        Curve::Composite(self.neg())
+    }
+
+    // At the joint of two segments, the tangent of the outgoing segment is returned.
+    fn tangent(&self, p: Point) -> Point {
+        for segment in self.segments.iter() {
+            if segment.start == p || segment.contains(p) {
+                return segment.curve.tangent(p);
+            }
+        }
+        panic!("Point is not on composite curve");
+    }
+
+    fn on_curve(&self, p: Point) -> bool {
+        self.segments.iter().any(|s| s.contains(p))
+    }
+
+    fn distance(&self, x: Point, y: Point) -> f64 {
+        let d = (self.position(y) - self.position(x)).abs();
+        match self.is_closed() {
+            true => d.min(self.length - d),
+            false => d,
+        }
+    }
+
+    fn interpolate(&self, start: Option<Point>, end: Option<Point>, t: f64) -> Point {
+        let (s0, s1) = self.interval(start, end);
+        self.point_at(s0 + t * (s1 - s0))
+    }
+
+    fn between(&self, m: Point, start: Option<Point>, end: Option<Point>) -> bool {
+        assert!(self.on_curve(m));
+        if Some(m) == start || Some(m) == end {
+            return true;
+        }
+        let (s0, s1) = self.interval(start, end);
+        let mut sm = self.position(m);
+        if self.is_closed() && sm < s0 {
+            sm += self.length;
+        }
+        s0 - EQ_THRESHOLD <= sm && sm <= s1 + EQ_THRESHOLD
+    }
+
+    fn get_midpoint(&self, start: Option<Point>, end: Option<Point>) -> Point {
+        self.interpolate(start, end, 0.5)
+    }
+
+    fn project(&self, p: Point) -> ClosestPoint {
+        let mut closest = self.start();
+        for segment in self.segments.iter() {
+            let mut candidates = vec![segment.start, segment.end];
+            let projected = segment.curve.project(p).point;
+            if segment.contains(projected) {
+                candidates.push(projected);
+            }
+            for candidate in candidates {
+                if (candidate - p).norm() < (closest - p).norm() {
+                    closest = candidate;
+                }
+            }
+        }
+        ClosestPoint::new(self.position(closest), closest, p)
+    }
+
+    fn get_bounding_box(&self, start: Option<Point>, end: Option<Point>) -> BoundingBox {
+        let (s0, s1) = self.interval(start, end);
+        let mut bounding_box = BoundingBox::with_2_points(self.point_at(s0), self.point_at(s1));
+        // Add all segments that overlap with the interval. Closed curves may wrap around once.
+        for round in 0..2 {
+            for (segment, offset) in self.segments.iter().zip(self.offsets.iter()) {
+                let offset = offset + round as f64 * self.length;
+                let length = segment.length();
+                if offset + length < s0 || offset > s1 {
+                    continue;
+                }
+                let a = match offset < s0 {
+                    true => self.point_at(s0),
+                    false => segment.start,
+                };
+                let b = match offset + length > s1 {
+                    true => self.point_at(s1),
+                    false => segment.end,
+                };
+                if a == b {
+                    continue;
+                }
+                let segment_box = segment.curve.get_bounding_box(Some(a), Some(b));
+                bounding_box.add_point(segment_box.min);
+                bounding_box.add_point(segment_box.max);
+            }
+        }
+        bounding_box
+    }
+}
+
+impl PartialEq for CompositeCurve {
+    fn eq(&self, other: &CompositeCurve) -> bool {
+        self.segments == other.segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::{circle::Circle, line::Line};
+
+    #[test]
+    fn test_composite_curve() {
+        // A straight line followed by a quarter circle.
+        let p1 = Point::new(0.0, 0.0, 0.0);
+        let p2 = Point::new(1.0, 0.0, 0.0);
+        let p3 = Point::new(2.0, 1.0, 0.0);
+        let composite = CompositeCurve::new(vec![
+            CompositeSegment::new(Curve::Line(Line::new(p1, p2 - p1)), p1, p2),
+            CompositeSegment::new(
+                Curve::Circle(Circle::new(Point::new(1.0, 1.0, 0.0), Point::unit_z(), 1.0)),
+                p2,
+                p3,
+            ),
+        ]);
+        let quarter = std::f64::consts::FRAC_PI_2;
+        assert!((composite.length() - (1.0 + quarter)).abs() < EQ_THRESHOLD);
+        assert!(composite.on_curve(Point::new(0.5, 0.0, 0.0)));
+        assert!(!composite.on_curve(Point::new(0.0, 1.0, 0.0)));
+
+        let t = 1.0 / (1.0 + quarter);
+        assert_eq!(composite.interpolate(Some(p1), Some(p3), t), p2);
+        assert_eq!(composite.tangent(p2), Point::unit_x());
+        assert!(composite.between(p2, Some(p1), Some(p3)));
+        assert!(!composite.between(p1, Some(p2), Some(p3)));
+        let closest = composite.project(Point::new(0.5, -1.0, 0.0));
+        assert_eq!(closest.point, Point::new(0.5, 0.0, 0.0));
+        assert!((closest.parameter - 0.5).abs() < EQ_THRESHOLD);
+        assert!((closest.distance - 1.0).abs() < EQ_THRESHOLD);
+
+        let neg = composite.neg();
+        assert_eq!(neg.start(), p3);
+        assert_eq!(neg.tangent(p2), -Point::unit_x());
+    }
+}
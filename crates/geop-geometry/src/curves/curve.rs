@@ -3,11 +3,13 @@ use std::fmt::Debug;
 use crate::{bounding_box::BoundingBox, points::point::Point, transforms::Transform};
 
 use super::{
+    bezier::Bezier,
     circle::{Circle, CircleTransform},
+    composite::CompositeCurve,
     ellipse::Ellipse,
     helix::Helix,
     line::Line,
-    CurveLike,
+    ClosestPoint, CurveLike,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -16,6 +18,8 @@ pub enum Curve {
     Circle(Circle),
     Ellipse(Ellipse),
     Helix(Helix),
+    Bezier(Bezier),
+    Composite(CompositeCurve),
 }
 
 // This represents a curve, which can be a line or a circle.
@@ -26,10 +30,12 @@ impl CurveLike for Curve {
             Curve::Line(line) => Curve::Line(line.transform(transform)),
             Curve::Circle(circle) => match circle.transform(transform) {
                 CircleTransform::Circle(circle) => Curve::Circle(circle),
-                CircleTransform::Ellipse() => todo!("Implement this"),
+                CircleTransform::Ellipse(ellipse) => Curve::Ellipse(ellipse),
             },
             Curve::Ellipse(ellipse) => Curve::Ellipse(ellipse.transform(transform)),
             Curve::Helix(helix) => Curve::Helix(helix.transform(transform)),
+            Curve::Bezier(bezier) => Curve::Bezier(bezier.transform(transform)),
+            Curve::Composite(composite) => Curve::Composite(composite.transform(transform)),
         }
     }
 
@@ -40,6 +46,8 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => Curve::Circle(circle.neg()),
             Curve::Ellipse(ellipse) => Curve::Ellipse(ellipse.neg()),
             Curve::Helix(helix) => Curve::Helix(helix.neg()),
+            Curve::Bezier(bezier) => Curve::Bezier(bezier.neg()),
+            Curve::Composite(composite) => Curve::Composite(composite.neg()),
         }
     }
 
@@ -50,6 +58,8 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => circle.tangent(p),
             Curve::Ellipse(ellipse) => ellipse.tangent(p),
             Curve::Helix(helix) => helix.tangent(p),
+            Curve::Bezier(bezier) => bezier.tangent(p),
+            Curve::Composite(composite) => composite.tangent(p),
         }
     }
 
@@ -60,6 +70,8 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => circle.on_curve(p),
             Curve::Ellipse(ellipse) => ellipse.on_curve(p),
             Curve::Helix(helix) => helix.on_curve(p),
+            Curve::Bezier(bezier) => bezier.on_curve(p),
+            Curve::Composite(composite) => composite.on_curve(p),
         }
     }
 
@@ -70,6 +82,8 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => circle.distance(x, y),
             Curve::Ellipse(ellipse) => ellipse.distance(x, y),
             Curve::Helix(helix) => helix.distance(x, y),
+            Curve::Bezier(bezier) => bezier.distance(x, y),
+            Curve::Composite(composite) => composite.distance(x, y),
         }
     }
 
@@ -80,6 +94,8 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => circle.interpolate(start, end, t),
             Curve::Ellipse(ellipse) => ellipse.interpolate(start, end, t),
             Curve::Helix(helix) => helix.interpolate(start, end, t),
+            Curve::Bezier(bezier) => bezier.interpolate(start, end, t),
+            Curve::Composite(composite) => composite.interpolate(start, end, t),
         }
     }
 
@@ -90,6 +106,8 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => circle.between(m, start, end),
             Curve::Ellipse(ellipse) => ellipse.between(m, start, end),
             Curve::Helix(helix) => helix.between(m, start, end),
+            Curve::Bezier(bezier) => bezier.between(m, start, end),
+            Curve::Composite(composite) => composite.between(m, start, end),
         }
     }
 
@@ -102,16 +120,20 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => circle.get_midpoint(start, end),
             Curve::Ellipse(ellipse) => ellipse.get_midpoint(start, end),
             Curve::Helix(helix) => helix.get_midpoint(start, end),
+            Curve::Bezier(bezier) => bezier.get_midpoint(start, end),
+            Curve::Composite(composite) => composite.get_midpoint(start, end),
         }
     }
 
     // Finds the closest point on the curve to the given point.
-    fn project(&self, p: Point) -> Point {
+    fn project(&self, p: Point) -> ClosestPoint {
         match self {
             Curve::Line(line) => line.project(p),
             Curve::Circle(circle) => circle.project(p),
             Curve::Ellipse(ellipse) => ellipse.project(p),
             Curve::Helix(helix) => helix.project(p),
+            Curve::Bezier(bezier) => bezier.project(p),
+            Curve::Composite(composite) => composite.project(p),
         }
     }
 
@@ -125,6 +147,8 @@ impl CurveLike for Curve {
             Curve::Circle(circle) => circle.get_bounding_box(interval_self, midpoint_self),
             Curve::Ellipse(ellipse) => ellipse.get_bounding_box(interval_self, midpoint_self),
             Curve::Helix(helix) => helix.get_bounding_box(interval_self, midpoint_self),
+            Curve::Bezier(bezier) => bezier.get_bounding_box(interval_self, midpoint_self),
+            Curve::Composite(composite) => composite.get_bounding_box(interval_self, midpoint_self),
         }
     }
 }
@@ -1,6 +1,9 @@
 use crate::{bounding_box::BoundingBox, points::point::Point, transforms::Transform, EQ_THRESHOLD};
 
-use super::{curve::Curve, CurveLike};
+use super::{curve::Curve, ClosestPoint, CurveLike};
+
+// Number of samples to find the starting point for projecting onto the ellipse.
+const PROJECTION_SAMPLES: usize = 32;
 
 #[derive(Debug, Clone)]
 pub struct Ellipse {
@@ -33,6 +36,38 @@ impl Ellipse {
         }
     }
 
+    // The ellipse through basis + a * cos(t) + b * sin(t), where a and b are any two conjugate radii, e.g. the images of two
+    // orthogonal radii of a circle under a linear map. The principal axes are found at the angle where the radius is orthogonal to
+    // the tangent, and the ellipse runs counterclockwise around a x b like the parametrization does.
+    pub fn from_conjugate_radii(basis: Point, a: Point, b: Point) -> Ellipse {
+        let normal = a.cross(b);
+        assert!(
+            normal.norm() > EQ_THRESHOLD,
+            "Conjugate radii must not be parallel"
+        );
+        let t = 0.5 * (2.0 * a.dot(b)).atan2(a.norm_sq() - b.norm_sq());
+        let first = a * t.cos() + b * t.sin();
+        let second = b * t.cos() - a * t.sin();
+        // Swapping the axes by a quarter turn keeps the orientation.
+        let (major_radius, minor_radius) = match first.norm() >= second.norm() {
+            true => (first, second),
+            false => (second, -first),
+        };
+        Ellipse::new(basis, normal, major_radius, minor_radius)
+    }
+
+    // The angle of p from the major radius towards the minor radius, in the parametrization of point_at.
+    pub fn angle(&self, p: Point) -> f64 {
+        let p = p - self.basis;
+        let x = self.major_radius.dot(p) / self.major_radius.norm_sq();
+        let y = self.minor_radius.dot(p) / self.minor_radius.norm_sq();
+        y.atan2(x)
+    }
+
+    pub fn point_at(&self, angle: f64) -> Point {
+        self.basis + self.major_radius * angle.cos() + self.minor_radius * angle.sin()
+    }
+
     fn transform_point_to_circle(&self, p: Point) -> Point {
         assert!(self.on_curve(p));
         let p = p - self.basis;
@@ -47,20 +82,21 @@ impl Ellipse {
         p.x * self.major_radius + p.y * self.minor_radius + self.basis
     }
 
+    // The radii stay conjugate under any affine transform, also under non-uniform scaling where they stop being orthogonal.
     pub fn transform(&self, transform: Transform) -> Ellipse {
         let basis = transform * self.basis;
-        let normal = transform * (self.normal + self.basis) - basis;
         let major_radius = transform * (self.major_radius + self.basis) - basis;
         let minor_radius = transform * (self.minor_radius + self.basis) - basis;
-        Ellipse::new(basis, normal, major_radius, minor_radius)
+        Ellipse::from_conjugate_radii(basis, major_radius, minor_radius)
     }
 
+    // The same ellipse running the other way around.
     pub fn neg(&self) -> Ellipse {
         Ellipse::new(
             self.basis,
             -self.normal,
             self.major_radius,
-            self.minor_radius,
+            -self.minor_radius,
         )
     }
 
@@ -110,10 +146,8 @@ impl CurveLike for Ellipse {
 
     fn tangent(&self, p: Point) -> Point {
         assert!(self.on_curve(p));
-        let p = p - self.basis;
-        let x = self.major_radius.dot(p) / self.major_radius.norm();
-        let y = self.minor_radius.dot(p) / self.minor_radius.norm();
-        let tangent = y * self.major_radius - x * self.minor_radius;
+        let angle = self.angle(p);
+        let tangent = self.minor_radius * angle.cos() - self.major_radius * angle.sin();
         tangent.normalize()
     }
 
@@ -247,10 +281,31 @@ impl CurveLike for Ellipse {
         }
     }
 
-    fn project(&self, p: Point) -> Point {
+    fn project(&self, p: Point) -> ClosestPoint {
         let v = p - self.basis;
-        let v = v - self.normal * (v.dot(self.normal));
-        v.normalize() * self.major_radius.norm() + self.basis
+        let point = |angle: f64| self.major_radius * angle.cos() + self.minor_radius * angle.sin();
+        let derivative =
+            |angle: f64| self.minor_radius * angle.cos() - self.major_radius * angle.sin();
+        // Newton iteration on (E(t) - v) . E'(t) = 0, starting from the closest of a few samples.
+        let mut angle = (0..PROJECTION_SAMPLES)
+            .map(|i| i as f64 / PROJECTION_SAMPLES as f64 * 2.0 * std::f64::consts::PI)
+            .min_by(|a, b| (point(*a) - v).norm().total_cmp(&(point(*b) - v).norm()))
+            .unwrap();
+        for _ in 0..32 {
+            let diff = point(angle) - v;
+            let f = diff.dot(derivative(angle));
+            let df = derivative(angle).norm_sq() - diff.dot(point(angle));
+            if df.abs() < EQ_THRESHOLD * EQ_THRESHOLD {
+                break;
+            }
+            let step = f / df;
+            angle -= step;
+            if step.abs() < EQ_THRESHOLD * EQ_THRESHOLD {
+                break;
+            }
+        }
+        let angle = angle.rem_euclid(2.0 * std::f64::consts::PI);
+        ClosestPoint::new(angle, self.basis + point(angle), p)
     }
 
     fn get_bounding_box(&self, start: Option<Point>, end: Option<Point>) -> BoundingBox {
@@ -300,3 +355,51 @@ impl PartialEq for Ellipse {
             && self.minor_radius == other.minor_radius
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::curves::circle::{Circle, CircleTransform};
+
+    use super::*;
+
+    #[test]
+    fn test_ellipse_from_scaled_circle() {
+        // A tilted circle, stretched along x, becomes an ellipse through the images of its points.
+        let circle = Circle::new(Point::zero(), Point::new(0.0, 1.0, 1.0), 1.0);
+        let stretch = Transform::from_scale(Point::new(3.0, 1.0, 1.0));
+        let ellipse = match circle.transform(stretch) {
+            CircleTransform::Ellipse(ellipse) => ellipse,
+            CircleTransform::Circle(_) => panic!("Expected an ellipse"),
+        };
+        assert!((ellipse.major_radius.norm() - 3.0).abs() < 1e-9);
+        assert!((ellipse.minor_radius.norm() - 1.0).abs() < 1e-9);
+        for t in [0.1, 0.3, 0.7] {
+            let p = circle.interpolate(None, None, t);
+            assert!(
+                ellipse.on_curve(stretch * p),
+                "{:?} is not on the ellipse",
+                p
+            );
+        }
+
+        // The tangent follows the parametrization, and the negated ellipse runs the other way.
+        let t = 0.4;
+        let step = ellipse.point_at(t + 1e-6) - ellipse.point_at(t);
+        let p = ellipse.point_at(t);
+        assert!(ellipse.tangent(p).dot(step) > 0.0);
+        assert!(ellipse.neg().tangent(p).dot(step) < 0.0);
+        assert!(
+            ellipse
+                .normal
+                .dot(ellipse.major_radius.cross(ellipse.minor_radius))
+                > 0.0
+        );
+
+        // Uniform scaling keeps a circle.
+        let scale = Transform::from_scale(Point::new(2.0, 2.0, 2.0));
+        assert!(matches!(
+            circle.transform(scale),
+            CircleTransform::Circle(_)
+        ));
+    }
+}
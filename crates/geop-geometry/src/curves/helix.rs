@@ -2,7 +2,7 @@ use core::f64;
 
 use crate::{points::point::Point, transforms::Transform, EQ_THRESHOLD, HORIZON_DIST};
 
-use super::{curve::Curve, CurveLike};
+use super::{curve::Curve, ClosestPoint, CurveLike};
 
 #[derive(Debug, Clone)]
 pub struct Helix {
@@ -39,6 +39,10 @@ impl Helix {
         Helix::new(basis, pitch, radius, self.right_winding)
     }
 
+    pub fn is_right_winding(&self) -> bool {
+        self.right_winding
+    }
+
     pub fn neg(&self) -> Helix {
         Helix::new(self.basis, -self.pitch, self.radius, self.right_winding)
     }
@@ -188,8 +192,41 @@ impl CurveLike for Helix {
         }
     }
 
-    fn project(&self, _p: Point) -> Point {
-        todo!("Implement this")
+    // The parameter is the number of turns t of the helix equation. The closest point is in the turn around the height of p, or in
+    // one of its neighbours, at about the angle of p around the axis. Newton's method then finds the foot of the perpendicular.
+    fn project(&self, p: Point) -> ClosestPoint {
+        let v = p - self.basis;
+        let height = v.dot(self.pitch) / self.pitch.norm_sq();
+        let angle = v.dot(self.dir_cross).atan2(v.dot(self.radius)) / (2.0 * f64::consts::PI);
+        let turn = (height - angle).round();
+        let mut best: Option<ClosestPoint> = None;
+        for k in -1..=1 {
+            let mut t = angle + turn + k as f64;
+            for _ in 0..32 {
+                let (c, s) = (
+                    (2.0 * f64::consts::PI * t).cos(),
+                    (2.0 * f64::consts::PI * t).sin(),
+                );
+                let w = 2.0 * f64::consts::PI;
+                let d1 = self.pitch + (self.dir_cross * c - self.radius * s) * w;
+                let d2 = -(self.radius * c + self.dir_cross * s) * (w * w);
+                let r = self.point_at_pitch(t) - p;
+                let slope = d2.dot(r) + d1.norm_sq();
+                if slope.abs() < EQ_THRESHOLD {
+                    break;
+                }
+                let step = d1.dot(r) / slope;
+                t -= step;
+                if step.abs() < EQ_THRESHOLD * EQ_THRESHOLD {
+                    break;
+                }
+            }
+            let candidate = ClosestPoint::new(t, self.point_at_pitch(t), p);
+            if best.is_none_or(|b| candidate.distance < b.distance) {
+                best = Some(candidate);
+            }
+        }
+        best.unwrap()
     }
 
     fn get_bounding_box(
@@ -207,3 +244,33 @@ impl PartialEq for Helix {
         self.basis == other.basis && self.pitch == other.pitch && self.radius == other.radius
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helix_project() {
+        let helix = Helix::new(Point::zero(), Point::unit_z(), Point::unit_x(), true);
+
+        // Points on the helix project onto themselves with their number of turns.
+        for t in [-1.7, 0.0, 0.25, 2.6] {
+            let closest = helix.project(helix.point_at_pitch(t));
+            assert!(
+                (closest.parameter - t).abs() < 1e-9,
+                "Expected {} got {}",
+                t,
+                closest.parameter
+            );
+            assert!(closest.distance < 1e-9);
+        }
+
+        // A point outside of the helix projects onto the turn next to it.
+        let on_helix = helix.point_at_pitch(1.25);
+        let closest = helix.project(on_helix + (on_helix - Point::new(0.0, 0.0, 1.25)));
+        assert!((closest.parameter - 1.25).abs() < 1e-9);
+        assert_eq!(closest.point, on_helix);
+        assert!((closest.distance - 1.0).abs() < 1e-9);
+        assert!(closest.inside_bounds);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{points::point::Point, transforms::Transform, EQ_THRESHOLD, HORIZON_DIST};
 
-use super::{curve::Curve, CurveLike};
+use super::{curve::Curve, ClosestPoint, CurveLike};
 
 #[derive(Debug, Clone)]
 pub struct Line {
@@ -100,9 +100,9 @@ impl CurveLike for Line {
         }
     }
 
-    fn project(&self, p: Point) -> Point {
-        let v = p - self.basis;
-        self.basis + self.direction * v.dot(self.direction)
+    fn project(&self, p: Point) -> ClosestPoint {
+        let t = (p - self.basis).dot(self.direction) / self.direction.norm_sq();
+        ClosestPoint::new(t, self.basis + self.direction * t, p)
     }
 
     fn get_bounding_box(
@@ -5,11 +5,37 @@ use crate::{bounding_box::BoundingBox, points::point::Point, transforms::Transfo
 pub mod curve;
 
 // Alphabetic order
+pub mod bezier;
 pub mod circle;
+pub mod composite;
 pub mod ellipse;
 pub mod helix;
 pub mod line;
 
+// The point on a curve that is closest to some other point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosestPoint {
+    // Where the point is on the curve, in the natural parameter of the curve: the multiple of the direction for lines, the angle for
+    // circles and ellipses, the number of turns for helices, t for bezier curves and the arc length for composite curves.
+    pub parameter: f64,
+    pub point: Point,
+    pub distance: f64,
+    // False if the curve is bounded, e.g. by the start and end point of an edge, and the closest point is a bound instead of the
+    // foot of the perpendicular.
+    pub inside_bounds: bool,
+}
+
+impl ClosestPoint {
+    pub fn new(parameter: f64, point: Point, other: Point) -> ClosestPoint {
+        ClosestPoint {
+            parameter,
+            point,
+            distance: (point - other).norm(),
+            inside_bounds: true,
+        }
+    }
+}
+
 // CurveLike is a trait that all curves should implement.
 pub trait CurveLike {
     // Transform
@@ -39,7 +65,7 @@ pub trait CurveLike {
     fn get_midpoint(&self, start: Option<Point>, end: Option<Point>) -> Point;
 
     // Finds the closest point on the curve to the given point.
-    fn project(&self, p: Point) -> Point;
+    fn project(&self, p: Point) -> ClosestPoint;
 
     fn get_bounding_box(
         &self,
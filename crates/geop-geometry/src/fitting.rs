@@ -0,0 +1,266 @@
+use crate::{
+    curves::{circle::Circle, line::Line},
+    points::point::Point,
+    surfaces::{plane::Plane, sphere::Sphere},
+    EQ_THRESHOLD,
+};
+
+// Least squares fits of primitives to point sets, e.g. to recognize primitives in meshes or to evaluate measured points.
+
+// A fitted primitive together with the distances of the points to it.
+#[derive(Debug, Clone)]
+pub struct Fit<T> {
+    pub shape: T,
+    pub rms_residual: f64,
+    pub max_residual: f64,
+}
+
+impl<T> Fit<T> {
+    fn new(shape: T, residuals: impl Iterator<Item = f64>) -> Fit<T> {
+        let mut sum_sq = 0.0;
+        let mut max: f64 = 0.0;
+        let mut n = 0;
+        for r in residuals {
+            sum_sq += r * r;
+            max = max.max(r.abs());
+            n += 1;
+        }
+        Fit {
+            shape,
+            rms_residual: (sum_sq / n as f64).sqrt(),
+            max_residual: max,
+        }
+    }
+}
+
+// Principal component analysis of a point set.
+#[derive(Debug, Clone)]
+pub struct PrincipalAxes {
+    pub centroid: Point,
+    // Orthonormal and right handed, sorted by descending variance.
+    pub axes: [Point; 3],
+    pub variances: [f64; 3],
+}
+
+// Eigen decomposition of a symmetric 3x3 matrix with the cyclic Jacobi method. Returns the eigenvalues and the eigenvectors as columns.
+fn symmetric_eigen(mut a: [[f64; 3]; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..50 {
+        let off = a[0][1].abs() + a[0][2].abs() + a[1][2].abs();
+        if off < 1e-15 {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q].abs() < 1e-300 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = [0, 1, 2].map(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = [0, 1, 2].map(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let (vkp, vkq) = (row[p], row[q]);
+                row[p] = c * vkp - s * vkq;
+                row[q] = s * vkp + c * vkq;
+            }
+        }
+    }
+    ([a[0][0], a[1][1], a[2][2]], v)
+}
+
+pub fn principal_axes(points: &[Point]) -> PrincipalAxes {
+    assert!(!points.is_empty());
+    let centroid = points.iter().fold(Point::zero(), |acc, p| acc + *p) / points.len() as f64;
+    let mut covariance = [[0.0; 3]; 3];
+    for p in points {
+        let d = *p - centroid;
+        let d = [d.x, d.y, d.z];
+        for i in 0..3 {
+            for j in 0..3 {
+                covariance[i][j] += d[i] * d[j] / points.len() as f64;
+            }
+        }
+    }
+    let (values, vectors) = symmetric_eigen(covariance);
+    let mut order = [0, 1, 2];
+    order.sort_by(|a, b| values[*b].total_cmp(&values[*a]));
+    let axis = |i: usize| Point::new(vectors[0][i], vectors[1][i], vectors[2][i]).normalize();
+    let x = axis(order[0]);
+    let y = axis(order[1]);
+    PrincipalAxes {
+        centroid,
+        axes: [x, y, x.cross(y)],
+        variances: [values[order[0]], values[order[1]], values[order[2]]],
+    }
+}
+
+// Solves the linear system a * x = b with Gaussian elimination and partial pivoting. Returns None if a is singular.
+fn solve<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> Option<[f64; N]> {
+    for col in 0..N {
+        let pivot = (col..N).max_by(|i, j| a[*i][col].abs().total_cmp(&a[*j][col].abs()))?;
+        if a[pivot][col].abs() < EQ_THRESHOLD * EQ_THRESHOLD {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..N {
+            let f = a[row][col] / a[col][col];
+            let pivot_row = a[col];
+            for (x, p) in a[row].iter_mut().zip(pivot_row).skip(col) {
+                *x -= f * p;
+            }
+            b[row] -= f * b[col];
+        }
+    }
+    let mut x = [0.0; N];
+    for row in (0..N).rev() {
+        let sum: f64 = (row + 1..N).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+// Least squares solution of rows * x = rhs via the normal equations.
+fn least_squares<const N: usize>(rows: &[[f64; N]], rhs: &[f64]) -> Option<[f64; N]> {
+    let mut ata = [[0.0; N]; N];
+    let mut atb = [0.0; N];
+    for (row, r) in rows.iter().zip(rhs) {
+        for i in 0..N {
+            for j in 0..N {
+                ata[i][j] += row[i] * row[j];
+            }
+            atb[i] += row[i] * r;
+        }
+    }
+    solve(ata, atb)
+}
+
+// Plane through the centroid, which minimizes the squared distances of the points. Needs at least 3 points that are not collinear.
+pub fn fit_plane(points: &[Point]) -> Fit<Plane> {
+    assert!(points.len() >= 3);
+    let pca = principal_axes(points);
+    let plane = Plane::new(pca.centroid, pca.axes[0], pca.axes[1]);
+    let normal = pca.axes[2];
+    Fit::new(
+        plane,
+        points.iter().map(|p| (*p - pca.centroid).dot(normal)),
+    )
+}
+
+// Line through the centroid, which minimizes the squared distances of the points. Needs at least 2 distinct points.
+pub fn fit_line(points: &[Point]) -> Fit<Line> {
+    assert!(points.len() >= 2);
+    let pca = principal_axes(points);
+    let direction = pca.axes[0];
+    Fit::new(
+        Line::new(pca.centroid, direction),
+        points.iter().map(|p| {
+            let d = *p - pca.centroid;
+            (d - direction * d.dot(direction)).norm()
+        }),
+    )
+}
+
+// Circle in the best fit plane of the points, fitted algebraically in the plane. Needs at least 3 points that are not collinear.
+pub fn fit_circle(points: &[Point]) -> Fit<Circle> {
+    assert!(points.len() >= 3);
+    let pca = principal_axes(points);
+    let [u, v, normal] = pca.axes;
+    // x^2 + y^2 = a * x + b * y + c, with center (a / 2, b / 2) and radius^2 = c + center^2.
+    let local: Vec<(f64, f64)> = points
+        .iter()
+        .map(|p| ((*p - pca.centroid).dot(u), (*p - pca.centroid).dot(v)))
+        .collect();
+    let rows: Vec<[f64; 3]> = local.iter().map(|(x, y)| [*x, *y, 1.0]).collect();
+    let rhs: Vec<f64> = local.iter().map(|(x, y)| x * x + y * y).collect();
+    let [a, b, c] = least_squares(&rows, &rhs).expect("Points must not be collinear");
+    let (cx, cy) = (a / 2.0, b / 2.0);
+    let radius = (c + cx * cx + cy * cy).sqrt();
+    let center = pca.centroid + u * cx + v * cy;
+    Fit::new(
+        Circle::new(center, normal, radius),
+        points.iter().map(|p| {
+            let d = *p - center;
+            let height = d.dot(normal);
+            let in_plane = (d - normal * height).norm() - radius;
+            (in_plane * in_plane + height * height).sqrt()
+        }),
+    )
+}
+
+// Sphere fitted algebraically. Needs at least 4 points that are not coplanar.
+pub fn fit_sphere(points: &[Point]) -> Fit<Sphere> {
+    assert!(points.len() >= 4);
+    // x^2 + y^2 + z^2 = a * x + b * y + c * z + d, with center (a, b, c) / 2.
+    let rows: Vec<[f64; 4]> = points.iter().map(|p| [p.x, p.y, p.z, 1.0]).collect();
+    let rhs: Vec<f64> = points.iter().map(|p| p.norm_sq()).collect();
+    let [a, b, c, d] = least_squares(&rows, &rhs).expect("Points must not be coplanar");
+    let center = Point::new(a, b, c) / 2.0;
+    let radius = (d + center.norm_sq()).sqrt();
+    Fit::new(
+        Sphere::new(center, radius, true),
+        points.iter().map(|p| (*p - center).norm() - radius),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{curves::CurveLike, surfaces::SurfaceLike};
+
+    #[test]
+    fn test_fitting() {
+        let center = Point::new(1.0, -2.0, 0.5);
+        let normal = Point::new(1.0, 1.0, 1.0).normalize();
+        let u = normal.cross(Point::unit_z()).normalize();
+        let v = normal.cross(u);
+        let circle_points: Vec<Point> = (0..12)
+            .map(|i| {
+                let angle = i as f64 * 0.3;
+                center + (u * angle.cos() + v * angle.sin()) * 2.0
+            })
+            .collect();
+
+        let plane = fit_plane(&circle_points);
+        assert!(plane.max_residual < 1e-9);
+        assert!(plane.shape.on_surface(center));
+
+        let circle = fit_circle(&circle_points);
+        assert!(circle.max_residual < 1e-9);
+        assert_eq!(circle.shape.basis, center);
+        assert!((circle.shape.radius.norm() - 2.0).abs() < 1e-9);
+
+        let line = fit_line(&[
+            Point::new(0.0, 0.1, 0.0),
+            Point::new(1.0, -0.1, 0.0),
+            Point::new(2.0, -0.1, 0.0),
+            Point::new(3.0, 0.1, 0.0),
+        ]);
+        assert!((line.max_residual - 0.1).abs() < 1e-9);
+        assert!(line.shape.on_curve(Point::new(7.0, 0.0, 0.0)));
+
+        let sphere_points: Vec<Point> = (0..20)
+            .map(|i| {
+                let (theta, phi) = (i as f64 * 0.7, i as f64 * 0.3);
+                center
+                    + Point::new(theta.cos() * phi.sin(), theta.sin() * phi.sin(), phi.cos()) * 3.0
+            })
+            .collect();
+        let sphere = fit_sphere(&sphere_points);
+        assert!(sphere.rms_residual < 1e-9);
+        assert_eq!(sphere.shape.basis, center);
+        assert!((sphere.shape.radius - 3.0).abs() < 1e-9);
+
+        let pca = principal_axes(&circle_points);
+        assert!(pca.axes[2].is_parallel(normal));
+        assert!(pca.variances[2] < 1e-12);
+    }
+}
@@ -0,0 +1,118 @@
+use std::rc::Rc;
+
+use crate::{
+    curves::curve::Curve,
+    points::{kd_tree::KdTree, point::Point},
+    surfaces::surface::Surface,
+    EQ_THRESHOLD,
+};
+
+// Deduplicates geometry during construction and import. Equal points, curves and surfaces are handed out as the same Rc,
+// so that Rc::ptr_eq can be used as a fast equality check and large models share their geometry.
+// Equality is the usual tolerance based equality of the geometry types.
+#[derive(Debug, Default)]
+pub struct GeometryInterner {
+    point_index: KdTree,
+    points: Vec<Rc<Point>>,
+    curves: Vec<Rc<Curve>>,
+    surfaces: Vec<Rc<Surface>>,
+}
+
+impl GeometryInterner {
+    pub fn new() -> GeometryInterner {
+        GeometryInterner::default()
+    }
+
+    pub fn point(&mut self, p: Point) -> Rc<Point> {
+        if let Some((index, dist)) = self.point_index.nearest(p) {
+            if dist < EQ_THRESHOLD {
+                return self.points[index].clone();
+            }
+        }
+        self.point_index.insert(p);
+        let p = Rc::new(p);
+        self.points.push(p.clone());
+        p
+    }
+
+    pub fn curve(&mut self, curve: Curve) -> Rc<Curve> {
+        if let Some(existing) = self.curves.iter().find(|c| ***c == curve) {
+            return existing.clone();
+        }
+        let curve = Rc::new(curve);
+        self.curves.push(curve.clone());
+        curve
+    }
+
+    pub fn surface(&mut self, surface: Surface) -> Rc<Surface> {
+        if let Some(existing) = self.surfaces.iter().find(|s| ***s == surface) {
+            return existing.clone();
+        }
+        let surface = Rc::new(surface);
+        self.surfaces.push(surface.clone());
+        surface
+    }
+
+    // Same as surface, but reuses an existing Rc if it is not known yet.
+    pub fn surface_rc(&mut self, surface: &Rc<Surface>) -> Rc<Surface> {
+        if let Some(existing) = self.surfaces.iter().find(|s| ***s == **surface) {
+            return existing.clone();
+        }
+        self.surfaces.push(surface.clone());
+        surface.clone()
+    }
+
+    pub fn num_points(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn num_curves(&self) -> usize {
+        self.curves.len()
+    }
+
+    pub fn num_surfaces(&self) -> usize {
+        self.surfaces.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{curves::line::Line, surfaces::plane::Plane};
+
+    #[test]
+    fn test_interner() {
+        let mut interner = GeometryInterner::new();
+        let p1 = interner.point(Point::new(1.0, 2.0, 3.0));
+        let p2 = interner.point(Point::new(1.0, 2.0, 3.0 + EQ_THRESHOLD / 10.0));
+        let p3 = interner.point(Point::new(1.0, 2.0, 4.0));
+        assert!(Rc::ptr_eq(&p1, &p2));
+        assert!(!Rc::ptr_eq(&p1, &p3));
+
+        let l1 = interner.curve(Curve::Line(Line::new(Point::zero(), Point::unit_x())));
+        let l2 = interner.curve(Curve::Line(Line::new(
+            Point::new(5.0, 0.0, 0.0),
+            Point::unit_x(),
+        )));
+        assert!(Rc::ptr_eq(&l1, &l2));
+
+        let s1 = interner.surface(Surface::Plane(Plane::new(
+            Point::zero(),
+            Point::unit_x(),
+            Point::unit_y(),
+        )));
+        let s2 = interner.surface(Surface::Plane(Plane::new(
+            Point::new(1.0, 1.0, 0.0),
+            Point::unit_x(),
+            Point::unit_y(),
+        )));
+        let s3 = interner.surface(Surface::Plane(Plane::new(
+            Point::new(0.0, 0.0, 1.0),
+            Point::unit_x(),
+            Point::unit_y(),
+        )));
+        assert!(Rc::ptr_eq(&s1, &s2));
+        assert!(!Rc::ptr_eq(&s1, &s3));
+        assert_eq!(interner.num_surfaces(), 2);
+    }
+}
@@ -0,0 +1,165 @@
+use std::{collections::HashMap, rc::Rc};
+
+use crate::{
+    curve_curve_intersection::curve_curve::{curve_curve_intersection, CurveCurveIntersection},
+    curve_surface_intersection::curve_surface::{
+        curve_surface_intersection, CurveSurfaceIntersection,
+    },
+    curves::{curve::Curve, CurveLike},
+    interner::GeometryInterner,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+// Memoizes curve-curve and curve-surface intersections, e.g. for boolean operations that query the same pairs repeatedly while splitting and classifying.
+// Operands are first mapped to a canonical instance with the GeometryInterner, so that curves and surfaces which are equal within tolerance share the cache entry,
+// even if they were constructed differently (e.g. a line with a different basis point).
+// Pairs are ordered, since the orientation of an overlapping curve depends on the order of the operands.
+// The cached points are in the parametrization of the canonical instances, so the parameters are recomputed for the operands of every query.
+#[derive(Debug, Default)]
+pub struct IntersectionCache {
+    interner: GeometryInterner,
+    curve_curve: HashMap<(usize, usize), CurveCurveIntersection>,
+    curve_surface: HashMap<(usize, usize), CurveSurfaceIntersection>,
+    hits: usize,
+    misses: usize,
+}
+
+impl IntersectionCache {
+    pub fn new() -> IntersectionCache {
+        IntersectionCache::default()
+    }
+
+    fn curve_key(&mut self, curve: &Curve) -> (usize, Rc<Curve>) {
+        let curve = self.interner.curve(curve.clone());
+        (Rc::as_ptr(&curve) as usize, curve)
+    }
+
+    fn surface_key(&mut self, surface: &Surface) -> (usize, Rc<Surface>) {
+        let surface = self.interner.surface(surface.clone());
+        (Rc::as_ptr(&surface) as usize, surface)
+    }
+
+    pub fn curve_curve(&mut self, a: &Curve, b: &Curve) -> CurveCurveIntersection {
+        let (key_a, canonical_a) = self.curve_key(a);
+        let (key_b, canonical_b) = self.curve_key(b);
+        let result = match self.curve_curve.get(&(key_a, key_b)) {
+            Some(result) => {
+                self.hits += 1;
+                result.clone()
+            }
+            None => {
+                self.misses += 1;
+                let result = curve_curve_intersection(&canonical_a, &canonical_b);
+                self.curve_curve.insert((key_a, key_b), result.clone());
+                result
+            }
+        };
+        match result {
+            CurveCurveIntersection::FinitePoints(mut points) => {
+                for point in points.iter_mut() {
+                    point.parameter_self = a.project(point.point).parameter;
+                    point.parameter_other = b.project(point.point).parameter;
+                }
+                points.sort_by(|x, y| x.parameter_self.total_cmp(&y.parameter_self));
+                CurveCurveIntersection::FinitePoints(points)
+            }
+            result => result,
+        }
+    }
+
+    pub fn curve_surface(&mut self, curve: &Curve, surface: &Surface) -> CurveSurfaceIntersection {
+        let (key_c, canonical_curve) = self.curve_key(curve);
+        let (key_s, canonical_surface) = self.surface_key(surface);
+        let result = match self.curve_surface.get(&(key_c, key_s)) {
+            Some(result) => {
+                self.hits += 1;
+                result.clone()
+            }
+            None => {
+                self.misses += 1;
+                let result = curve_surface_intersection(&canonical_curve, &canonical_surface);
+                self.curve_surface.insert((key_c, key_s), result.clone());
+                result
+            }
+        };
+        match result {
+            CurveSurfaceIntersection::Points(mut points) => {
+                for point in points.iter_mut() {
+                    point.parameter = curve.project(point.point).parameter;
+                    point.uv = surface.parameters(point.point);
+                }
+                points.sort_by(|x, y| x.parameter.total_cmp(&y.parameter));
+                CurveSurfaceIntersection::Points(points)
+            }
+            result => result,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = IntersectionCache::new();
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        curves::{circle::Circle, line::Line},
+        points::point::Point,
+        surfaces::plane::Plane,
+    };
+
+    #[test]
+    fn test_intersection_cache() {
+        let mut cache = IntersectionCache::new();
+        let circle = Curve::Circle(Circle::new(Point::zero(), Point::unit_z(), 1.0));
+        let line = Curve::Line(Line::new(Point::zero(), Point::unit_x()));
+        // The same line with a different basis point.
+        let line2 = Curve::Line(Line::new(Point::new(3.0, 0.0, 0.0), Point::unit_x()));
+
+        let result = cache.curve_curve(&circle, &line);
+        assert_eq!(result, curve_curve_intersection(&circle, &line));
+        assert_eq!(cache.misses(), 1);
+        // A hit is reported in the parametrization of the operands, not of the cached instance.
+        let result2 = cache.curve_curve(&circle, &line2);
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(result2, curve_curve_intersection(&circle, &line2));
+        assert_ne!(
+            result2, result,
+            "Parameters on line2 are shifted by its basis"
+        );
+        assert_eq!(
+            cache.curve_curve(&line2, &circle),
+            curve_curve_intersection(&line2, &circle)
+        );
+
+        let plane = Surface::Plane(Plane::new(
+            Point::new(0.5, 0.0, 0.0),
+            Point::unit_y(),
+            Point::unit_z(),
+        ));
+        let points = match cache.curve_surface(&line, &plane) {
+            CurveSurfaceIntersection::Points(points) => points,
+            _ => panic!("Expected points"),
+        };
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].point, Point::new(0.5, 0.0, 0.0));
+        match cache.curve_surface(&line2, &plane) {
+            CurveSurfaceIntersection::Points(points2) => {
+                assert_eq!(points2.len(), 1);
+                assert!((points2[0].parameter - (points[0].parameter - 3.0)).abs() < 1e-9);
+            }
+            _ => panic!("Expected points"),
+        }
+        assert_eq!(cache.hits(), 2);
+        assert_eq!(cache.misses(), 3);
+    }
+}
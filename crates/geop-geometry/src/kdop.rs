@@ -0,0 +1,77 @@
+use crate::points::point::Point;
+
+// Number of directions of a Kdop: the three coordinate axes and the four diagonals of the unit cube.
+pub const KDOP_AXES: usize = 7;
+
+fn kdop_axes() -> [Point; KDOP_AXES] {
+    let d = 1.0 / 3.0_f64.sqrt();
+    [
+        Point::unit_x(),
+        Point::unit_y(),
+        Point::unit_z(),
+        Point::new(d, d, d),
+        Point::new(d, d, -d),
+        Point::new(d, -d, d),
+        Point::new(-d, d, d),
+    ]
+}
+
+// A discrete oriented polytope with 14 faces, i.e. the intersection of the slabs between the smallest and largest extent of a set
+// of points along 7 fixed directions. It is a bounding box with its corners cut off, which fits diagonal and rounded parts tighter
+// at the cost of testing 7 instead of 3 intervals.
+#[derive(Debug, Clone)]
+pub struct Kdop {
+    pub min: [f64; KDOP_AXES],
+    pub max: [f64; KDOP_AXES],
+}
+
+impl Kdop {
+    pub fn from_points(points: &[Point]) -> Kdop {
+        assert!(!points.is_empty());
+        let axes = kdop_axes();
+        let mut kdop = Kdop {
+            min: [f64::INFINITY; KDOP_AXES],
+            max: [f64::NEG_INFINITY; KDOP_AXES],
+        };
+        for p in points.iter() {
+            for (i, axis) in axes.iter().enumerate() {
+                let d = p.dot(*axis);
+                kdop.min[i] = kdop.min[i].min(d);
+                kdop.max[i] = kdop.max[i].max(d);
+            }
+        }
+        kdop
+    }
+
+    // Checks if the polytopes overlap along all directions. Like for bounding boxes, this may report overlaps of polytopes that are
+    // disjoint, but never misses one.
+    pub fn intersects(&self, other: &Kdop, tolerance: f64) -> bool {
+        (0..KDOP_AXES).all(|i| {
+            self.min[i] <= other.max[i] + tolerance && self.max[i] >= other.min[i] - tolerance
+        })
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        kdop_axes().iter().enumerate().all(|(i, axis)| {
+            let d = p.dot(*axis);
+            d >= self.min[i] && d <= self.max[i]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kdop() {
+        // A diagonal of the unit cube fills its bounding box, but not its polytope, which is cut off along the other diagonals.
+        let diagonal = Kdop::from_points(&[Point::zero(), Point::new(1.0, 1.0, 1.0)]);
+        let corner = Kdop::from_points(&[Point::new(1.0, 0.0, 0.0), Point::new(0.9, 0.1, 0.0)]);
+        assert!(diagonal.contains(Point::new(0.5, 0.5, 0.5)));
+        assert!(!diagonal.contains(Point::new(1.0, 0.0, 0.0)));
+        assert!(!diagonal.intersects(&corner, 0.0));
+        let middle = Kdop::from_points(&[Point::new(0.4, 0.5, 0.5), Point::new(1.0, 0.0, 0.0)]);
+        assert!(diagonal.intersects(&middle, 0.0));
+    }
+}
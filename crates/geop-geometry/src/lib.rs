@@ -6,7 +6,16 @@ pub mod points;
 pub mod surfaces;
 
 pub mod bounding_box;
+pub mod bounding_sphere;
 pub mod curve_curve_intersection;
+pub mod curve_on_surface;
 pub mod curve_surface_intersection;
+pub mod fitting;
+pub mod interner;
+pub mod intersection_cache;
+pub mod kdop;
+pub mod normal_cone;
+pub mod oriented_bounding_box;
+pub mod periodic;
 pub mod surface_surface_intersection;
 pub mod transforms;
@@ -0,0 +1,106 @@
+use std::f64::consts::PI;
+
+use crate::{points::point::Point, EQ_THRESHOLD};
+
+// A cone of directions around an axis, which bounds the normals of a surface patch (i.e. a bounding region of its Gauss map).
+// A half angle of PI contains all directions.
+#[derive(Debug, Clone)]
+pub struct NormalCone {
+    pub axis: Point,
+    pub half_angle: f64,
+}
+
+impl NormalCone {
+    pub fn new(axis: Point, half_angle: f64) -> NormalCone {
+        assert!(half_angle >= 0.0);
+        NormalCone {
+            axis: axis.normalize(),
+            half_angle: half_angle.min(PI),
+        }
+    }
+
+    pub fn full() -> NormalCone {
+        NormalCone::new(Point::unit_z(), PI)
+    }
+
+    // Bounds the given normals with a cone around their mean direction. The cone is not minimal, but close to it for clustered normals.
+    pub fn from_normals(normals: &[Point]) -> NormalCone {
+        assert!(!normals.is_empty());
+        let sum = normals
+            .iter()
+            .fold(Point::zero(), |acc, n| acc + n.normalize());
+        if sum.norm() < EQ_THRESHOLD {
+            return NormalCone::full();
+        }
+        let axis = sum.normalize();
+        let half_angle = normals.iter().map(|n| axis.angle(*n)).fold(0.0, f64::max);
+        NormalCone::new(axis, half_angle)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.half_angle >= PI - EQ_THRESHOLD
+    }
+
+    pub fn contains(&self, direction: Point) -> bool {
+        self.axis.angle(direction) <= self.half_angle + EQ_THRESHOLD
+    }
+
+    // The smallest cone around the axis of self that contains both cones.
+    pub fn union(&self, other: &NormalCone) -> NormalCone {
+        let half_angle = self
+            .half_angle
+            .max(self.axis.angle(other.axis) + other.half_angle);
+        NormalCone::new(self.axis, half_angle)
+    }
+
+    // Whether some normal in the cone may be perpendicular to the direction. If not, a patch with these normals has no silhouette when viewed from the direction.
+    pub fn may_be_perpendicular_to(&self, direction: Point) -> bool {
+        let angle = self.axis.angle(direction);
+        (angle - PI / 2.0).abs() <= self.half_angle + EQ_THRESHOLD
+    }
+
+    // Lower bound of the angle between the normals and the plane perpendicular to the pull direction, e.g. for a draft analysis.
+    pub fn min_angle_to_plane(&self, direction: Point) -> f64 {
+        let angle = self.axis.angle(direction);
+        let closest = (angle - PI / 2.0).abs() - self.half_angle;
+        closest.max(0.0)
+    }
+
+    // Whether a normal of self may be parallel or anti-parallel to a normal of other, which is needed for two surface patches to be parallel.
+    pub fn may_be_parallel_to(&self, other: &NormalCone) -> bool {
+        let angle = self.axis.angle(other.axis);
+        let sum = self.half_angle + other.half_angle + EQ_THRESHOLD;
+        angle <= sum || PI - angle <= sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_cone() {
+        let cone = NormalCone::from_normals(&[
+            Point::new(1.0, 0.0, 1.0),
+            Point::new(-1.0, 0.0, 1.0),
+            Point::new(0.0, 1.0, 1.0),
+            Point::new(0.0, -1.0, 1.0),
+        ]);
+        assert_eq!(cone.axis, Point::unit_z());
+        assert!((cone.half_angle - PI / 4.0).abs() < EQ_THRESHOLD);
+        assert!(cone.contains(Point::new(0.5, 0.5, 1.0)));
+        assert!(!cone.contains(Point::unit_x()));
+
+        assert!(!cone.may_be_perpendicular_to(Point::unit_z()));
+        assert!(cone.may_be_perpendicular_to(Point::new(1.0, 0.0, 0.5)));
+        assert!((cone.min_angle_to_plane(Point::unit_z()) - PI / 4.0).abs() < EQ_THRESHOLD);
+
+        let other = NormalCone::new(-Point::unit_z(), 0.1);
+        assert!(cone.may_be_parallel_to(&other));
+        assert!(!cone.may_be_parallel_to(&NormalCone::new(Point::unit_x(), 0.1)));
+
+        let union = cone.union(&NormalCone::new(Point::unit_x(), 0.0));
+        assert!((union.half_angle - PI / 2.0).abs() < EQ_THRESHOLD);
+        assert!(NormalCone::from_normals(&[Point::unit_x(), -Point::unit_x()]).is_full());
+    }
+}
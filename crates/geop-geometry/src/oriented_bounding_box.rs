@@ -0,0 +1,154 @@
+use crate::{
+    fitting::principal_axes, points::point::Point, transforms::frame::Frame, EQ_THRESHOLD,
+};
+
+// A box that is aligned with a local frame instead of the world axes. It fits elongated or rotated parts much tighter than a BoundingBox,
+// e.g. as a collision proxy, to orient a part for printing, or to pack parts.
+#[derive(Debug, Clone)]
+pub struct OrientedBoundingBox {
+    // The origin of the frame is the center of the box.
+    pub frame: Frame,
+    // Half of the size of the box along the axes of the frame.
+    pub half_extents: Point,
+}
+
+impl OrientedBoundingBox {
+    pub fn new(frame: Frame, half_extents: Point) -> OrientedBoundingBox {
+        OrientedBoundingBox {
+            frame,
+            half_extents,
+        }
+    }
+
+    // Box around the points in the given orientation.
+    pub fn from_points_in_frame(points: &[Point], frame: &Frame) -> OrientedBoundingBox {
+        assert!(!points.is_empty());
+        let local: Vec<Point> = points.iter().map(|p| frame.to_local(*p)).collect();
+        let mut min = local[0];
+        let mut max = local[0];
+        for p in local.iter() {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z));
+        }
+        let center = frame.to_global((min + max) / 2.0);
+        OrientedBoundingBox::new(
+            Frame::new(center, frame.x_dir, frame.y_dir),
+            (max - min) / 2.0,
+        )
+    }
+
+    // Box aligned with the principal axes of the points. The longest side usually ends up along the x axis of the frame.
+    // The box is not guaranteed to be minimal, but it is for most practical shapes, like boxes and cylinders.
+    pub fn from_points(points: &[Point]) -> OrientedBoundingBox {
+        let pca = principal_axes(points);
+        let frame = Frame::new(pca.centroid, pca.axes[0], pca.axes[1]);
+        OrientedBoundingBox::from_points_in_frame(points, &frame)
+    }
+
+    pub fn center(&self) -> Point {
+        self.frame.origin
+    }
+
+    pub fn axes(&self) -> [Point; 3] {
+        [self.frame.x_dir, self.frame.y_dir, self.frame.z_dir]
+    }
+
+    pub fn size(&self) -> Point {
+        self.half_extents * 2.0
+    }
+
+    pub fn volume(&self) -> f64 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    pub fn corners(&self) -> Vec<Point> {
+        let e = self.half_extents;
+        let mut corners = Vec::with_capacity(8);
+        for sx in [-1.0, 1.0] {
+            for sy in [-1.0, 1.0] {
+                for sz in [-1.0, 1.0] {
+                    corners.push(
+                        self.frame
+                            .to_global(Point::new(sx * e.x, sy * e.y, sz * e.z)),
+                    );
+                }
+            }
+        }
+        corners
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        let local = self.frame.to_local(p);
+        local.x.abs() <= self.half_extents.x + EQ_THRESHOLD
+            && local.y.abs() <= self.half_extents.y + EQ_THRESHOLD
+            && local.z.abs() <= self.half_extents.z + EQ_THRESHOLD
+    }
+
+    // Radius of the projection of the box onto the axis.
+    fn projected_radius(&self, axis: Point) -> f64 {
+        let [x, y, z] = self.axes();
+        self.half_extents.x * x.dot(axis).abs()
+            + self.half_extents.y * y.dot(axis).abs()
+            + self.half_extents.z * z.dot(axis).abs()
+    }
+
+    // Separating axis test with the 15 candidate axes of two boxes.
+    pub fn intersects(&self, other: &OrientedBoundingBox, tolerance: f64) -> bool {
+        let d = other.center() - self.center();
+        let mut candidates = Vec::with_capacity(15);
+        candidates.extend(self.axes());
+        candidates.extend(other.axes());
+        for a in self.axes() {
+            for b in other.axes() {
+                let axis = a.cross(b);
+                if axis.norm() > EQ_THRESHOLD {
+                    candidates.push(axis.normalize());
+                }
+            }
+        }
+        candidates.into_iter().all(|axis| {
+            d.dot(axis).abs()
+                <= self.projected_radius(axis) + other.projected_radius(axis) + tolerance
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transforms::rotation::Rotation;
+
+    #[test]
+    fn test_oriented_bounding_box() {
+        // A rotated 4 x 2 x 1 box.
+        let frame = Frame::from_rotation(
+            Point::new(1.0, 2.0, 3.0),
+            Rotation::from_axis_angle(Point::new(1.0, 2.0, 0.5), 0.7),
+        );
+        let mut points = Vec::<Point>::new();
+        for x in [-2.0, 0.0, 2.0] {
+            for y in [-1.0, 1.0] {
+                for z in [-0.5, 0.5] {
+                    points.push(frame.to_global(Point::new(x, y, z)));
+                }
+            }
+        }
+        let obb = OrientedBoundingBox::from_points(&points);
+        assert_eq!(obb.center(), frame.origin);
+        assert!((obb.volume() - 8.0).abs() < 1e-9);
+        assert!(obb.axes()[0].is_parallel(frame.x_dir));
+        assert!(points.iter().all(|p| obb.contains(*p)));
+        assert_eq!(obb.corners().len(), 8);
+
+        let moved = |offset: Point| {
+            OrientedBoundingBox::new(
+                Frame::new(obb.center() + offset, obb.frame.x_dir, obb.frame.y_dir),
+                obb.half_extents,
+            )
+        };
+        assert!(obb.intersects(&moved(frame.x_dir * 3.9), 0.0));
+        assert!(!obb.intersects(&moved(frame.x_dir * 4.1), 0.0));
+        assert!(!obb.intersects(&moved(frame.z_dir * 1.1), 0.0));
+    }
+}
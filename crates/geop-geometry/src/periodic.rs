@@ -0,0 +1,80 @@
+use crate::{
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+};
+
+// Helpers for periodic parameters, like the angle on a circle or the u parameter of spheres and cylinders, which wrap around at the seam.
+// Comparisons have to be done modulo the period, otherwise values just below the period and just above 0 are considered far apart.
+
+// Maps x into [0, period).
+pub fn wrap(x: f64, period: f64) -> f64 {
+    let result = x.rem_euclid(period);
+    // rem_euclid can return period for tiny negative values due to rounding.
+    if result >= period {
+        0.0
+    } else {
+        result
+    }
+}
+
+// Distance between two periodic values, going the shorter way around.
+pub fn periodic_distance(a: f64, b: f64, period: f64) -> f64 {
+    let d = wrap(a - b, period);
+    d.min(period - d)
+}
+
+pub fn periodic_eq(a: f64, b: f64, period: f64, tolerance: f64) -> bool {
+    periodic_distance(a, b, period) <= tolerance
+}
+
+// Checks if x is in the interval going from start in positive direction to end. Values within tolerance of start or end are inside.
+pub fn periodic_between(x: f64, start: f64, end: f64, period: f64, tolerance: f64) -> bool {
+    if periodic_eq(x, start, period, tolerance) || periodic_eq(x, end, period, tolerance) {
+        return true;
+    }
+    wrap(x - start, period) <= wrap(end - start, period)
+}
+
+// Returns the half plane where the u parameter of a periodic surface wraps from the period to 0, as the plane through the axis of the surface
+// and the direction that points from the axis into the half plane. Returns None for surfaces that are not periodic.
+pub fn seam_half_plane(surface: &Surface) -> Option<(Plane, Point)> {
+    match surface {
+        Surface::Plane(_) => None,
+        Surface::Sphere(sphere) => Some((
+            Plane::new(sphere.basis, Point::unit_x(), Point::unit_z()),
+            Point::unit_x(),
+        )),
+        Surface::Cylinder(cylinder) => Some((
+            Plane::new(cylinder.basis, cylinder.radius, cylinder.extend_dir),
+            cylinder.radius.normalize(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn test_periodic() {
+        assert_eq!(wrap(-PI / 2.0, 2.0 * PI), 1.5 * PI);
+        assert_eq!(wrap(-1e-20, 2.0 * PI), 0.0);
+        assert!(periodic_eq(2.0 * PI - 1e-9, 1e-9, 2.0 * PI, 1e-7));
+        assert!((periodic_distance(0.1, 2.0 * PI - 0.1, 2.0 * PI) - 0.2).abs() < 1e-12);
+
+        // An interval across the seam.
+        assert!(periodic_between(0.0, 1.5 * PI, 0.5 * PI, 2.0 * PI, 1e-7));
+        assert!(periodic_between(
+            1.9 * PI,
+            1.5 * PI,
+            0.5 * PI,
+            2.0 * PI,
+            1e-7
+        ));
+        assert!(!periodic_between(PI, 1.5 * PI, 0.5 * PI, 2.0 * PI, 1e-7));
+        // Values just below the start are on the start.
+        assert!(periodic_between(1.0 - 1e-9, 1.0, 2.0, 2.0 * PI, 1e-7));
+    }
+}
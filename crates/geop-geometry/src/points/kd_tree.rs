@@ -0,0 +1,193 @@
+use super::point::Point;
+
+#[derive(Clone, Debug)]
+struct KdNode {
+    point: Point,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+// A 3d tree for nearest neighbor and range queries over points.
+// Points are identified by the index in which they were inserted. The tree is not rebalanced, so inserting presorted points degrades the queries to linear time.
+#[derive(Clone, Debug, Default)]
+pub struct KdTree {
+    nodes: Vec<KdNode>,
+}
+
+fn coordinate(p: Point, axis: usize) -> f64 {
+    match axis {
+        0 => p.x,
+        1 => p.y,
+        _ => p.z,
+    }
+}
+
+impl KdTree {
+    pub fn new() -> KdTree {
+        KdTree { nodes: Vec::new() }
+    }
+
+    pub fn from_points(points: &[Point]) -> KdTree {
+        let mut tree = KdTree::new();
+        for p in points {
+            tree.insert(*p);
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Point {
+        self.nodes[index].point
+    }
+
+    // Inserts a point and returns its index.
+    pub fn insert(&mut self, point: Point) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(KdNode {
+            point,
+            left: None,
+            right: None,
+        });
+        if index == 0 {
+            return index;
+        }
+
+        let mut current = 0;
+        let mut axis = 0;
+        loop {
+            let node = &mut self.nodes[current];
+            let child = match coordinate(point, axis) < coordinate(node.point, axis) {
+                true => &mut node.left,
+                false => &mut node.right,
+            };
+            match child {
+                Some(next) => current = *next,
+                None => {
+                    *child = Some(index);
+                    return index;
+                }
+            }
+            axis = (axis + 1) % 3;
+        }
+    }
+
+    // Returns the index and distance of the point closest to the query point.
+    pub fn nearest(&self, point: Point) -> Option<(usize, f64)> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+        let mut best = (0, f64::INFINITY);
+        self.nearest_recursive(Some(0), 0, point, &mut best);
+        Some(best)
+    }
+
+    fn nearest_recursive(
+        &self,
+        node: Option<usize>,
+        axis: usize,
+        point: Point,
+        best: &mut (usize, f64),
+    ) {
+        let index = match node {
+            Some(index) => index,
+            None => return,
+        };
+        let node = &self.nodes[index];
+        let dist = (node.point - point).norm();
+        if dist < best.1 {
+            *best = (index, dist);
+        }
+
+        let diff = coordinate(point, axis) - coordinate(node.point, axis);
+        let (near, far) = match diff < 0.0 {
+            true => (node.left, node.right),
+            false => (node.right, node.left),
+        };
+        self.nearest_recursive(near, (axis + 1) % 3, point, best);
+        if diff.abs() < best.1 {
+            self.nearest_recursive(far, (axis + 1) % 3, point, best);
+        }
+    }
+
+    // Returns the indices of all points within radius of the query point, in insertion order.
+    pub fn range(&self, point: Point, radius: f64) -> Vec<usize> {
+        let mut result = Vec::new();
+        if !self.nodes.is_empty() {
+            self.range_recursive(Some(0), 0, point, radius, &mut result);
+        }
+        result.sort();
+        result
+    }
+
+    fn range_recursive(
+        &self,
+        node: Option<usize>,
+        axis: usize,
+        point: Point,
+        radius: f64,
+        result: &mut Vec<usize>,
+    ) {
+        let index = match node {
+            Some(index) => index,
+            None => return,
+        };
+        let node = &self.nodes[index];
+        if (node.point - point).norm() <= radius {
+            result.push(index);
+        }
+
+        let diff = coordinate(point, axis) - coordinate(node.point, axis);
+        if diff - radius < 0.0 {
+            self.range_recursive(node.left, (axis + 1) % 3, point, radius, result);
+        }
+        if diff + radius >= 0.0 {
+            self.range_recursive(node.right, (axis + 1) % 3, point, radius, result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kd_tree() {
+        let mut points = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                for k in 0..10 {
+                    points.push(Point::new(i as f64, j as f64 * 0.5, k as f64 * 0.25));
+                }
+            }
+        }
+        let tree = KdTree::from_points(&points);
+        assert_eq!(tree.len(), 1000);
+
+        for query in [
+            Point::new(3.2, 1.1, 0.6),
+            Point::new(-5.0, 2.0, 1.0),
+            Point::new(4.5, 4.5, 4.5),
+        ] {
+            let (index, dist) = tree.nearest(query).unwrap();
+            let expected = points
+                .iter()
+                .map(|p| (*p - query).norm())
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(dist, expected);
+            assert_eq!((tree.get(index) - query).norm(), expected);
+
+            let radius = 1.3;
+            let expected: Vec<usize> = (0..points.len())
+                .filter(|i| (points[*i] - query).norm() <= radius)
+                .collect();
+            assert_eq!(tree.range(query, radius), expected);
+        }
+    }
+}
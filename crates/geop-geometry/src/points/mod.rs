@@ -1 +1,2 @@
+pub mod kd_tree;
 pub mod point;
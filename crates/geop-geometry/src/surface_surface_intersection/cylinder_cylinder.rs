@@ -0,0 +1,88 @@
+use crate::{curves::line::Line, surfaces::cylinder::Cylinder, EQ_THRESHOLD};
+
+pub enum CylinderCylinderIntersection {
+    Cylinder(Cylinder),
+    TwoLines(Line, Line),
+    Line(Line),
+    None,
+}
+
+// Only cylinders with parallel axes are supported for now. They meet in lines parallel to the axes, where their circles in a cross
+// section meet.
+pub fn cylinder_cylinder_intersection(a: &Cylinder, b: &Cylinder) -> CylinderCylinderIntersection {
+    assert!(
+        a.extend_dir.is_parallel(b.extend_dir),
+        "Intersection of cylinders with skew axes is not supported"
+    );
+    let axis = a.extend_dir.normalize();
+    let (r_a, r_b) = (a.radius.norm(), b.radius.norm());
+    let offset = b.basis - a.basis;
+    let offset = offset - axis * offset.dot(axis);
+    let d = offset.norm();
+
+    if d < EQ_THRESHOLD {
+        return match (r_a - r_b).abs() < EQ_THRESHOLD {
+            true => CylinderCylinderIntersection::Cylinder(a.clone()),
+            false => CylinderCylinderIntersection::None,
+        };
+    }
+    if d > r_a + r_b + EQ_THRESHOLD || d < (r_a - r_b).abs() - EQ_THRESHOLD {
+        return CylinderCylinderIntersection::None;
+    }
+
+    // The foot of the chord between the two points of the circles, and the half length of the chord.
+    let dir = offset / d;
+    let x = (d * d + r_a * r_a - r_b * r_b) / (2.0 * d);
+    let foot = a.basis + dir * x;
+    let h = r_a * r_a - x * x;
+    if h < EQ_THRESHOLD * EQ_THRESHOLD {
+        return CylinderCylinderIntersection::Line(Line::new(foot, axis));
+    }
+    let side = axis.cross(dir) * h.sqrt();
+    CylinderCylinderIntersection::TwoLines(
+        Line::new(foot + side, axis),
+        Line::new(foot - side, axis),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{points::point::Point, surfaces::SurfaceLike};
+
+    use super::*;
+
+    #[test]
+    fn test_cylinder_cylinder_intersection() {
+        let a = Cylinder::new(Point::zero(), Point::unit_z(), 1.0, true);
+
+        // Overlapping cylinders meet in two lines on both of them
+        let b = Cylinder::new(Point::new(1.0, 0.0, 5.0), Point::unit_z(), 1.0, true);
+        match cylinder_cylinder_intersection(&a, &b) {
+            CylinderCylinderIntersection::TwoLines(l1, l2) => {
+                for l in [l1, l2] {
+                    assert!(a.on_surface(l.basis) && b.on_surface(l.basis));
+                }
+            }
+            _ => panic!("Intersection should be two lines"),
+        }
+
+        // Touching cylinders meet in one line, and separate ones not at all
+        let b = Cylinder::new(Point::new(0.0, 3.0, 0.0), -Point::unit_z(), 2.0, true);
+        match cylinder_cylinder_intersection(&a, &b) {
+            CylinderCylinderIntersection::Line(l) => assert_eq!(l.basis, Point::unit_y()),
+            _ => panic!("Intersection should be a line"),
+        }
+        let b = Cylinder::new(Point::new(0.0, 4.0, 0.0), Point::unit_z(), 2.0, true);
+        assert!(matches!(
+            cylinder_cylinder_intersection(&a, &b),
+            CylinderCylinderIntersection::None
+        ));
+
+        // Coaxial cylinders of the same radius are the same surface
+        let b = Cylinder::new(Point::unit_z(), Point::unit_z(), 1.0, false);
+        assert!(matches!(
+            cylinder_cylinder_intersection(&a, &b),
+            CylinderCylinderIntersection::Cylinder(_)
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod cylinder_cylinder;
+pub mod plane_cylinder;
 pub mod plane_plane;
 pub mod plane_sphere;
 pub mod surface_surface;
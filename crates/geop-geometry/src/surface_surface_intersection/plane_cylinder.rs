@@ -0,0 +1,100 @@
+use crate::{
+    curves::{circle::Circle, ellipse::Ellipse, line::Line},
+    surfaces::{cylinder::Cylinder, plane::Plane, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+pub enum PlaneCylinderIntersection {
+    Circle(Circle),
+    Ellipse(Ellipse),
+    TwoLines(Line, Line),
+    Line(Line),
+    None,
+}
+
+pub fn plane_cylinder_intersection(a: &Plane, b: &Cylinder) -> PlaneCylinderIntersection {
+    let n = a.normal(a.basis).normalize();
+    let axis = b.extend_dir.normalize();
+    let r = b.radius.norm();
+    let cos = n.dot(axis);
+
+    // A plane along the axis cuts the cylinder in lines parallel to the axis, on both sides of the foot of the axis on the plane.
+    if cos.abs() < EQ_THRESHOLD {
+        let d = (b.basis - a.basis).dot(n);
+        let foot = b.basis - n * d;
+        if (d.abs() - r).abs() < EQ_THRESHOLD {
+            return PlaneCylinderIntersection::Line(Line::new(foot, axis));
+        }
+        if d.abs() > r {
+            return PlaneCylinderIntersection::None;
+        }
+        let side = axis.cross(n).normalize() * (r * r - d * d).sqrt();
+        return PlaneCylinderIntersection::TwoLines(
+            Line::new(foot + side, axis),
+            Line::new(foot - side, axis),
+        );
+    }
+
+    // Any other plane cuts the axis once. The minor radius is the one of the cylinder, across the axis, and the major radius is
+    // stretched along the slope of the plane.
+    let center = b.basis + axis * ((a.basis - b.basis).dot(n) / cos);
+    if (cos.abs() - 1.0).abs() < EQ_THRESHOLD {
+        return PlaneCylinderIntersection::Circle(Circle::new(center, n, r));
+    }
+    let minor = axis.cross(n).normalize();
+    let major = n.cross(minor);
+    PlaneCylinderIntersection::Ellipse(Ellipse::new(center, n, major * (r / cos.abs()), minor * r))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{curves::CurveLike, points::point::Point};
+
+    use super::*;
+
+    #[test]
+    fn test_plane_cylinder_intersection() {
+        // Cylinder of radius 1 around the z axis
+        let cylinder = Cylinder::new(Point::zero(), Point::unit_z(), 1.0, true);
+
+        // A horizontal plane gives a circle of radius 1
+        let plane = Plane::new(Point::unit_z(), Point::unit_x(), Point::unit_y());
+        match plane_cylinder_intersection(&plane, &cylinder) {
+            PlaneCylinderIntersection::Circle(circle) => {
+                assert_eq!(circle.basis, Point::unit_z());
+                assert!((circle.radius.norm() - 1.0).abs() < EQ_THRESHOLD);
+            }
+            _ => panic!("Intersection should be a circle"),
+        }
+
+        // A plane at 45 degrees gives an ellipse, which lies on both surfaces
+        let slope = Point::new(1.0, 0.0, 1.0).normalize();
+        let plane = Plane::new(Point::zero(), slope, Point::unit_y());
+        match plane_cylinder_intersection(&plane, &cylinder) {
+            PlaneCylinderIntersection::Ellipse(ellipse) => {
+                assert!((ellipse.major_radius.norm() - 2.0_f64.sqrt()).abs() < EQ_THRESHOLD);
+                assert!((ellipse.minor_radius.norm() - 1.0).abs() < EQ_THRESHOLD);
+                let p = ellipse.interpolate(None, None, 0.3);
+                assert!(cylinder.on_surface(p) && plane.on_surface(p));
+            }
+            _ => panic!("Intersection should be an ellipse"),
+        }
+
+        // Vertical planes give two lines, one touching line or nothing
+        for (x, lines) in [(0.5, 2), (1.0, 1), (2.0, 0)] {
+            let plane = Plane::new(Point::unit_x() * x, Point::unit_y(), Point::unit_z());
+            match plane_cylinder_intersection(&plane, &cylinder) {
+                PlaneCylinderIntersection::TwoLines(l1, l2) => {
+                    assert_eq!(lines, 2);
+                    assert!(cylinder.on_surface(l1.basis) && cylinder.on_surface(l2.basis));
+                }
+                PlaneCylinderIntersection::Line(l) => {
+                    assert_eq!(lines, 1);
+                    assert_eq!(l.basis, Point::unit_x());
+                }
+                PlaneCylinderIntersection::None => assert_eq!(lines, 0),
+                _ => panic!("Intersection should be parallel to the axis"),
+            }
+        }
+    }
+}
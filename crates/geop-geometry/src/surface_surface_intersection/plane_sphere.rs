@@ -17,7 +17,7 @@ pub fn plane_sphere_intersection(a: &Sphere, b: &Plane) -> PlaneSphereIntersecti
     let r = a.radius;
 
     if rho < r && rho > -r {
-        let new_circle_center = a.basis + n * rho;
+        let new_circle_center = a.basis - n * rho;
         let new_circle_radius = (r * r - rho * rho).sqrt();
         return PlaneSphereIntersection::Circle(Circle::new(
             new_circle_center,
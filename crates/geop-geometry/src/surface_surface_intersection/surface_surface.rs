@@ -1,6 +1,41 @@
 use crate::{curves::curve::Curve, points::point::Point, surfaces::surface::Surface};
 
-use super::plane_plane::{plane_plane_intersection, PlanePlaneIntersection};
+use super::{
+    cylinder_cylinder::{cylinder_cylinder_intersection, CylinderCylinderIntersection},
+    plane_cylinder::{plane_cylinder_intersection, PlaneCylinderIntersection},
+    plane_plane::{plane_plane_intersection, PlanePlaneIntersection},
+    plane_sphere::{plane_sphere_intersection, PlaneSphereIntersection},
+};
+
+fn from_plane_sphere(intersection: PlaneSphereIntersection) -> FaceSurfaceIntersection {
+    match intersection {
+        PlaneSphereIntersection::Circle(c) => {
+            FaceSurfaceIntersection::CurvesAndPoints(vec![Curve::Circle(c)], vec![])
+        }
+        PlaneSphereIntersection::Point(p) => {
+            FaceSurfaceIntersection::CurvesAndPoints(vec![], vec![p])
+        }
+        PlaneSphereIntersection::None => FaceSurfaceIntersection::None,
+    }
+}
+
+fn from_plane_cylinder(intersection: PlaneCylinderIntersection) -> FaceSurfaceIntersection {
+    match intersection {
+        PlaneCylinderIntersection::Circle(c) => {
+            FaceSurfaceIntersection::CurvesAndPoints(vec![Curve::Circle(c)], vec![])
+        }
+        PlaneCylinderIntersection::Ellipse(e) => {
+            FaceSurfaceIntersection::CurvesAndPoints(vec![Curve::Ellipse(e)], vec![])
+        }
+        PlaneCylinderIntersection::TwoLines(l1, l2) => {
+            FaceSurfaceIntersection::CurvesAndPoints(vec![Curve::Line(l1), Curve::Line(l2)], vec![])
+        }
+        PlaneCylinderIntersection::Line(l) => {
+            FaceSurfaceIntersection::CurvesAndPoints(vec![Curve::Line(l)], vec![])
+        }
+        PlaneCylinderIntersection::None => FaceSurfaceIntersection::None,
+    }
+}
 
 pub enum FaceSurfaceIntersection {
     None,
@@ -25,16 +60,16 @@ pub fn surface_surface_intersection(
                     }
                 }
             }
-            Surface::Sphere(_sphere_other) => {
-                todo!("Plane-Sphere intersection")
+            Surface::Sphere(sphere_other) => {
+                from_plane_sphere(plane_sphere_intersection(sphere_other, plane_self))
             }
-            Surface::Cylinder(_cylinder_other) => {
-                todo!("Plane-Cylinder intersection")
+            Surface::Cylinder(cylinder_other) => {
+                from_plane_cylinder(plane_cylinder_intersection(plane_self, cylinder_other))
             }
         },
-        Surface::Sphere(_sphere_self) => match face_other {
-            Surface::Plane(_plane_other) => {
-                todo!("Sphere-Plane intersection")
+        Surface::Sphere(sphere_self) => match face_other {
+            Surface::Plane(plane_other) => {
+                from_plane_sphere(plane_sphere_intersection(sphere_self, plane_other))
             }
             Surface::Sphere(_sphere_other) => {
                 todo!("Sphere-Sphere intersection")
@@ -43,15 +78,29 @@ pub fn surface_surface_intersection(
                 todo!("Sphere-Cylinder intersection")
             }
         },
-        Surface::Cylinder(_cylinder_self) => match face_other {
-            Surface::Plane(_plane_other) => {
-                todo!("Cylinder-Plane intersection")
+        Surface::Cylinder(cylinder_self) => match face_other {
+            Surface::Plane(plane_other) => {
+                from_plane_cylinder(plane_cylinder_intersection(plane_other, cylinder_self))
             }
             Surface::Sphere(_sphere_other) => {
                 todo!("Cylinder-Sphere intersection")
             }
-            Surface::Cylinder(_cylinder_other) => {
-                todo!("Cylinder-Cylinder intersection")
+            Surface::Cylinder(cylinder_other) => {
+                match cylinder_cylinder_intersection(cylinder_self, cylinder_other) {
+                    CylinderCylinderIntersection::Cylinder(c) => {
+                        FaceSurfaceIntersection::Surface(Surface::Cylinder(c))
+                    }
+                    CylinderCylinderIntersection::TwoLines(l1, l2) => {
+                        FaceSurfaceIntersection::CurvesAndPoints(
+                            vec![Curve::Line(l1), Curve::Line(l2)],
+                            vec![],
+                        )
+                    }
+                    CylinderCylinderIntersection::Line(l) => {
+                        FaceSurfaceIntersection::CurvesAndPoints(vec![Curve::Line(l)], vec![])
+                    }
+                    CylinderCylinderIntersection::None => FaceSurfaceIntersection::None,
+                }
             }
         },
     }
@@ -1,11 +1,13 @@
 use crate::{
     curves::{circle::Circle, curve::Curve, helix::Helix, line::Line, CurveLike},
+    periodic::wrap,
     points::point::Point,
     transforms::Transform,
     EQ_THRESHOLD, HORIZON_DIST,
 };
 
 use super::{
+    grid_cache::GridCache,
     surface::{Surface, TangentPoint},
     SurfaceLike,
 };
@@ -17,6 +19,7 @@ pub struct Cylinder {
     pub radius: Point,
     pub normal_outwards: bool,
     dir_cross: Point,
+    pub grid_cache: GridCache,
 }
 
 impl Cylinder {
@@ -34,6 +37,7 @@ impl Cylinder {
             radius,
             normal_outwards,
             dir_cross: extend_dir.normalize().cross(radius),
+            grid_cache: GridCache::new(),
         }
     }
     fn transform(&self, transform: Transform) -> Self {
@@ -226,6 +230,24 @@ impl SurfaceLike for Cylinder {
         let grad = -normal * dist;
         Some(grad)
     }
+
+    // u is the angle around the axis in [0, 2pi), starting at the radius vector, and v the height along the axis.
+    fn parameters(&self, p: Point) -> Point {
+        let d = p - self.basis;
+        let u = wrap(
+            d.dot(self.dir_cross).atan2(d.dot(self.radius)),
+            2.0 * std::f64::consts::PI,
+        );
+        Point::new(u, d.dot(self.extend_dir), 0.0)
+    }
+
+    fn point_at(&self, uv: Point) -> Point {
+        self.basis + self.radius * uv.x.cos() + self.dir_cross * uv.x.sin() + self.extend_dir * uv.y
+    }
+
+    fn u_period(&self) -> Option<f64> {
+        Some(2.0 * std::f64::consts::PI)
+    }
 }
 
 impl PartialEq for Cylinder {
@@ -0,0 +1,109 @@
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{Arc, Mutex},
+};
+
+use crate::points::point::Point;
+
+// Number of densities that are kept per surface. Renderers usually ask for one or two, so older entries are dropped first.
+const MAX_ENTRIES: usize = 4;
+
+// The point grid of a surface together with the normals at its points.
+#[derive(Debug, Clone)]
+pub struct SurfaceGrid {
+    pub points: Vec<Point>,
+    pub normals: Vec<Point>,
+}
+
+// Grids by the bits of their density, oldest first.
+type GridEntries = Vec<(u64, Arc<SurfaceGrid>)>;
+
+// Memoized point grids of one surface, keyed by density. Clones of a surface share the cache, since they have the same geometry,
+// while transformed or negated surfaces are built anew with an empty cache. The fields of a surface must not be changed after
+// it was sampled, or the cache has to be cleared. The cache is behind a mutex, so surfaces can be shared between threads.
+#[derive(Clone, Default)]
+pub struct GridCache {
+    entries: Arc<Mutex<GridEntries>>,
+}
+
+impl GridCache {
+    pub fn new() -> GridCache {
+        GridCache::default()
+    }
+
+    // The grid of the density, computed with compute the first time it is asked for. The computation runs without holding the
+    // lock, so two threads may compute the same grid, but only the first result is kept.
+    pub fn get_or_compute(
+        &self,
+        density: f64,
+        compute: impl FnOnce() -> SurfaceGrid,
+    ) -> Arc<SurfaceGrid> {
+        let key = density.to_bits();
+        if let Some((_, grid)) = self.entries.lock().unwrap().iter().find(|e| e.0 == key) {
+            return grid.clone();
+        }
+        let grid = Arc::new(compute());
+        let mut entries = self.entries.lock().unwrap();
+        if let Some((_, existing)) = entries.iter().find(|e| e.0 == key) {
+            return existing.clone();
+        }
+        if entries.len() == MAX_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push((key, grid.clone()));
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+impl Debug for GridCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GridCache({} grids)", self.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        surfaces::{sphere::Sphere, surface::Surface, SurfaceLike},
+        transforms::Transform,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_grid_cache() {
+        let sphere = Surface::Sphere(Sphere::new(Point::zero(), 2.0, true));
+        let grid = sphere.grid(1.0);
+        assert_eq!(grid.points, sphere.point_grid(1.0));
+        for (p, n) in grid.points.iter().zip(grid.normals.iter()) {
+            assert_eq!(*n, *p / 2.0);
+        }
+        // Clones share the grids, transformed surfaces start over.
+        assert!(Arc::ptr_eq(&grid, &sphere.clone().grid(1.0)));
+        assert!(!Arc::ptr_eq(&grid, &sphere.grid(2.0)));
+        let moved = sphere.transform(Transform::from_translation(Point::unit_x()));
+        assert!(moved.grid_cache().is_empty());
+        assert_eq!(moved.grid(1.0).points[0], grid.points[0] + Point::unit_x());
+
+        for density in 3..10 {
+            sphere.grid(density as f64);
+        }
+        assert_eq!(sphere.grid_cache().len(), MAX_ENTRIES);
+        sphere.grid_cache().clear();
+        assert!(sphere.grid_cache().is_empty());
+    }
+}
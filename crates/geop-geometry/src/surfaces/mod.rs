@@ -3,6 +3,7 @@ use surface::{Surface, TangentPoint};
 use crate::{curves::curve::Curve, points::point::Point, transforms::Transform};
 
 pub mod cylinder;
+pub mod grid_cache;
 pub mod plane;
 pub mod sphere;
 pub mod surface;
@@ -51,4 +52,13 @@ pub trait SurfaceLike {
 
     // Returns a gradient that leads to the surface.
     fn unsigned_l2_squared_distance_gradient(&self, point: Point) -> Option<Point>;
+
+    // Returns the parameters (u, v) of the point p on the surface. Z coordinate is set to 0.
+    fn parameters(&self, p: Point) -> Point;
+
+    // Returns the point on the surface at the parameters (u, v). uv_z is ignored.
+    fn point_at(&self, uv: Point) -> Point;
+
+    // Returns the period of the u parameter, if the surface wraps around in u.
+    fn u_period(&self) -> Option<f64>;
 }
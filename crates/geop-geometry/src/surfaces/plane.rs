@@ -6,6 +6,7 @@ use crate::{
 };
 
 use super::{
+    grid_cache::GridCache,
     surface::{Surface, TangentPoint},
     SurfaceLike,
 };
@@ -15,6 +16,7 @@ pub struct Plane {
     pub basis: Point,
     pub u_slope: Point,
     pub v_slope: Point,
+    pub grid_cache: GridCache,
 }
 
 impl Plane {
@@ -24,6 +26,7 @@ impl Plane {
             basis,
             u_slope: u_slope.normalize(),
             v_slope: v_slope.normalize(),
+            grid_cache: GridCache::new(),
         }
     }
 
@@ -134,6 +137,24 @@ impl SurfaceLike for Plane {
         let distance = (point - self.basis).dot(normal);
         Some(-normal * distance)
     }
+
+    fn parameters(&self, p: Point) -> Point {
+        // The slopes are not necessarily orthogonal, so solve the 2x2 system of the Gram matrix.
+        let d = p - self.basis;
+        let uv = self.u_slope.dot(self.v_slope);
+        let du = d.dot(self.u_slope);
+        let dv = d.dot(self.v_slope);
+        let det = 1.0 - uv * uv;
+        Point::new((du - uv * dv) / det, (dv - uv * du) / det, 0.0)
+    }
+
+    fn point_at(&self, uv: Point) -> Point {
+        self.basis + self.u_slope * uv.x + self.v_slope * uv.y
+    }
+
+    fn u_period(&self) -> Option<f64> {
+        None
+    }
 }
 
 impl PartialEq for Plane {
@@ -141,7 +162,7 @@ impl PartialEq for Plane {
         self.u_slope.is_parallel(other.u_slope)
             && self.v_slope.is_parallel(other.v_slope)
             && (self.basis - other.basis)
-                .dot(self.u_slope.cross(other.u_slope))
+                .dot(self.u_slope.cross(self.v_slope))
                 .abs()
                 < EQ_THRESHOLD
     }
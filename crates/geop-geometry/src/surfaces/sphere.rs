@@ -1,11 +1,13 @@
 use crate::{
     curves::{circle::Circle, curve::Curve},
+    periodic::wrap,
     points::point::Point,
     transforms::Transform,
     EQ_THRESHOLD,
 };
 
 use super::{
+    grid_cache::GridCache,
     surface::{Surface, TangentPoint},
     SurfaceLike,
 };
@@ -15,6 +17,7 @@ pub struct Sphere {
     pub basis: Point,
     pub radius: f64,
     pub normal_outwards: bool,
+    pub grid_cache: GridCache,
 }
 
 pub enum SphereTransform {
@@ -28,9 +31,42 @@ impl Sphere {
             basis,
             radius,
             normal_outwards,
+            grid_cache: GridCache::new(),
         }
     }
 
+    // The great circle through p and q, running from p to q along the shorter way. p and q must not be antipodal, since every
+    // great circle through the poles connects them.
+    pub fn great_circle(&self, p: Point, q: Point) -> Circle {
+        let normal = (p - self.basis).cross(q - self.basis);
+        assert!(
+            normal.norm() > EQ_THRESHOLD * self.radius,
+            "Points are equal or antipodal, so the great circle is not defined"
+        );
+        Circle::new(self.basis, normal.normalize(), self.radius)
+    }
+
+    // The circle of the points at the latitude in radians relative to the axis, i.e. -pi / 2 at the south pole, 0 on the equator
+    // and pi / 2 at the north pole, in the direction of the axis. The circle runs counterclockwise around the axis.
+    pub fn small_circle(&self, axis: Point, latitude: f64) -> Circle {
+        assert!(
+            latitude.abs() < std::f64::consts::FRAC_PI_2,
+            "Small circles at the poles are points"
+        );
+        let axis = axis.normalize();
+        Circle::new(
+            self.basis + axis * (self.radius * latitude.sin()),
+            axis,
+            self.radius * latitude.cos(),
+        )
+    }
+
+    // The latitude of a point on the sphere relative to the axis, like for small_circle.
+    pub fn latitude(&self, axis: Point, p: Point) -> f64 {
+        let sin = (p - self.basis).dot(axis.normalize()) / self.radius;
+        sin.clamp(-1.0, 1.0).asin()
+    }
+
     fn transform(&self, transform: Transform) -> SphereTransform {
         let basis = transform * self.basis;
         let radius = self.radius * transform.uniform_scale_factor();
@@ -193,6 +229,22 @@ impl SurfaceLike for Sphere {
         let dist = diff.norm() - self.radius;
         Some(-(diff / diff_norm) * dist)
     }
+
+    // u is the azimuth around the z axis in [0, 2pi) and v the polar angle from the z axis in [0, pi], like in the point grid.
+    fn parameters(&self, p: Point) -> Point {
+        let d = (p - self.basis) / self.radius;
+        let u = wrap(d.y.atan2(d.x), 2.0 * std::f64::consts::PI);
+        Point::new(u, d.z.clamp(-1.0, 1.0).acos(), 0.0)
+    }
+
+    fn point_at(&self, uv: Point) -> Point {
+        self.basis
+            + Point::new(uv.x.cos() * uv.y.sin(), uv.x.sin() * uv.y.sin(), uv.y.cos()) * self.radius
+    }
+
+    fn u_period(&self) -> Option<f64> {
+        Some(2.0 * std::f64::consts::PI)
+    }
 }
 
 impl PartialEq for Sphere {
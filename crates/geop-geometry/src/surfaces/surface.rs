@@ -1,6 +1,14 @@
+use std::sync::Arc;
+
 use crate::{curves::curve::Curve, points::point::Point, transforms::Transform};
 
-use super::{cylinder::Cylinder, plane::Plane, sphere::Sphere, SurfaceLike};
+use super::{
+    cylinder::Cylinder,
+    grid_cache::{GridCache, SurfaceGrid},
+    plane::Plane,
+    sphere::Sphere,
+    SurfaceLike,
+};
 
 pub type TangentPoint = Point;
 
@@ -11,6 +19,26 @@ pub enum Surface {
     Cylinder(Cylinder),
 }
 
+impl Surface {
+    pub fn grid_cache(&self) -> &GridCache {
+        match self {
+            Surface::Plane(plane) => &plane.grid_cache,
+            Surface::Sphere(sphere) => &sphere.grid_cache,
+            Surface::Cylinder(cylinder) => &cylinder.grid_cache,
+        }
+    }
+
+    // Like point_grid, with the normals at the points. The grid is computed once per density and surface, so renderers can ask
+    // for it on every frame.
+    pub fn grid(&self, density: f64) -> Arc<SurfaceGrid> {
+        self.grid_cache().get_or_compute(density, || {
+            let points = self.point_grid(density);
+            let normals = points.iter().map(|p| self.normal(*p)).collect();
+            SurfaceGrid { points, normals }
+        })
+    }
+}
+
 impl SurfaceLike for Surface {
     // Transforms the surface by the given transform.
     fn transform(&self, transform: Transform) -> Surface {
@@ -125,4 +153,31 @@ impl SurfaceLike for Surface {
             Surface::Cylinder(cylinder) => cylinder.unsigned_l2_squared_distance_gradient(point),
         }
     }
+
+    // Returns the parameters (u, v) of the point p on the surface. Z coordinate is set to 0.
+    fn parameters(&self, p: Point) -> Point {
+        match self {
+            Surface::Plane(plane) => plane.parameters(p),
+            Surface::Sphere(sphere) => sphere.parameters(p),
+            Surface::Cylinder(cylinder) => cylinder.parameters(p),
+        }
+    }
+
+    // Returns the point on the surface at the parameters (u, v). uv_z is ignored.
+    fn point_at(&self, uv: Point) -> Point {
+        match self {
+            Surface::Plane(plane) => plane.point_at(uv),
+            Surface::Sphere(sphere) => sphere.point_at(uv),
+            Surface::Cylinder(cylinder) => cylinder.point_at(uv),
+        }
+    }
+
+    // Returns the period of the u parameter, if the surface wraps around in u.
+    fn u_period(&self) -> Option<f64> {
+        match self {
+            Surface::Plane(plane) => plane.u_period(),
+            Surface::Sphere(sphere) => sphere.u_period(),
+            Surface::Cylinder(cylinder) => cylinder.u_period(),
+        }
+    }
 }
@@ -0,0 +1,147 @@
+use crate::{
+    curves::{curve::Curve, CurveLike},
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+use super::{rotation::Rotation, Transform};
+
+// A right handed coordinate system with an origin and an orthonormal basis, e.g. the placement of a sketch or of a part in an assembly.
+// Local coordinates are relative to the frame, global coordinates are world coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame {
+    pub origin: Point,
+    pub x_dir: Point,
+    pub y_dir: Point,
+    pub z_dir: Point,
+}
+
+impl Frame {
+    // x_dir is kept, y_dir is made orthogonal to it.
+    pub fn new(origin: Point, x_dir: Point, y_dir: Point) -> Frame {
+        let x_dir = x_dir.normalize();
+        let z_dir = x_dir.cross(y_dir).normalize();
+        let y_dir = z_dir.cross(x_dir);
+        Frame {
+            origin,
+            x_dir,
+            y_dir,
+            z_dir,
+        }
+    }
+
+    pub fn world() -> Frame {
+        Frame::new(Point::zero(), Point::unit_x(), Point::unit_y())
+    }
+
+    pub fn from_rotation(origin: Point, rotation: Rotation) -> Frame {
+        Frame::new(
+            origin,
+            rotation * Point::unit_x(),
+            rotation * Point::unit_y(),
+        )
+    }
+
+    pub fn rotation(&self) -> Rotation {
+        Rotation::from_transform(&self.to_global_transform())
+    }
+
+    // Moves the frame with the transform. Scaling is removed, since the basis stays orthonormal.
+    pub fn transform(&self, transform: Transform) -> Frame {
+        let origin = transform * self.origin;
+        Frame::new(
+            origin,
+            transform * (self.origin + self.x_dir) - origin,
+            transform * (self.origin + self.y_dir) - origin,
+        )
+    }
+
+    pub fn to_global(&self, p: Point) -> Point {
+        self.origin + self.to_global_vector(p)
+    }
+
+    pub fn to_local(&self, p: Point) -> Point {
+        self.to_local_vector(p - self.origin)
+    }
+
+    // Vectors are only rotated, not translated.
+    pub fn to_global_vector(&self, v: Point) -> Point {
+        self.x_dir * v.x + self.y_dir * v.y + self.z_dir * v.z
+    }
+
+    pub fn to_local_vector(&self, v: Point) -> Point {
+        Point::new(v.dot(self.x_dir), v.dot(self.y_dir), v.dot(self.z_dir))
+    }
+
+    // The transform that maps local coordinates to global coordinates.
+    pub fn to_global_transform(&self) -> Transform {
+        let mut matrix = [[0.0; 4]; 4];
+        for (j, axis) in [self.x_dir, self.y_dir, self.z_dir, self.origin]
+            .iter()
+            .enumerate()
+        {
+            matrix[0][j] = axis.x;
+            matrix[1][j] = axis.y;
+            matrix[2][j] = axis.z;
+        }
+        matrix[3][3] = 1.0;
+        Transform { matrix }
+    }
+
+    // The transform that maps global coordinates to local coordinates.
+    pub fn to_local_transform(&self) -> Transform {
+        let mut matrix = [[0.0; 4]; 4];
+        for (i, axis) in [self.x_dir, self.y_dir, self.z_dir].iter().enumerate() {
+            matrix[i][0] = axis.x;
+            matrix[i][1] = axis.y;
+            matrix[i][2] = axis.z;
+            matrix[i][3] = -axis.dot(self.origin);
+        }
+        matrix[3][3] = 1.0;
+        Transform { matrix }
+    }
+
+    pub fn curve_to_global(&self, curve: &Curve) -> Curve {
+        curve.transform(self.to_global_transform())
+    }
+
+    pub fn curve_to_local(&self, curve: &Curve) -> Curve {
+        curve.transform(self.to_local_transform())
+    }
+
+    pub fn surface_to_global(&self, surface: &Surface) -> Surface {
+        surface.transform(self.to_global_transform())
+    }
+
+    pub fn surface_to_local(&self, surface: &Surface) -> Surface {
+        surface.transform(self.to_local_transform())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::circle::Circle;
+
+    #[test]
+    fn test_frame() {
+        let frame = Frame::new(
+            Point::new(1.0, 2.0, 3.0),
+            Point::unit_y(),
+            Point::new(-1.0, 0.5, 0.0),
+        );
+        assert_eq!(frame.z_dir, Point::unit_z());
+        let p = Point::new(0.5, -2.0, 4.0);
+        assert_eq!(frame.to_global(frame.to_local(p)), p);
+        assert_eq!(frame.to_global(Point::unit_x()), Point::new(1.0, 3.0, 3.0));
+        assert_eq!(frame.to_global_transform() * p, frame.to_global(p));
+        assert_eq!(frame.to_local_transform() * p, frame.to_local(p));
+        assert_eq!(frame.to_global_vector(Point::unit_x()), Point::unit_y());
+        assert_eq!(Frame::from_rotation(frame.origin, frame.rotation()), frame);
+
+        let circle = Curve::Circle(Circle::new(Point::zero(), Point::unit_z(), 1.0));
+        let global = frame.curve_to_global(&circle);
+        assert!(global.on_curve(frame.to_global(Point::unit_y())));
+        assert_eq!(frame.curve_to_local(&global), circle);
+    }
+}
@@ -2,6 +2,9 @@ use std::ops::Mul;
 
 use crate::{points::point::Point, EQ_THRESHOLD};
 
+pub mod frame;
+pub mod rotation;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Transform {
     pub matrix: [[f64; 4]; 4],
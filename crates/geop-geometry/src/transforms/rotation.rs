@@ -0,0 +1,230 @@
+use std::ops::Mul;
+
+use crate::{points::point::Point, EQ_THRESHOLD};
+
+use super::Transform;
+
+// A rotation around the origin, stored as a unit quaternion w + x*i + y*j + z*k.
+// Unlike Transform, rotations can be interpolated smoothly with slerp, e.g. for animations.
+#[derive(Debug, Clone, Copy)]
+pub struct Rotation {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Rotation {
+    fn normalized(w: f64, x: f64, y: f64, z: f64) -> Rotation {
+        let norm = (w * w + x * x + y * y + z * z).sqrt();
+        assert!(norm > EQ_THRESHOLD, "Quaternion must not be zero");
+        Rotation {
+            w: w / norm,
+            x: x / norm,
+            y: y / norm,
+            z: z / norm,
+        }
+    }
+
+    pub fn identity() -> Rotation {
+        Rotation::normalized(1.0, 0.0, 0.0, 0.0)
+    }
+
+    // Rotation by angle around axis, counterclockwise when looking against the axis.
+    pub fn from_axis_angle(axis: Point, angle: f64) -> Rotation {
+        let axis = axis.normalize();
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Rotation::normalized(cos, axis.x * sin, axis.y * sin, axis.z * sin)
+    }
+
+    // The shortest rotation that turns the direction from into the direction to.
+    pub fn between(from: Point, to: Point) -> Rotation {
+        let from = from.normalize();
+        let to = to.normalize();
+        let d = from.dot(to);
+        if d < -1.0 + EQ_THRESHOLD {
+            // Opposite directions, so any axis perpendicular to from works.
+            let axis = match Point::unit_x().cross(from).norm_sq() > EQ_THRESHOLD {
+                true => Point::unit_x().cross(from),
+                false => Point::unit_y().cross(from),
+            };
+            return Rotation::from_axis_angle(axis, std::f64::consts::PI);
+        }
+        let axis = from.cross(to);
+        Rotation::normalized(1.0 + d, axis.x, axis.y, axis.z)
+    }
+
+    // Extracts the rotation of a transform, which must not contain scaling or shearing. Translation is ignored.
+    pub fn from_transform(transform: &Transform) -> Rotation {
+        let m = transform.matrix;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Rotation::normalized(
+                s / 4.0,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+            Rotation::normalized(
+                (m[2][1] - m[1][2]) / s,
+                s / 4.0,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+            Rotation::normalized(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / 4.0,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+            Rotation::normalized(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / 4.0,
+            )
+        }
+    }
+
+    pub fn to_transform(&self) -> Transform {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        let mut matrix = [[0.0; 4]; 4];
+        matrix[0][0] = 1.0 - 2.0 * (y * y + z * z);
+        matrix[0][1] = 2.0 * (x * y - w * z);
+        matrix[0][2] = 2.0 * (x * z + w * y);
+        matrix[1][0] = 2.0 * (x * y + w * z);
+        matrix[1][1] = 1.0 - 2.0 * (x * x + z * z);
+        matrix[1][2] = 2.0 * (y * z - w * x);
+        matrix[2][0] = 2.0 * (x * z - w * y);
+        matrix[2][1] = 2.0 * (y * z + w * x);
+        matrix[2][2] = 1.0 - 2.0 * (x * x + y * y);
+        matrix[3][3] = 1.0;
+        Transform { matrix }
+    }
+
+    pub fn inverse(&self) -> Rotation {
+        Rotation::normalized(self.w, -self.x, -self.y, -self.z)
+    }
+
+    // Rotation angle in [0, pi].
+    pub fn angle(&self) -> f64 {
+        2.0 * self.w.abs().min(1.0).acos()
+    }
+
+    // Rotation axis, matching the direction of angle(). Returns None for the identity.
+    pub fn axis(&self) -> Option<Point> {
+        let v = Point::new(self.x, self.y, self.z) * self.w.signum();
+        match v.norm() > EQ_THRESHOLD {
+            true => Some(v.normalize()),
+            false => None,
+        }
+    }
+
+    fn dot(&self, other: &Rotation) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    // Spherical linear interpolation, which rotates with constant angular velocity from self at t = 0 to other at t = 1 on the shorter way.
+    pub fn slerp(&self, other: &Rotation, t: f64) -> Rotation {
+        let mut cos = self.dot(other);
+        // q and -q are the same rotation. Flip to take the shorter way.
+        let other = match cos < 0.0 {
+            true => {
+                cos = -cos;
+                Rotation {
+                    w: -other.w,
+                    x: -other.x,
+                    y: -other.y,
+                    z: -other.z,
+                }
+            }
+            false => *other,
+        };
+        let (s0, s1) = match cos > 1.0 - EQ_THRESHOLD {
+            // Nearly identical rotations, where the sine below vanishes.
+            true => (1.0 - t, t),
+            false => {
+                let theta = cos.acos();
+                let sin = theta.sin();
+                (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+            }
+        };
+        Rotation::normalized(
+            s0 * self.w + s1 * other.w,
+            s0 * self.x + s1 * other.x,
+            s0 * self.y + s1 * other.y,
+            s0 * self.z + s1 * other.z,
+        )
+    }
+}
+
+// Composition, which applies other first and then self, like for Transform.
+impl Mul for Rotation {
+    type Output = Rotation;
+
+    fn mul(self, other: Rotation) -> Rotation {
+        let (a, b) = (self, other);
+        Rotation::normalized(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+}
+
+impl Mul<Point> for Rotation {
+    type Output = Point;
+
+    fn mul(self, other: Point) -> Point {
+        let q = Point::new(self.x, self.y, self.z);
+        let t = q.cross(other) * 2.0;
+        other + t * self.w + q.cross(t)
+    }
+}
+
+impl PartialEq for Rotation {
+    // q and -q represent the same rotation.
+    fn eq(&self, other: &Rotation) -> bool {
+        self.dot(other).abs() > 1.0 - EQ_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn test_rotation() {
+        let r = Rotation::from_axis_angle(Point::unit_z(), PI / 2.0);
+        assert_eq!(r * Point::unit_x(), Point::unit_y());
+        assert_eq!(r.to_transform() * Point::unit_x(), Point::unit_y());
+        assert_eq!(Rotation::from_transform(&r.to_transform()), r);
+        assert!((r.angle() - PI / 2.0).abs() < EQ_THRESHOLD);
+        assert_eq!(r.axis(), Some(Point::unit_z()));
+        assert_eq!(r.inverse() * r, Rotation::identity());
+
+        let t = Transform::from_euler_angles(0.3, -1.2, 2.5);
+        let from_t = Rotation::from_transform(&t);
+        let p = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(from_t * p, t * p);
+
+        let a = Point::new(1.0, 1.0, 0.0);
+        let b = Point::new(0.0, -2.0, 1.0);
+        assert_eq!(Rotation::between(a, b) * a.normalize(), b.normalize());
+        assert_eq!(Rotation::between(a, -a) * a, -a);
+
+        let half = Rotation::identity().slerp(&r, 0.5);
+        assert_eq!(half, Rotation::from_axis_angle(Point::unit_z(), PI / 4.0));
+        assert_eq!(Rotation::identity().slerp(&r, 1.0), r);
+    }
+}
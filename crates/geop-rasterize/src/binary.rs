@@ -0,0 +1,68 @@
+use std::borrow::Cow;
+
+use bytemuck::Pod;
+use geop_topology::topology::binary::{check_header, header, DecodeError};
+
+use crate::{
+    edge_buffer::{EdgeBuffer, RenderEdge},
+    triangle_buffer::{RenderTriangle, TriangleBuffer},
+};
+
+// Tessellations in the binary format of geop_topology::topology::binary. After the header and a count, the vertex data is stored
+// exactly as it is laid out in memory and on the GPU, so it can be used straight from the received bytes without parsing.
+
+pub const KIND_TRIANGLES: u8 = 2;
+pub const KIND_EDGES: u8 = 3;
+
+// The header and the count are padded to 16 bytes, so that the data is aligned for f32 whenever the whole buffer is.
+const DATA_OFFSET: usize = 16;
+
+fn items_to_bytes<T: Pod>(kind: u8, items: &[T]) -> Vec<u8> {
+    let mut bytes = header(kind);
+    bytes.resize(DATA_OFFSET - 4, 0);
+    bytes.extend((items.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(bytemuck::cast_slice(items));
+    bytes
+}
+
+// Borrows the items from the bytes if they are aligned, and copies them otherwise.
+fn items_from_bytes<T: Pod>(bytes: &[u8], kind: u8) -> Result<Cow<'_, [T]>, DecodeError> {
+    check_header(bytes, kind)?;
+    if bytes.len() < DATA_OFFSET {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+    let count =
+        u32::from_le_bytes(bytes[DATA_OFFSET - 4..DATA_OFFSET].try_into().unwrap()) as usize;
+    let data = &bytes[DATA_OFFSET..];
+    let size = std::mem::size_of::<T>();
+    if data.len() < count * size {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+    if data.len() > count * size {
+        return Err(DecodeError::Invalid("Trailing data".to_string()));
+    }
+    match bytemuck::try_cast_slice(data) {
+        Ok(items) => Ok(Cow::Borrowed(items)),
+        Err(_) => Ok(Cow::Owned(
+            data.chunks_exact(size)
+                .map(bytemuck::pod_read_unaligned)
+                .collect(),
+        )),
+    }
+}
+
+pub fn triangles_to_bytes(buffer: &TriangleBuffer) -> Vec<u8> {
+    items_to_bytes(KIND_TRIANGLES, &buffer.triangles)
+}
+
+pub fn triangles_from_bytes(bytes: &[u8]) -> Result<Cow<'_, [RenderTriangle]>, DecodeError> {
+    items_from_bytes(bytes, KIND_TRIANGLES)
+}
+
+pub fn edges_to_bytes(buffer: &EdgeBuffer) -> Vec<u8> {
+    items_to_bytes(KIND_EDGES, &buffer.edges)
+}
+
+pub fn edges_from_bytes(bytes: &[u8]) -> Result<Cow<'_, [RenderEdge]>, DecodeError> {
+    items_from_bytes(bytes, KIND_EDGES)
+}
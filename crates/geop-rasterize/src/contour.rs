@@ -1,6 +1,10 @@
 use geop_topology::topology::{contour::Contour, scene::Color};
 
-use crate::{edge::rasterize_edge_into_line_list, edge_buffer::EdgeBuffer};
+use crate::{
+    edge::{rasterize_edge_into_line_list, rasterize_edge_into_line_list_with_tolerance},
+    edge_buffer::EdgeBuffer,
+    tolerance::Tolerance,
+};
 
 // Rasterizes an edge loop into triangle list.
 pub fn rasterize_contour_into_line_list(contour: &Contour, color: Color) -> EdgeBuffer {
@@ -13,6 +17,22 @@ pub fn rasterize_contour_into_line_list(contour: &Contour, color: Color) -> Edge
     edges
 }
 
+pub fn rasterize_contour_into_line_list_with_tolerance(
+    contour: &Contour,
+    color: Color,
+    tolerance: &Tolerance,
+) -> EdgeBuffer {
+    let mut edges = EdgeBuffer::new(Vec::new());
+
+    for edge in contour.edges.iter() {
+        edges.join(&rasterize_edge_into_line_list_with_tolerance(
+            edge, color, tolerance,
+        ));
+    }
+
+    edges
+}
+
 // Rasterizes multiple edge loop into triangle list.
 pub fn rasterize_contours_into_line_list(contour: &[Contour], color: Color) -> EdgeBuffer {
     contour
@@ -1,19 +1,29 @@
-use geop_geometry::{curves::curve::Curve, HORIZON_DIST};
+use geop_geometry::{curves::curve::Curve, points::point::Point, HORIZON_DIST};
 use geop_topology::topology::{edge::Edge, scene::Color};
 
 use crate::{
     edge_buffer::{EdgeBuffer, RenderEdge},
+    tolerance::Tolerance,
     vertex_buffer::{RenderVertex, VertexBuffer},
 };
 
-// Rasterizes an edge loop into triangle list.
-pub fn rasterize_edge_into_line_list(edge: &Edge, color: Color) -> EdgeBuffer {
-    let n = match edge.curve {
+// Segments of curved edges are bisected at most this often, which bounds the number of segments for very tight tolerances.
+const MAX_BISECTIONS: usize = 12;
+
+fn default_segments(edge: &Edge) -> usize {
+    match &edge.curve {
         Curve::Line(_) => 10,
         Curve::Circle(_) => 32,
         Curve::Ellipse(_) => 32,
         Curve::Helix(_) => 32 * HORIZON_DIST as usize,
-    };
+        Curve::Bezier(_) => 32,
+        Curve::Composite(composite) => 32 * composite.segments.len(),
+    }
+}
+
+// Rasterizes an edge loop into triangle list.
+pub fn rasterize_edge_into_line_list(edge: &Edge, color: Color) -> EdgeBuffer {
+    let n = default_segments(edge);
     let mut edges = Vec::<RenderEdge>::with_capacity(n);
     for j in 0..n {
         let v1 = (j as f64) / n as f64;
@@ -27,6 +37,53 @@ pub fn rasterize_edge_into_line_list(edge: &Edge, color: Color) -> EdgeBuffer {
     EdgeBuffer::new(edges)
 }
 
+fn bisect(
+    edge: &Edge,
+    (t0, p0): (f64, Point),
+    (t1, p1): (f64, Point),
+    tolerance: &Tolerance,
+    depth: usize,
+    points: &mut Vec<Point>,
+) {
+    let t = (t0 + t1) / 2.0;
+    let p = edge.interpolate(t);
+    if depth < MAX_BISECTIONS && !tolerance.accepts(p0, p1, p, edge.tangent(p0), edge.tangent(p1)) {
+        bisect(edge, (t0, p0), (t, p), tolerance, depth + 1, points);
+        bisect(edge, (t, p), (t1, p1), tolerance, depth + 1, points);
+    } else {
+        points.push(p1);
+    }
+}
+
+// Rasterizes an edge with segments that are bisected until they are within the tolerance, so that tight bends get more segments than
+// gentle ones. Curved edges start out with an eighth of the default number of segments, lines are never refined.
+pub fn rasterize_edge_into_line_list_with_tolerance(
+    edge: &Edge,
+    color: Color,
+    tolerance: &Tolerance,
+) -> EdgeBuffer {
+    let n = match &edge.curve {
+        Curve::Line(_) => default_segments(edge),
+        _ => (default_segments(edge) / 8).max(4),
+    };
+    let mut points = vec![edge.interpolate(0.0)];
+    for j in 0..n {
+        let (t0, t1) = (j as f64 / n as f64, (j + 1) as f64 / n as f64);
+        let p0 = *points.last().unwrap();
+        let p1 = edge.interpolate(t1);
+        match &edge.curve {
+            Curve::Line(_) => points.push(p1),
+            _ => bisect(edge, (t0, p0), (t1, p1), tolerance, 0, &mut points),
+        }
+    }
+    EdgeBuffer::new(
+        points
+            .windows(2)
+            .map(|w| RenderEdge::new(w[0], w[1], color))
+            .collect(),
+    )
+}
+
 // Rasterizes multiple edge loop into triangle list.
 pub fn rasterize_edges_into_line_list(edges: &[Edge], color: Color) -> EdgeBuffer {
     edges
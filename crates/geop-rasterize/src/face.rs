@@ -7,16 +7,20 @@ use geop_geometry::{
 };
 use geop_topology::{
     contains::face_point::{face_point_contains, FacePointContains},
-    topology::{face::Face, scene::Color},
+    topology::{contour::Contour, face::Face, scene::Color},
 };
 
 use crate::{
-    contour::rasterize_contour_into_line_list,
+    contour::{rasterize_contour_into_line_list, rasterize_contour_into_line_list_with_tolerance},
     edge_buffer::{EdgeBuffer, RenderEdge},
+    tolerance::Tolerance,
     triangle_buffer::{RenderTriangle, TriangleBuffer},
     vertex_buffer::{RenderVertex, VertexBuffer},
 };
 
+// Triangles are refined at most this often, which bounds the number of triangles for very tight tolerances.
+const MAX_REFINEMENTS: usize = 8;
+
 // struct PointBuffer {
 //     vertices: Vec<RenderVertex>
 // }
@@ -315,24 +319,110 @@ pub fn rasterize_face_into_triangle_list(face: &Face, color: Color) -> TriangleB
     println!("/////////////////////////////////////////////////////////");
     println!("Rasterizing face {}", face);
     // Now we have to divide the face into triangles. First rasterize the boundaries. This will give us a set of open edges to work with
+    let contours = face_contours(face, |contour| {
+        rasterize_contour_into_line_list(contour, color)
+    });
+    TriangleBuffer::new(triangulate(face, &contours, color))
+}
+
+// Rasterizes the face so that the result stays within the tolerance. The contours are tessellated to the tolerance, and triangles
+// of the initial triangulation are refined where the surface bends.
+pub fn rasterize_face_into_triangle_list_with_tolerance(
+    face: &Face,
+    color: Color,
+    tolerance: &Tolerance,
+) -> TriangleBuffer {
+    let contours = face_contours(face, |contour| {
+        rasterize_contour_into_line_list_with_tolerance(contour, color, tolerance)
+    });
+    let triangles = triangulate(face, &contours, color);
+    TriangleBuffer::new(refine_triangles(
+        &face.surface,
+        triangles,
+        &contours,
+        tolerance,
+        color,
+    ))
+}
+
+fn face_contours(face: &Face, rasterize: impl Fn(&Contour) -> EdgeBuffer) -> Vec<EdgeBuffer> {
     let mut contours = Vec::<EdgeBuffer>::new();
-    if let Some(boundary) = &face.boundary {
-        let edge_buffer = rasterize_contour_into_line_list(&boundary, color);
+    for contour in face.boundary.iter().chain(face.holes.iter()) {
+        let edge_buffer = rasterize(contour);
         for edge in edge_buffer.edges.iter() {
             assert!(face.surface.on_surface(edge.start.point()));
             assert!(face.surface.on_surface(edge.end.point()));
         }
         contours.push(edge_buffer);
     }
-    for contour in face.holes.iter() {
-        let edge_buffer = rasterize_contour_into_line_list(contour, color);
-        for edge in edge_buffer.edges.iter() {
-            assert!(face.surface.on_surface(edge.start.point()));
-            assert!(face.surface.on_surface(edge.end.point()));
+    contours
+}
+
+// Splits a counter clockwise triangle at the midpoints of some of its edges. mids[i] is the midpoint of the edge from corner i to corner i + 1.
+fn split_triangle(corners: [Point; 3], mids: [Option<Point>; 3]) -> Vec<[Point; 3]> {
+    // Rotate the triangle, so that the first edge is split, and if two edges are split, the second one as well.
+    for r in 0..3 {
+        let p = [corners[r], corners[(r + 1) % 3], corners[(r + 2) % 3]];
+        match [mids[r], mids[(r + 1) % 3], mids[(r + 2) % 3]] {
+            [Some(a), Some(b), Some(c)] => {
+                return vec![[p[0], a, c], [a, p[1], b], [c, b, p[2]], [a, b, c]]
+            }
+            [Some(a), Some(b), None] => return vec![[a, p[1], b], [p[0], a, b], [p[0], b, p[2]]],
+            [Some(a), None, None] => return vec![[p[0], a, p[2]], [a, p[1], p[2]]],
+            _ => {}
         }
-        contours.push(edge_buffer);
     }
+    vec![corners]
+}
 
+// Splits triangle edges at their midpoint on the surface until every edge is within the tolerance. Whether an edge is split only
+// depends on its end points, so both triangles next to it agree and no cracks open up. Edges on the contours are never split, since
+// the contours are already tessellated to the tolerance.
+fn refine_triangles(
+    surface: &Surface,
+    mut triangles: Vec<RenderTriangle>,
+    contours: &[EdgeBuffer],
+    tolerance: &Tolerance,
+    color: Color,
+) -> Vec<RenderTriangle> {
+    for _ in 0..MAX_REFINEMENTS {
+        let split_point = |a: Point, b: Point| {
+            if edge_will_be_blocked_by_contour(&RenderEdge::new(a, b, color), contours) {
+                return None;
+            }
+            let mid = surface.project((a + b) / 2.0);
+            match tolerance.accepts(a, b, mid, surface.normal(a), surface.normal(b)) {
+                true => None,
+                false => Some(mid),
+            }
+        };
+        let mut refined = Vec::<RenderTriangle>::with_capacity(triangles.len());
+        let mut changed = false;
+        for triangle in triangles.iter() {
+            let corners = [triangle.a.point(), triangle.b.point(), triangle.c.point()];
+            let mids = [
+                split_point(corners[0], corners[1]),
+                split_point(corners[1], corners[2]),
+                split_point(corners[2], corners[0]),
+            ];
+            if mids.iter().all(Option::is_none) {
+                refined.push(*triangle);
+                continue;
+            }
+            changed = true;
+            for [a, b, c] in split_triangle(corners, mids) {
+                refined.push(RenderTriangle::on_surface(surface, a, b, c, color));
+            }
+        }
+        triangles = refined;
+        if !changed {
+            break;
+        }
+    }
+    triangles
+}
+
+fn triangulate(face: &Face, contours: &[EdgeBuffer], color: Color) -> Vec<RenderTriangle> {
     // Rasterize the edges first
     let mut open_edges: VecDeque<RenderEdge> = contours
         .iter()
@@ -348,8 +438,10 @@ pub fn rasterize_face_into_triangle_list(face: &Face, color: Color) -> TriangleB
     // Then generate additional points on the surface
     connection_points.extend(
         face.surface
-            .point_grid(1.0)
-            .drain(..)
+            .grid(1.0)
+            .points
+            .iter()
+            .cloned()
             .filter(|p| face_point_contains(face, *p) == FacePointContains::Inside)
             .map(|point| RenderVertex::new(point.clone(), color)),
     );
@@ -426,14 +518,12 @@ pub fn rasterize_face_into_triangle_list(face: &Face, color: Color) -> TriangleB
             }
         }
         if let Some(point) = best_triangle_point {
-            triangles.push(RenderTriangle::new(
+            triangles.push(RenderTriangle::on_surface(
+                &face.surface,
                 edge.start.into(),
                 edge.end.into(),
                 point.into(),
                 color,
-                face.surface.normal(edge.start.point()),
-                face.surface.normal(edge.end.point()),
-                face.surface.normal(point.point()),
             ));
             processed_edges.push(RenderEdge::new(point.into(), edge.start.into(), color));
             processed_edges.push(RenderEdge::new(edge.end.into(), point.into(), color));
@@ -443,7 +533,7 @@ pub fn rasterize_face_into_triangle_list(face: &Face, color: Color) -> TriangleB
                 RenderEdge::new(point.into(), edge.end.into(), color),
             ] {
                 // This will prevent the algorithm from spreading out of the face and filling the holes
-                if !edge_will_be_blocked_by_contour(&inner_edge, &contours) {
+                if !edge_will_be_blocked_by_contour(&inner_edge, contours) {
                     if !open_edges.contains(&inner_edge) {
                         open_edges.push_front(inner_edge);
                     }
@@ -454,7 +544,7 @@ pub fn rasterize_face_into_triangle_list(face: &Face, color: Color) -> TriangleB
         }
     }
 
-    return TriangleBuffer::new(triangles);
+    return triangles;
 }
 
 pub fn rasterize_face_into_line_list(face: &Face, color: Color) -> EdgeBuffer {
@@ -1,8 +1,12 @@
+pub mod binary;
 pub mod contour;
 pub mod edge;
 pub mod edge_buffer;
 pub mod face;
+pub mod stats;
+pub mod tolerance;
 pub mod triangle_buffer;
 pub mod vertex_buffer;
 pub mod vertex_normal_buffer;
 pub mod volume;
+pub mod zebra;
@@ -0,0 +1,38 @@
+use geop_topology::topology::{
+    scene::{Color, Scene},
+    stats::ModelStats,
+    volume::Volume,
+};
+
+use crate::{
+    face::rasterize_face_into_triangle_list_with_tolerance, tolerance::Tolerance,
+    volume::rasterize_volume_into_triangle_list_with_tolerance,
+};
+
+// Volume::stats with the number of triangles that the volume tessellates into at the tolerance.
+pub fn volume_stats(volume: &Volume, tolerance: &Tolerance) -> ModelStats {
+    let mut stats = volume.stats();
+    stats.triangles = Some(
+        rasterize_volume_into_triangle_list_with_tolerance(volume, Color::white(), tolerance)
+            .triangles
+            .len(),
+    );
+    stats
+}
+
+// Scene::stats with the number of triangles of the volumes and faces of the scene at the tolerance.
+pub fn scene_stats(scene: &Scene, tolerance: &Tolerance) -> ModelStats {
+    let mut stats = scene.stats();
+    let volumes = scene.volumes.iter().map(|(volume, _)| {
+        rasterize_volume_into_triangle_list_with_tolerance(volume, Color::white(), tolerance)
+            .triangles
+            .len()
+    });
+    let faces = scene.faces.iter().map(|(face, _)| {
+        rasterize_face_into_triangle_list_with_tolerance(face, Color::white(), tolerance)
+            .triangles
+            .len()
+    });
+    stats.triangles = Some(volumes.chain(faces).sum());
+    stats
+}
@@ -0,0 +1,37 @@
+use geop_geometry::points::point::Point;
+
+// Limits on how far a tessellation may deviate from the exact geometry. Both have to hold, so curves and surfaces with a small radius
+// get shorter segments and smaller triangles than flat ones.
+#[derive(Debug, Clone, Copy)]
+pub struct Tolerance {
+    // Maximum distance between a segment and the exact curve or surface, measured halfway along the segment.
+    pub chordal: f64,
+    // Maximum angle in radians between the tangents or normals at both ends of a segment.
+    pub angular: f64,
+}
+
+impl Tolerance {
+    pub fn new(chordal: f64, angular: f64) -> Tolerance {
+        assert!(chordal > 0.0, "The chordal tolerance has to be positive");
+        assert!(angular > 0.0, "The angular tolerance has to be positive");
+        Tolerance { chordal, angular }
+    }
+
+    // Whether the segment from start to end is fine enough. exact_mid is the point on the curve or surface halfway between the ends,
+    // and the directions are the tangents or normals at the ends.
+    pub fn accepts(
+        &self,
+        start: Point,
+        end: Point,
+        exact_mid: Point,
+        start_direction: Point,
+        end_direction: Point,
+    ) -> bool {
+        if (exact_mid - (start + end) / 2.0).norm() > self.chordal {
+            return false;
+        }
+        let cos =
+            start_direction.dot(end_direction) / (start_direction.norm() * end_direction.norm());
+        cos.clamp(-1.0, 1.0).acos() <= self.angular
+    }
+}
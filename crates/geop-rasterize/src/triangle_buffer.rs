@@ -1,4 +1,7 @@
-use geop_geometry::points::point::Point;
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
 use geop_topology::topology::scene::Color;
 
 use crate::{
@@ -31,6 +34,24 @@ impl RenderTriangle {
             c: RenderNormalVertex::new(c, color, normal_c),
         }
     }
+
+    // A triangle on the surface, with the exact normals and surface parameters at its corners. On periodic surfaces, u is unwrapped
+    // to be within half a period of u at a, so that triangles across the seam do not stretch over the whole texture.
+    pub fn on_surface(surface: &Surface, a: Point, b: Point, c: Point, color: Color) -> Self {
+        let uv_a = surface.parameters(a);
+        let vertex = |p: Point| {
+            let mut uv = surface.parameters(p);
+            if let Some(period) = surface.u_period() {
+                uv.x += ((uv_a.x - uv.x) / period).round() * period;
+            }
+            RenderNormalVertex::new(p, color, surface.normal(p)).with_uv(uv)
+        };
+        RenderTriangle {
+            a: vertex(a),
+            b: vertex(b),
+            c: vertex(c),
+        }
+    }
 }
 
 pub struct TriangleBuffer {
@@ -67,3 +88,47 @@ impl TriangleBuffer {
         EdgeBuffer::new(edges)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use geop_geometry::surfaces::cylinder::Cylinder;
+
+    use super::*;
+
+    #[test]
+    fn test_triangle_on_surface_uv() {
+        let surface = Surface::Cylinder(Cylinder::new(Point::zero(), Point::unit_z(), 1.0, true));
+        let at = |u: f64, v: f64| surface.point_at(Point::new(u, v, 0.0));
+
+        let triangle = RenderTriangle::on_surface(
+            &surface,
+            at(0.5, 0.0),
+            at(1.0, 0.0),
+            at(0.5, 1.0),
+            Color::white(),
+        );
+        assert!((triangle.b.uv[0] - 1.0).abs() < 1e-6);
+        assert!((triangle.c.uv[1] - 1.0).abs() < 1e-6);
+        assert!(
+            (triangle.a.normal[0] as f64 - 0.5_f64.cos()).abs() < 1e-6,
+            "Expected the exact normal of the cylinder"
+        );
+
+        // A triangle across the seam keeps u within half a period of its first corner.
+        let triangle = RenderTriangle::on_surface(
+            &surface,
+            at(2.0 * PI - 0.1, 0.0),
+            at(0.1, 0.0),
+            at(2.0 * PI - 0.1, 1.0),
+            Color::white(),
+        );
+        let du = triangle.b.uv[0] - triangle.a.uv[0];
+        assert!(
+            (du as f64 - 0.2).abs() < 1e-5,
+            "Expected u to be unwrapped, got {}",
+            du
+        );
+    }
+}
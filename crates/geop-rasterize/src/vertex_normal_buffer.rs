@@ -8,6 +8,8 @@ pub struct RenderNormalVertex {
     pub position: [f32; 3],
     pub color: [f32; 4],
     pub normal: [f32; 3],
+    // Surface parameters of the vertex, for texture mapping.
+    pub uv: [f32; 2],
 }
 
 impl RenderNormalVertex {
@@ -21,9 +23,15 @@ impl RenderNormalVertex {
                 color.a as f32,
             ],
             normal: [normal.x as f32, normal.y as f32, normal.z as f32],
+            uv: [0.0, 0.0],
         }
     }
 
+    pub fn with_uv(mut self, uv: Point) -> Self {
+        self.uv = [uv.x as f32, uv.y as f32];
+        self
+    }
+
     pub fn point(&self) -> Point {
         Point::new(
             self.position[0] as f64,
@@ -1,29 +1,105 @@
-use geop_topology::topology::{scene::Color, volume::Volume};
+use geop_topology::{
+    progress::{Cancelled, Progress},
+    topology::{
+        binary::{face_from_bytes, face_to_bytes},
+        face::Face,
+        scene::Color,
+        volume::Volume,
+    },
+};
+use rayon::prelude::*;
 
 use crate::{
     edge_buffer::EdgeBuffer,
-    face::{rasterize_face_into_line_list, rasterize_face_into_triangle_list},
+    face::{
+        rasterize_face_into_line_list, rasterize_face_into_triangle_list,
+        rasterize_face_into_triangle_list_with_tolerance,
+    },
+    tolerance::Tolerance,
     triangle_buffer::TriangleBuffer,
     vertex_buffer::{RenderVertex, VertexBuffer},
 };
 
+// Tessellates the faces one at a time, so that large volumes can be displayed progressively and only one face has to be held in memory.
+// The faces of the boundary come first, then the faces of the cavities.
+pub fn rasterize_volume_face_by_face(
+    volume: &Volume,
+    color: Color,
+) -> impl Iterator<Item = (Face, TriangleBuffer)> + '_ {
+    volume
+        .boundary
+        .faces
+        .iter()
+        .chain(volume.cavities.iter().flat_map(|c| c.faces.iter()))
+        .map(move |face| (face.clone(), rasterize_face_into_triangle_list(face, color)))
+}
+
+// Calls the callback with the index and the triangles of every face as soon as the face is tessellated.
+pub fn rasterize_volume_streaming(
+    volume: &Volume,
+    color: Color,
+    mut callback: impl FnMut(usize, &Face, &TriangleBuffer),
+) {
+    for (i, (face, buffer)) in rasterize_volume_face_by_face(volume, color).enumerate() {
+        callback(i, &face, &buffer);
+    }
+}
+
 pub fn rasterize_volume_into_triangle_list(volume: &Volume, color: Color) -> TriangleBuffer {
     let mut buffer = TriangleBuffer::empty();
+    rasterize_volume_streaming(volume, color, |_, _, face_buffer| buffer.join(face_buffer));
+    buffer
+}
 
-    for face in volume.boundary.faces.iter() {
-        println!("Rasterizing face: {}", face);
-        let face_buffer = rasterize_face_into_triangle_list(face, color);
+// Like rasterize_volume_into_triangle_list, reporting the progress after every face and stopping early when it is cancelled.
+pub fn rasterize_volume_into_triangle_list_with_progress(
+    volume: &Volume,
+    color: Color,
+    progress: &Progress,
+) -> Result<TriangleBuffer, Cancelled> {
+    let total = volume.all_faces().len();
+    let mut buffer = TriangleBuffer::empty();
+    for (i, (_, face_buffer)) in rasterize_volume_face_by_face(volume, color).enumerate() {
         buffer.join(&face_buffer);
+        progress.report_step(0.0, 1.0, i + 1, total)?;
     }
+    Ok(buffer)
+}
 
-    for hole in volume.cavities.iter() {
-        for face in hole.faces.iter() {
-            println!("Rasterizing face: {}", face);
-            let face_buffer = rasterize_face_into_triangle_list(face, color);
-            buffer.join(&face_buffer);
-        }
+pub fn rasterize_volume_into_triangle_list_with_tolerance(
+    volume: &Volume,
+    color: Color,
+    tolerance: &Tolerance,
+) -> TriangleBuffer {
+    let mut buffer = TriangleBuffer::empty();
+    for face in volume.all_faces().iter() {
+        buffer.join(&rasterize_face_into_triangle_list_with_tolerance(
+            face, color, tolerance,
+        ));
     }
+    buffer
+}
+
+// Tessellates the faces on all cores. Faces share their surfaces through Rc, which cannot be sent to other threads, so every face
+// is handed to its worker in the binary format and rebuilt there. The triangles come in the same order as those of
+// rasterize_volume_into_triangle_list, no matter which face finishes first.
+pub fn rasterize_volume_into_triangle_list_parallel(
+    volume: &Volume,
+    color: Color,
+) -> TriangleBuffer {
+    let faces: Vec<Vec<u8>> = volume.all_faces().iter().map(face_to_bytes).collect();
+    let buffers: Vec<TriangleBuffer> = faces
+        .par_iter()
+        .map(|bytes| {
+            let face = face_from_bytes(bytes).expect("Faces survive the round trip");
+            rasterize_face_into_triangle_list(&face, color)
+        })
+        .collect();
 
+    let mut buffer = TriangleBuffer::empty();
+    for face_buffer in buffers.iter() {
+        buffer.join(face_buffer);
+    }
     buffer
 }
 
@@ -56,3 +132,132 @@ pub fn rasterize_volume_into_vertex_list(volume: &Volume, color: Color) -> Verte
 
     buffer
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use geop_geometry::{
+        points::point::Point,
+        surfaces::{plane::Plane, surface::Surface},
+    };
+    use geop_topology::{
+        operations::extrude::extrude,
+        primitive_objects::{edges::circle::primitive_circle, volumes::cube::primitive_cube},
+        progress::CancellationToken,
+        topology::contour::Contour,
+    };
+
+    use super::*;
+
+    fn positions(buffer: &TriangleBuffer) -> Vec<[f32; 3]> {
+        buffer
+            .triangles
+            .iter()
+            .flat_map(|t| [t.a.position, t.b.position, t.c.position])
+            .collect()
+    }
+
+    // A cylinder of radius 0.5 around the z axis, from z = 0 to z = 2.
+    fn cylinder() -> Volume {
+        let disk = Face::new(
+            Some(Contour::new(vec![primitive_circle(
+                Point::zero(),
+                -Point::unit_z(),
+                0.5,
+            )])),
+            vec![],
+            Rc::new(Surface::Plane(Plane::new(
+                Point::zero(),
+                Point::unit_y(),
+                Point::unit_x(),
+            ))),
+        );
+        extrude(disk, Point::new(0.0, 0.0, 2.0))
+    }
+
+    #[test]
+    fn test_rasterize_volume_streaming() {
+        let cube = primitive_cube(1.0, 1.0, 1.0);
+        let mut indices = Vec::<usize>::new();
+        let mut streamed = TriangleBuffer::empty();
+        rasterize_volume_streaming(&cube, Color::white(), |i, face, buffer| {
+            assert!(
+                !buffer.triangles.is_empty(),
+                "Expected triangles for face {}",
+                face
+            );
+            indices.push(i);
+            streamed.join(buffer);
+        });
+        assert_eq!(indices, (0..6).collect::<Vec<usize>>());
+        let list = rasterize_volume_into_triangle_list(&cube, Color::white());
+        assert_eq!(positions(&streamed), positions(&list));
+
+        // The progress is reported after every face, and a cancelled token stops before the first face is done.
+        let reports = RefCell::new(Vec::<f64>::new());
+        let callback = |fraction: f64| reports.borrow_mut().push(fraction);
+        let progress = Progress::with_callback(&callback);
+        let buffer =
+            rasterize_volume_into_triangle_list_with_progress(&cube, Color::white(), &progress)
+                .unwrap();
+        assert_eq!(positions(&buffer), positions(&list));
+        assert_eq!(reports.borrow().len(), 6);
+        assert_eq!(reports.borrow().last(), Some(&1.0));
+        let token = CancellationToken::new();
+        token.cancel();
+        let progress = Progress::with_token(&token);
+        assert!(rasterize_volume_into_triangle_list_with_progress(
+            &cube,
+            Color::white(),
+            &progress
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_rasterize_volume_parallel() {
+        let volume = primitive_cube(2.0, 2.0, 2.0).with_cavity(&primitive_cube(1.0, 1.0, 1.0));
+        for volume in [volume, cylinder()] {
+            let serial = rasterize_volume_into_triangle_list(&volume, Color::white());
+            let parallel = rasterize_volume_into_triangle_list_parallel(&volume, Color::white());
+            assert_eq!(
+                serial.to_u8_slice(),
+                parallel.to_u8_slice(),
+                "Expected the same triangles in the same order"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rasterize_volume_with_tolerance() {
+        let volume = cylinder();
+        let coarse = rasterize_volume_into_triangle_list_with_tolerance(
+            &volume,
+            Color::white(),
+            &Tolerance::new(0.1, 1.0),
+        );
+        let fine = rasterize_volume_into_triangle_list_with_tolerance(
+            &volume,
+            Color::white(),
+            &Tolerance::new(0.001, 0.1),
+        );
+        assert!(
+            fine.triangles.len() > coarse.triangles.len(),
+            "Expected a tighter tolerance to give more triangles"
+        );
+        // The centers of the triangles on the side stay within the chordal tolerance of the cylinder.
+        for t in fine.triangles.iter() {
+            let center = (t.a.point() + t.b.point() + t.c.point()) / 3.0;
+            if center.z.abs() < 1e-6 || (center.z - 2.0).abs() < 1e-6 {
+                continue;
+            }
+            let radius = Point::new(center.x, center.y, 0.0).norm();
+            assert!(
+                (radius - 0.5).abs() < 0.001 + 1e-5,
+                "Triangle center {:?} is off the cylinder",
+                center
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use geop_geometry::points::point::Point;
+use geop_topology::topology::scene::Color;
+
+use crate::{triangle_buffer::TriangleBuffer, vertex_normal_buffer::RenderNormalVertex};
+
+// Zebra stripes show how light stripes reflect off the surface. The view direction is mirrored at the vertex normal, and the angle
+// between the reflected direction and the stripe axis selects the stripe. Since this only depends on the normals, the stripes jump
+// where faces only touch (G0), kink where they are tangent (G1), and run smoothly across edges with matching curvature (G2).
+
+// The stripe phase at a vertex with the given normal, in [0, 1). Phases below 0.5 are light stripes, the others dark ones.
+// The number of stripes is the number of light and dark pairs between the directions along and against the stripe axis.
+pub fn zebra_phase(
+    normal: Point,
+    view_direction: Point,
+    stripe_axis: Point,
+    stripes: usize,
+) -> f64 {
+    let normal = normal.normalize();
+    let view = view_direction.normalize();
+    let reflected = view - normal * (2.0 * view.dot(normal));
+    let angle = reflected
+        .dot(stripe_axis.normalize())
+        .clamp(-1.0, 1.0)
+        .acos();
+    (angle / std::f64::consts::PI * stripes as f64).fract()
+}
+
+fn vertex_phase(
+    vertex: &RenderNormalVertex,
+    view_direction: Point,
+    stripe_axis: Point,
+    stripes: usize,
+) -> f64 {
+    let normal = Point::new(
+        vertex.normal[0] as f64,
+        vertex.normal[1] as f64,
+        vertex.normal[2] as f64,
+    );
+    zebra_phase(normal, view_direction, stripe_axis, stripes)
+}
+
+// The stripe phase of every vertex of the tessellation, three per triangle.
+pub fn zebra_phases(
+    buffer: &TriangleBuffer,
+    view_direction: Point,
+    stripe_axis: Point,
+    stripes: usize,
+) -> Vec<f64> {
+    buffer
+        .triangles
+        .iter()
+        .flat_map(|t| [t.a, t.b, t.c])
+        .map(|v| vertex_phase(&v, view_direction, stripe_axis, stripes))
+        .collect()
+}
+
+// Colors the vertices of the tessellation with light and dark zebra stripes. Finer tessellations give sharper stripes.
+pub fn zebra_triangle_list(
+    buffer: &TriangleBuffer,
+    view_direction: Point,
+    stripe_axis: Point,
+    stripes: usize,
+    light: Color,
+    dark: Color,
+) -> TriangleBuffer {
+    let color = |vertex: &RenderNormalVertex| {
+        let c = match vertex_phase(vertex, view_direction, stripe_axis, stripes) < 0.5 {
+            true => light,
+            false => dark,
+        };
+        [c.r, c.g, c.b, c.a]
+    };
+    let mut triangles = buffer.triangles.clone();
+    for triangle in triangles.iter_mut() {
+        for vertex in [&mut triangle.a, &mut triangle.b, &mut triangle.c] {
+            vertex.color = color(vertex);
+        }
+    }
+    TriangleBuffer::new(triangles)
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_topology::primitive_objects::volumes::cube::primitive_cube;
+
+    use crate::volume::rasterize_volume_into_triangle_list;
+
+    use super::*;
+
+    #[test]
+    fn test_zebra() {
+        // Looking straight down onto a flat face reflects the view straight up, perpendicular to the stripe axis.
+        let down = -Point::unit_z();
+        assert!((zebra_phase(Point::unit_z(), down, Point::unit_x(), 1) - 0.5).abs() < 1e-9);
+        assert!(zebra_phase(Point::unit_z(), down, Point::unit_x(), 4) < 1e-9);
+        // Viewed at 45 degrees the reflection is a quarter turn away from the axis.
+        let oblique = Point::new(1.0, 0.0, -1.0);
+        assert!((zebra_phase(Point::unit_z(), oblique, Point::unit_x(), 2) - 0.5).abs() < 1e-9);
+        // The phase only depends on the direction of the normal.
+        assert!(
+            (zebra_phase(Point::unit_z() * 3.0, oblique, Point::unit_x(), 3)
+                - zebra_phase(Point::unit_z(), oblique, Point::unit_x(), 3))
+            .abs()
+                < 1e-9
+        );
+
+        let buffer =
+            rasterize_volume_into_triangle_list(&primitive_cube(1.0, 1.0, 1.0), Color::white());
+        let view = Point::new(1.0, 2.0, -3.0);
+        let phases = zebra_phases(&buffer, view, Point::unit_x(), 3);
+        assert_eq!(phases.len(), 3 * buffer.triangles.len());
+        assert!(phases.iter().all(|p| (0.0..1.0).contains(p)));
+
+        let light = Color::white();
+        let dark = Color::black();
+        let striped = zebra_triangle_list(&buffer, view, Point::unit_x(), 3, light, dark);
+        assert_eq!(striped.triangles.len(), buffer.triangles.len());
+        let vertices = striped.triangles.iter().flat_map(|t| [t.a, t.b, t.c]);
+        for (vertex, phase) in vertices.zip(phases) {
+            let expected = match phase < 0.5 {
+                true => light,
+                false => dark,
+            };
+            assert_eq!(
+                vertex.color,
+                [expected.r, expected.g, expected.b, expected.a]
+            );
+        }
+        // Vertices with the same normal get the same stripe.
+        assert!(striped
+            .triangles
+            .iter()
+            .all(|t| t.a.normal != t.b.normal || t.a.color == t.b.color));
+    }
+}
@@ -0,0 +1,59 @@
+use geop_geometry::points::point::Point;
+use geop_topology::{
+    operations::extrude::extrude,
+    primitive_objects::{edges::circle::primitive_circle, faces::rectangle::primitive_rectangle},
+    rng::Rng,
+    topology::{contour::Contour, face::Face, volume::Volume},
+};
+
+// A plate standing on the xy plane with up to four round holes through it. Every hole lies in its own slice of the plate along
+// x, so the holes never touch each other or the sides.
+pub fn box_with_holes(seed: u64) -> Volume {
+    let mut rng = Rng::new(seed);
+    let (width, depth, height) = (
+        rng.range(1.0, 3.0),
+        rng.range(1.0, 3.0),
+        rng.range(0.2, 1.0),
+    );
+    let count = rng.range_usize(0, 4);
+
+    // The bottom face points down, so its holes run counterclockwise around z.
+    let bottom = primitive_rectangle(
+        Point::zero(),
+        Point::unit_x() * (width / 2.0),
+        -Point::unit_y() * (depth / 2.0),
+    );
+    let slice = width / count.max(1) as f64;
+    let holes = (0..count)
+        .map(|i| {
+            let radius = slice.min(depth) / 2.0 * rng.range(0.3, 0.8);
+            let room_x = slice / 2.0 - radius;
+            let room_y = depth / 2.0 - radius;
+            let center = Point::new(
+                -width / 2.0 + (i as f64 + 0.5) * slice + rng.range(-0.5, 0.5) * room_x,
+                rng.range(-0.5, 0.5) * room_y,
+                0.0,
+            );
+            Contour::new(vec![primitive_circle(center, Point::unit_z(), radius)])
+        })
+        .collect();
+    let bottom = Face::new(bottom.boundary, holes, bottom.surface);
+    extrude(bottom, Point::unit_z() * height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_with_holes() {
+        for seed in 0..20 {
+            let volume = box_with_holes(seed);
+            assert!(Volume::try_new(volume.boundary.clone(), vec![]).is_ok());
+            // The bottom face comes after the side faces.
+            let faces = volume.all_faces();
+            assert_eq!(faces.len(), 6 + faces[faces.len() - 2].holes.len());
+            assert_eq!(faces.len(), box_with_holes(seed).all_faces().len());
+        }
+    }
+}
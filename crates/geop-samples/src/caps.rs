@@ -0,0 +1,79 @@
+use std::{f64::consts::PI, rc::Rc};
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{sphere::Sphere, surface::Surface},
+};
+use geop_topology::{
+    primitive_objects::edges::circle::primitive_circle,
+    rng::Rng,
+    topology::{contour::Contour, face::Face},
+};
+
+// The part of a sphere around one of its poles, cut off by a circle of latitude. The sphere, the direction of the pole and the size
+// of the cap, from a small dome to nearly the whole sphere, are random.
+pub fn spherical_cap(seed: u64) -> Face {
+    let mut rng = Rng::new(seed);
+    let center = Point::new(
+        rng.range(-1.0, 1.0),
+        rng.range(-1.0, 1.0),
+        rng.range(-1.0, 1.0),
+    );
+    let radius = rng.range(0.5, 2.0);
+    let pole = loop {
+        let p = Point::new(
+            rng.range(-1.0, 1.0),
+            rng.range(-1.0, 1.0),
+            rng.range(-1.0, 1.0),
+        );
+        if p.norm() > 0.1 && p.norm() <= 1.0 {
+            break p.normalize();
+        }
+    };
+    // Angle between the pole and the rim, seen from the center.
+    let angle = rng.range(0.1, 0.9) * PI;
+
+    // The rim runs counterclockwise around the outward normals of the cap.
+    let rim = primitive_circle(
+        center + pole * (radius * angle.cos()),
+        pole,
+        radius * angle.sin(),
+    );
+    Face::new(
+        Some(Contour::new(vec![rim])),
+        vec![],
+        Rc::new(Surface::Sphere(Sphere::new(center, radius, true))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_topology::contains::face_point::{face_point_contains, FacePointContains};
+
+    use super::*;
+
+    #[test]
+    fn test_spherical_cap() {
+        for seed in 0..20 {
+            let cap = spherical_cap(seed);
+            assert_eq!(cap.all_edges(), spherical_cap(seed).all_edges());
+            // The pole is on the cap and the opposite pole is not.
+            let sphere = match &*cap.surface {
+                Surface::Sphere(sphere) => sphere.clone(),
+                _ => unreachable!(),
+            };
+            let rim = cap.all_edges()[0].clone();
+            let pole = match &rim.curve {
+                geop_geometry::curves::curve::Curve::Circle(circle) => circle.normal,
+                _ => unreachable!(),
+            };
+            let top = sphere.basis + pole * sphere.radius;
+            let bottom = sphere.basis - pole * sphere.radius;
+            assert_eq!(face_point_contains(&cap, top), FacePointContains::Inside);
+            assert_eq!(
+                face_point_contains(&cap, bottom),
+                FacePointContains::Outside
+            );
+        }
+    }
+}
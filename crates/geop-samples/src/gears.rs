@@ -0,0 +1,83 @@
+use std::{f64::consts::PI, rc::Rc};
+
+use geop_geometry::{
+    curves::{circle::Circle, curve::Curve},
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+};
+use geop_topology::{
+    primitive_objects::edges::{circle::primitive_circle, line::primitive_line},
+    rng::Rng,
+    topology::{contour::Contour, edge::Edge, face::Face},
+};
+
+// A face on the xy plane with the normal along z that looks like a gear: a ring of trapezoid teeth on arcs of the root circle,
+// and a bore in about half of the samples. It has many short edges of both lines and arcs, so it is a good stress test for
+// extrusion, booleans and tessellation.
+pub fn gear_like_face(seed: u64) -> Face {
+    let mut rng = Rng::new(seed);
+    let teeth = rng.range_usize(8, 24);
+    let root_radius = rng.range(1.0, 2.0);
+    let tip_radius = root_radius * rng.range(1.1, 1.3);
+    let pitch = 2.0 * PI / teeth as f64;
+    // Fractions of the pitch taken by the flanks and the top land. The rest is on the root circle.
+    let flank = rng.range(0.1, 0.2);
+    let land = rng.range(0.1, 0.3);
+
+    let at = |radius: f64, angle: f64| Point::new(radius * angle.cos(), radius * angle.sin(), 0.0);
+    let root = Circle::new(Point::zero(), Point::unit_z(), root_radius);
+    let mut edges = Vec::<Edge>::new();
+    for i in 0..teeth {
+        let start = i as f64 * pitch;
+        let corners = [
+            at(root_radius, start),
+            at(tip_radius, start + flank * pitch),
+            at(tip_radius, start + (flank + land) * pitch),
+            at(root_radius, start + (2.0 * flank + land) * pitch),
+        ];
+        for j in 0..3 {
+            edges.push(primitive_line(corners[j], corners[j + 1]));
+        }
+        edges.push(Edge::new(
+            Some(corners[3]),
+            Some(at(root_radius, start + pitch)),
+            Curve::Circle(root.clone()),
+        ));
+    }
+
+    let holes = match rng.next_f64() < 0.5 {
+        true => vec![Contour::new(vec![primitive_circle(
+            Point::zero(),
+            -Point::unit_z(),
+            root_radius * rng.range(0.2, 0.5),
+        )])],
+        false => vec![],
+    };
+    Face::new(
+        Some(Contour::new(edges)),
+        holes,
+        Rc::new(Surface::Plane(Plane::new(
+            Point::zero(),
+            Point::unit_x(),
+            Point::unit_y(),
+        ))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::surfaces::SurfaceLike;
+
+    use super::*;
+
+    #[test]
+    fn test_gear_like_face() {
+        for seed in 0..20 {
+            let face = gear_like_face(seed);
+            assert_eq!(face.all_edges(), gear_like_face(seed).all_edges());
+            assert_eq!(face.surface.normal(Point::zero()), Point::unit_z());
+            assert_eq!(face.boundary.as_ref().unwrap().edges.len() % 4, 0);
+        }
+        assert_ne!(gear_like_face(1).all_edges(), gear_like_face(2).all_edges());
+    }
+}
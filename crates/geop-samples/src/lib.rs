@@ -0,0 +1,3 @@
+pub mod boxes;
+pub mod caps;
+pub mod gears;
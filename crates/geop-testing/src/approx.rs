@@ -0,0 +1,152 @@
+use geop_geometry::surfaces::surface::Surface;
+use geop_topology::{
+    analysis::duplicates::{find_duplicate_faces, find_duplicate_faces_between},
+    measure::{
+        estimate::estimate_volume,
+        mass::{measure_area, measure_volume},
+    },
+    topology::{bounded::Bounded, face::Face, volume::Volume},
+};
+
+// Samples of the Monte Carlo estimates of curved volumes, and the seed, so that the assertions are reproducible.
+const ESTIMATE_SAMPLES: usize = 100_000;
+const ESTIMATE_SEED: u64 = 1;
+
+// Faces and volumes are compared by their shape and not by how they are built. Two faces are equal if every sample of one is
+// within the tolerance of the other and vice versa and their normals agree, so it does not matter where the contours start,
+// in which order the holes are, or whether an edge is split into several. On top of that, areas and volumes have to agree
+// within the tolerance times the perimeter or the surface area, to catch missing holes and faces that the samples miss.
+
+fn perimeter(face: &Face) -> f64 {
+    face.all_edges()
+        .iter()
+        .map(|e| {
+            e.length()
+                .expect("Faces with infinite edges cannot be compared")
+        })
+        .sum()
+}
+
+// Describes how the faces differ, or returns None if they are equal within the tolerance.
+pub fn face_difference(a: &Face, b: &Face, tolerance: f64) -> Option<String> {
+    let duplicates = find_duplicate_faces(&[a.clone(), b.clone()], tolerance);
+    match duplicates.first() {
+        None => return Some("The faces cover different regions".to_string()),
+        Some(duplicate) if duplicate.opposite => {
+            return Some("The faces point in opposite directions".to_string())
+        }
+        _ => {}
+    }
+    if let (Surface::Plane(_), Surface::Plane(_)) = (&*a.surface, &*b.surface) {
+        let (area_a, area_b) = (measure_area(a), measure_area(b));
+        if (area_a - area_b).abs() > tolerance * perimeter(a).max(perimeter(b)) {
+            return Some(format!("The areas differ: {} and {}", area_a, area_b));
+        }
+    }
+    None
+}
+
+// Volume of a volume with planar faces, or an estimate with its standard error for curved ones.
+fn volume_of(volume: &Volume) -> (f64, f64) {
+    match volume
+        .all_faces()
+        .iter()
+        .all(|f| matches!(&*f.surface, Surface::Plane(_)))
+    {
+        true => (measure_volume(volume), 0.0),
+        false => {
+            let estimate = estimate_volume(volume, 0.0, ESTIMATE_SAMPLES, ESTIMATE_SEED);
+            (estimate.value, estimate.standard_error)
+        }
+    }
+}
+
+// Describes how the volumes differ, or returns None if they are equal within the tolerance. Every face of one volume has to match a
+// face of the other with the same orientation. Faces that are split differently do not match, so the volumes have to be built
+// from the same faces, e.g. by a boolean and by hand.
+pub fn volume_difference(a: &Volume, b: &Volume, tolerance: f64) -> Option<String> {
+    let (faces_a, faces_b) = (a.all_faces(), b.all_faces());
+    if faces_a.len() != faces_b.len() {
+        return Some(format!(
+            "The volumes have {} and {} faces",
+            faces_a.len(),
+            faces_b.len()
+        ));
+    }
+    let matches: Vec<(usize, usize)> = find_duplicate_faces_between(a, b, tolerance)
+        .into_iter()
+        .filter(|d| !d.opposite)
+        .map(|d| (d.first, d.second))
+        .collect();
+    if let Some(i) = (0..faces_a.len()).find(|i| !matches.iter().any(|m| m.0 == *i)) {
+        return Some(format!(
+            "Face {} of the first volume has no match",
+            faces_a[i]
+        ));
+    }
+    if let Some(j) = (0..faces_b.len()).find(|j| !matches.iter().any(|m| m.1 == *j)) {
+        return Some(format!(
+            "Face {} of the second volume has no match",
+            faces_b[j]
+        ));
+    }
+    let ((volume_a, error_a), (volume_b, error_b)) = (volume_of(a), volume_of(b));
+    let size = a.bounding_box().max - a.bounding_box().min;
+    let surface_area = 2.0 * (size.x * size.y + size.y * size.z + size.z * size.x);
+    if (volume_a - volume_b).abs() > tolerance * surface_area + 4.0 * (error_a + error_b) {
+        return Some(format!("The volumes differ: {} and {}", volume_a, volume_b));
+    }
+    None
+}
+
+#[track_caller]
+pub fn assert_face_approx_eq(a: &Face, b: &Face, tolerance: f64) {
+    if let Some(difference) = face_difference(a, b, tolerance) {
+        panic!(
+            "Faces are not equal within {}: {}\n{}\n{}",
+            tolerance, difference, a, b
+        );
+    }
+}
+
+#[track_caller]
+pub fn assert_volume_approx_eq(a: &Volume, b: &Volume, tolerance: f64) {
+    if let Some(difference) = volume_difference(a, b, tolerance) {
+        panic!("Volumes are not equal within {}: {}", tolerance, difference);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform};
+    use geop_topology::{
+        primitive_objects::{faces::rectangle::primitive_rectangle, volumes::cube::primitive_cube},
+        topology::{contour::Contour, shell::Shell},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_approx_eq() {
+        // The same rectangle with the contour starting at another corner.
+        let face = primitive_rectangle(Point::zero(), Point::unit_x(), Point::unit_y());
+        let mut edges = face.boundary.clone().unwrap().edges;
+        edges.rotate_left(1);
+        let rotated = Face::new(Some(Contour::new(edges)), vec![], face.surface.clone());
+        assert_face_approx_eq(&face, &rotated, 1e-6);
+        let shifted = face.transform(Transform::from_translation(Point::new(1e-3, 0.0, 0.0)));
+        assert_face_approx_eq(&face, &shifted, 1e-2);
+        assert!(face_difference(&face, &shifted, 1e-4).is_some());
+        assert!(face_difference(&face, &face.flip(), 1e-6).is_some());
+
+        // Volumes with the faces in another order are equal.
+        let cube = primitive_cube(1.0, 2.0, 3.0);
+        let mut faces = cube.boundary.faces.clone();
+        faces.reverse();
+        let reordered = Volume::new(Shell::new(faces), vec![]);
+        assert_volume_approx_eq(&cube, &reordered, 1e-6);
+        let larger = primitive_cube(1.0, 2.0, 3.01);
+        assert!(volume_difference(&cube, &larger, 1e-3).is_some());
+        assert_volume_approx_eq(&cube, &larger, 1e-2);
+    }
+}
@@ -0,0 +1,138 @@
+use geop_geometry::{points::point::Point, surfaces::SurfaceLike, EQ_THRESHOLD};
+
+use crate::topology::{edge::Edge, face::Face};
+
+// Number of intervals between the samples along the shared edge.
+const SAMPLES: usize = 16;
+// Step along the surface for the finite difference of the normal curvature.
+const CURVATURE_STEP: f64 = 1e-3;
+// Tolerances for the classification. The curvature is estimated numerically, so it gets a looser tolerance.
+const ANGLE_TOLERANCE: f64 = 1e-6;
+const CURVATURE_TOLERANCE: f64 = 1e-4;
+
+// Geometric continuity of two faces along an edge, from weakest to strongest.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Continuity {
+    Discontinuous, // There is a gap between the faces
+    G0,            // The faces touch, but meet at an angle
+    G1,            // The faces are tangent, but their curvature jumps
+    G2,            // The curvature across the edge is the same on both sides
+}
+
+#[derive(Debug, Clone)]
+pub struct ContinuityReport {
+    pub continuity: Continuity,
+    // Maximum distance of the edge from the surfaces of the faces.
+    pub position_deviation: f64,
+    // Maximum angle between the normals of the faces in radians.
+    pub tangent_deviation: f64,
+    // Maximum difference of the normal curvatures of the faces across the edge.
+    pub curvature_deviation: f64,
+}
+
+// Normal curvature of the face in the direction, positive where the surface bends towards the normal of the face.
+fn normal_curvature(face: &Face, p: Point, direction: Point) -> f64 {
+    let q = face.surface.project(p + direction * CURVATURE_STEP);
+    let d = q - p;
+    2.0 * d.dot(face.normal(p)) / d.norm_sq()
+}
+
+// Checks how smoothly the faces meet along the shared edge, e.g. after stitching imported or lofted surfaces. The edge is sampled
+// at its ends and in between. The curvatures are compared in the direction across the edge.
+pub fn check_continuity(face_a: &Face, face_b: &Face, shared_edge: &Edge) -> ContinuityReport {
+    let mut position_deviation: f64 = 0.0;
+    let mut tangent_deviation: f64 = 0.0;
+    let mut curvature_deviation: f64 = 0.0;
+    for i in 0..=SAMPLES {
+        let p = shared_edge.interpolate(i as f64 / SAMPLES as f64);
+        for face in [face_a, face_b] {
+            position_deviation = position_deviation.max((face.surface.project(p) - p).norm());
+        }
+        let (pa, pb) = (face_a.surface.project(p), face_b.surface.project(p));
+        let (na, nb) = (face_a.normal(pa), face_b.normal(pb));
+        let cos = na.dot(nb) / (na.norm() * nb.norm());
+        tangent_deviation = tangent_deviation.max(cos.clamp(-1.0, 1.0).acos());
+
+        let across = shared_edge.tangent(p).cross(na).normalize();
+        let ka = normal_curvature(face_a, pa, across);
+        let kb = normal_curvature(face_b, pb, across);
+        curvature_deviation = curvature_deviation.max((ka - kb).abs());
+    }
+    let continuity = if position_deviation > EQ_THRESHOLD {
+        Continuity::Discontinuous
+    } else if tangent_deviation > ANGLE_TOLERANCE {
+        Continuity::G0
+    } else if curvature_deviation > CURVATURE_TOLERANCE {
+        Continuity::G1
+    } else {
+        Continuity::G2
+    };
+    ContinuityReport {
+        continuity,
+        position_deviation,
+        tangent_deviation,
+        curvature_deviation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::PI, rc::Rc};
+
+    use geop_geometry::surfaces::{sphere::Sphere, surface::Surface};
+
+    use super::*;
+    use crate::primitive_objects::{
+        edges::{circle::primitive_circle, line::primitive_line},
+        faces::{cylinder::primitive_cylinder, rectangle::primitive_rectangle},
+        volumes::cube::primitive_cube,
+    };
+
+    #[test]
+    fn test_check_continuity() {
+        // Two faces of a cube meet at a right angle.
+        let cube = primitive_cube(1.0, 1.0, 1.0);
+        let faces = cube.all_faces();
+        let edge = faces[0]
+            .all_edges()
+            .into_iter()
+            .find(|e| faces[1..].iter().any(|f| f.all_edges().contains(e)))
+            .unwrap();
+        let other = faces[1..]
+            .iter()
+            .find(|f| f.all_edges().contains(&edge))
+            .unwrap();
+        let report = check_continuity(&faces[0], other, &edge);
+        assert_eq!(report.continuity, Continuity::G0);
+        assert!((report.tangent_deviation - PI / 2.0).abs() < 1e-9);
+
+        // Neighbouring rectangles in the same plane are as smooth as it gets.
+        let left = primitive_rectangle(Point::zero(), Point::unit_x(), Point::unit_y());
+        let right =
+            primitive_rectangle(Point::new(2.0, 0.0, 0.0), Point::unit_x(), Point::unit_y());
+        let edge = primitive_line(Point::new(1.0, -1.0, 0.0), Point::new(1.0, 1.0, 0.0));
+        assert_eq!(
+            check_continuity(&left, &right, &edge).continuity,
+            Continuity::G2
+        );
+
+        // A sphere is tangent to a cylinder around its equator, but curved across it, while the cylinder is straight.
+        let sphere = Face::new(
+            None,
+            vec![],
+            Rc::new(Surface::Sphere(Sphere::new(Point::zero(), 2.0, true))),
+        );
+        let cylinder = primitive_cylinder(Point::zero(), Point::unit_z(), 2.0);
+        let equator = primitive_circle(Point::zero(), Point::unit_z(), 2.0);
+        let report = check_continuity(&sphere, &cylinder, &equator);
+        assert_eq!(report.continuity, Continuity::G1);
+        assert!((report.curvature_deviation - 0.5).abs() < 1e-4);
+
+        // A wider cylinder does not touch the equator of the sphere.
+        let cylinder = primitive_cylinder(Point::zero(), Point::unit_z(), 2.1);
+        assert_eq!(
+            check_continuity(&sphere, &cylinder, &equator).continuity,
+            Continuity::Discontinuous
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use geop_geometry::{points::point::Point, EQ_THRESHOLD};
+
+use crate::{
+    primitive_objects::edges::line::primitive_line,
+    topology::{
+        edge::Edge,
+        scene::{Color, Scene},
+    },
+};
+
+// A curvature comb shows the curvature along a curve as spikes perpendicular to it, with a length proportional to the curvature.
+// The spikes point away from the center of curvature, and the line through their tips makes bumps and flat spots easy to see.
+// Inflections, where the curve changes from bending left to bending right, are where the comb crosses over to the other side.
+
+#[derive(Clone, Debug)]
+pub struct CurvatureComb {
+    // Base on the curve and tip of every spike.
+    pub spikes: Vec<(Point, Point)>,
+    // Signed curvature at the base of every spike, positive where the curve bends to the left around the normal.
+    pub curvatures: Vec<f64>,
+    pub inflections: Vec<Point>,
+}
+
+impl CurvatureComb {
+    // The spikes and the line through their tips in the comb color, the inflections as points in the inflection color.
+    pub fn to_scene(&self, comb_color: Color, inflection_color: Color) -> Scene {
+        let mut edges: Vec<(Edge, Color)> = self
+            .spikes
+            .iter()
+            .filter(|(base, tip)| (*tip - *base).norm() > EQ_THRESHOLD)
+            .map(|(base, tip)| (primitive_line(*base, *tip), comb_color))
+            .collect();
+        for pair in self.spikes.windows(2) {
+            if (pair[1].1 - pair[0].1).norm() > EQ_THRESHOLD {
+                edges.push((primitive_line(pair[0].1, pair[1].1), comb_color));
+            }
+        }
+        Scene::new(
+            vec![],
+            vec![],
+            edges,
+            self.inflections
+                .iter()
+                .map(|p| (*p, inflection_color))
+                .collect(),
+        )
+    }
+}
+
+// Curvature and direction towards the center of curvature from three nearby points on the edge, i.e. of the circle through them.
+fn local_curvature(edge: &Edge, t: f64, h: f64, normal: Point) -> (f64, Point) {
+    let t = t.clamp(h, 1.0 - h);
+    let (a, b, c) = (
+        edge.interpolate(t - h),
+        edge.interpolate(t),
+        edge.interpolate(t + h),
+    );
+    let cross = (b - a).cross(c - b);
+    let lengths = (b - a).norm() * (c - b).norm() * (c - a).norm();
+    if lengths < EQ_THRESHOLD || cross.norm() < EQ_THRESHOLD * lengths {
+        return (0.0, Point::zero());
+    }
+    let curvature = 2.0 * cross.norm() / lengths;
+    // In the plane of the three points, perpendicular to the chord, on the side of b.
+    let inward = cross.cross(c - a).normalize();
+    match cross.dot(normal) >= 0.0 {
+        true => (curvature, inward),
+        false => (-curvature, inward),
+    }
+}
+
+// Samples the curvature comb of a chain of edges, e.g. the edges of a contour, with samples_per_edge spikes on every edge. The
+// spike length is the curvature times the scale. The normal is the normal of the sketch plane, which decides the sign of the
+// curvature and thereby where the inflections are.
+pub fn curvature_comb(
+    edges: &[Edge],
+    normal: Point,
+    samples_per_edge: usize,
+    scale: f64,
+) -> CurvatureComb {
+    assert!(samples_per_edge >= 2);
+    // Step of the finite differences, small compared to the spacing of the spikes.
+    let h = 0.1 / samples_per_edge as f64;
+    let mut spikes = Vec::<(Point, Point)>::new();
+    let mut curvatures = Vec::<f64>::new();
+    let mut inflections = Vec::<Point>::new();
+    for edge in edges.iter() {
+        let mut last: Option<(f64, f64)> = None;
+        for i in 0..=samples_per_edge {
+            let t = i as f64 / samples_per_edge as f64;
+            let base = edge.interpolate(t);
+            let (curvature, inward) = local_curvature(edge, t, h, normal);
+            spikes.push((base, base - inward * (curvature.abs() * scale)));
+            curvatures.push(curvature);
+
+            // The curvature is linear between the samples for finding the zero crossing.
+            if let Some((last_t, last_curvature)) = last {
+                if last_curvature * curvature < 0.0 {
+                    let s = last_curvature / (last_curvature - curvature);
+                    inflections.push(edge.interpolate(last_t + s * (t - last_t)));
+                }
+            }
+            if curvature.abs() > EQ_THRESHOLD {
+                last = Some((t, curvature));
+            }
+        }
+    }
+    CurvatureComb {
+        spikes,
+        curvatures,
+        inflections,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::curves::{bezier::Bezier, curve::Curve};
+
+    use super::*;
+    use crate::primitive_objects::edges::circle::primitive_circle;
+
+    #[test]
+    fn test_curvature_comb() {
+        let circle = primitive_circle(Point::zero(), Point::unit_z(), 2.0);
+        let comb = curvature_comb(&[circle], Point::unit_z(), 16, 1.0);
+        assert_eq!(comb.spikes.len(), 17);
+        assert!(comb.inflections.is_empty());
+        for ((base, tip), curvature) in comb.spikes.iter().zip(comb.curvatures.iter()) {
+            assert!((curvature - 0.5).abs() < 1e-3);
+            assert!((tip.norm() - 2.5).abs() < 1e-3);
+            assert!((*tip - *base).dot(*base) > 0.0);
+        }
+
+        // A symmetric S-curve has its inflection in the middle.
+        let s = Edge::new(
+            Some(Point::zero()),
+            Some(Point::new(3.0, 0.0, 0.0)),
+            Curve::Bezier(Bezier::new(
+                Point::zero(),
+                Point::new(1.0, 1.0, 0.0),
+                Point::new(2.0, -1.0, 0.0),
+                Point::new(3.0, 0.0, 0.0),
+            )),
+        );
+        let comb = curvature_comb(&[s], Point::unit_z(), 9, 1.0);
+        assert_eq!(comb.inflections.len(), 1);
+        assert!((comb.inflections[0] - Point::new(1.5, 0.0, 0.0)).norm() < 1e-6);
+        assert!(comb.curvatures[0] < 0.0 && comb.curvatures[9] > 0.0);
+        let scene = comb.to_scene(Color::blue(), Color::red());
+        assert_eq!(scene.points.len(), 1);
+    }
+}
@@ -0,0 +1,169 @@
+use geop_geometry::{points::point::Point, surfaces::SurfaceLike};
+
+use crate::{
+    measure::distance::face_point_closest,
+    topology::{
+        face::{bounds::face_sample_points, Face},
+        volume::Volume,
+    },
+};
+
+// Two faces that cover the same region of the same surface within the tolerance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuplicateFace {
+    pub first: usize,
+    pub second: usize,
+    // Whether the faces point in opposite directions, like the touching faces of two volumes after a boolean.
+    pub opposite: bool,
+}
+
+// The samples of a face that have to be close to the other face, and a point inside of it to compare the normals.
+struct FaceSamples {
+    points: Vec<Point>,
+    inner: Point,
+}
+
+impl FaceSamples {
+    fn new(face: &Face) -> FaceSamples {
+        let inner = face.inner_point();
+        let mut points = face_sample_points(face);
+        points.push(inner);
+        FaceSamples { points, inner }
+    }
+}
+
+fn covers(face: &Face, samples: &FaceSamples, tolerance: f64) -> bool {
+    samples
+        .points
+        .iter()
+        .all(|p| (face_point_closest(face, *p) - *p).norm() <= tolerance)
+}
+
+// Returns whether the faces are duplicates, and if so, whether they point in opposite directions. Every sample of one face has to
+// be within the tolerance of the other face and vice versa, and both need the same number of contours, so that a hole is not missed.
+fn compare(
+    a: &Face,
+    samples_a: &FaceSamples,
+    b: &Face,
+    samples_b: &FaceSamples,
+    tolerance: f64,
+) -> Option<bool> {
+    if a.boundary.is_some() != b.boundary.is_some()
+        || a.holes.len() != b.holes.len()
+        || !covers(b, samples_a, tolerance)
+        || !covers(a, samples_b, tolerance)
+    {
+        return None;
+    }
+    let p = samples_a.inner;
+    Some(a.normal(p).dot(b.normal(b.surface.project(p))) < 0.0)
+}
+
+// Finds all pairs of faces in the set that are identical within the tolerance, e.g. faces that were imported twice. The first
+// index of every pair is the smaller one.
+pub fn find_duplicate_faces(faces: &[Face], tolerance: f64) -> Vec<DuplicateFace> {
+    let samples: Vec<FaceSamples> = faces.iter().map(FaceSamples::new).collect();
+    let mut duplicates = Vec::<DuplicateFace>::new();
+    for i in 0..faces.len() {
+        for j in i + 1..faces.len() {
+            if let Some(opposite) =
+                compare(&faces[i], &samples[i], &faces[j], &samples[j], tolerance)
+            {
+                duplicates.push(DuplicateFace {
+                    first: i,
+                    second: j,
+                    opposite,
+                });
+            }
+        }
+    }
+    duplicates
+}
+
+// Finds the faces of both volumes that are identical within the tolerance. The indices are into a.all_faces() and b.all_faces().
+pub fn find_duplicate_faces_between(a: &Volume, b: &Volume, tolerance: f64) -> Vec<DuplicateFace> {
+    let (faces_a, faces_b) = (a.all_faces(), b.all_faces());
+    let samples_a: Vec<FaceSamples> = faces_a.iter().map(FaceSamples::new).collect();
+    let samples_b: Vec<FaceSamples> = faces_b.iter().map(FaceSamples::new).collect();
+    let mut duplicates = Vec::<DuplicateFace>::new();
+    for i in 0..faces_a.len() {
+        for j in 0..faces_b.len() {
+            if let Some(opposite) = compare(
+                &faces_a[i],
+                &samples_a[i],
+                &faces_b[j],
+                &samples_b[j],
+                tolerance,
+            ) {
+                duplicates.push(DuplicateFace {
+                    first: i,
+                    second: j,
+                    opposite,
+                });
+            }
+        }
+    }
+    duplicates
+}
+
+// Removes faces that duplicate an earlier face with the same orientation. Duplicates with opposite orientation are kept, since
+// they usually separate two volumes.
+pub fn remove_duplicate_faces(faces: &[Face], tolerance: f64) -> Vec<Face> {
+    let duplicates = find_duplicate_faces(faces, tolerance);
+    (0..faces.len())
+        .filter(|i| !duplicates.iter().any(|d| d.second == *i && !d.opposite))
+        .map(|i| faces[i].clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::transforms::Transform;
+
+    use super::*;
+    use crate::primitive_objects::{
+        faces::rectangle::primitive_rectangle, volumes::cube::primitive_cube,
+    };
+
+    #[test]
+    fn test_find_duplicate_faces() {
+        let face = primitive_rectangle(Point::zero(), Point::unit_x(), Point::unit_y());
+        let shifted = face.transform(Transform::from_translation(Point::new(0.0, 0.0, 1e-4)));
+        let smaller = primitive_rectangle(Point::zero(), Point::unit_x() * 0.5, Point::unit_y());
+        let faces = vec![face.clone(), smaller, shifted, face.flip()];
+
+        let duplicates = find_duplicate_faces(&faces, 1e-3);
+        assert_eq!(
+            duplicates,
+            vec![
+                DuplicateFace {
+                    first: 0,
+                    second: 2,
+                    opposite: false
+                },
+                DuplicateFace {
+                    first: 0,
+                    second: 3,
+                    opposite: true
+                },
+                DuplicateFace {
+                    first: 2,
+                    second: 3,
+                    opposite: true
+                },
+            ]
+        );
+        assert_eq!(find_duplicate_faces(&faces, 1e-5).len(), 1);
+        assert_eq!(remove_duplicate_faces(&faces, 1e-3).len(), 3);
+
+        // Two cubes that touch along a face.
+        let a = primitive_cube(1.0, 1.0, 1.0);
+        let b = a.transform(Transform::from_translation(Point::unit_x()));
+        let duplicates = find_duplicate_faces_between(&a, &b, 1e-6);
+        assert_eq!(duplicates.len(), 1);
+        assert!(duplicates[0].opposite);
+        assert!(find_duplicate_faces_between(&a, &a, 1e-6)
+            .iter()
+            .all(|d| d.first == d.second && !d.opposite));
+    }
+}
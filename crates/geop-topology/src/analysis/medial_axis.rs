@@ -0,0 +1,276 @@
+use std::collections::{BTreeMap, HashMap};
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    primitive_objects::edges::line::primitive_line,
+    topology::{edge::Edge, face::Face},
+};
+
+// The medial axis of a planar face is the set of centers of the largest circles that fit into the face, i.e. circles that touch the
+// boundary at two or more points. It is approximated from the Voronoi diagram of points sampled on the boundary: the Voronoi
+// vertices are the circumcenters of a Delaunay triangulation of the samples, and every Delaunay edge between samples on different
+// parts of the boundary contributes the segment between the circumcenters of its two triangles.
+
+// Samples used to estimate the length of curved edges.
+const LENGTH_SAMPLES: usize = 64;
+
+#[derive(Debug, Clone, Copy)]
+pub struct MedialPoint {
+    pub point: Point,
+    // Radius of the largest circle around the point that fits into the face, i.e. half of the local width of the face.
+    pub radius: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MedialAxis {
+    pub segments: Vec<(MedialPoint, MedialPoint)>,
+}
+
+impl MedialAxis {
+    pub fn edges(&self) -> Vec<Edge> {
+        self.segments
+            .iter()
+            .map(|(a, b)| primitive_line(a.point, b.point))
+            .collect()
+    }
+}
+
+// A boundary sample in the coordinates of the plane, with its contour and arc length position on the contour.
+struct Sample {
+    x: f64,
+    y: f64,
+    contour: usize,
+    arc: f64,
+}
+
+struct Triangle {
+    vertices: [usize; 3],
+    center: (f64, f64),
+    radius_sq: f64,
+}
+
+impl Triangle {
+    fn new(vertices: [usize; 3], points: &[(f64, f64)]) -> Triangle {
+        let [(ax, ay), (bx, by), (cx, cy)] = vertices.map(|i| points[i]);
+        let d = 2.0 * (ax * (by - cy) + bx * (cy - ay) + cx * (ay - by));
+        if d.abs() < EQ_THRESHOLD * EQ_THRESHOLD {
+            // Degenerate triangles are replaced by the next point that is inserted.
+            return Triangle {
+                vertices,
+                center: (f64::NAN, f64::NAN),
+                radius_sq: f64::INFINITY,
+            };
+        }
+        let (a2, b2, c2) = (ax * ax + ay * ay, bx * bx + by * by, cx * cx + cy * cy);
+        let ux = (a2 * (by - cy) + b2 * (cy - ay) + c2 * (ay - by)) / d;
+        let uy = (a2 * (cx - bx) + b2 * (ax - cx) + c2 * (bx - ax)) / d;
+        Triangle {
+            vertices,
+            center: (ux, uy),
+            radius_sq: (ax - ux) * (ax - ux) + (ay - uy) * (ay - uy),
+        }
+    }
+
+    fn circumcircle_contains(&self, (x, y): (f64, f64)) -> bool {
+        if self.radius_sq.is_infinite() {
+            return true;
+        }
+        let (dx, dy) = (x - self.center.0, y - self.center.1);
+        dx * dx + dy * dy < self.radius_sq
+    }
+}
+
+// Delaunay triangulation by Bowyer-Watson. Returns the triangles, without the ones that use the corners of the super triangle.
+fn delaunay(points: &[(f64, f64)]) -> Vec<Triangle> {
+    let min_x = points.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+    let max_x = points.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+    let min_y = points.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let max_y = points.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+    let size = (max_x - min_x).max(max_y - min_y).max(1.0) * 100.0;
+    let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+    let n = points.len();
+    let mut all = points.to_vec();
+    all.push((mid_x - size, mid_y - size));
+    all.push((mid_x + size, mid_y - size));
+    all.push((mid_x, mid_y + size));
+    let mut triangles = vec![Triangle::new([n, n + 1, n + 2], &all)];
+
+    for i in 0..n {
+        let (bad, good): (Vec<Triangle>, Vec<Triangle>) = triangles
+            .into_iter()
+            .partition(|t| t.circumcircle_contains(all[i]));
+        // The edges of the cavity are the edges of bad triangles that are not shared with another bad triangle.
+        let mut count = HashMap::<(usize, usize), usize>::new();
+        for t in bad.iter() {
+            for k in 0..3 {
+                let (a, b) = (t.vertices[k], t.vertices[(k + 1) % 3]);
+                *count.entry((a.min(b), a.max(b))).or_default() += 1;
+            }
+        }
+        triangles = good;
+        for t in bad.iter() {
+            for k in 0..3 {
+                let (a, b) = (t.vertices[k], t.vertices[(k + 1) % 3]);
+                if count[&(a.min(b), a.max(b))] == 1 {
+                    triangles.push(Triangle::new([a, b, i], &all));
+                }
+            }
+        }
+    }
+    triangles.retain(|t| t.vertices.iter().all(|v| *v < n));
+    triangles
+}
+
+// Samples the contours of the face with about the given spacing, in the coordinates of its plane.
+fn boundary_samples(face: &Face, origin: Point, u: Point, v: Point, spacing: f64) -> Vec<Sample> {
+    let mut samples = Vec::<Sample>::new();
+    for (c, contour) in face.boundary.iter().chain(face.holes.iter()).enumerate() {
+        let mut arc = 0.0;
+        for edge in contour.edges.iter() {
+            let length = edge.length().unwrap_or_else(|| {
+                (0..LENGTH_SAMPLES)
+                    .map(|i| {
+                        let a = edge.interpolate(i as f64 / LENGTH_SAMPLES as f64);
+                        let b = edge.interpolate((i + 1) as f64 / LENGTH_SAMPLES as f64);
+                        (b - a).norm()
+                    })
+                    .sum()
+            });
+            let n = ((length / spacing).ceil() as usize).max(1);
+            for i in 0..n {
+                let p = edge.interpolate(i as f64 / n as f64) - origin;
+                samples.push(Sample {
+                    x: p.dot(u),
+                    y: p.dot(v),
+                    contour: c,
+                    arc: arc + length * i as f64 / n as f64,
+                });
+            }
+            arc += length;
+        }
+    }
+    samples
+}
+
+// Approximates the medial axis of a bounded planar face with holes. The boundary is sampled every spacing units, and branches
+// between samples closer than two spacings along the boundary are dropped, since they only resolve the sampling. The result gets
+// more accurate with smaller spacings, at quadratic cost.
+pub fn medial_axis(face: &Face, spacing: f64) -> MedialAxis {
+    assert!(spacing > 0.0);
+    let (origin, u) = match &*face.surface {
+        Surface::Plane(plane) => (plane.basis, plane.u_slope.normalize()),
+        _ => todo!("Medial axis of curved faces"),
+    };
+    let v = face.surface.normal(origin).cross(u);
+    let samples = boundary_samples(face, origin, u, v, spacing);
+    let contour_lengths: Vec<f64> = face
+        .boundary
+        .iter()
+        .chain(face.holes.iter())
+        .enumerate()
+        .map(|(c, _)| {
+            let last = samples.iter().rev().find(|s| s.contour == c).unwrap();
+            let first = samples.iter().find(|s| s.contour == c).unwrap();
+            last.arc + ((last.x - first.x).powi(2) + (last.y - first.y).powi(2)).sqrt()
+        })
+        .collect();
+    // Samples on circles and lines are cocircular or collinear, which makes the triangulation ambiguous and prone to rounding errors.
+    // A tiny deterministic jitter breaks these ties.
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let jitter = spacing * 1e-4;
+            // Successive samples are turned by the golden angle.
+            let angle = i as f64 * 2.399963;
+            (s.x + jitter * angle.cos(), s.y + jitter * angle.sin())
+        })
+        .collect();
+    let triangles = delaunay(&points);
+
+    let far_apart = |a: &Sample, b: &Sample| {
+        if a.contour != b.contour {
+            return true;
+        }
+        let d = (a.arc - b.arc).abs();
+        d.min(contour_lengths[a.contour] - d) > 2.0 * spacing + EQ_THRESHOLD
+    };
+    let to_medial_point = |t: &Triangle| {
+        let point = origin + u * t.center.0 + v * t.center.1;
+        match face_point_contains(face, point) {
+            FacePointContains::Inside => Some(MedialPoint {
+                point,
+                radius: t.radius_sq.sqrt(),
+            }),
+            _ => None,
+        }
+    };
+
+    let mut neighbours = BTreeMap::<(usize, usize), Vec<usize>>::new();
+    for (i, t) in triangles.iter().enumerate() {
+        for k in 0..3 {
+            let (a, b) = (t.vertices[k], t.vertices[(k + 1) % 3]);
+            neighbours.entry((a.min(b), a.max(b))).or_default().push(i);
+        }
+    }
+    let mut segments = Vec::<(MedialPoint, MedialPoint)>::new();
+    for ((a, b), adjacent) in neighbours.iter() {
+        if adjacent.len() != 2 || !far_apart(&samples[*a], &samples[*b]) {
+            continue;
+        }
+        let (t1, t2) = (&triangles[adjacent[0]], &triangles[adjacent[1]]);
+        if let (Some(p1), Some(p2)) = (to_medial_point(t1), to_medial_point(t2)) {
+            if (p1.point - p2.point).norm() > EQ_THRESHOLD {
+                segments.push((p1, p2));
+            }
+        }
+    }
+    MedialAxis { segments }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::faces::rectangle::primitive_rectangle;
+    use geop_geometry::surfaces::plane::Plane;
+
+    #[test]
+    fn test_medial_axis() {
+        // A 4 x 2 rectangle. The axis runs along y = 0 between x = -1 and x = 1 with a radius of 1, and branches off to the corners.
+        let face = primitive_rectangle(Point::zero(), Point::unit_x() * 2.0, Point::unit_y());
+        let axis = medial_axis(&face, 0.1);
+        assert!(!axis.segments.is_empty());
+        let points: Vec<MedialPoint> = axis.segments.iter().flat_map(|(a, b)| [*a, *b]).collect();
+        for p in points.iter() {
+            let width = (1.0 - p.point.y.abs()).min(2.0 - p.point.x.abs());
+            assert!((p.radius - width).abs() < 0.05);
+        }
+        assert!(points
+            .iter()
+            .any(|p| p.point.norm() < 0.1 && (p.radius - 1.0).abs() < 0.01));
+        assert!(points.iter().any(|p| p.point.x > 1.5 && p.point.y > 0.3));
+
+        // An annulus between radius 1 and 2 has its axis on the circle of radius 1.5.
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let face = Face::disk(&plane, Point::zero(), 2.0).with_hole(Face::disk(
+            &plane,
+            Point::zero(),
+            1.0,
+        ));
+        let axis = medial_axis(&face, 0.1);
+        assert!(axis.segments.len() > 50);
+        for (a, b) in axis.segments.iter() {
+            for p in [a, b] {
+                assert!((p.point.norm() - 1.5).abs() < 0.02);
+                assert!((p.radius - 0.5).abs() < 0.02);
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod continuity;
+pub mod curvature_comb;
+pub mod duplicates;
+pub mod medial_axis;
+pub mod orient;
+pub mod overhang;
+pub mod parting;
+pub mod scan;
+pub mod thickness;
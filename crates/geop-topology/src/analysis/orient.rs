@@ -0,0 +1,83 @@
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    transforms::{rotation::Rotation, Transform},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    measure::mass::{measure_area, measure_volume_centroid},
+    topology::{face::bounds::face_sample_points, volume::Volume},
+};
+
+// Finds a pose in which the volume lies flat on the XY-plane, e.g. to prepare it for printing or fixturing, and returns the
+// transform that puts it there. Candidates are the planar faces that touch a plane below the volume. A candidate is stable if the
+// center of mass, dropped along the face normal, lands on the face. The largest stable face wins; if no face is stable, the largest
+// candidate is used. The transform puts the face at z = 0 with the center of mass above the origin.
+pub fn orient_flat(volume: &Volume) -> Transform {
+    let center = measure_volume_centroid(volume);
+    let points: Vec<Point> = volume
+        .all_faces()
+        .iter()
+        .flat_map(face_sample_points)
+        .collect();
+
+    // (area, stable, normal, distance of the face plane from the center of mass)
+    let mut best: Option<(f64, bool, Point, f64)> = None;
+    for face in volume.boundary.faces.iter() {
+        let plane = match &*face.surface {
+            Surface::Plane(plane) => plane,
+            _ => continue,
+        };
+        let normal = face.surface.normal(plane.basis);
+        let offset = plane.basis.dot(normal);
+        // The face has to be the lowest part of the volume in the direction of its normal.
+        if points.iter().any(|p| p.dot(normal) > offset + EQ_THRESHOLD) {
+            continue;
+        }
+        let foot = center + normal * (offset - center.dot(normal));
+        let stable = face_point_contains(face, foot) != FacePointContains::Outside;
+        let area = measure_area(face);
+        let better = match best {
+            None => true,
+            Some((best_area, best_stable, _, _)) => {
+                (stable, area) > (best_stable, best_area + EQ_THRESHOLD)
+            }
+        };
+        if better {
+            best = Some((area, stable, normal, offset - center.dot(normal)));
+        }
+    }
+
+    let (_, _, normal, height) = best.expect("Volume has no planar face to rest on");
+    let rotation = Rotation::between(normal, -Point::unit_z()).to_transform();
+    Transform::from_translation(Point::new(0.0, 0.0, height))
+        * rotation
+        * Transform::from_translation(-center)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    #[test]
+    fn test_orient_flat() {
+        let offset = Point::new(3.0, -1.0, 2.0);
+        let cube = primitive_cube(1.0, 2.0, 3.0).transform(Transform::from_translation(offset));
+        let flat = cube.transform(orient_flat(&cube));
+        let points: Vec<Point> = flat
+            .all_faces()
+            .iter()
+            .flat_map(face_sample_points)
+            .collect();
+        let min_z = points.iter().map(|p| p.z).fold(f64::INFINITY, f64::min);
+        let max_z = points.iter().map(|p| p.z).fold(f64::NEG_INFINITY, f64::max);
+        // The 2 x 3 face is the largest, so the cube ends up 1 high.
+        assert!(min_z.abs() < 1e-9);
+        assert!((max_z - 1.0).abs() < 1e-9);
+        let center = measure_volume_centroid(&flat);
+        assert!((center - Point::new(0.0, 0.0, 0.5)).norm() < 1e-9);
+    }
+}
@@ -0,0 +1,142 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    measure::mass::{measure_area, measure_centroid},
+    topology::{
+        face::{bounds::face_sample_points, Face},
+        scene::{Color, Scene},
+        volume::Volume,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OverhangClass {
+    Supported,       // Steep enough to be printed without support
+    Overhang,        // Needs support everywhere
+    PartialOverhang, // Curved face, where some region needs support
+    OnBuildPlate,    // Rests on the build plate
+}
+
+#[derive(Clone, Debug)]
+pub struct OverhangAnalysis {
+    pub faces: Vec<(Face, OverhangClass)>,
+    // Area and support volume of the planar overhangs. Partial overhangs of curved faces are only classified, not measured.
+    pub overhang_area: f64,
+    pub support_volume: f64,
+    // The faces colored by their class.
+    pub scene: Scene,
+}
+
+impl OverhangClass {
+    pub fn color(&self) -> Color {
+        match self {
+            OverhangClass::Supported => Color::light_gray(),
+            OverhangClass::Overhang => Color::red(),
+            OverhangClass::PartialOverhang => Color::new(1.0, 0.6, 0.0, 1.0),
+            OverhangClass::OnBuildPlate => Color::blue(),
+        }
+    }
+}
+
+// Finds the faces that need support when the volume is printed in the build direction, i.e. faces that point down and are flatter
+// than the threshold angle, which is measured from the build direction (e.g. 45 degrees for typical FDM printers).
+// The support volume is the volume between the overhanging faces and the build plate, which is at the lowest point of the volume.
+pub fn overhang_analysis(
+    volume: &Volume,
+    build_direction: Point,
+    threshold_angle: f64,
+) -> OverhangAnalysis {
+    let up = build_direction.normalize();
+    // Normals closer than this to straight down need support.
+    let max_angle_to_down = PI / 2.0 - threshold_angle;
+    let base = volume
+        .all_faces()
+        .iter()
+        .flat_map(face_sample_points)
+        .map(|p| p.dot(up))
+        .fold(f64::INFINITY, f64::min);
+
+    let mut faces = Vec::<(Face, OverhangClass)>::new();
+    let mut overhang_area = 0.0;
+    let mut support_volume = 0.0;
+    for face in volume.all_faces() {
+        let class = match &*face.surface {
+            Surface::Plane(plane) => {
+                let normal = face.surface.normal(plane.basis);
+                if normal.angle(-up) >= max_angle_to_down - EQ_THRESHOLD {
+                    OverhangClass::Supported
+                } else if normal.angle(-up) < EQ_THRESHOLD
+                    && (plane.basis.dot(up) - base).abs() < EQ_THRESHOLD
+                {
+                    OverhangClass::OnBuildPlate
+                } else {
+                    let area = measure_area(&face);
+                    let height = measure_centroid(&face).dot(up) - base;
+                    overhang_area += area;
+                    // The height is linear on the face, so the centroid gives the average height.
+                    support_volume += area * normal.dot(up).abs() * height;
+                    OverhangClass::Overhang
+                }
+            }
+            _ => {
+                let cone = face.bounds().normal_cone.clone();
+                if cone.axis.angle(-up) - cone.half_angle >= max_angle_to_down - EQ_THRESHOLD {
+                    OverhangClass::Supported
+                } else {
+                    OverhangClass::PartialOverhang
+                }
+            }
+        };
+        faces.push((face, class));
+    }
+
+    let scene = Scene::new(
+        vec![],
+        faces.iter().map(|(f, c)| (f.clone(), c.color())).collect(),
+        vec![],
+        vec![],
+    );
+    OverhangAnalysis {
+        faces,
+        overhang_area,
+        support_volume,
+        scene,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    fn count(result: &OverhangAnalysis, class: OverhangClass) -> usize {
+        result.faces.iter().filter(|(_, c)| *c == class).count()
+    }
+
+    #[test]
+    fn test_overhang_analysis() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let result = overhang_analysis(&cube, Point::unit_z(), PI / 4.0);
+        assert_eq!(count(&result, OverhangClass::OnBuildPlate), 1);
+        assert_eq!(count(&result, OverhangClass::Supported), 5);
+        assert_eq!(result.support_volume, 0.0);
+        assert_eq!(result.scene.faces.len(), 6);
+
+        // Standing on an edge, the two lower faces are 45 degree overhangs, which can be printed without support.
+        let diagonal = Point::new(1.0, 1.0, 0.0);
+        let result = overhang_analysis(&cube, diagonal, PI / 4.0);
+        assert_eq!(count(&result, OverhangClass::Overhang), 0);
+
+        // With a stricter threshold they need support down to the edge.
+        let result = overhang_analysis(&cube, diagonal, PI / 8.0);
+        assert_eq!(count(&result, OverhangClass::Overhang), 2);
+        assert!((result.overhang_area - 8.0).abs() < 1e-9);
+        assert!((result.support_volume - 4.0).abs() < 1e-9);
+    }
+}
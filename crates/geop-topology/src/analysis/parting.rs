@@ -0,0 +1,355 @@
+use std::{
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use geop_geometry::{
+    curves::curve::Curve,
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    primitive_objects::edges::line::primitive_line,
+    topology::{
+        contour::Contour,
+        edge::Edge,
+        face::Face,
+        scene::{Color, Scene},
+        volume::Volume,
+    },
+};
+
+// The split of a volume into the halves of a two plate mold. The upper plate is pulled off in the pull direction.
+#[derive(Clone, Debug)]
+pub struct PartingLine {
+    // Indices into volume.all_faces(). Faces parallel to the pull direction belong to the upper half.
+    pub upper: Vec<usize>,
+    pub lower: Vec<usize>,
+    // The edges between both halves, oriented like in the faces of the lower half, and chained into closed loops.
+    pub loops: Vec<Contour>,
+    // The upper half in blue, the lower half in red and the parting line in black.
+    pub scene: Scene,
+}
+
+#[derive(Debug, Clone)]
+pub enum PartingError {
+    CurvedEdge(Point), // A curved edge, given by its midpoint, of a parting line that is not in a plane perpendicular to the pull direction
+    AlongPull(Point), // An edge, given by its midpoint, that runs along the pull direction, so it cannot be extended outwards
+}
+
+impl Display for PartingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartingError::CurvedEdge(p) => {
+                write!(
+                    f,
+                    "Curved edge through {:?} cannot be extended into a planar strip",
+                    p
+                )
+            }
+            PartingError::AlongPull(p) => {
+                write!(f, "Edge through {:?} runs along the pull direction", p)
+            }
+        }
+    }
+}
+
+// Points along every edge of the loops, used to measure the parting line. Closed edges need more than their end points.
+const EDGE_SAMPLES: usize = 8;
+
+// Chains oriented edges into closed loops. Closed edges are loops on their own.
+fn chain_loops(mut edges: Vec<Edge>) -> Vec<Contour> {
+    let mut loops = Vec::<Contour>::new();
+    while let Some(first) = edges.pop() {
+        let Some(start) = first.start else {
+            loops.push(Contour::new(vec![first]));
+            continue;
+        };
+        let mut chain = vec![first];
+        while chain[chain.len() - 1].end.unwrap() != start {
+            let end = chain[chain.len() - 1].end;
+            let next = edges
+                .iter()
+                .position(|e| e.start == end)
+                .expect("The parting line of a closed volume consists of closed loops");
+            chain.push(edges.remove(next));
+        }
+        loops.push(Contour::new(chain));
+    }
+    loops
+}
+
+// Finds the parting line of the volume for the pull direction, i.e. the silhouette between the faces that point along the pull
+// direction and the faces that point against it. Faces are classified by their normal at an inner point, so silhouettes that run
+// through the inside of curved faces are not found. Those faces have to be split along their silhouette first.
+pub fn parting_line(volume: &Volume, pull_direction: Point) -> PartingLine {
+    let pull = pull_direction.normalize();
+    let faces = volume.all_faces();
+    let (mut upper, mut lower) = (Vec::<usize>::new(), Vec::<usize>::new());
+    for (i, face) in faces.iter().enumerate() {
+        match face.normal(face.inner_point()).dot(pull) < -EQ_THRESHOLD {
+            true => lower.push(i),
+            false => upper.push(i),
+        }
+    }
+
+    let mut edges = Vec::<Edge>::new();
+    for i in lower.iter() {
+        for edge in faces[*i].all_edges() {
+            let in_upper = upper.iter().any(|j| faces[*j].all_edges().contains(&edge));
+            if in_upper {
+                edges.push(edge);
+            }
+        }
+    }
+    let loops = chain_loops(edges);
+
+    let mut scene_faces: Vec<(Face, Color)> = upper
+        .iter()
+        .map(|i| (faces[*i].clone(), Color::blue()))
+        .collect();
+    scene_faces.extend(lower.iter().map(|i| (faces[*i].clone(), Color::red())));
+    let scene = Scene::new(
+        vec![],
+        scene_faces,
+        loops
+            .iter()
+            .flat_map(|l| l.edges.iter())
+            .map(|e| (e.clone(), Color::black()))
+            .collect(),
+        vec![],
+    );
+    PartingLine {
+        upper,
+        lower,
+        loops,
+        scene,
+    }
+}
+
+// Extends the parting line outwards into a parting surface, which separates the plates of the mold, pointing in the pull direction.
+// A parting line in a plane perpendicular to the pull direction gives a single planar face up to margin beyond the line, with the
+// loops as holes. Otherwise every straight edge of the line is extended outwards by margin into a planar strip, and triangles close
+// the gaps between the strips at convex corners. Curved edges out of such a plane would need a ruled surface and are rejected.
+pub fn parting_surface(
+    parting: &PartingLine,
+    pull_direction: Point,
+    margin: f64,
+) -> Result<Vec<Face>, PartingError> {
+    let pull = pull_direction.normalize();
+    let samples = |l: &Contour| -> Vec<Point> {
+        l.edges
+            .iter()
+            .flat_map(|e| (0..EDGE_SAMPLES).map(|i| e.interpolate(i as f64 / EDGE_SAMPLES as f64)))
+            .collect()
+    };
+    let points: Vec<Point> = parting.loops.iter().flat_map(samples).collect();
+    assert!(!points.is_empty(), "The parting line is empty");
+    let height = points[0].dot(pull);
+    let planar = points
+        .iter()
+        .all(|p| (p.dot(pull) - height).abs() < EQ_THRESHOLD);
+
+    // Directions in the plane perpendicular to the pull direction.
+    let u = match pull.cross(Point::unit_x()).norm() > 0.1 {
+        true => pull.cross(Point::unit_x()).normalize(),
+        false => pull.cross(Point::unit_y()).normalize(),
+    };
+    let v = pull.cross(u);
+
+    if planar {
+        let center = points.iter().fold(Point::zero(), |a, p| a + *p) / points.len() as f64;
+        let (half_u, half_v) = points.iter().fold((0.0_f64, 0.0_f64), |(a, b), p| {
+            (
+                a.max((*p - center).dot(u).abs()),
+                b.max((*p - center).dot(v).abs()),
+            )
+        });
+        let (du, dv) = (u * (half_u + margin), v * (half_v + margin));
+        let corners = [
+            center + du + dv,
+            center - du + dv,
+            center - du - dv,
+            center + du - dv,
+        ];
+        let boundary = Contour::new(
+            (0..4)
+                .map(|i| primitive_line(corners[i], corners[(i + 1) % 4]))
+                .collect(),
+        );
+        // Holes run clockwise around the normal of the face.
+        let holes = parting
+            .loops
+            .iter()
+            .map(|l| {
+                let p = samples(l);
+                let area: f64 = (0..p.len())
+                    .map(|i| {
+                        (p[i] - center)
+                            .cross(p[(i + 1) % p.len()] - center)
+                            .dot(pull)
+                    })
+                    .sum();
+                match area > 0.0 {
+                    true => l.flip(),
+                    false => l.clone(),
+                }
+            })
+            .collect();
+        return Ok(vec![Face::new(
+            Some(boundary),
+            holes,
+            Rc::new(Surface::Plane(Plane::new(center, u, v))),
+        )]);
+    }
+
+    let mut faces = Vec::<Face>::new();
+    for l in parting.loops.iter() {
+        let mut offsets = Vec::<Point>::new();
+        for edge in l.edges.iter() {
+            if !matches!(edge.curve, Curve::Line(_)) {
+                return Err(PartingError::CurvedEdge(edge.get_midpoint()));
+            }
+            let (start, end) = (edge.start.unwrap(), edge.end.unwrap());
+            // The lower half is on the right of the edge, seen from the pull direction, so outwards is on its left.
+            let along = (end - start).normalize();
+            let outwards = pull.cross(along);
+            if outwards.norm() < EQ_THRESHOLD {
+                return Err(PartingError::AlongPull(edge.get_midpoint()));
+            }
+            let outwards = outwards.normalize() * margin;
+            offsets.push(outwards);
+            // The strip turns counterclockwise from along to outwards, so its normal has a positive component along the pull direction.
+            let normal = along.cross(outwards);
+            faces.push(polygon(
+                &[start, end, end + outwards, start + outwards],
+                Plane::new(start, along, normal.cross(along)),
+            ));
+        }
+        // Where the loop turns away from the outside, the strips of both edges spread apart and leave a triangular gap at the
+        // corner. Where it turns towards the outside, the strips overlap instead.
+        for (i, edge) in l.edges.iter().enumerate() {
+            let corner = edge.end.unwrap();
+            let (before, after) = (offsets[i], offsets[(i + 1) % offsets.len()]);
+            let turn = after.cross(before);
+            if turn.dot(pull) < EQ_THRESHOLD * margin * margin {
+                continue;
+            }
+            faces.push(polygon(
+                &[corner, corner + after, corner + before],
+                Plane::new(corner, after.normalize(), turn.cross(after).normalize()),
+            ));
+        }
+    }
+    Ok(faces)
+}
+
+// A planar face bounded by the lines between the points.
+fn polygon(points: &[Point], plane: Plane) -> Face {
+    let contour = Contour::new(
+        (0..points.len())
+            .map(|i| primitive_line(points[i], points[(i + 1) % points.len()]))
+            .collect(),
+    );
+    Face::new(Some(contour), vec![], Rc::new(Surface::Plane(plane)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        contains::face_point::{face_point_contains, FacePointContains},
+        operations::extrude::extrude,
+        primitive_objects::{edges::circle::primitive_circle, volumes::cube::primitive_cube},
+    };
+
+    #[test]
+    fn test_parting_line() {
+        // A cube pulled upwards parts along the edges of its bottom face.
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let parting = parting_line(&cube, Point::unit_z());
+        assert_eq!(parting.upper.len(), 5);
+        assert_eq!(parting.lower.len(), 1);
+        assert_eq!(parting.loops.len(), 1);
+        assert_eq!(parting.loops[0].edges.len(), 4);
+        assert!(parting.loops[0]
+            .all_points()
+            .iter()
+            .all(|p| (p.z + 1.0).abs() < 1e-9));
+
+        let surface = parting_surface(&parting, Point::unit_z(), 1.0).unwrap();
+        assert_eq!(surface.len(), 1);
+        assert_eq!(surface[0].holes.len(), 1);
+        assert!((surface[0].normal(Point::new(1.5, 0.0, -1.0)) - Point::unit_z()).norm() < 1e-9);
+
+        // Pulled along a diagonal, the parting line zigzags around the cube.
+        let diagonal = Point::new(1.0, 1.0, 1.0);
+        let parting = parting_line(&cube, diagonal);
+        assert_eq!(parting.upper.len(), 3);
+        assert_eq!(parting.loops[0].edges.len(), 6);
+        let faces = parting_surface(&parting, diagonal, 1.0).unwrap();
+        assert_eq!(faces.len(), 12, "Expected 6 strips and 6 corners");
+        for face in faces.iter() {
+            let p = face.inner_point();
+            assert!(face.normal(p).dot(diagonal) > 0.0);
+        }
+        // Without gaps, every point away from the parting line is shared by a strip and a corner.
+        let line = &parting.loops[0].all_points();
+        for p in faces.iter().flat_map(|f| f.all_points()) {
+            if line.contains(&p) {
+                continue;
+            }
+            let shared = faces.iter().filter(|f| f.all_points().contains(&p)).count();
+            assert_eq!(shared, 2, "Expected {:?} to be shared by two faces", p);
+        }
+
+        // A cylinder pulled along its axis parts along the circle around its bottom face.
+        let disk = Face::new(
+            Some(Contour::new(vec![primitive_circle(
+                Point::zero(),
+                -Point::unit_z(),
+                0.5,
+            )])),
+            vec![],
+            Rc::new(Surface::Plane(Plane::new(
+                Point::zero(),
+                Point::unit_y(),
+                Point::unit_x(),
+            ))),
+        );
+        let cylinder = extrude(disk, Point::new(0.0, 0.0, 2.0));
+        let parting = parting_line(&cylinder, Point::unit_z());
+        assert_eq!(parting.lower.len(), 1);
+        assert_eq!(parting.loops.len(), 1);
+        assert_eq!(parting.loops[0].edges.len(), 1);
+        let surface = parting_surface(&parting, Point::unit_z(), 1.0).unwrap();
+        assert_eq!(surface.len(), 1);
+        assert_eq!(surface[0].holes.len(), 1);
+        assert!(matches!(
+            face_point_contains(&surface[0], Point::new(1.0, 0.0, 0.0)),
+            FacePointContains::Inside
+        ));
+        assert!(matches!(
+            face_point_contains(&surface[0], Point::new(0.25, 0.0, 0.0)),
+            FacePointContains::Outside
+        ));
+
+        // A tilted circle would need a ruled surface.
+        let tilted = PartingLine {
+            upper: vec![],
+            lower: vec![],
+            loops: vec![Contour::new(vec![primitive_circle(
+                Point::zero(),
+                Point::new(0.0, 1.0, 1.0).normalize(),
+                1.0,
+            )])],
+            scene: Scene::new(vec![], vec![], vec![], vec![]),
+        };
+        assert!(matches!(
+            parting_surface(&tilted, Point::unit_z(), 1.0),
+            Err(PartingError::CurvedEdge(_))
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+use geop_geometry::{
+    curve_surface_intersection::curve_surface::{
+        curve_surface_intersection, CurveSurfaceIntersection,
+    },
+    curves::{curve::Curve, line::Line},
+    points::point::Point,
+};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    topology::{face::Face, volume::Volume},
+};
+
+// A grid of parallel rays. Ray (i, j) starts at origin + u * i + v * j, where u and v are the steps between neighbouring rays.
+#[derive(Clone, Debug)]
+pub struct RayGrid {
+    pub origin: Point,
+    pub u: Point,
+    pub v: Point,
+    pub columns: usize,
+    pub rows: usize,
+    pub direction: Point,
+}
+
+impl RayGrid {
+    pub fn new(
+        origin: Point,
+        u: Point,
+        v: Point,
+        columns: usize,
+        rows: usize,
+        direction: Point,
+    ) -> RayGrid {
+        RayGrid {
+            origin,
+            u,
+            v,
+            columns,
+            rows,
+            direction: direction.normalize(),
+        }
+    }
+
+    pub fn ray_origin(&self, column: usize, row: usize) -> Point {
+        self.origin + self.u * column as f64 + self.v * row as f64
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SurfaceHit {
+    pub point: Point,
+    // Distance from the start of the ray.
+    pub depth: f64,
+    // Outward normal of the hit face.
+    pub normal: Point,
+}
+
+// Where a ray enters the volume first and leaves it last.
+#[derive(Clone, Copy, Debug)]
+pub struct RayHit {
+    pub first: SurfaceHit,
+    pub last: SurfaceHit,
+}
+
+#[derive(Clone, Debug)]
+pub struct DepthMap {
+    pub columns: usize,
+    pub rows: usize,
+    // Row by row, None where the ray misses the volume.
+    pub hits: Vec<Option<RayHit>>,
+}
+
+impl DepthMap {
+    pub fn get(&self, column: usize, row: usize) -> Option<RayHit> {
+        self.hits[row * self.columns + column]
+    }
+
+    // Depths of the first hits, e.g. the heightmap of the volume when the rays point down.
+    pub fn first_depths(&self) -> Vec<Option<f64>> {
+        self.hits.iter().map(|h| h.map(|h| h.first.depth)).collect()
+    }
+
+    pub fn last_depths(&self) -> Vec<Option<f64>> {
+        self.hits.iter().map(|h| h.map(|h| h.last.depth)).collect()
+    }
+}
+
+// All points where the ray hits one of the faces in front of its start, sorted by depth.
+fn ray_hits(faces: &[Face], start: Point, direction: Point) -> Vec<SurfaceHit> {
+    let ray = Curve::Line(Line::new(start, direction));
+    let mut hits = Vec::<SurfaceHit>::new();
+    for face in faces.iter() {
+        let points = match curve_surface_intersection(&ray, &face.surface) {
+            CurveSurfaceIntersection::Points(points) => points.into_iter().map(|p| p.point),
+            // Rays that graze along a face hit its neighbours instead.
+            CurveSurfaceIntersection::Curve(_) | CurveSurfaceIntersection::None => continue,
+        };
+        for point in points {
+            let depth = (point - start).dot(direction);
+            if depth < 0.0 {
+                continue;
+            }
+            if matches!(
+                face_point_contains(face, point),
+                FacePointContains::Inside
+                    | FacePointContains::OnEdge(_)
+                    | FacePointContains::OnPoint(_)
+            ) {
+                hits.push(SurfaceHit {
+                    point,
+                    depth,
+                    normal: face.normal(point),
+                });
+            }
+        }
+    }
+    hits.sort_by(|a, b| a.depth.total_cmp(&b.depth));
+    hits
+}
+
+// Casts the rays of the grid at the volume and records where each of them hits it first and last. Rays start at the grid, so the
+// grid should be placed outside of the volume, e.g. above it for a heightmap or a 2.5D machining preview.
+pub fn scan_volume(volume: &Volume, grid: &RayGrid) -> DepthMap {
+    let faces = volume.all_faces();
+    let mut hits = Vec::<Option<RayHit>>::with_capacity(grid.columns * grid.rows);
+    for row in 0..grid.rows {
+        for column in 0..grid.columns {
+            let ray_hits = ray_hits(&faces, grid.ray_origin(column, row), grid.direction);
+            hits.push(match (ray_hits.first(), ray_hits.last()) {
+                (Some(first), Some(last)) => Some(RayHit {
+                    first: *first,
+                    last: *last,
+                }),
+                _ => None,
+            });
+        }
+    }
+    DepthMap {
+        columns: grid.columns,
+        rows: grid.rows,
+        hits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    #[test]
+    fn test_scan_volume() {
+        // Rays from z = 5 down onto a cube from -1 to 1, spaced by 0.5 from x, y = -1.75 to 1.75.
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let grid = RayGrid::new(
+            Point::new(-1.75, -1.75, 5.0),
+            Point::unit_x() * 0.5,
+            Point::unit_y() * 0.5,
+            8,
+            8,
+            -Point::unit_z(),
+        );
+        let map = scan_volume(&cube, &grid);
+        assert_eq!(map.hits.len(), 64);
+        assert_eq!(map.hits.iter().filter(|h| h.is_some()).count(), 16);
+        assert!(map.get(0, 0).is_none());
+
+        let hit = map.get(3, 4).unwrap();
+        assert!((hit.first.depth - 4.0).abs() < 1e-9);
+        assert!((hit.last.depth - 6.0).abs() < 1e-9);
+        assert!((hit.first.normal - Point::unit_z()).norm() < 1e-9);
+        assert!((hit.last.normal + Point::unit_z()).norm() < 1e-9);
+        assert!((hit.first.point - Point::new(-0.25, 0.25, 1.0)).norm() < 1e-9);
+        assert_eq!(map.first_depths().iter().flatten().count(), 16);
+    }
+}
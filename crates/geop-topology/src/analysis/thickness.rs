@@ -0,0 +1,188 @@
+use geop_geometry::{
+    curve_surface_intersection::curve_surface::{
+        curve_surface_intersection, CurveSurfaceIntersection,
+    },
+    curves::{curve::Curve, line::Line},
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    primitive_objects::edges::line::primitive_line,
+    topology::{
+        face::{bounds::face_sample_points, Face},
+        scene::{Color, Scene},
+        volume::Volume,
+    },
+};
+
+// Density of the samples on curved faces, see SurfaceLike::point_grid.
+const GRID_DENSITY: f64 = 2.0;
+
+#[derive(Clone, Debug)]
+pub struct ThicknessSample {
+    pub point: Point,
+    // Index into volume.all_faces().
+    pub face: usize,
+    // Where the ray into the material leaves it again.
+    pub opposite: Point,
+    pub thickness: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct ThicknessAnalysis {
+    pub samples: Vec<ThicknessSample>,
+    // The samples that are thinner than the threshold.
+    pub thin: Vec<ThicknessSample>,
+    pub min_thickness: f64,
+    // Faces with thin samples in red, the others in light gray, and the rays through the thin walls in red.
+    pub scene: Scene,
+}
+
+// Points inside of the face. Planar faces are sampled on a grid with the given spacing, curved faces on the grid of their surface.
+fn face_interior_samples(face: &Face, spacing: f64) -> Vec<Point> {
+    let candidates = match &*face.surface {
+        Surface::Plane(plane) => {
+            let u = plane.u_slope.normalize();
+            let v = face.surface.normal(plane.basis).cross(u);
+            let local: Vec<(f64, f64)> = face_sample_points(face)
+                .iter()
+                .map(|p| ((*p - plane.basis).dot(u), (*p - plane.basis).dot(v)))
+                .collect();
+            let min_u = local.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+            let max_u = local.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+            let min_v = local.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+            let max_v = local.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+            let (n, m) = (
+                ((max_u - min_u) / spacing).ceil() as usize,
+                ((max_v - min_v) / spacing).ceil() as usize,
+            );
+            let mut points = Vec::<Point>::new();
+            // Cell centers, so that no sample lies exactly on the boundary.
+            for i in 0..n {
+                for j in 0..m {
+                    let a = min_u + (max_u - min_u) * (i as f64 + 0.5) / n as f64;
+                    let b = min_v + (max_v - min_v) * (j as f64 + 0.5) / m as f64;
+                    points.push(plane.basis + u * a + v * b);
+                }
+            }
+            points
+        }
+        _ => face.surface.point_grid(GRID_DENSITY),
+    };
+    candidates
+        .into_iter()
+        .filter(|p| face_point_contains(face, *p) == FacePointContains::Inside)
+        .collect()
+}
+
+// The closest point where the ray from the point in the direction leaves the volume through one of the faces.
+fn ray_hit(faces: &[Face], point: Point, direction: Point) -> Option<(Point, f64)> {
+    let ray = Curve::Line(Line::new(point, direction));
+    let mut closest: Option<(Point, f64)> = None;
+    for face in faces.iter() {
+        let points = match curve_surface_intersection(&ray, &face.surface) {
+            CurveSurfaceIntersection::Points(points) => points.into_iter().map(|p| p.point),
+            // Rays along a face or without hit do not measure it.
+            CurveSurfaceIntersection::Curve(_) | CurveSurfaceIntersection::None => continue,
+        };
+        for q in points {
+            let t = (q - point).dot(direction);
+            if t < 10.0 * EQ_THRESHOLD || closest.is_some_and(|(_, c)| c <= t) {
+                continue;
+            }
+            if matches!(
+                face_point_contains(face, q),
+                FacePointContains::Inside
+                    | FacePointContains::OnEdge(_)
+                    | FacePointContains::OnPoint(_)
+            ) {
+                closest = Some((q, t));
+            }
+        }
+    }
+    closest
+}
+
+// Measures the wall thickness by casting rays from samples on the faces straight into the material, and reports the samples
+// where the wall is thinner than the threshold, e.g. the minimum wall thickness of a manufacturing process. Planar faces are
+// sampled every spacing units. Rays only measure the thickness perpendicular to the face, so thin spots at sharp inner corners
+// can be missed.
+pub fn thickness_analysis(volume: &Volume, threshold: f64, spacing: f64) -> ThicknessAnalysis {
+    assert!(spacing > 0.0);
+    let faces = volume.all_faces();
+    let mut samples = Vec::<ThicknessSample>::new();
+    for (i, face) in faces.iter().enumerate() {
+        for point in face_interior_samples(face, spacing) {
+            // Faces point out of the material, also the faces of cavities.
+            let direction = -face.normal(point);
+            if let Some((opposite, thickness)) = ray_hit(&faces, point, direction) {
+                samples.push(ThicknessSample {
+                    point,
+                    face: i,
+                    opposite,
+                    thickness,
+                });
+            }
+        }
+    }
+
+    let thin: Vec<ThicknessSample> = samples
+        .iter()
+        .filter(|s| s.thickness < threshold)
+        .cloned()
+        .collect();
+    let min_thickness = samples
+        .iter()
+        .map(|s| s.thickness)
+        .fold(f64::INFINITY, f64::min);
+    let scene = Scene::new(
+        vec![],
+        faces
+            .iter()
+            .enumerate()
+            .map(|(i, f)| match thin.iter().any(|s| s.face == i) {
+                true => (f.clone(), Color::red()),
+                false => (f.clone(), Color::light_gray()),
+            })
+            .collect(),
+        thin.iter()
+            .map(|s| (primitive_line(s.point, s.opposite), Color::red()))
+            .collect(),
+        vec![],
+    );
+    ThicknessAnalysis {
+        samples,
+        thin,
+        min_thickness,
+        scene,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    #[test]
+    fn test_thickness_analysis() {
+        // The walls of a hollow cube are 0.5 thick, and 2 where the rays miss the cavity.
+        let hollow = primitive_cube(2.0, 2.0, 2.0).with_cavity(&primitive_cube(1.0, 1.0, 1.0));
+        let result = thickness_analysis(&hollow, 0.6, 0.25);
+        assert!((result.min_thickness - 0.5).abs() < 1e-9);
+        assert!(!result.thin.is_empty());
+        for s in result.samples.iter() {
+            assert!((s.thickness - 0.5).abs() < 1e-9 || (s.thickness - 2.0).abs() < 1e-9);
+        }
+        // Every face of the cube and of the cavity has samples across the thin wall.
+        let mut thin_faces: Vec<usize> = result.thin.iter().map(|s| s.face).collect();
+        thin_faces.dedup();
+        assert_eq!(thin_faces.len(), 12);
+
+        let result = thickness_analysis(&hollow, 0.4, 0.25);
+        assert!(result.thin.is_empty());
+        assert!(result.scene.edges.is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use geop_geometry::{bounding_sphere::BoundingSphere, points::point::Point, transforms::Transform};
+
+use crate::{
+    measure::{
+        distance::{measure_distance, DistanceMeasurement},
+        MeasureEntity,
+    },
+    topology::face::Face,
+};
+
+#[derive(Debug, Clone)]
+pub enum BvhNode {
+    Leaf(BoundingSphere, usize), // Index of the face
+    Branch(BoundingSphere, Box<BvhNode>, Box<BvhNode>),
+}
+
+impl BvhNode {
+    pub fn sphere(&self) -> &BoundingSphere {
+        match self {
+            BvhNode::Leaf(sphere, _) => sphere,
+            BvhNode::Branch(sphere, _, _) => sphere,
+        }
+    }
+}
+
+// Bounding volume hierarchy of spheres around the faces of a shape. It answers distance queries between two shapes in arbitrary
+// rigid placements, without rebuilding the hierarchy for every placement.
+#[derive(Debug, Clone)]
+pub struct FaceBvh {
+    pub faces: Vec<Face>,
+    pub root: BvhNode,
+}
+
+// Splits the faces at the median of the longest axis of their centers.
+fn build(leaves: &mut [(BoundingSphere, usize)]) -> BvhNode {
+    if leaves.len() == 1 {
+        return BvhNode::Leaf(leaves[0].0.clone(), leaves[0].1);
+    }
+    let mut min = leaves[0].0.center;
+    let mut max = leaves[0].0.center;
+    for (sphere, _) in leaves.iter() {
+        let c = sphere.center;
+        min = Point::new(min.x.min(c.x), min.y.min(c.y), min.z.min(c.z));
+        max = Point::new(max.x.max(c.x), max.y.max(c.y), max.z.max(c.z));
+    }
+    let size = max - min;
+    let key = |p: Point| match (size.x >= size.y && size.x >= size.z, size.y >= size.z) {
+        (true, _) => p.x,
+        (false, true) => p.y,
+        (false, false) => p.z,
+    };
+    leaves.sort_by(|a, b| key(a.0.center).total_cmp(&key(b.0.center)));
+    let (left, right) = leaves.split_at_mut(leaves.len() / 2);
+    let left = build(left);
+    let right = build(right);
+    BvhNode::Branch(
+        left.sphere().merge(right.sphere()),
+        Box::new(left),
+        Box::new(right),
+    )
+}
+
+impl FaceBvh {
+    pub fn new(faces: Vec<Face>) -> FaceBvh {
+        assert!(!faces.is_empty(), "Cannot build a hierarchy without faces");
+        let mut leaves: Vec<(BoundingSphere, usize)> = faces
+            .iter()
+            .enumerate()
+            .map(|(i, face)| (face.bounds().bounds.bounding_sphere.clone(), i))
+            .collect();
+        let root = build(&mut leaves);
+        FaceBvh { faces, root }
+    }
+
+    // Minimal distance between the faces of both hierarchies, where the other hierarchy is placed with the rigid transform.
+    // Pairs of nodes whose spheres are further apart than the best distance found so far are skipped.
+    pub fn distance(&self, other: &FaceBvh, transform: Transform) -> DistanceMeasurement {
+        let mut best: Option<DistanceMeasurement> = None;
+        self.distance_nodes(&self.root, other, &other.root, transform, &mut best);
+        best.expect("Both hierarchies have faces")
+    }
+
+    fn distance_nodes(
+        &self,
+        node: &BvhNode,
+        other: &FaceBvh,
+        other_node: &BvhNode,
+        transform: Transform,
+        best: &mut Option<DistanceMeasurement>,
+    ) {
+        let bound = node
+            .sphere()
+            .distance(&other_node.sphere().transform(transform));
+        if let Some(b) = best {
+            if bound >= b.distance {
+                return;
+            }
+        }
+        match (node, other_node) {
+            (BvhNode::Leaf(_, i), BvhNode::Leaf(_, j)) => {
+                let other_face = other.faces[*j].transform(transform);
+                let m = measure_distance(
+                    &MeasureEntity::Face(&self.faces[*i]),
+                    &MeasureEntity::Face(&other_face),
+                );
+                if best.as_ref().is_none_or(|b| m.distance < b.distance) {
+                    *best = Some(m);
+                }
+            }
+            // Descend into the larger node first, since it separates the faces better.
+            (BvhNode::Branch(sphere, left, right), BvhNode::Branch(other_sphere, _, _))
+                if sphere.radius >= other_sphere.radius =>
+            {
+                self.distance_nodes(left, other, other_node, transform, best);
+                self.distance_nodes(right, other, other_node, transform, best);
+            }
+            (BvhNode::Branch(_, left, right), BvhNode::Leaf(_, _)) => {
+                self.distance_nodes(left, other, other_node, transform, best);
+                self.distance_nodes(right, other, other_node, transform, best);
+            }
+            (_, BvhNode::Branch(_, left, right)) => {
+                self.distance_nodes(node, other, left, transform, best);
+                self.distance_nodes(node, other, right, transform, best);
+            }
+        }
+    }
+}
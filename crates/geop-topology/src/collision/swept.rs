@@ -0,0 +1,157 @@
+use geop_geometry::{
+    points::point::Point,
+    transforms::{rotation::Rotation, Transform},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::volume_point::{volume_point_contains, VolumePointContains},
+    topology::volume::Volume,
+};
+
+use super::bvh::FaceBvh;
+
+// Motion of a volume over the time t from 0 to 1.
+#[derive(Debug, Clone)]
+pub enum Motion {
+    Translation(Point),
+    // Rotation by the angle around the axis through the origin, combined with a shift of the advance along the axis.
+    Screw {
+        origin: Point,
+        axis: Point,
+        angle: f64,
+        advance: f64,
+    },
+}
+
+impl Motion {
+    pub fn transform_at(&self, t: f64) -> Transform {
+        match self {
+            Motion::Translation(v) => Transform::from_translation(*v * t),
+            Motion::Screw {
+                origin,
+                axis,
+                angle,
+                advance,
+            } => {
+                let axis = axis.normalize();
+                let rotation = Rotation::from_axis_angle(axis, angle * t).to_transform();
+                Transform::from_translation(*origin + axis * (advance * t))
+                    * rotation
+                    * Transform::from_translation(-*origin)
+            }
+        }
+    }
+
+    // Upper bound of the speed of any point in the sphere around the center with the radius.
+    fn max_speed(&self, center: Point, radius: f64) -> f64 {
+        match self {
+            Motion::Translation(v) => v.norm(),
+            Motion::Screw {
+                origin,
+                axis,
+                angle,
+                advance,
+            } => {
+                // Rotations keep the distance to the axis, so the sphere stays at the same distance during the whole motion.
+                let axis = axis.normalize();
+                let d = center - *origin;
+                let axis_distance = (d - axis * d.dot(axis)).norm() + radius;
+                advance.abs() + angle.abs() * axis_distance
+            }
+        }
+    }
+}
+
+pub enum SweptInterference {
+    None,
+    Contact(f64, Point), // Time of the first contact and the contact point at that time
+}
+
+// Checks if the moving volume touches the fixed volume during the motion, by conservative advancement: the distance between both
+// volumes divided by the maximal speed of the moving one is a time step that cannot skip a contact. Contacts are reported when
+// the volumes come closer than the tolerance.
+pub fn swept_interference(
+    fixed: &Volume,
+    moving: &Volume,
+    motion: &Motion,
+    tolerance: f64,
+) -> SweptInterference {
+    assert!(tolerance > EQ_THRESHOLD);
+    // A volume inside of the other one is not found by distances between faces.
+    for (a, b) in [(fixed, moving), (moving, fixed)] {
+        let p = b.all_faces()[0].inner_point();
+        if let VolumePointContains::Inside = volume_point_contains(a, p) {
+            return SweptInterference::Contact(0.0, p);
+        }
+    }
+
+    let fixed_bvh = FaceBvh::new(fixed.all_faces());
+    let moving_bvh = FaceBvh::new(moving.all_faces());
+    let sphere = moving_bvh.root.sphere();
+    let speed = motion.max_speed(sphere.center, sphere.radius);
+
+    let mut t = 0.0;
+    loop {
+        let m = fixed_bvh.distance(&moving_bvh, motion.transform_at(t));
+        if m.distance <= tolerance {
+            return SweptInterference::Contact(t, (m.witness_a + m.witness_b) / 2.0);
+        }
+        if speed < EQ_THRESHOLD {
+            return SweptInterference::None;
+        }
+        // Stopping half a tolerance short guarantees that the volumes do not penetrate, while still making progress.
+        t += (m.distance - tolerance / 2.0) / speed;
+        if t > 1.0 {
+            return SweptInterference::None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    #[test]
+    fn test_swept_interference() {
+        let fixed = primitive_cube(2.0, 2.0, 2.0);
+        let moving = primitive_cube(2.0, 2.0, 2.0)
+            .transform(Transform::from_translation(Point::new(5.0, 0.0, 0.0)));
+        let motion = Motion::Translation(Point::new(-6.0, 0.0, 0.0));
+        match swept_interference(&fixed, &moving, &motion, 1e-4) {
+            SweptInterference::Contact(t, p) => {
+                assert!((t - 0.5).abs() < 1e-4);
+                assert!((p.x - 1.0).abs() < 1e-3);
+            }
+            SweptInterference::None => panic!("Cubes should collide"),
+        }
+        let motion = Motion::Translation(Point::new(0.0, 0.0, 6.0));
+        assert!(matches!(
+            swept_interference(&fixed, &moving, &motion, 1e-4),
+            SweptInterference::None
+        ));
+
+        // A small cube orbiting around the z axis hits a cube on the negative y axis after three quarters of a turn.
+        let fixed = primitive_cube(1.0, 1.0, 1.0)
+            .transform(Transform::from_translation(Point::new(0.0, -3.0, 0.0)));
+        let moving = primitive_cube(1.0, 1.0, 1.0)
+            .transform(Transform::from_translation(Point::new(3.0, 0.0, 0.0)));
+        let orbit = |angle: f64| Motion::Screw {
+            origin: Point::zero(),
+            axis: Point::unit_z(),
+            angle,
+            advance: 0.0,
+        };
+        assert!(matches!(
+            swept_interference(&fixed, &moving, &orbit(PI), 1e-4),
+            SweptInterference::None
+        ));
+        match swept_interference(&fixed, &moving, &orbit(2.0 * PI), 1e-4) {
+            SweptInterference::Contact(t, _) => assert!(t > 0.6 && t < 0.75),
+            SweptInterference::None => panic!("Cubes should collide"),
+        }
+    }
+}
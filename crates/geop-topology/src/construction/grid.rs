@@ -0,0 +1,87 @@
+use geop_geometry::points::point::Point;
+
+use crate::{
+    primitive_objects::edges::line::primitive_line,
+    topology::scene::{Color, Scene},
+};
+
+use super::work_plane::WorkPlane;
+
+// Generates a square reference grid on the work plane. The grid spans [-extent, extent] in both directions and has a line every spacing units.
+// Every major_every-th line is drawn with the major color, the others with the minor color.
+pub fn construction_grid(
+    work_plane: &WorkPlane,
+    spacing: f64,
+    extent: f64,
+    major_every: usize,
+    minor_color: Color,
+    major_color: Color,
+) -> Scene {
+    assert!(spacing > 0.0);
+    assert!(extent > 0.0);
+    assert!(major_every > 0);
+
+    let n = (extent / spacing).floor() as i64;
+    let mut edges = Vec::new();
+    for i in -n..=n {
+        let offset = i as f64 * spacing;
+        let color = match i.unsigned_abs() as usize % major_every {
+            0 => major_color,
+            _ => minor_color,
+        };
+        edges.push((
+            primitive_line(
+                work_plane.to_world(-extent, offset),
+                work_plane.to_world(extent, offset),
+            ),
+            color,
+        ));
+        edges.push((
+            primitive_line(
+                work_plane.to_world(offset, -extent),
+                work_plane.to_world(offset, extent),
+            ),
+            color,
+        ));
+    }
+    Scene::new(vec![], vec![], edges, vec![])
+}
+
+// Generates the axes of the work plane, starting at its origin. x is red, y is green and the normal is blue.
+pub fn construction_axes(work_plane: &WorkPlane, length: f64) -> Scene {
+    assert!(length > 0.0);
+    let origin = work_plane.origin;
+    let axis = |dir: Point| primitive_line(origin, origin + dir * length);
+    Scene::new(
+        vec![],
+        vec![],
+        vec![
+            (axis(work_plane.x_dir), Color::red()),
+            (axis(work_plane.y_dir), Color::green()),
+            (axis(work_plane.normal), Color::blue()),
+        ],
+        vec![(origin, Color::white())],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construction_grid() {
+        // Lines every unit from -2 to 2 in both directions, with major lines through the origin and at the border.
+        let grid = construction_grid(&WorkPlane::xy(), 1.0, 2.0, 2, Color::gray(), Color::black());
+        assert_eq!(grid.edges.len(), 10);
+        let majors = grid.edges.iter().filter(|(_, c)| c.r == 0.0).count();
+        assert_eq!(majors, 6);
+        for (edge, _) in grid.edges.iter() {
+            let length = (edge.end.unwrap() - edge.start.unwrap()).norm();
+            assert!((length - 4.0).abs() < 1e-9);
+        }
+
+        let axes = construction_axes(&WorkPlane::xy(), 3.0);
+        assert_eq!(axes.edges[2].0.end, Some(Point::unit_z() * 3.0));
+        assert_eq!(axes.points.len(), 1);
+    }
+}
@@ -0,0 +1,89 @@
+use geop_geometry::{
+    points::point::Point,
+    surfaces::plane::Plane,
+    transforms::{frame::Frame, Transform},
+};
+
+// A local coordinate system in which the user sketches. x_dir and y_dir span the plane, normal points towards the viewer.
+#[derive(Clone, Debug)]
+pub struct WorkPlane {
+    pub origin: Point,
+    pub x_dir: Point,
+    pub y_dir: Point,
+    pub normal: Point,
+}
+
+impl WorkPlane {
+    pub fn new(origin: Point, x_dir: Point, y_dir: Point) -> WorkPlane {
+        WorkPlane::from_frame(Frame::new(origin, x_dir, y_dir))
+    }
+
+    pub fn from_frame(frame: Frame) -> WorkPlane {
+        WorkPlane {
+            origin: frame.origin,
+            x_dir: frame.x_dir,
+            y_dir: frame.y_dir,
+            normal: frame.z_dir,
+        }
+    }
+
+    pub fn frame(&self) -> Frame {
+        Frame {
+            origin: self.origin,
+            x_dir: self.x_dir,
+            y_dir: self.y_dir,
+            z_dir: self.normal,
+        }
+    }
+
+    pub fn xy() -> WorkPlane {
+        WorkPlane::new(Point::zero(), Point::unit_x(), Point::unit_y())
+    }
+
+    pub fn yz() -> WorkPlane {
+        WorkPlane::new(Point::zero(), Point::unit_y(), Point::unit_z())
+    }
+
+    pub fn zx() -> WorkPlane {
+        WorkPlane::new(Point::zero(), Point::unit_z(), Point::unit_x())
+    }
+
+    pub fn transform(&self, transform: Transform) -> WorkPlane {
+        WorkPlane::from_frame(self.frame().transform(transform))
+    }
+
+    // Converts local coordinates on the work plane to world coordinates.
+    pub fn to_world(&self, x: f64, y: f64) -> Point {
+        self.frame().to_global(Point::new(x, y, 0.0))
+    }
+
+    // Projects a world point onto the work plane and returns its local coordinates.
+    pub fn to_local(&self, p: Point) -> (f64, f64) {
+        let local = self.frame().to_local(p);
+        (local.x, local.y)
+    }
+
+    pub fn plane(&self) -> Plane {
+        Plane::new(self.origin, self.x_dir, self.y_dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::surfaces::SurfaceLike;
+
+    use super::*;
+
+    #[test]
+    fn test_work_plane() {
+        // Local coordinates on a shifted zx plane map to world coordinates and back.
+        let work_plane =
+            WorkPlane::zx().transform(Transform::from_translation(Point::unit_y() * 2.0));
+        assert_eq!(work_plane.normal, Point::unit_y());
+        let p = work_plane.to_world(3.0, 4.0);
+        assert_eq!(p, Point::new(4.0, 2.0, 3.0));
+        let (x, y) = work_plane.to_local(p + Point::unit_y() * 5.0);
+        assert!((x - 3.0).abs() < 1e-9 && (y - 4.0).abs() < 1e-9);
+        assert!(work_plane.plane().on_surface(p));
+    }
+}
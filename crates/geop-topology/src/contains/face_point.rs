@@ -1,11 +1,13 @@
 use geop_geometry::{
-    curve_curve_intersection::curve_curve::{curve_curve_intersection, CurveCurveIntersection},
+    curve_curve_intersection::curve_curve::{
+        curve_curve_intersection, is_tangential, CurveCurveIntersection,
+    },
     curves::curve::Curve,
     points::point::Point,
     surfaces::SurfaceLike,
 };
 
-use crate::topology::{edge::Edge, face::Face};
+use crate::topology::{contour::ContourTangent, edge::Edge, face::Face};
 
 use super::edge_point::{edge_point_contains, EdgePointContains};
 
@@ -18,6 +20,35 @@ pub enum FacePointContains {
     NotOnSurface,
 }
 
+// Points along the edges of the border that are tried as the end of the line from the point.
+const BOUNDARY_SAMPLES: [f64; 5] = [0.5, 0.3, 0.7, 0.1, 0.9];
+
+// The line from the point to a point on the border that crosses the border there. A line that touches the border tangentially, like
+// a line along the side of a circle, does not tell from which side it comes. If no sampled point is crossed, q is kept.
+fn crossing_geodesic(face: &Face, point: Point, q: Point) -> (Point, Edge) {
+    let contours = face.boundary.iter().chain(face.holes.iter());
+    let candidates = std::iter::once(q).chain(contours.flat_map(|contour| {
+        contour
+            .edges
+            .iter()
+            .flat_map(|edge| BOUNDARY_SAMPLES.iter().map(|t| edge.interpolate(*t)))
+    }));
+    for candidate in candidates {
+        if candidate == point {
+            continue;
+        }
+        let geodesic = face.edge_from_to(point, candidate);
+        let crossing = match face.boundary_tangent(candidate) {
+            ContourTangent::OnEdge(tangent) => !is_tangential(tangent, geodesic.tangent(candidate)),
+            ContourTangent::OnCorner(_, _) => true,
+        };
+        if crossing {
+            return (candidate, geodesic);
+        }
+    }
+    (q, face.edge_from_to(point, q))
+}
+
 pub fn face_point_contains(face: &Face, point: Point) -> FacePointContains {
     if !face.surface.on_surface(point) {
         return FacePointContains::NotOnSurface;
@@ -38,7 +69,7 @@ pub fn face_point_contains(face: &Face, point: Point) -> FacePointContains {
             return FacePointContains::Inside;
         }
     };
-    let geodesic = face.edge_from_to(point, q);
+    let (q, geodesic) = crossing_geodesic(face, point, q);
 
     // Find the closest intersection point and check by using the face normal and the curve tangent if the intersection is from inside or outside.
     let mut closest_distance = face.surface.distance(point, q);
@@ -51,7 +82,8 @@ pub fn face_point_contains(face: &Face, point: Point) -> FacePointContains {
     for edge in face.all_edges() {
         match curve_curve_intersection(&edge.curve, &geodesic.curve) {
             CurveCurveIntersection::FinitePoints(points) => {
-                for p in points {
+                // Where the line only grazes the border, it stays on the same side of it.
+                for p in points.iter().filter(|p| !p.is_tangent()).map(|p| p.point) {
                     if edge_point_contains(&geodesic, p) != EdgePointContains::Outside {
                         if edge_point_contains(&edge, p) != EdgePointContains::Outside {
                             intersection_points.push(p)
@@ -110,3 +142,27 @@ pub fn face_point_contains(face: &Face, point: Point) -> FacePointContains {
         false => FacePointContains::Outside,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::surfaces::plane::Plane;
+
+    use super::*;
+
+    #[test]
+    fn test_face_point_contains_grazing() {
+        // The line to the border point at (-1, 0) runs along the circle there, so another border point is used.
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let outside_disk = Face::disk(&plane, Point::zero(), 1.0).neg();
+        for p in [Point::new(-1.0, 2.0, 0.0), Point::new(1.0, 2.0, 0.0)] {
+            assert_eq!(
+                face_point_contains(&outside_disk, p),
+                FacePointContains::Inside
+            );
+        }
+        assert_eq!(
+            face_point_contains(&outside_disk, Point::new(0.2, 0.3, 0.0)),
+            FacePointContains::Outside
+        );
+    }
+}
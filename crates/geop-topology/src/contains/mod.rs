@@ -5,3 +5,4 @@ pub mod face_edge;
 pub mod face_point;
 pub mod shell_point;
 pub mod volume_point;
+pub mod winding;
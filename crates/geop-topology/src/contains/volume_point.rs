@@ -50,9 +50,12 @@ pub fn volume_point_contains(volume: &Volume, other: Point) -> VolumePointContai
                 for edge in face.all_edges() {
                     match curve_curve_intersection(&geodesic.curve, &edge.curve) {
                         CurveCurveIntersection::FinitePoints(points) => {
-                            for point in points {
+                            for point in points.iter().map(|p| p.point) {
+                                // The curve of the edge is unbounded, so the point also has to be on the edge itself.
                                 if edge_point_contains(&geodesic, point)
                                     != EdgePointContains::Outside
+                                    && edge_point_contains(&edge, point)
+                                        != EdgePointContains::Outside
                                 {
                                     intersection_points.push(point)
                                 }
@@ -79,11 +82,15 @@ pub fn volume_point_contains(volume: &Volume, other: Point) -> VolumePointContai
                 }
             }
             CurveSurfaceIntersection::Points(points) => {
-                for point in points {
-                    match edge_point_contains(&geodesic, point) {
-                        EdgePointContains::Inside => todo!(),
-                        EdgePointContains::Outside => todo!(),
-                        EdgePointContains::OnPoint(_) => todo!(),
+                for point in points.iter().map(|p| p.point) {
+                    if edge_point_contains(&geodesic, point) == EdgePointContains::Outside {
+                        continue;
+                    }
+                    match face_point_contains(face, point) {
+                        FacePointContains::Inside
+                        | FacePointContains::OnEdge(_)
+                        | FacePointContains::OnPoint(_) => intersection_points.push(point),
+                        FacePointContains::Outside | FacePointContains::NotOnSurface => {}
                     }
                 }
             }
@@ -110,3 +117,44 @@ pub fn volume_point_contains(volume: &Volume, other: Point) -> VolumePointContai
         false => VolumePointContains::Outside,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    use super::*;
+
+    #[test]
+    fn test_volume_point_contains_with_cavity() {
+        let volume = primitive_cube(2.0, 2.0, 2.0).with_cavity(&primitive_cube(1.0, 1.0, 1.0));
+        let contains = |p: Point| volume_point_contains(&volume, p);
+        assert!(matches!(
+            contains(Point::new(0.75, 0.1, 0.2)),
+            VolumePointContains::Inside
+        ));
+        assert!(matches!(
+            contains(Point::new(0.1, 0.2, 0.0)),
+            VolumePointContains::Outside
+        ));
+        assert!(matches!(
+            contains(Point::new(2.0, 0.1, 0.2)),
+            VolumePointContains::Outside
+        ));
+        assert!(matches!(
+            contains(Point::new(0.5, 0.1, 0.2)),
+            VolumePointContains::OnFace(_)
+        ));
+    }
+
+    #[test]
+    fn test_volume_point_contains_ray_in_face_plane() {
+        // The ray to the inner point of the first face lies in the plane of that face and crosses the extension of one of its edges.
+        let volume = primitive_cube(2.0, 2.0, 2.0);
+        let q = volume.all_faces()[0].inner_point();
+        let other = q + Point::new(3.0, 0.0, 3.0);
+        assert!(matches!(
+            volume_point_contains(&volume, other),
+            VolumePointContains::Outside
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    curves::curve::Curve,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+use crate::topology::{edge::Edge, face::Face, volume::Volume};
+
+use super::{
+    edge_point::{edge_point_contains, EdgePointContains},
+    face_point::{face_point_contains, FacePointContains},
+    volume_point::{volume_point_contains, VolumePointContains},
+};
+
+// Number of segments that approximate a curved edge.
+const EDGE_SEGMENTS: usize = 256;
+
+// How containment of points is decided. Ray casting follows a single geodesic and looks at the closest crossing of the boundary,
+// which is fast, but can go wrong when the geodesic grazes a vertex or runs along an edge. The winding number sums up the angles
+// under which the boundary is seen from the point, so it does not depend on any particular direction.
+// The method is passed to each query by the caller. face_point_contains and volume_point_contains, which the booleans use, always cast rays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainmentMethod {
+    RayCast,
+    WindingNumber, // Only for planar faces. Faces with curved surfaces and volumes with curved faces fall back to ray casting.
+}
+
+fn edge_polyline(edge: &Edge) -> Vec<Point> {
+    match (&edge.curve, edge.start, edge.end) {
+        (Curve::Line(_), Some(start), Some(end)) => vec![start, end],
+        _ => (0..=EDGE_SEGMENTS)
+            .map(|i| edge.interpolate(i as f64 / EDGE_SEGMENTS as f64))
+            .collect(),
+    }
+}
+
+// Number of times the boundary of a planar face winds counterclockwise around the point. Holes run clockwise, so points in holes
+// have a winding number of 0. Curved edges are approximated by polylines, so points very close to them may be off.
+// None for faces on curved surfaces, where a closed contour does not separate the surface into an inside and an outside that
+// the angles alone could tell apart, e.g. on a sphere.
+pub fn face_winding_number(face: &Face, point: Point) -> Option<f64> {
+    let normal = match &*face.surface {
+        Surface::Plane(plane) => face.surface.normal(plane.basis),
+        _ => return None,
+    };
+    let mut angle = 0.0;
+    for edge in face.all_edges() {
+        for segment in edge_polyline(&edge).windows(2) {
+            // Point::angle2 snaps small angles to zero, which adds up over many short segments.
+            let (a, b) = (segment[0] - point, segment[1] - point);
+            angle += a.cross(b).dot(normal).atan2(a.dot(b));
+        }
+    }
+    Some(angle / (2.0 * PI))
+}
+
+// Signed solid angle of the triangle seen from the origin (Van Oosterom and Strackee).
+fn triangle_solid_angle(a: Point, b: Point, c: Point) -> f64 {
+    let (la, lb, lc) = (a.norm(), b.norm(), c.norm());
+    let numerator = a.dot(b.cross(c));
+    let denominator = la * lb * lc + a.dot(b) * lc + a.dot(c) * lb + b.dot(c) * la;
+    2.0 * numerator.atan2(denominator)
+}
+
+// Number of times the boundary of a volume with planar faces encloses the point, i.e. the solid angle of the faces seen from the point divided by 4 pi.
+// Every contour is fanned from the same point on its plane, which also takes care of holes in the faces. The fan does not follow
+// curved faces, so volumes with curved faces give None.
+pub fn volume_winding_number(volume: &Volume, point: Point) -> Option<f64> {
+    let mut solid_angle = 0.0;
+    for face in volume.all_faces() {
+        let apex = match &*face.surface {
+            Surface::Plane(plane) => plane.basis - point,
+            _ => return None,
+        };
+        for edge in face.all_edges() {
+            for segment in edge_polyline(&edge).windows(2) {
+                solid_angle += triangle_solid_angle(apex, segment[0] - point, segment[1] - point);
+            }
+        }
+    }
+    Some(solid_angle / (4.0 * PI))
+}
+
+pub fn face_point_contains_with(
+    face: &Face,
+    point: Point,
+    method: ContainmentMethod,
+) -> FacePointContains {
+    let winding_number = match method {
+        ContainmentMethod::RayCast => None,
+        ContainmentMethod::WindingNumber => face_winding_number(face, point),
+    };
+    let winding_number = match winding_number {
+        Some(winding_number) => winding_number,
+        None => return face_point_contains(face, point),
+    };
+    if !face.surface.on_surface(point) {
+        return FacePointContains::NotOnSurface;
+    }
+    for edge in face.all_edges() {
+        match edge_point_contains(&edge, point) {
+            EdgePointContains::Inside => return FacePointContains::OnEdge(edge.clone()),
+            EdgePointContains::OnPoint(point) => return FacePointContains::OnPoint(point),
+            EdgePointContains::Outside => continue,
+        }
+    }
+    if face.boundary.is_none() && face.holes.is_empty() {
+        return FacePointContains::Inside;
+    }
+    // Faces without an outer boundary extend to infinity.
+    let outer = match face.boundary {
+        Some(_) => 0.0,
+        None => 1.0,
+    };
+    match outer + winding_number > 0.5 {
+        true => FacePointContains::Inside,
+        false => FacePointContains::Outside,
+    }
+}
+
+pub fn volume_point_contains_with(
+    volume: &Volume,
+    point: Point,
+    method: ContainmentMethod,
+) -> VolumePointContains {
+    let winding_number = match method {
+        ContainmentMethod::RayCast => None,
+        ContainmentMethod::WindingNumber => volume_winding_number(volume, point),
+    };
+    let winding_number = match winding_number {
+        Some(winding_number) => winding_number,
+        None => return volume_point_contains(volume, point),
+    };
+    for face in volume.all_faces().iter() {
+        match face_point_contains_with(face, point, method) {
+            FacePointContains::Inside => return VolumePointContains::OnFace(face.clone()),
+            FacePointContains::OnEdge(edge) => return VolumePointContains::OnEdge(edge),
+            FacePointContains::OnPoint(point) => return VolumePointContains::OnPoint(point),
+            FacePointContains::Outside | FacePointContains::NotOnSurface => {}
+        }
+    }
+    match winding_number > 0.5 {
+        true => VolumePointContains::Inside,
+        false => VolumePointContains::Outside,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use geop_geometry::{
+        curves::{circle::Circle, line::Line},
+        surfaces::{plane::Plane, sphere::Sphere},
+    };
+
+    use super::*;
+    use crate::{
+        primitive_objects::volumes::cube::primitive_cube,
+        topology::{contour::Contour, shell::Shell},
+    };
+
+    #[test]
+    fn test_face_winding_number() {
+        let surface = Rc::new(Surface::Plane(Plane::new(
+            Point::zero(),
+            Point::unit_x(),
+            Point::unit_y(),
+        )));
+        // An L shape with a reflex vertex at (1, 1) and a round hole.
+        let corners = [
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ];
+        let points: Vec<Point> = corners
+            .iter()
+            .map(|(x, y)| Point::new(*x, *y, 0.0))
+            .collect();
+        let edges = (0..points.len())
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                Edge::new(Some(a), Some(b), Curve::Line(Line::new(a, b - a)))
+            })
+            .collect();
+        let hole = Contour::new(vec![Edge::new(
+            None,
+            None,
+            Curve::Circle(Circle::new(
+                Point::new(0.5, 0.5, 0.0),
+                -Point::unit_z(),
+                0.25,
+            )),
+        )]);
+        let face = Face::new(Some(Contour::new(edges)), vec![hole], surface);
+
+        let contains =
+            |p: Point| face_point_contains_with(&face, p, ContainmentMethod::WindingNumber);
+        assert_eq!(
+            contains(Point::new(0.1, 0.1, 0.0)),
+            FacePointContains::Inside
+        );
+        assert_eq!(
+            contains(Point::new(0.5, 0.5, 0.0)),
+            FacePointContains::Outside
+        );
+        assert_eq!(
+            contains(Point::new(1.5, 1.5, 0.0)),
+            FacePointContains::Outside
+        );
+        assert_eq!(
+            contains(Point::new(1.5, 0.5, 0.0)),
+            FacePointContains::Inside
+        );
+        assert_eq!(
+            contains(Point::new(1.0, 1.0, 0.0)),
+            FacePointContains::OnPoint(Point::new(1.0, 1.0, 0.0))
+        );
+        assert!(
+            (face_winding_number(&face, Point::new(0.5, 1.5, 0.0)).unwrap() - 1.0).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_volume_winding_number() {
+        let volume = primitive_cube(2.0, 2.0, 2.0).with_cavity(&primitive_cube(1.0, 1.0, 1.0));
+        let contains =
+            |p: Point| volume_point_contains_with(&volume, p, ContainmentMethod::WindingNumber);
+        assert!(matches!(
+            contains(Point::new(0.75, 0.1, 0.2)),
+            VolumePointContains::Inside
+        ));
+        assert!(matches!(
+            contains(Point::new(0.1, 0.2, 0.0)),
+            VolumePointContains::Outside
+        ));
+        assert!(matches!(
+            contains(Point::new(2.0, 0.1, 0.2)),
+            VolumePointContains::Outside
+        ));
+        assert!(matches!(
+            contains(Point::new(0.5, 0.1, 0.2)),
+            VolumePointContains::OnFace(_)
+        ));
+        // Points on the diagonal see the corners of the cube exactly edge on.
+        assert!(matches!(
+            contains(Point::new(0.8, 0.8, 0.8)),
+            VolumePointContains::Inside
+        ));
+        assert!(
+            (volume_winding_number(&volume, Point::new(0.75, 0.0, 0.0)).unwrap() - 1.0).abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_winding_number_curved() {
+        // Curved surfaces have no winding number and fall back to ray casting.
+        let sphere = Sphere::new(Point::zero(), 1.0, true);
+        let face = Face::spherical_polygon(
+            &sphere,
+            &[Point::unit_x(), Point::unit_y(), Point::unit_z()],
+        );
+        let inside = Point::new(1.0, 1.0, 1.0).normalize();
+        assert!(face_winding_number(&face, inside).is_none());
+        assert_eq!(
+            face_point_contains_with(&face, inside, ContainmentMethod::WindingNumber),
+            face_point_contains(&face, inside)
+        );
+        assert_eq!(
+            face_point_contains_with(&face, -inside, ContainmentMethod::WindingNumber),
+            FacePointContains::Outside
+        );
+
+        let ball = Volume::new(
+            Shell::new(vec![Face::new(
+                None,
+                vec![],
+                Rc::new(Surface::Sphere(sphere)),
+            )]),
+            vec![],
+        );
+        assert!(volume_winding_number(&ball, Point::zero()).is_none());
+        assert!(matches!(
+            volume_point_contains_with(&ball, Point::zero(), ContainmentMethod::WindingNumber),
+            VolumePointContains::Inside
+        ));
+    }
+}
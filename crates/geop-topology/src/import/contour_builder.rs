@@ -0,0 +1,259 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    curves::{bezier::Bezier, circle::Circle, curve::Curve, line::Line},
+    interner::GeometryInterner,
+    points::point::Point,
+};
+
+use crate::{
+    construction::work_plane::WorkPlane,
+    topology::{contour::Contour, edge::Edge},
+};
+
+// Turns 2d outlines, e.g. from SVG paths or font glyphs, into closed contours on a work plane.
+// Coordinates are multiplied by scale. If flip_y is set, the y axis is flipped, e.g. because SVG has the y axis pointing down.
+// Points go through an interner, so that the ends of edges which meet within tolerance, e.g. after rounding in the file, are the
+// same point and the contours close exactly.
+pub(crate) struct ContourBuilder<'a> {
+    work_plane: &'a WorkPlane,
+    scale: f64,
+    flip_y: bool,
+    pub start: (f64, f64),
+    pub current: (f64, f64),
+    edges: Vec<Edge>,
+    contours: Vec<Contour>,
+    interner: GeometryInterner,
+}
+
+impl<'a> ContourBuilder<'a> {
+    pub fn new(work_plane: &'a WorkPlane, scale: f64, flip_y: bool) -> ContourBuilder<'a> {
+        assert!(scale > 0.0);
+        ContourBuilder {
+            work_plane,
+            scale,
+            flip_y,
+            start: (0.0, 0.0),
+            current: (0.0, 0.0),
+            edges: Vec::new(),
+            contours: Vec::new(),
+            interner: GeometryInterner::new(),
+        }
+    }
+
+    fn point(&mut self, p: (f64, f64)) -> Point {
+        let y = match self.flip_y {
+            true => -p.1,
+            false => p.1,
+        };
+        *self
+            .interner
+            .point(self.work_plane.to_world(p.0 * self.scale, y * self.scale))
+    }
+
+    pub fn move_to(&mut self, p: (f64, f64)) {
+        self.close();
+        self.start = p;
+        self.current = p;
+    }
+
+    pub fn line_to(&mut self, p: (f64, f64)) {
+        let from = self.point(self.current);
+        let to = self.point(p);
+        if from != to {
+            self.edges.push(Edge::new(
+                Some(from),
+                Some(to),
+                Curve::Line(Line::new(from, to - from)),
+            ));
+        }
+        self.current = p;
+    }
+
+    pub fn cubic_to(&mut self, c1: (f64, f64), c2: (f64, f64), p: (f64, f64)) {
+        let p0 = self.point(self.current);
+        let p1 = self.point(c1);
+        let p2 = self.point(c2);
+        let p3 = self.point(p);
+        if p0 == p3 {
+            // A loop that returns to its start point is split in two, since Bezier edges cannot be closed.
+            if p0 != p1 || p0 != p2 {
+                let lerp = |a: Point, b: Point| (a + b) / 2.0;
+                let (a, b, c) = (lerp(p0, p1), lerp(p1, p2), lerp(p2, p3));
+                let (d, e) = (lerp(a, b), lerp(b, c));
+                let m = lerp(d, e);
+                self.push_bezier(Bezier::new(p0, a, d, m));
+                self.push_bezier(Bezier::new(m, e, c, p3));
+            }
+        } else {
+            self.push_bezier(Bezier::new(p0, p1, p2, p3));
+        }
+        self.current = p;
+    }
+
+    // Quadratic Beziers are converted to cubic ones, which is exact.
+    pub fn quad_to(&mut self, q: (f64, f64), p: (f64, f64)) {
+        let current = self.current;
+        let c1 = (
+            current.0 + 2.0 / 3.0 * (q.0 - current.0),
+            current.1 + 2.0 / 3.0 * (q.1 - current.1),
+        );
+        let c2 = (p.0 + 2.0 / 3.0 * (q.0 - p.0), p.1 + 2.0 / 3.0 * (q.1 - p.1));
+        self.cubic_to(c1, c2, p);
+    }
+
+    fn push_bezier(&mut self, bezier: Bezier) {
+        self.edges.push(Edge::new(
+            Some(bezier.p0),
+            Some(bezier.p3),
+            Curve::Bezier(bezier),
+        ));
+    }
+
+    // Elliptical arc as specified in the SVG implementation notes, appendix F.6.
+    pub fn arc_to(
+        &mut self,
+        radii: (f64, f64),
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        p: (f64, f64),
+    ) {
+        let (x1, y1) = self.current;
+        let (x2, y2) = p;
+        let (mut rx, mut ry) = (radii.0.abs(), radii.1.abs());
+        if self.point(self.current) == self.point(p) {
+            return;
+        }
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(p);
+            return;
+        }
+
+        // Conversion from endpoint to center parameterization.
+        let (sin_phi, cos_phi) = rotation.to_radians().sin_cos();
+        let dx = (x1 - x2) / 2.0;
+        let dy = (y1 - y2) / 2.0;
+        let x1p = cos_phi * dx + sin_phi * dy;
+        let y1p = -sin_phi * dx + cos_phi * dy;
+        let lambda = x1p * x1p / (rx * rx) + y1p * y1p / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+        let num = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+        let den = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+        let mut coef = (num / den).max(0.0).sqrt();
+        if large_arc == sweep {
+            coef = -coef;
+        }
+        let cxp = coef * rx * y1p / ry;
+        let cyp = -coef * ry * x1p / rx;
+        let cx = cos_phi * cxp - sin_phi * cyp + (x1 + x2) / 2.0;
+        let cy = sin_phi * cxp + cos_phi * cyp + (y1 + y2) / 2.0;
+
+        if (rx - ry).abs() <= 1e-9 * rx.max(ry) {
+            // Positive angles in SVG are clockwise once the y axis is flipped.
+            let normal = match sweep {
+                true => -self.work_plane.normal,
+                false => self.work_plane.normal,
+            };
+            let from = self.point(self.current);
+            let to = self.point(p);
+            let circle = Circle::new(self.point((cx, cy)), normal, rx * self.scale);
+            self.edges
+                .push(Edge::new(Some(from), Some(to), Curve::Circle(circle)));
+            self.current = p;
+            return;
+        }
+
+        // Non circular arcs are approximated by one cubic Bezier per quarter turn.
+        let theta1 = ((y1p - cyp) / ry).atan2((x1p - cxp) / rx);
+        let theta2 = ((-y1p - cyp) / ry).atan2((-x1p - cxp) / rx);
+        let mut delta = (theta2 - theta1).rem_euclid(2.0 * PI);
+        if !sweep && delta > 0.0 {
+            delta -= 2.0 * PI;
+        }
+        let ellipse = |theta: f64| {
+            let (s, c) = theta.sin_cos();
+            (
+                cx + cos_phi * rx * c - sin_phi * ry * s,
+                cy + sin_phi * rx * c + cos_phi * ry * s,
+            )
+        };
+        let derivative = |theta: f64| {
+            let (s, c) = theta.sin_cos();
+            (
+                -cos_phi * rx * s - sin_phi * ry * c,
+                -sin_phi * rx * s + cos_phi * ry * c,
+            )
+        };
+        let n = (delta.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+        let step = delta / n as f64;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        for i in 0..n {
+            let ta = theta1 + step * i as f64;
+            let tb = ta + step;
+            let a = self.current;
+            let b = match i + 1 == n {
+                true => p,
+                false => ellipse(tb),
+            };
+            let da = derivative(ta);
+            let db = derivative(tb);
+            self.cubic_to(
+                (a.0 + k * da.0, a.1 + k * da.1),
+                (b.0 - k * db.0, b.1 - k * db.1),
+                b,
+            );
+        }
+    }
+
+    // Finishes the current subpath. Open subpaths are closed with a straight line, just like SVG does when filling them.
+    pub fn close(&mut self) {
+        if self.edges.is_empty() {
+            return;
+        }
+        self.line_to(self.start);
+        let edges = std::mem::take(&mut self.edges);
+        self.contours.push(Contour::new(edges));
+    }
+
+    pub fn finish(mut self) -> Vec<Contour> {
+        self.close();
+        self.contours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contour_builder_welds_points() {
+        // The last point is within tolerance of the start, so the contour is closed without a closing line, and ends exactly where
+        // it starts.
+        let work_plane = WorkPlane::xy();
+        let mut builder = ContourBuilder::new(&work_plane, 2.0, false);
+        builder.move_to((0.0, 0.0));
+        builder.line_to((1.0, 0.0));
+        builder.line_to((1.0, 1.0));
+        builder.line_to((1e-9, -1e-9));
+        builder.move_to((1.0, 1.0));
+        builder.line_to((0.0, 1.0));
+        let contours = builder.finish();
+        assert_eq!(contours.len(), 2);
+        assert_eq!(contours[0].edges.len(), 3);
+        let (start, end) = (
+            contours[0].edges[0].start.unwrap(),
+            contours[0].edges[2].end.unwrap(),
+        );
+        assert!(
+            start.x == end.x && start.y == end.y,
+            "Contour is not closed exactly"
+        );
+
+        // The second contour starts at a corner of the first one, which is shared.
+        assert_eq!(contours[1].edges[0].start, Some(Point::new(2.0, 2.0, 0.0)));
+    }
+}
@@ -0,0 +1,219 @@
+use std::rc::Rc;
+
+use geop_geometry::surfaces::surface::Surface;
+use ttf_parser::{GlyphId, OutlineBuilder};
+
+use crate::{
+    construction::work_plane::WorkPlane,
+    measure::mass::contour_polygon,
+    topology::{contour::Contour, face::Face},
+};
+
+use super::contour_builder::ContourBuilder;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontError {
+    pub message: String,
+}
+
+impl std::fmt::Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "FontError: {}", self.message)
+    }
+}
+
+impl std::error::Error for FontError {}
+
+// Feeds the glyph outline of ttf_parser into the contour builder, shifted by the pen position in font units.
+struct GlyphOutline<'a, 'b> {
+    builder: &'a mut ContourBuilder<'b>,
+    pen: f64,
+}
+
+impl GlyphOutline<'_, '_> {
+    fn point(&self, x: f32, y: f32) -> (f64, f64) {
+        (x as f64 + self.pen, y as f64)
+    }
+}
+
+impl OutlineBuilder for GlyphOutline<'_, '_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let p = self.point(x, y);
+        self.builder.move_to(p);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let p = self.point(x, y);
+        self.builder.line_to(p);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let q = self.point(x1, y1);
+        let p = self.point(x, y);
+        self.builder.quad_to(q, p);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let c1 = self.point(x1, y1);
+        let c2 = self.point(x2, y2);
+        let p = self.point(x, y);
+        self.builder.cubic_to(c1, c2, p);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
+// Groups the outlines of glyphs into faces on the work plane, whatever their orientation in the font. Contours nested in
+// an even number of other contours bound faces and run counterclockwise around the normal of the work plane, the others are
+// holes of the smallest contour around them and run clockwise. This is the even-odd rule, which agrees with the nonzero rule of
+// fonts for outlines that do not overlap.
+fn contours_to_faces(contours: Vec<Contour>, work_plane: &WorkPlane) -> Vec<Face> {
+    let surface = Rc::new(Surface::Plane(work_plane.plane()));
+    let mut contours: Vec<_> = contours
+        .into_iter()
+        .map(|contour| {
+            let polygon: Vec<(f64, f64)> = contour_polygon(&contour)
+                .into_iter()
+                .map(|p| work_plane.to_local(p))
+                .collect();
+            (polygon_signed_area(&polygon), polygon, contour)
+        })
+        .filter(|(area, _, _)| area.abs() > 0.0)
+        .collect();
+    contours.sort_by(|a, b| b.0.abs().total_cmp(&a.0.abs()));
+
+    // Contours can only be inside of larger ones, so the parent is the last larger one around the contour.
+    let mut parents = Vec::<Option<usize>>::new();
+    let mut depths = Vec::<usize>::new();
+    for i in 0..contours.len() {
+        let parent = (0..i)
+            .rev()
+            .find(|j| polygon_contains(&contours[*j].1, contours[i].1[0]));
+        depths.push(parent.map_or(0, |j| depths[j] + 1));
+        parents.push(parent);
+    }
+
+    let mut faces = Vec::<(usize, Contour, Vec<Contour>)>::new();
+    for (i, (area, _, contour)) in contours.into_iter().enumerate() {
+        let outer = depths[i].is_multiple_of(2);
+        let contour = match (area > 0.0) == outer {
+            true => contour,
+            false => contour.flip(),
+        };
+        match outer {
+            true => faces.push((i, contour, Vec::new())),
+            false => {
+                let parent = parents[i].unwrap();
+                let face = faces.iter_mut().find(|(j, _, _)| *j == parent).unwrap();
+                face.2.push(contour);
+            }
+        }
+    }
+    faces
+        .into_iter()
+        .map(|(_, boundary, holes)| Face::new(Some(boundary), holes, Rc::clone(&surface)))
+        .collect()
+}
+
+// Signed area of a polygon in work plane coordinates, positive if it runs counterclockwise.
+fn polygon_signed_area(polygon: &[(f64, f64)]) -> f64 {
+    let mut area = 0.0;
+    for i in 0..polygon.len() {
+        let (p, q) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+        area += p.0 * q.1 - q.0 * p.1;
+    }
+    area / 2.0
+}
+
+// Whether the point is inside of the polygon, by the number of its edges that a ray along x crosses.
+fn polygon_contains(polygon: &[(f64, f64)], p: (f64, f64)) -> bool {
+    let mut inside = false;
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+        if (a.1 > p.1) != (b.1 > p.1) && p.0 < a.0 + (p.1 - a.1) / (b.1 - a.1) * (b.0 - a.0) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+fn parse_face(font_data: &[u8]) -> Result<ttf_parser::Face<'_>, FontError> {
+    ttf_parser::Face::parse(font_data, 0).map_err(|e| FontError {
+        message: format!("Could not parse font: {}", e),
+    })
+}
+
+// Converts a line of text into faces on the work plane, starting at the origin on the baseline and running along x_dir.
+// size is the height of an em in world units. Characters that are missing in the font are replaced by the .notdef glyph.
+// The faces point along the normal of the work plane, with counterclockwise boundaries and clockwise holes, whether the font is
+// TrueType with clockwise outer contours or CFF with counterclockwise ones. So they can be extruded directly.
+pub fn text_to_faces(
+    font_data: &[u8],
+    text: &str,
+    work_plane: &WorkPlane,
+    size: f64,
+) -> Result<Vec<Face>, FontError> {
+    let face = parse_face(font_data)?;
+    let scale = size / face.units_per_em() as f64;
+    let mut builder = ContourBuilder::new(work_plane, scale, false);
+    let mut pen = 0.0;
+    for c in text.chars() {
+        let glyph = face.glyph_index(c).unwrap_or(GlyphId(0));
+        // Glyphs without outline, like spaces, only advance the pen.
+        face.outline_glyph(
+            glyph,
+            &mut GlyphOutline {
+                builder: &mut builder,
+                pen,
+            },
+        );
+        pen += face.glyph_hor_advance(glyph).unwrap_or(0) as f64;
+    }
+    Ok(contours_to_faces(builder.finish(), work_plane))
+}
+
+// Converts a single character into faces on the work plane, with its origin on the baseline at the work plane origin.
+pub fn glyph_to_faces(
+    font_data: &[u8],
+    c: char,
+    work_plane: &WorkPlane,
+    size: f64,
+) -> Result<Vec<Face>, FontError> {
+    let face = parse_face(font_data)?;
+    if face.glyph_index(c).is_none() {
+        return Err(FontError {
+            message: format!("Font has no glyph for {:?}", c),
+        });
+    }
+    text_to_faces(font_data, &c.to_string(), work_plane, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::points::point::Point;
+
+    use crate::measure::mass::measure_area;
+
+    use super::*;
+
+    // The test font of ttf-parser, with an "A" and a .notdef glyph, which both have one hole.
+    const DEMO_FONT: &[u8] = include_bytes!("test_fonts/demo.ttf");
+
+    #[test]
+    fn test_glyph_to_faces() {
+        // The outer contour of the TrueType "A" runs clockwise, and is turned around to point along the normal.
+        let faces = glyph_to_faces(DEMO_FONT, 'A', &WorkPlane::xy(), 1.0).unwrap();
+        assert_eq!(faces.len(), 1);
+        assert_eq!(faces[0].holes.len(), 1);
+        assert!((measure_area(&faces[0]) - 0.115008).abs() < 1e-9);
+        assert_eq!(faces[0].normal(faces[0].inner_point()), Point::unit_z());
+
+        // Missing characters are an error for single glyphs, and the .notdef box in text.
+        assert!(glyph_to_faces(DEMO_FONT, 'B', &WorkPlane::xy(), 1.0).is_err());
+        let faces = text_to_faces(DEMO_FONT, "B", &WorkPlane::xy(), 1.0).unwrap();
+        assert_eq!(faces.len(), 1);
+        assert!((measure_area(&faces[0]) - (0.35 - 0.2604)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,4 @@
+mod contour_builder;
+#[cfg(feature = "font")]
+pub mod font;
+pub mod svg;
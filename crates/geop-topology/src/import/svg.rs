@@ -0,0 +1,298 @@
+use crate::{construction::work_plane::WorkPlane, topology::contour::Contour};
+
+use super::contour_builder::ContourBuilder;
+
+// Scale factors from SVG user units to millimeters. SVG assumes 96 pixels per inch.
+pub const SVG_PX_TO_MM: f64 = 25.4 / 96.0;
+pub const SVG_PT_TO_MM: f64 = 25.4 / 72.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgPathError {
+    pub message: String,
+    pub position: usize, // Byte offset in the path data
+}
+
+impl std::fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SvgPathError at {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for SvgPathError {}
+
+struct PathParser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PathParser<'a> {
+    fn new(data: &'a str) -> PathParser<'a> {
+        PathParser {
+            data: data.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn error(&self, message: &str) -> SvgPathError {
+        SvgPathError {
+            message: message.to_string(),
+            position: self.pos,
+        }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.pos < self.data.len()
+            && (self.data[self.pos].is_ascii_whitespace() || self.data[self.pos] == b',')
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn is_done(&mut self) -> bool {
+        self.skip_separators();
+        self.pos >= self.data.len()
+    }
+
+    fn command(&mut self) -> Result<u8, SvgPathError> {
+        self.skip_separators();
+        match self.data.get(self.pos) {
+            Some(c) if c.is_ascii_alphabetic() => {
+                self.pos += 1;
+                Ok(*c)
+            }
+            _ => Err(self.error("Expected a command")),
+        }
+    }
+
+    // Whether another set of arguments follows, i.e. the previous command is repeated implicitly.
+    fn has_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(
+            self.data.get(self.pos),
+            Some(c) if c.is_ascii_digit() || *c == b'.' || *c == b'-' || *c == b'+'
+        )
+    }
+
+    fn number(&mut self) -> Result<f64, SvgPathError> {
+        self.skip_separators();
+        let start = self.pos;
+        let digits = |parser: &mut PathParser| {
+            let begin = parser.pos;
+            while parser.pos < parser.data.len() && parser.data[parser.pos].is_ascii_digit() {
+                parser.pos += 1;
+            }
+            parser.pos > begin
+        };
+        if matches!(self.data.get(self.pos), Some(b'-') | Some(b'+')) {
+            self.pos += 1;
+        }
+        let mut has_digits = digits(self);
+        // A second dot starts a new number, e.g. "0.5.5" is 0.5 followed by 0.5.
+        if self.data.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            has_digits |= digits(self);
+        }
+        if !has_digits {
+            self.pos = start;
+            return Err(self.error("Expected a number"));
+        }
+        if matches!(self.data.get(self.pos), Some(b'e') | Some(b'E')) {
+            let exponent = self.pos;
+            self.pos += 1;
+            if matches!(self.data.get(self.pos), Some(b'-') | Some(b'+')) {
+                self.pos += 1;
+            }
+            if !digits(self) {
+                self.pos = exponent;
+            }
+        }
+        let text = std::str::from_utf8(&self.data[start..self.pos]).unwrap();
+        text.parse::<f64>().map_err(|_| SvgPathError {
+            message: format!("Invalid number {}", text),
+            position: start,
+        })
+    }
+
+    // Arc flags are single digits and may be written without separators, e.g. "a1 1 0 0110 10".
+    fn flag(&mut self) -> Result<bool, SvgPathError> {
+        self.skip_separators();
+        match self.data.get(self.pos) {
+            Some(b'0') => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.pos += 1;
+                Ok(true)
+            }
+            _ => Err(self.error("Expected a flag")),
+        }
+    }
+
+    fn pair(&mut self) -> Result<(f64, f64), SvgPathError> {
+        Ok((self.number()?, self.number()?))
+    }
+}
+
+fn reflect(control: (f64, f64), about: (f64, f64)) -> (f64, f64) {
+    (2.0 * about.0 - control.0, 2.0 * about.1 - control.1)
+}
+
+// Parses SVG path data, i.e. the d attribute of a path element, into closed contours on the work plane.
+// Supports all path commands (M, L, H, V, C, S, Q, T, A, Z) in absolute and relative form. Quadratic Beziers are converted to cubic ones.
+// scale converts SVG user units to world units, e.g. SVG_PX_TO_MM to import pixel artwork in millimeters.
+pub fn svg_path_to_contours(
+    data: &str,
+    work_plane: &WorkPlane,
+    scale: f64,
+) -> Result<Vec<Contour>, SvgPathError> {
+    let mut parser = PathParser::new(data);
+    let mut builder = ContourBuilder::new(work_plane, scale, true);
+    // Control point of the previous command, which is reflected by the smooth curve commands S and T.
+    let mut last_cubic: Option<(f64, f64)> = None;
+    let mut last_quadratic: Option<(f64, f64)> = None;
+
+    let mut command = parser.command()?;
+    if command != b'M' && command != b'm' {
+        return Err(parser.error("Path data has to start with a move command"));
+    }
+    loop {
+        let relative = command.is_ascii_lowercase();
+        let offset = |p: (f64, f64), current: (f64, f64)| match relative {
+            true => (p.0 + current.0, p.1 + current.1),
+            false => p,
+        };
+        let current = builder.current;
+        let mut next_cubic = None;
+        let mut next_quadratic = None;
+        match command.to_ascii_uppercase() {
+            b'M' => {
+                let p = offset(parser.pair()?, current);
+                builder.move_to(p);
+                // Further coordinate pairs are implicit line commands.
+                command = match relative {
+                    true => b'l',
+                    false => b'L',
+                };
+            }
+            b'L' => builder.line_to(offset(parser.pair()?, current)),
+            b'H' => {
+                let x = parser.number()?;
+                let x = match relative {
+                    true => current.0 + x,
+                    false => x,
+                };
+                builder.line_to((x, current.1));
+            }
+            b'V' => {
+                let y = parser.number()?;
+                let y = match relative {
+                    true => current.1 + y,
+                    false => y,
+                };
+                builder.line_to((current.0, y));
+            }
+            b'C' => {
+                let c1 = offset(parser.pair()?, current);
+                let c2 = offset(parser.pair()?, current);
+                let p = offset(parser.pair()?, current);
+                builder.cubic_to(c1, c2, p);
+                next_cubic = Some(c2);
+            }
+            b'S' => {
+                let c1 = last_cubic.map_or(current, |c| reflect(c, current));
+                let c2 = offset(parser.pair()?, current);
+                let p = offset(parser.pair()?, current);
+                builder.cubic_to(c1, c2, p);
+                next_cubic = Some(c2);
+            }
+            b'Q' | b'T' => {
+                let q = match command.to_ascii_uppercase() {
+                    b'Q' => offset(parser.pair()?, current),
+                    _ => last_quadratic.map_or(current, |c| reflect(c, current)),
+                };
+                let p = offset(parser.pair()?, current);
+                builder.quad_to(q, p);
+                next_quadratic = Some(q);
+            }
+            b'A' => {
+                let radii = parser.pair()?;
+                let rotation = parser.number()?;
+                let large_arc = parser.flag()?;
+                let sweep = parser.flag()?;
+                let p = offset(parser.pair()?, current);
+                builder.arc_to(radii, rotation, large_arc, sweep, p);
+            }
+            b'Z' => {
+                builder.close();
+                builder.current = builder.start;
+            }
+            _ => return Err(parser.error("Unknown command")),
+        }
+        last_cubic = next_cubic;
+        last_quadratic = next_quadratic;
+
+        if parser.is_done() {
+            break;
+        }
+        if command.eq_ignore_ascii_case(&b'Z') || !parser.has_number() {
+            command = parser.command()?;
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geop_geometry::{
+        curves::{curve::Curve, CurveLike},
+        points::point::Point,
+    };
+
+    #[test]
+    fn test_svg_path_to_contours() {
+        let work_plane = WorkPlane::xy();
+
+        // Two squares, the second one relative and with implicit line commands.
+        let contours =
+            svg_path_to_contours("M0,0 H10 V10 H0 Z m20 0 10 0 0 10 -10 0z", &work_plane, 1.0)
+                .unwrap();
+        assert_eq!(contours.len(), 2);
+        assert_eq!(contours[0].edges.len(), 4);
+        assert_eq!(contours[1].edges.len(), 4);
+        // The y axis is flipped.
+        assert_eq!(contours[0].edges[1].end, Some(Point::new(10.0, -10.0, 0.0)));
+        assert_eq!(contours[1].edges[0].start, Some(Point::new(20.0, 0.0, 0.0)));
+
+        // A half circle with a flag written without separators, closed implicitly.
+        let contours = svg_path_to_contours("M0 0a5 5 0 0110 0", &work_plane, 2.0).unwrap();
+        assert_eq!(contours.len(), 1);
+        assert_eq!(contours[0].edges.len(), 2);
+        match &contours[0].edges[0].curve {
+            Curve::Circle(circle) => {
+                assert_eq!(circle.basis, Point::new(10.0, 0.0, 0.0));
+                assert!((circle.radius.norm() - 10.0).abs() < 1e-9);
+            }
+            _ => panic!("Expected a circle"),
+        }
+        // Sweeping in positive direction runs over the top of the artwork.
+        assert_eq!(
+            contours[0].edges[0].get_midpoint(),
+            Point::new(10.0, 10.0, 0.0)
+        );
+
+        // An elliptical arc is approximated by Beziers.
+        let contours =
+            svg_path_to_contours("M0 0 A10 5 0 0 1 20 0 Q 10 -10 0 0", &work_plane, 1.0).unwrap();
+        assert_eq!(contours[0].edges.len(), 3);
+        let top = contours[0].edges[0]
+            .curve
+            .project(Point::new(10.0, 10.0, 0.0))
+            .point;
+        assert!((top - Point::new(10.0, 5.0, 0.0)).norm() < 1e-2);
+
+        assert!(svg_path_to_contours("L 10 10", &work_plane, 1.0).is_err());
+        assert!(svg_path_to_contours("M 0 0 L 10", &work_plane, 1.0).is_err());
+    }
+}
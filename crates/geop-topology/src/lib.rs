@@ -1,9 +1,19 @@
 pub mod debug_data;
 pub mod topology;
 
+pub mod analysis;
+pub mod collision;
+pub mod construction;
 pub mod contains;
+pub mod import;
+pub mod measure;
+pub mod nesting;
 pub mod operations;
 pub mod primitive_objects;
+pub mod progress;
+pub mod rng;
+pub mod selection;
+pub mod snap;
 
 // use topology::scene::Scene;
 
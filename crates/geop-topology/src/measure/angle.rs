@@ -0,0 +1,126 @@
+use geop_geometry::{curves::curve::Curve, points::point::Point, surfaces::surface::Surface};
+
+use super::MeasureEntity;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AngleKind {
+    LineLine, // Angle between the directions of two straight edges
+    LineFace, // Angle between a straight edge and a planar face
+    FaceFace, // Angle between the normals of two planar faces
+}
+
+#[derive(Clone, Debug)]
+pub struct AngleMeasurement {
+    pub kind: AngleKind,
+    pub angle: f64,         // In radians
+    pub direction_a: Point, // Line direction or face normal of the first entity
+    pub direction_b: Point, // Line direction or face normal of the second entity
+}
+
+enum Direction {
+    Line(Point),
+    Normal(Point),
+}
+
+fn entity_direction(entity: &MeasureEntity) -> Option<Direction> {
+    match entity {
+        MeasureEntity::Edge(edge) => match &edge.curve {
+            Curve::Line(line) => Some(Direction::Line(line.direction.normalize())),
+            _ => None,
+        },
+        MeasureEntity::Face(face) => match &*face.surface {
+            Surface::Plane(plane) => Some(Direction::Normal(
+                plane.u_slope.cross(plane.v_slope).normalize(),
+            )),
+            _ => None,
+        },
+        MeasureEntity::Point(_) => None,
+    }
+}
+
+// Measures the angle between straight edges and planar faces.
+// Between two lines or two faces it is the angle between the directions / normals, in [0, pi].
+// Between a line and a face it is the angle between the line and the plane, in [-pi/2, pi/2]. It is positive if the line points in the same direction as the normal.
+// Returns None if the angle is not defined for the given entities, e.g. for curved edges or faces.
+pub fn measure_angle(a: &MeasureEntity, b: &MeasureEntity) -> Option<AngleMeasurement> {
+    let (kind, angle, direction_a, direction_b) = match (entity_direction(a)?, entity_direction(b)?)
+    {
+        (Direction::Line(da), Direction::Line(db)) => (AngleKind::LineLine, da.angle(db), da, db),
+        (Direction::Normal(na), Direction::Normal(nb)) => {
+            (AngleKind::FaceFace, na.angle(nb), na, nb)
+        }
+        (Direction::Line(da), Direction::Normal(nb)) => (
+            AngleKind::LineFace,
+            std::f64::consts::FRAC_PI_2 - da.angle(nb),
+            da,
+            nb,
+        ),
+        (Direction::Normal(na), Direction::Line(db)) => (
+            AngleKind::LineFace,
+            std::f64::consts::FRAC_PI_2 - db.angle(na),
+            na,
+            db,
+        ),
+    };
+    Some(AngleMeasurement {
+        kind,
+        angle,
+        direction_a,
+        direction_b,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use geop_geometry::EQ_THRESHOLD;
+
+    use crate::{
+        primitive_objects::{
+            edges::{circle::primitive_circle, line::primitive_line},
+            volumes::cube::primitive_cube,
+        },
+        topology::face::Face,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_measure_angle() {
+        let x = primitive_line(Point::zero(), Point::new(1.0, 0.0, 0.0));
+        let diagonal = primitive_line(Point::zero(), Point::new(1.0, 1.0, 0.0));
+        let m = measure_angle(&MeasureEntity::Edge(&x), &MeasureEntity::Edge(&diagonal)).unwrap();
+        assert_eq!(m.kind, AngleKind::LineLine);
+        assert!((m.angle - FRAC_PI_4).abs() < EQ_THRESHOLD);
+
+        // Faces of a cube meet at right angles, and opposite faces point in opposite directions.
+        let faces = primitive_cube(1.0, 1.0, 1.0).all_faces();
+        let normal = |f: &Face| f.normal(f.inner_point());
+        let top = faces.iter().find(|f| normal(f).z > 0.5).unwrap();
+        let bottom = faces.iter().find(|f| normal(f).z < -0.5).unwrap();
+        let side = faces.iter().find(|f| normal(f).x > 0.5).unwrap();
+        let m = measure_angle(&MeasureEntity::Face(top), &MeasureEntity::Face(side)).unwrap();
+        assert_eq!(m.kind, AngleKind::FaceFace);
+        assert!((m.angle - FRAC_PI_2).abs() < EQ_THRESHOLD);
+        let m = measure_angle(&MeasureEntity::Face(top), &MeasureEntity::Face(bottom)).unwrap();
+        assert!((m.angle - std::f64::consts::PI).abs() < EQ_THRESHOLD);
+
+        // A line along the normal of a face is perpendicular to its plane, and a line in the plane is at angle 0.
+        let up = primitive_line(Point::zero(), Point::new(0.0, 0.0, 2.0));
+        let m = measure_angle(&MeasureEntity::Edge(&up), &MeasureEntity::Face(top)).unwrap();
+        assert_eq!(m.kind, AngleKind::LineFace);
+        assert!((m.angle - FRAC_PI_2).abs() < EQ_THRESHOLD);
+        let m = measure_angle(&MeasureEntity::Face(bottom), &MeasureEntity::Edge(&x)).unwrap();
+        assert!(m.angle.abs() < EQ_THRESHOLD);
+
+        // Curved edges and points have no direction.
+        let circle = primitive_circle(Point::zero(), Point::unit_z(), 1.0);
+        assert!(measure_angle(&MeasureEntity::Edge(&circle), &MeasureEntity::Edge(&x)).is_none());
+        assert!(measure_angle(
+            &MeasureEntity::Point(Point::zero()),
+            &MeasureEntity::Face(top)
+        )
+        .is_none());
+    }
+}
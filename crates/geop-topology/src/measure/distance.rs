@@ -0,0 +1,193 @@
+use geop_geometry::{points::point::Point, surfaces::SurfaceLike, EQ_THRESHOLD};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    topology::{edge::Edge, face::Face},
+};
+
+use super::MeasureEntity;
+
+// Number of samples taken along each edge to find starting points for the minimization.
+const EDGE_SAMPLES: usize = 16;
+// Maximum number of alternating projections per starting point.
+const MAX_ITERATIONS: usize = 100;
+
+#[derive(Clone, Debug)]
+pub struct DistanceMeasurement {
+    pub distance: f64,
+    pub witness_a: Point, // Closest point on the first entity
+    pub witness_b: Point, // Closest point on the second entity
+}
+
+impl DistanceMeasurement {
+    fn new(witness_a: Point, witness_b: Point) -> DistanceMeasurement {
+        DistanceMeasurement {
+            distance: (witness_a - witness_b).norm(),
+            witness_a,
+            witness_b,
+        }
+    }
+
+    fn swap(self) -> DistanceMeasurement {
+        DistanceMeasurement {
+            distance: self.distance,
+            witness_a: self.witness_b,
+            witness_b: self.witness_a,
+        }
+    }
+}
+
+// Returns the point on the edge that is closest to the given point.
+pub fn edge_point_closest(edge: &Edge, point: Point) -> Point {
+    edge.project(point).point
+}
+
+// Returns the point on the face that is closest to the given point.
+pub fn face_point_closest(face: &Face, point: Point) -> Point {
+    let projected = face.surface.project(point);
+    match face_point_contains(face, projected) {
+        FacePointContains::Inside
+        | FacePointContains::OnEdge(_)
+        | FacePointContains::OnPoint(_) => return projected,
+        FacePointContains::Outside | FacePointContains::NotOnSurface => {}
+    }
+
+    let mut closest: Option<Point> = None;
+    for edge in face.all_edges() {
+        let candidate = edge_point_closest(&edge, point);
+        closest = match closest {
+            Some(c) if (c - point).norm() <= (candidate - point).norm() => Some(c),
+            _ => Some(candidate),
+        };
+    }
+    match closest {
+        Some(closest) => closest,
+        // A face without boundary covers the whole surface, the projection is not on the surface only for degenerate points like the center of a sphere.
+        None => face.surface.point_grid(1.0)[0],
+    }
+}
+
+fn entity_point_closest(entity: &MeasureEntity, point: Point) -> Point {
+    match entity {
+        MeasureEntity::Point(p) => *p,
+        MeasureEntity::Edge(edge) => edge_point_closest(edge, point),
+        MeasureEntity::Face(face) => face_point_closest(face, point),
+    }
+}
+
+fn edge_samples(edge: &Edge) -> Vec<Point> {
+    (0..=EDGE_SAMPLES)
+        .map(|i| edge.interpolate(i as f64 / EDGE_SAMPLES as f64))
+        .collect()
+}
+
+fn entity_samples(entity: &MeasureEntity) -> Vec<Point> {
+    match entity {
+        MeasureEntity::Point(p) => vec![*p],
+        MeasureEntity::Edge(edge) => edge_samples(edge),
+        MeasureEntity::Face(face) => {
+            let mut samples = Vec::<Point>::new();
+            for edge in face.all_edges() {
+                samples.extend(edge_samples(&edge));
+            }
+            if samples.is_empty() {
+                samples.extend(face.surface.point_grid(1.0));
+            }
+            samples
+        }
+    }
+}
+
+// Finds a local minimum of the distance between both entities by alternating projections, starting on the given point on a.
+fn refine(a: &MeasureEntity, b: &MeasureEntity, start: Point) -> DistanceMeasurement {
+    let mut pa = start;
+    let mut pb = entity_point_closest(b, pa);
+    for _ in 0..MAX_ITERATIONS {
+        let next_a = entity_point_closest(a, pb);
+        let next_b = entity_point_closest(b, next_a);
+        let converged = (next_a - pa).norm() < EQ_THRESHOLD && (next_b - pb).norm() < EQ_THRESHOLD;
+        pa = next_a;
+        pb = next_b;
+        if converged {
+            break;
+        }
+    }
+    DistanceMeasurement::new(pa, pb)
+}
+
+// Measures the minimal distance between two entities and returns the points where it is attained.
+// For points against anything the result is exact. For edges and faces the distance is found by starting alternating projections from samples on both entities, so for non-convex configurations it is the best local minimum that was found.
+pub fn measure_distance(a: &MeasureEntity, b: &MeasureEntity) -> DistanceMeasurement {
+    match (a, b) {
+        (MeasureEntity::Point(pa), _) => {
+            return DistanceMeasurement::new(*pa, entity_point_closest(b, *pa));
+        }
+        (_, MeasureEntity::Point(pb)) => {
+            return DistanceMeasurement::new(entity_point_closest(a, *pb), *pb);
+        }
+        _ => {}
+    }
+
+    let mut best: Option<DistanceMeasurement> = None;
+    let candidates = entity_samples(a)
+        .into_iter()
+        .map(|s| refine(a, b, s))
+        .chain(
+            entity_samples(b)
+                .into_iter()
+                .map(|s| refine(b, a, s).swap()),
+        );
+    for candidate in candidates {
+        best = match best {
+            Some(best) if best.distance <= candidate.distance => Some(best),
+            _ => Some(candidate),
+        };
+    }
+    best.expect("Entities always have samples")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::{
+        edges::{circle::primitive_circle, line::primitive_line},
+        faces::rectangle::primitive_rectangle,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_measure_distance() {
+        let line = primitive_line(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0));
+        let m = measure_distance(
+            &MeasureEntity::Point(Point::new(2.0, 1.0, 0.0)),
+            &MeasureEntity::Edge(&line),
+        );
+        assert!((m.distance - 2.0_f64.sqrt()).abs() < EQ_THRESHOLD);
+        assert_eq!(m.witness_b, Point::new(1.0, 0.0, 0.0));
+        let closest = line.project(Point::new(2.0, 1.0, 0.0));
+        assert!(!closest.inside_bounds);
+        assert!((closest.parameter - 1.0).abs() < EQ_THRESHOLD);
+        assert!(line.project(Point::new(0.5, 1.0, 0.0)).inside_bounds);
+
+        let circle = primitive_circle(Point::new(0.0, 0.0, 0.0), Point::unit_z(), 1.0);
+        let m = measure_distance(
+            &MeasureEntity::Point(Point::zero()),
+            &MeasureEntity::Edge(&circle),
+        );
+        assert!((m.distance - 1.0).abs() < EQ_THRESHOLD);
+
+        let other = primitive_line(Point::new(0.5, 1.0, 2.0), Point::new(0.5, -1.0, 2.0));
+        let m = measure_distance(&MeasureEntity::Edge(&line), &MeasureEntity::Edge(&other));
+        assert!((m.distance - 2.0).abs() < EQ_THRESHOLD);
+        assert_eq!(m.witness_a, Point::new(0.5, 0.0, 0.0));
+        assert_eq!(m.witness_b, Point::new(0.5, 0.0, 2.0));
+
+        let face = primitive_rectangle(Point::zero(), Point::unit_x(), Point::unit_y());
+        let m = measure_distance(
+            &MeasureEntity::Point(Point::new(0.5, 0.5, 3.0)),
+            &MeasureEntity::Face(&face),
+        );
+        assert!((m.distance - 3.0).abs() < EQ_THRESHOLD);
+        assert_eq!(m.witness_b, Point::new(0.5, 0.5, 0.0));
+    }
+}
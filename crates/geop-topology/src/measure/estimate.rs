@@ -0,0 +1,213 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    bounding_box::BoundingBox,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+use crate::{
+    contains::{
+        face_point::{face_point_contains, FacePointContains},
+        volume_point::{volume_point_contains, VolumePointContains},
+    },
+    rng::Rng,
+    topology::{
+        face::{bounds::face_sample_points, Face},
+        volume::Volume,
+    },
+};
+
+// Monte Carlo estimates of mass properties. They only need containment queries, so they work for every surface combination that
+// containment supports, which makes them a useful cross-check for the exact formulas in tests.
+
+// Samples are drawn in batches of this size until the standard error is small enough.
+const BATCH_SIZE: usize = 1000;
+
+#[derive(Debug, Clone)]
+pub struct Estimate {
+    pub value: f64,
+    // Standard error of the value. The exact value is within 3 standard errors with a probability of 99.7%.
+    pub standard_error: f64,
+    pub samples: usize,
+}
+
+// Estimates the fraction of hits of a sampler and scales it by the measure of the sampled domain. Stops as soon as the standard error
+// is below the target, or after max_samples.
+fn estimate_fraction(
+    domain: f64,
+    target_error: f64,
+    max_samples: usize,
+    mut hit: impl FnMut() -> bool,
+) -> Estimate {
+    let mut hits = 0;
+    let mut samples = 0;
+    loop {
+        for _ in 0..BATCH_SIZE {
+            if hit() {
+                hits += 1;
+            }
+        }
+        samples += BATCH_SIZE;
+        let fraction = hits as f64 / samples as f64;
+        let standard_error = domain * (fraction * (1.0 - fraction) / samples as f64).sqrt();
+        if standard_error <= target_error || samples >= max_samples {
+            return Estimate {
+                value: domain * fraction,
+                standard_error,
+                samples,
+            };
+        }
+    }
+}
+
+// Estimates the volume by the fraction of random points in the bounding box that are inside.
+pub fn estimate_volume(
+    volume: &Volume,
+    target_error: f64,
+    max_samples: usize,
+    seed: u64,
+) -> Estimate {
+    let points: Vec<Point> = volume
+        .all_faces()
+        .iter()
+        .flat_map(face_sample_points)
+        .collect();
+    let mut bounds = BoundingBox::new(points[0], points[0]);
+    for p in points.iter() {
+        bounds.add_point(*p);
+    }
+    let size = bounds.max - bounds.min;
+    let mut rng = Rng::new(seed);
+    estimate_fraction(size.x * size.y * size.z, target_error, max_samples, || {
+        let p = Point::new(
+            rng.range(bounds.min.x, bounds.max.x),
+            rng.range(bounds.min.y, bounds.max.y),
+            rng.range(bounds.min.z, bounds.max.z),
+        );
+        !matches!(
+            volume_point_contains(volume, p),
+            VolumePointContains::Outside
+        )
+    })
+}
+
+// Estimates the area of a face by the fraction of random points on the surface that are inside of the face. Planar faces are sampled
+// in the rectangle around their boundary, spherical faces on the whole sphere, and cylindrical faces on the band of the cylinder
+// between the lowest and highest point of their boundary.
+pub fn estimate_area(face: &Face, target_error: f64, max_samples: usize, seed: u64) -> Estimate {
+    let mut rng = Rng::new(seed);
+    let inside = |p: Point| face_point_contains(face, p) != FacePointContains::Outside;
+    match &*face.surface {
+        Surface::Plane(plane) => {
+            let u = plane.u_slope.normalize();
+            let v = face.surface.normal(plane.basis).cross(u);
+            let local: Vec<(f64, f64)> = face_sample_points(face)
+                .iter()
+                .map(|p| ((*p - plane.basis).dot(u), (*p - plane.basis).dot(v)))
+                .collect();
+            let min_u = local.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+            let max_u = local.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+            let min_v = local.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+            let max_v = local.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+            estimate_fraction(
+                (max_u - min_u) * (max_v - min_v),
+                target_error,
+                max_samples,
+                || inside(plane.basis + u * rng.range(min_u, max_u) + v * rng.range(min_v, max_v)),
+            )
+        }
+        Surface::Sphere(sphere) => estimate_fraction(
+            4.0 * PI * sphere.radius * sphere.radius,
+            target_error,
+            max_samples,
+            || {
+                // Uniform on the sphere by Archimedes' hat-box theorem.
+                let z = rng.range(-1.0, 1.0);
+                let angle = rng.range(0.0, 2.0 * PI);
+                let r = (1.0 - z * z).sqrt();
+                inside(
+                    sphere.basis + Point::new(r * angle.cos(), r * angle.sin(), z) * sphere.radius,
+                )
+            },
+        ),
+        Surface::Cylinder(cylinder) => {
+            // The angle and the height are uniform on the cylinder, since its area element does not depend on them.
+            let heights: Vec<f64> = face_sample_points(face)
+                .iter()
+                .map(|p| face.surface.parameters(*p).y)
+                .collect();
+            assert!(
+                !heights.is_empty(),
+                "Cylindrical faces without boundary are infinite"
+            );
+            let min_v = heights.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max_v = heights.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            estimate_fraction(
+                2.0 * PI * cylinder.radius.norm() * (max_v - min_v),
+                target_error,
+                max_samples,
+                || {
+                    let uv = Point::new(rng.range(0.0, 2.0 * PI), rng.range(min_v, max_v), 0.0);
+                    inside(face.surface.point_at(uv))
+                },
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        measure::mass::{measure_area, measure_volume},
+        operations::extrude::extrude,
+        primitive_objects::{
+            edges::circle::primitive_circle, faces::rectangle::primitive_rectangle,
+            volumes::cube::primitive_cube,
+        },
+        topology::contour::Contour,
+    };
+    use geop_geometry::surfaces::plane::Plane;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_estimates() {
+        let hollow = primitive_cube(2.0, 2.0, 2.0).with_cavity(&primitive_cube(1.0, 1.0, 1.0));
+        let estimate = estimate_volume(&hollow, 0.05, 100000, 42);
+        assert!(estimate.standard_error <= 0.05);
+        assert!((estimate.value - measure_volume(&hollow)).abs() < 4.0 * estimate.standard_error);
+
+        let mut face = primitive_rectangle(Point::zero(), Point::unit_x(), Point::unit_y());
+        let normal = face.normal(Point::zero());
+        face.holes.push(Contour::new(vec![primitive_circle(
+            Point::zero(),
+            -normal,
+            0.5,
+        )]));
+        let estimate = estimate_area(&face, 0.02, 100000, 7);
+        assert!(estimate.standard_error <= 0.02);
+        assert!((estimate.value - measure_area(&face)).abs() < 4.0 * estimate.standard_error);
+
+        // The side of a cylinder with radius 0.5 and height 2.
+        let plane = Plane::new(Point::zero(), Point::unit_y(), Point::unit_x());
+        let disk = Face::new(
+            Some(Contour::new(vec![primitive_circle(
+                Point::zero(),
+                -Point::unit_z(),
+                0.5,
+            )])),
+            vec![],
+            Rc::new(Surface::Plane(plane)),
+        );
+        let side = extrude(disk, Point::new(0.0, 0.0, 2.0))
+            .all_faces()
+            .into_iter()
+            .find(|f| matches!(&*f.surface, Surface::Cylinder(_)))
+            .expect("Expected a cylindrical face");
+        let estimate = estimate_area(&side, 0.05, 100000, 11);
+        assert!(estimate.standard_error <= 0.05);
+        // The face covers the whole band, so every sample is inside.
+        assert!((estimate.value - 2.0 * PI).abs() <= 4.0 * estimate.standard_error + 1e-9);
+    }
+}
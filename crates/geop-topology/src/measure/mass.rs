@@ -0,0 +1,279 @@
+use geop_geometry::{
+    curves::curve::Curve,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+use crate::topology::{contour::Contour, face::Face, volume::Volume};
+
+// Number of segments used to approximate curved edges.
+const EDGE_SEGMENTS: usize = 256;
+
+// The contour as a closed polygon. Curved edges are approximated by EDGE_SEGMENTS segments.
+pub fn contour_polygon(contour: &Contour) -> Vec<Point> {
+    let mut points = Vec::<Point>::new();
+    for edge in contour.edges.iter() {
+        match (&edge.curve, edge.start) {
+            (Curve::Line(_), Some(start)) => points.push(start),
+            _ => {
+                for i in 0..EDGE_SEGMENTS {
+                    points.push(edge.interpolate(i as f64 / EDGE_SEGMENTS as f64));
+                }
+            }
+        }
+    }
+    points
+}
+
+// The normal of a polygon scaled by its area. Newell's method gives the normal of a planar polygon independent of its convexity, and
+// the sum is taken around the center of the points, which keeps it accurate far from the origin.
+pub fn polygon_area_vector(points: &[Point]) -> Point {
+    let center = points.iter().fold(Point::zero(), |sum, p| sum + *p) / points.len() as f64;
+    let mut normal = Point::zero();
+    for i in 0..points.len() {
+        let (p, q) = (points[i] - center, points[(i + 1) % points.len()] - center);
+        normal = normal + p.cross(q);
+    }
+    normal / 2.0
+}
+
+// Signed area enclosed by a contour on a plane, positive if the contour runs counterclockwise around the normal.
+pub fn contour_signed_area(contour: &Contour, normal: Point) -> f64 {
+    let points = contour_polygon(contour);
+    let mut area = 0.0;
+    for i in 0..points.len() {
+        let p = points[i];
+        let q = points[(i + 1) % points.len()];
+        area += p.cross(q).dot(normal);
+    }
+    area / 2.0
+}
+
+// Area of a bounded face. Only planar faces are supported for now.
+pub fn measure_area(face: &Face) -> f64 {
+    let boundary = face
+        .boundary
+        .as_ref()
+        .expect("Face has to be bounded to have an area");
+    match &*face.surface {
+        Surface::Plane(plane) => {
+            let normal = face.surface.normal(plane.basis);
+            let mut area = contour_signed_area(boundary, normal);
+            for hole in face.holes.iter() {
+                area += contour_signed_area(hole, normal);
+            }
+            area
+        }
+        _ => todo!("Area of curved faces"),
+    }
+}
+
+// Area weighted center of a bounded face. Only planar faces are supported for now.
+pub fn measure_centroid(face: &Face) -> Point {
+    let boundary = face
+        .boundary
+        .as_ref()
+        .expect("Face has to be bounded to have a centroid");
+    let normal = match &*face.surface {
+        Surface::Plane(plane) => face.surface.normal(plane.basis),
+        _ => todo!("Centroid of curved faces"),
+    };
+    // Sum over a triangle fan from a common origin. Triangles of holes have negative area.
+    let origin = boundary.edges[0].interpolate(0.0);
+    let mut area = 0.0;
+    let mut moment = Point::zero();
+    for contour in std::iter::once(boundary).chain(face.holes.iter()) {
+        let points = contour_polygon(contour);
+        for i in 0..points.len() {
+            let p = points[i];
+            let q = points[(i + 1) % points.len()];
+            let a = (p - origin).cross(q - origin).dot(normal) / 2.0;
+            area += a;
+            moment = moment + (origin + p + q) * (a / 3.0);
+        }
+    }
+    moment / area
+}
+
+// Properties of a planar face as the cross-section of a beam. The axes lie in the plane of the face: x along the u direction of the
+// plane and y perpendicular to it, so that x, y and the face normal are right handed. Second moments are taken about axes through
+// the centroid, Ixx about the x axis (the integral of y^2) and Iyy about the y axis (the integral of x^2).
+#[derive(Debug, Clone)]
+pub struct SectionProperties {
+    pub area: f64,
+    pub centroid: Point,
+    pub x_axis: Point,
+    pub y_axis: Point,
+    pub ixx: f64,
+    pub iyy: f64,
+    pub ixy: f64,
+    // Second moment divided by the distance to the farthest point of the section, for bending about the x or y axis.
+    pub section_modulus_x: f64,
+    pub section_modulus_y: f64,
+}
+
+// Section properties of a bounded planar face. Holes are subtracted, since they run the other way around.
+pub fn section_properties(face: &Face) -> SectionProperties {
+    let boundary = face
+        .boundary
+        .as_ref()
+        .expect("Face has to be bounded to have section properties");
+    let (origin, x_axis) = match &*face.surface {
+        Surface::Plane(plane) => (plane.basis, plane.u_slope.normalize()),
+        _ => todo!("Section properties of curved faces"),
+    };
+    let y_axis = face.surface.normal(origin).cross(x_axis);
+
+    // Integrals over the polygons by Green's theorem, in coordinates relative to the origin of the plane.
+    let (mut area, mut sx, mut sy, mut ixx, mut iyy, mut ixy) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let mut local_points = Vec::<(f64, f64)>::new();
+    for contour in std::iter::once(boundary).chain(face.holes.iter()) {
+        let points: Vec<(f64, f64)> = contour_polygon(contour)
+            .iter()
+            .map(|p| ((*p - origin).dot(x_axis), (*p - origin).dot(y_axis)))
+            .collect();
+        for i in 0..points.len() {
+            let (x0, y0) = points[i];
+            let (x1, y1) = points[(i + 1) % points.len()];
+            let cross = x0 * y1 - x1 * y0;
+            area += cross / 2.0;
+            sx += (x0 + x1) * cross / 6.0;
+            sy += (y0 + y1) * cross / 6.0;
+            ixx += (y0 * y0 + y0 * y1 + y1 * y1) * cross / 12.0;
+            iyy += (x0 * x0 + x0 * x1 + x1 * x1) * cross / 12.0;
+            ixy += (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) * cross / 24.0;
+        }
+        local_points.extend(points);
+    }
+    let (cx, cy) = (sx / area, sy / area);
+    // Parallel axis theorem, to move the second moments to the centroid.
+    let ixx = ixx - area * cy * cy;
+    let iyy = iyy - area * cx * cx;
+    let ixy = ixy - area * cx * cy;
+    let max_y = local_points
+        .iter()
+        .map(|p| (p.1 - cy).abs())
+        .fold(0.0, f64::max);
+    let max_x = local_points
+        .iter()
+        .map(|p| (p.0 - cx).abs())
+        .fold(0.0, f64::max);
+    SectionProperties {
+        area,
+        centroid: origin + x_axis * cx + y_axis * cy,
+        x_axis,
+        y_axis,
+        ixx,
+        iyy,
+        ixy,
+        section_modulus_x: ixx / max_y,
+        section_modulus_y: iyy / max_x,
+    }
+}
+
+// Enclosed volume of a volume, computed with the divergence theorem as 1/3 of the flux of the position vector through the faces.
+// Cavities are subtracted automatically, since their faces point into the void. Only planar faces are supported for now.
+pub fn measure_volume(volume: &Volume) -> f64 {
+    let mut result = 0.0;
+    for face in volume.all_faces() {
+        match &*face.surface {
+            Surface::Plane(plane) => {
+                result += plane.basis.dot(face.surface.normal(plane.basis)) * measure_area(&face);
+            }
+            _ => todo!("Volume of curved faces"),
+        }
+    }
+    result / 3.0
+}
+
+// Center of mass of a volume of uniform density. Every triangle of a fan over the faces forms a tetrahedron with the world origin,
+// whose signed volume weights its centroid. Only planar faces are supported for now.
+pub fn measure_volume_centroid(volume: &Volume) -> Point {
+    let mut total = 0.0;
+    let mut moment = Point::zero();
+    for face in volume.all_faces() {
+        if !matches!(&*face.surface, Surface::Plane(_)) {
+            todo!("Centroid of volumes with curved faces");
+        }
+        let boundary = face
+            .boundary
+            .as_ref()
+            .expect("Faces of a volume have to be bounded");
+        let origin = boundary.edges[0].interpolate(0.0);
+        for contour in std::iter::once(boundary).chain(face.holes.iter()) {
+            let points = contour_polygon(contour);
+            for i in 0..points.len() {
+                let p = points[i];
+                let q = points[(i + 1) % points.len()];
+                let v = origin.dot(p.cross(q)) / 6.0;
+                total += v;
+                moment = moment + (origin + p + q) * (v / 4.0);
+            }
+        }
+    }
+    moment / total
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use geop_geometry::transforms::Transform;
+
+    use super::*;
+    use crate::primitive_objects::{
+        edges::circle::primitive_circle, faces::rectangle::primitive_rectangle,
+        volumes::cube::primitive_cube,
+    };
+
+    #[test]
+    fn test_measure_volume() {
+        let cube = primitive_cube(1.0, 2.0, 3.0);
+        for face in cube.all_faces() {
+            assert!(measure_area(&face) > 0.0);
+        }
+        assert!((measure_volume(&cube) - 6.0).abs() < 1e-9);
+        let centroid = cube
+            .all_faces()
+            .iter()
+            .fold(Point::zero(), |acc, f| acc + measure_centroid(f));
+        assert_eq!(centroid, Point::zero());
+
+        let hollow = primitive_cube(4.0, 4.0, 4.0).with_cavity(&cube);
+        assert!((measure_volume(&hollow) - 58.0).abs() < 1e-9);
+
+        let offset = Point::new(1.0, -2.0, 0.5);
+        let moved = hollow.transform(Transform::from_translation(offset));
+        assert!((measure_volume_centroid(&moved) - offset).norm() < 1e-9);
+    }
+
+    #[test]
+    fn test_section_properties() {
+        // A 3 x 1 rectangle around (1, 2, 0).
+        let center = Point::new(1.0, 2.0, 0.0);
+        let mut face = primitive_rectangle(center, Point::unit_x() * 1.5, Point::unit_y() * 0.5);
+        let section = section_properties(&face);
+        assert!((section.area - 3.0).abs() < 1e-9);
+        assert_eq!(section.centroid, center);
+        assert!((section.ixx - 0.25).abs() < 1e-9);
+        assert!((section.iyy - 2.25).abs() < 1e-9);
+        assert!(section.ixy.abs() < 1e-9);
+        assert!((section.section_modulus_x - 0.5).abs() < 1e-9);
+        assert!((section.section_modulus_y - 1.5).abs() < 1e-9);
+
+        // A round hole moves the centroid away from it and removes pi r^4 / 4 about its own axes.
+        let hole = Point::new(2.0, 2.0, 0.0);
+        let normal = face.normal(center);
+        face.holes
+            .push(Contour::new(vec![primitive_circle(hole, -normal, 0.25)]));
+        let section = section_properties(&face);
+        let hole_area = PI * 0.25 * 0.25;
+        assert!((section.area - (3.0 - hole_area)).abs() < 1e-4);
+        let shift = hole_area / section.area;
+        assert!((section.centroid - (center - Point::unit_x() * shift)).norm() < 1e-4);
+        let hole_ixx = PI * 0.25_f64.powi(4) / 4.0;
+        assert!((section.ixx - (0.25 - hole_ixx)).abs() < 1e-4);
+        let iyy = 2.25 + 3.0 * shift * shift - hole_ixx - hole_area * (1.0 + shift).powi(2);
+        assert!((section.iyy - iyy).abs() < 1e-4);
+    }
+}
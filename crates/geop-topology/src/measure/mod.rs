@@ -0,0 +1,17 @@
+use geop_geometry::points::point::Point;
+
+use crate::topology::{edge::Edge, face::Face};
+
+pub mod angle;
+pub mod distance;
+pub mod estimate;
+pub mod mass;
+pub mod radius;
+
+// An entity that was picked by the user and can be measured against another entity.
+#[derive(Clone, Debug)]
+pub enum MeasureEntity<'a> {
+    Point(Point),
+    Edge(&'a Edge),
+    Face(&'a Face),
+}
@@ -0,0 +1,114 @@
+use geop_geometry::{curves::curve::Curve, points::point::Point, surfaces::surface::Surface};
+
+use super::MeasureEntity;
+
+#[derive(Clone, Debug)]
+pub enum RadiusMeasurement {
+    Circle {
+        center: Point,
+        normal: Point,
+        radius: f64,
+    },
+    Sphere {
+        center: Point,
+        radius: f64,
+    },
+    Cylinder {
+        basis: Point,
+        axis: Point,
+        radius: f64,
+    },
+}
+
+impl RadiusMeasurement {
+    pub fn radius(&self) -> f64 {
+        match self {
+            RadiusMeasurement::Circle { radius, .. } => *radius,
+            RadiusMeasurement::Sphere { radius, .. } => *radius,
+            RadiusMeasurement::Cylinder { radius, .. } => *radius,
+        }
+    }
+}
+
+// Measures the radius of circular edges, spherical faces and cylindrical faces. Returns None for all other entities.
+pub fn measure_radius(entity: &MeasureEntity) -> Option<RadiusMeasurement> {
+    match entity {
+        MeasureEntity::Edge(edge) => match &edge.curve {
+            Curve::Circle(circle) => Some(RadiusMeasurement::Circle {
+                center: circle.basis,
+                normal: circle.normal,
+                radius: circle.radius.norm(),
+            }),
+            _ => None,
+        },
+        MeasureEntity::Face(face) => match &*face.surface {
+            Surface::Sphere(sphere) => Some(RadiusMeasurement::Sphere {
+                center: sphere.basis,
+                radius: sphere.radius,
+            }),
+            Surface::Cylinder(cylinder) => Some(RadiusMeasurement::Cylinder {
+                basis: cylinder.basis,
+                axis: cylinder.extend_dir,
+                radius: cylinder.radius.norm(),
+            }),
+            _ => None,
+        },
+        MeasureEntity::Point(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use geop_geometry::{surfaces::cylinder::Cylinder, EQ_THRESHOLD};
+
+    use crate::{
+        primitive_objects::{
+            edges::{circle::primitive_circle, line::primitive_line},
+            faces::sphere::primitive_sphere,
+            volumes::cube::primitive_cube,
+        },
+        topology::face::Face,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_measure_radius() {
+        let circle = primitive_circle(Point::new(1.0, 2.0, 3.0), Point::unit_z(), 0.75);
+        match measure_radius(&MeasureEntity::Edge(&circle)) {
+            Some(RadiusMeasurement::Circle { center, radius, .. }) => {
+                assert_eq!(center, Point::new(1.0, 2.0, 3.0));
+                assert!((radius - 0.75).abs() < EQ_THRESHOLD);
+            }
+            other => panic!("Expected a circle, got {:?}", other),
+        }
+
+        let cylinder = Face::new(
+            None,
+            vec![],
+            Rc::new(Surface::Cylinder(Cylinder::new(
+                Point::zero(),
+                Point::unit_z(),
+                0.5,
+                true,
+            ))),
+        );
+        let m = measure_radius(&MeasureEntity::Face(&cylinder)).unwrap();
+        assert!(matches!(m, RadiusMeasurement::Cylinder { .. }));
+        assert!((m.radius() - 0.5).abs() < EQ_THRESHOLD);
+
+        let sphere = primitive_sphere(Point::zero(), 2.0);
+        let m = measure_radius(&MeasureEntity::Face(&sphere)).unwrap();
+        assert!(matches!(m, RadiusMeasurement::Sphere { .. }));
+        assert!((m.radius() - 2.0).abs() < EQ_THRESHOLD);
+
+        // Straight edges, planar faces and points have no radius.
+        let line = primitive_line(Point::zero(), Point::unit_x());
+        assert!(measure_radius(&MeasureEntity::Edge(&line)).is_none());
+        let faces = primitive_cube(1.0, 1.0, 1.0).all_faces();
+        assert!(measure_radius(&MeasureEntity::Face(&faces[0])).is_none());
+        assert!(measure_radius(&MeasureEntity::Point(Point::zero())).is_none());
+    }
+}
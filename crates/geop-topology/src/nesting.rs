@@ -0,0 +1,251 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    transforms::{rotation::Rotation, Transform},
+};
+
+use crate::topology::face::{bounds::face_sample_points, Face};
+
+// Packs flat parts onto a rectangular sheet, e.g. for laser cutting. The sheet spans [0, width] x [0, height] on the XY-plane.
+// Parts are packed by their bounding rectangles with the skyline bottom-left heuristic: the largest parts go first, and every part
+// is put at the lowest, then leftmost, position where it fits, trying every rotation step. Holes of parts are not filled with
+// other parts.
+
+#[derive(Debug, Clone)]
+pub struct Sheet {
+    pub width: f64,
+    pub height: f64,
+    // Minimum gap between the bounding rectangles of neighbouring parts.
+    pub spacing: f64,
+    // Number of rotations tried per part, evenly spread over a full turn. 1 only allows the original orientation.
+    pub rotation_steps: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Rectangle {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+// A horizontal piece of the upper outline of the parts placed so far.
+#[derive(Debug, Clone)]
+struct SkylineSegment {
+    x: f64,
+    width: f64,
+    y: f64,
+}
+
+// Rotates the plane of the face onto the XY-plane.
+fn flatten_transform(face: &Face) -> Transform {
+    let plane = match &*face.surface {
+        Surface::Plane(plane) => plane,
+        _ => panic!("Only planar faces can be nested"),
+    };
+    let rotation =
+        Rotation::between(face.surface.normal(plane.basis), Point::unit_z()).to_transform();
+    let basis = rotation * plane.basis;
+    Transform::from_translation(Point::new(0.0, 0.0, -basis.z)) * rotation
+}
+
+// The bounding rectangle of the transformed points on the XY-plane.
+fn bounds(points: &[Point], transform: Transform) -> Rectangle {
+    let points: Vec<Point> = points.iter().map(|p| transform * *p).collect();
+    let min_x = points.iter().map(|p| p.x).fold(f64::INFINITY, f64::min);
+    let max_x = points.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max);
+    let min_y = points.iter().map(|p| p.y).fold(f64::INFINITY, f64::min);
+    let max_y = points.iter().map(|p| p.y).fold(f64::NEG_INFINITY, f64::max);
+    Rectangle {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x,
+        height: max_y - min_y,
+    }
+}
+
+// The lowest position for a part of the given width whose left side is at the start of the segment. The spacing to its right has
+// to be clear as well, but may stick out of the sheet.
+fn skyline_position(
+    skyline: &[SkylineSegment],
+    start: usize,
+    width: f64,
+    sheet: &Sheet,
+) -> Option<(f64, f64)> {
+    let x = skyline[start].x;
+    if x + width > sheet.width {
+        return None;
+    }
+    let mut y: f64 = 0.0;
+    for segment in skyline[start..].iter() {
+        if segment.x >= x + width + sheet.spacing {
+            break;
+        }
+        y = y.max(segment.y);
+    }
+    Some((x, y))
+}
+
+// Raises the skyline below the placed rectangle, which includes the spacing to the right and above it.
+fn add_to_skyline(skyline: &mut Vec<SkylineSegment>, rectangle: &Rectangle) {
+    let (left, right) = (rectangle.x, rectangle.x + rectangle.width);
+    let mut result = Vec::<SkylineSegment>::new();
+    for segment in skyline.iter() {
+        let end = segment.x + segment.width;
+        if segment.x < left {
+            result.push(SkylineSegment {
+                x: segment.x,
+                width: end.min(left) - segment.x,
+                y: segment.y,
+            });
+        }
+        if end > right {
+            let x = segment.x.max(right);
+            result.push(SkylineSegment {
+                x,
+                width: end - x,
+                y: segment.y,
+            });
+        }
+    }
+    result.push(SkylineSegment {
+        x: left,
+        width: right - left,
+        y: rectangle.y + rectangle.height,
+    });
+    result.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+    *skyline = result;
+}
+
+// Returns, for every face in the same order, the transform that puts it onto the sheet, or None if it does not fit anymore.
+pub fn nest_faces(faces: &[Face], sheet: &Sheet) -> Vec<Option<Transform>> {
+    assert!(sheet.rotation_steps > 0);
+    let parts: Vec<(Transform, Vec<Point>)> = faces
+        .iter()
+        .map(|face| (flatten_transform(face), face_sample_points(face)))
+        .collect();
+    let mut order: Vec<usize> = (0..faces.len()).collect();
+    let area = |i: &usize| {
+        let b = bounds(&parts[*i].1, parts[*i].0);
+        b.width * b.height
+    };
+    order.sort_by(|a, b| area(b).partial_cmp(&area(a)).unwrap());
+
+    let mut skyline = vec![SkylineSegment {
+        x: 0.0,
+        width: sheet.width + sheet.spacing,
+        y: 0.0,
+    }];
+    let mut placements = vec![None; faces.len()];
+    for i in order {
+        let (flatten, points) = &parts[i];
+        // (y, x, rotated transform, bounds after rotation)
+        let mut best: Option<(f64, f64, Transform, Rectangle)> = None;
+        for step in 0..sheet.rotation_steps {
+            let angle = 2.0 * PI * step as f64 / sheet.rotation_steps as f64;
+            let rotated =
+                Rotation::from_axis_angle(Point::unit_z(), angle).to_transform() * *flatten;
+            let b = bounds(points, rotated);
+            for start in 0..skyline.len() {
+                let (x, y) = match skyline_position(&skyline, start, b.width, sheet) {
+                    Some(position) => position,
+                    None => continue,
+                };
+                if y + b.height > sheet.height {
+                    continue;
+                }
+                if best
+                    .as_ref()
+                    .is_none_or(|(by, bx, _, _)| (y, x) < (*by, *bx))
+                {
+                    best = Some((y, x, rotated, b.clone()));
+                }
+            }
+        }
+        if let Some((y, x, rotated, b)) = best {
+            placements[i] =
+                Some(Transform::from_translation(Point::new(x - b.x, y - b.y, 0.0)) * rotated);
+            add_to_skyline(
+                &mut skyline,
+                &Rectangle {
+                    x,
+                    y,
+                    width: b.width + sheet.spacing,
+                    height: b.height + sheet.spacing,
+                },
+            );
+        }
+    }
+    placements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::faces::rectangle::primitive_rectangle;
+
+    fn placed_bounds(face: &Face, transform: Transform) -> Rectangle {
+        bounds(&face_sample_points(face), transform)
+    }
+
+    #[test]
+    fn test_nest_faces() {
+        // Five 2 x 1 rectangles, one of them standing upright in the YZ-plane. Four fit on the sheet.
+        let mut faces: Vec<Face> = (0..4)
+            .map(|i| {
+                primitive_rectangle(
+                    Point::new(5.0 * i as f64, 0.0, 1.0),
+                    Point::unit_x(),
+                    Point::unit_y() * 0.5,
+                )
+            })
+            .collect();
+        faces.push(primitive_rectangle(
+            Point::new(0.0, 0.0, 7.0),
+            Point::unit_y(),
+            Point::unit_z() * 0.5,
+        ));
+        let sheet = Sheet {
+            width: 4.2,
+            height: 2.2,
+            spacing: 0.1,
+            rotation_steps: 1,
+        };
+        let placements = nest_faces(&faces, &sheet);
+        assert_eq!(placements.iter().filter(|p| p.is_none()).count(), 1);
+        let placed: Vec<Rectangle> = faces
+            .iter()
+            .zip(placements.iter())
+            .filter_map(|(f, t)| t.map(|t| placed_bounds(f, t)))
+            .collect();
+        for (i, a) in placed.iter().enumerate() {
+            assert!(a.x > -1e-9 && a.x + a.width < sheet.width + 1e-9);
+            assert!(a.y > -1e-9 && a.y + a.height < sheet.height + 1e-9);
+            for b in placed[i + 1..].iter() {
+                let apart = a.x + a.width + sheet.spacing <= b.x + 1e-9
+                    || b.x + b.width + sheet.spacing <= a.x + 1e-9
+                    || a.y + a.height + sheet.spacing <= b.y + 1e-9
+                    || b.y + b.height + sheet.spacing <= a.y + 1e-9;
+                assert!(apart);
+            }
+        }
+        let transform = placements.iter().flatten().next().unwrap();
+        for p in face_sample_points(&faces[0]) {
+            assert!((*transform * p).z.abs() < 1e-9);
+        }
+
+        // A long part only fits on a narrow sheet when it is turned by 90 degrees.
+        let sheet = Sheet {
+            width: 1.5,
+            height: 3.5,
+            spacing: 0.1,
+            rotation_steps: 4,
+        };
+        let long = primitive_rectangle(Point::zero(), Point::unit_x() * 1.5, Point::unit_y() * 0.5);
+        let transform = nest_faces(std::slice::from_ref(&long), &sheet)[0].unwrap();
+        let b = placed_bounds(&long, transform);
+        assert!((b.width - 1.0).abs() < 1e-9 && (b.height - 3.0).abs() < 1e-9);
+    }
+}
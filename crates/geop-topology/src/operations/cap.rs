@@ -0,0 +1,172 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    measure::mass::{contour_polygon, polygon_area_vector},
+    topology::{contour::Contour, edge::Edge, face::Face, shell::Shell},
+};
+
+// A closed loop of open edges, running against the faces next to it, together with the plane it lies in. The normal of the plane is
+// the one around which the loop runs counterclockwise.
+struct Opening {
+    contour: Contour,
+    center: Point,
+    normal: Point,
+    area: f64,
+}
+
+// Edges that belong to only one face of the shell.
+fn open_edges(shell: &Shell) -> Vec<Edge> {
+    let mut edges = Vec::<(Edge, usize)>::new();
+    for edge in shell.faces.iter().flat_map(|f| f.all_edges()) {
+        match edges.iter_mut().find(|(e, _)| *e == edge) {
+            Some((_, uses)) => *uses += 1,
+            None => edges.push((edge, 1)),
+        }
+    }
+    edges
+        .into_iter()
+        .filter(|(_, uses)| *uses == 1)
+        .map(|(e, _)| e)
+        .collect()
+}
+
+// Chains the edges into closed loops, following the end of each edge to the start of the next one. Chains that do not close up are
+// dropped.
+fn loops(mut edges: Vec<Edge>) -> Vec<Contour> {
+    let mut result = Vec::<Contour>::new();
+    while let Some(first) = edges.pop() {
+        if first.start.is_none() {
+            result.push(Contour::new(vec![first]));
+            continue;
+        }
+        let mut chain = vec![first];
+        while chain.last().unwrap().end != chain[0].start {
+            let end = chain.last().unwrap().end;
+            match edges.iter().position(|e| e.start == end) {
+                Some(i) => chain.push(edges.remove(i)),
+                None => break,
+            }
+        }
+        if chain.last().unwrap().end == chain[0].start {
+            result.push(Contour::new(chain));
+        }
+    }
+    result
+}
+
+// The plane of the loop, if all of it lies in one.
+fn opening(contour: Contour) -> Option<Opening> {
+    let points = contour_polygon(&contour);
+    let center = points.iter().fold(Point::zero(), |sum, p| sum + *p) / points.len() as f64;
+    let normal = polygon_area_vector(&points);
+    let area = normal.norm();
+    if area < EQ_THRESHOLD {
+        return None;
+    }
+    let normal = normal.normalize();
+    match points
+        .iter()
+        .all(|p| (*p - center).dot(normal).abs() < EQ_THRESHOLD)
+    {
+        true => Some(Opening {
+            contour,
+            center,
+            normal,
+            area,
+        }),
+        false => None,
+    }
+}
+
+// Closes the openings of an open shell that lie in a plane with planar faces, e.g. the side walls of an extrusion or an imported
+// solid with missing faces. The open edges, which belong to only one face, are chained into loops, and every loop that lies in a
+// plane becomes the boundary of a new face, which runs along the open edges in the opposite direction, so its normal points away
+// from the faces around it. Loops inside a larger loop in the same plane, which run the other way around, become its holes. Loops
+// that are not planar are left open.
+pub fn cap_planar_openings(shell: &Shell) -> Shell {
+    let edges = open_edges(shell).iter().map(|e| e.flip()).collect();
+    let mut openings: Vec<Opening> = loops(edges).into_iter().filter_map(opening).collect();
+    openings.sort_by(|a, b| b.area.total_cmp(&a.area));
+
+    let mut caps = Vec::<(Face, Point)>::new();
+    for opening in openings {
+        let p = opening.contour.edges[0].interpolate(0.0);
+        let outer = caps.iter_mut().find(|(cap, normal)| {
+            normal.dot(opening.normal) < -1.0 + EQ_THRESHOLD
+                && matches!(face_point_contains(cap, p), FacePointContains::Inside)
+        });
+        match outer {
+            Some((cap, _)) => cap.holes.push(opening.contour),
+            None => {
+                let u = (p - opening.center).normalize();
+                let u = (u - opening.normal * u.dot(opening.normal)).normalize();
+                let plane = Plane::new(opening.center, u, opening.normal.cross(u));
+                let cap = Face::new(
+                    Some(opening.contour),
+                    vec![],
+                    Rc::new(Surface::Plane(plane)),
+                );
+                caps.push((cap, opening.normal));
+            }
+        }
+    }
+
+    let mut faces = shell.faces.clone();
+    faces.extend(caps.into_iter().map(|(cap, _)| cap));
+    Shell::new(faces)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        measure::mass::measure_volume,
+        operations::extrude::extrude,
+        primitive_objects::volumes::cube::primitive_cube,
+        topology::{diagnostics::check_volume, volume::Volume},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_cap_planar_openings() {
+        // The side walls of a cube get their top and bottom back.
+        let walls: Vec<Face> = primitive_cube(2.0, 2.0, 2.0)
+            .all_faces()
+            .into_iter()
+            .filter(|f| match &*f.surface {
+                Surface::Plane(plane) => plane.u_slope.cross(plane.v_slope).z.abs() < 0.5,
+                _ => true,
+            })
+            .collect();
+        assert_eq!(walls.len(), 4);
+        let capped = cap_planar_openings(&Shell::new(walls));
+        assert_eq!(capped.faces.len(), 6);
+        let volume = Volume::new(capped, vec![]);
+        assert!(check_volume(&volume.boundary, &volume.cavities).is_empty());
+        assert!((measure_volume(&volume) - 8.0).abs() < 1e-9);
+
+        // The walls of an extruded annulus get annuli as caps.
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let annulus = Face::disk(&plane, Point::zero(), 2.0).with_hole(Face::disk(
+            &plane,
+            Point::zero(),
+            1.0,
+        ));
+        let walls: Vec<Face> = extrude(annulus, Point::unit_z())
+            .all_faces()
+            .into_iter()
+            .filter(|f| matches!(&*f.surface, Surface::Cylinder(_)))
+            .collect();
+        let capped = cap_planar_openings(&Shell::new(walls));
+        assert_eq!(capped.faces.len(), 4);
+        assert!(capped.faces[2..].iter().all(|f| f.holes.len() == 1));
+        assert!(check_volume(&capped, &[]).is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt::{Display, Formatter};
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+    EQ_THRESHOLD,
+};
+
+use crate::topology::{shell::Shell, volume::Volume};
+
+use super::replace_planes::{replace_planes, ReplacePlanesError};
+
+#[derive(Debug, Clone)]
+pub enum DraftError {
+    CurvedFace(usize), // The face is not planar. There is no conical surface to draft cylinders into, and curved neighbours cannot be re-trimmed
+    UndefinedVertex(Point), // The planes of the faces around the vertex do not meet in a single point
+    CollapsedEdge(usize), // An edge of the face shrinks to a point or turns around, because the draft angle is too steep
+}
+
+impl Display for DraftError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DraftError::CurvedFace(i) => write!(f, "Face {} is curved and cannot be drafted", i),
+            DraftError::UndefinedVertex(p) => {
+                write!(f, "Drafted faces around {:?} do not meet in a point", p)
+            }
+            DraftError::CollapsedEdge(i) => write!(f, "An edge of face {} collapses", i),
+        }
+    }
+}
+
+impl From<ReplacePlanesError> for DraftError {
+    fn from(error: ReplacePlanesError) -> Self {
+        match error {
+            ReplacePlanesError::UndefinedVertex(p) => DraftError::UndefinedVertex(p),
+            ReplacePlanesError::CollapsedEdge(i) => DraftError::CollapsedEdge(i),
+        }
+    }
+}
+
+// The plane of the face tilted by the draft angle, such that it leans away from the pull direction. It pivots around the line where
+// the face meets the neutral plane, so that the face keeps its size there.
+fn drafted_plane(plane: &Plane, pull: Point, angle: f64, neutral: &Plane) -> Plane {
+    let normal = plane.u_slope.cross(plane.v_slope).normalize();
+    let sideways = normal - pull * normal.dot(pull);
+    assert!(
+        sideways.norm() > EQ_THRESHOLD,
+        "Faces perpendicular to the pull direction cannot be drafted"
+    );
+    let sideways = sideways.normalize();
+    let drafted = sideways * angle.cos() + pull * angle.sin();
+
+    // A point on the line where the face meets the neutral plane, found by walking from the basis of the face within its plane.
+    let neutral_normal = neutral.u_slope.cross(neutral.v_slope).normalize();
+    let along = neutral_normal - normal * neutral_normal.dot(normal);
+    assert!(
+        along.norm() > EQ_THRESHOLD,
+        "Faces parallel to the neutral plane cannot be drafted"
+    );
+    let s = (neutral.basis - plane.basis).dot(neutral_normal) / along.dot(neutral_normal);
+    let pivot = plane.basis + along * s;
+
+    let u = normal.cross(pull).normalize();
+    Plane::new(pivot, u, drafted.cross(u))
+}
+
+// Tilts the selected planar faces by the draft angle relative to the pull direction, so that the part can be pulled out of a
+// mold, and re-trims the neighbouring faces. Faces are indices into volume.all_faces() and keep their size where they cross the
+// neutral plane. Positive angles taper the volume towards the pull direction.
+// Only volumes with planar faces can be drafted. Drafting a cylindrical face would turn it into a cone, and there is no conical
+// surface yet, so volumes with cylinders, like bosses and holes, return DraftError::CurvedFace until one is added.
+pub fn draft(
+    volume: &Volume,
+    faces: &[usize],
+    pull_direction: Point,
+    angle: f64,
+    neutral_plane: &Plane,
+) -> Result<Volume, DraftError> {
+    let pull = pull_direction.normalize();
+    let all_faces = volume.all_faces();
+    let planes = all_faces
+        .iter()
+        .enumerate()
+        .map(|(i, face)| match &*face.surface {
+            Surface::Plane(plane) => match faces.contains(&i) {
+                true => Ok(drafted_plane(plane, pull, angle, neutral_plane)),
+                false => Ok(plane.clone()),
+            },
+            _ => Err(DraftError::CurvedFace(i)),
+        })
+        .collect::<Result<Vec<Plane>, DraftError>>()?;
+    let mut moved = replace_planes(&all_faces, &planes)?.into_iter();
+    let boundary = Shell::new(moved.by_ref().take(volume.boundary.faces.len()).collect());
+    let cavities = volume
+        .cavities
+        .iter()
+        .map(|cavity| Shell::new(moved.by_ref().take(cavity.faces.len()).collect()))
+        .collect();
+    Ok(Volume::new(boundary, cavities))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{
+        measure::mass::measure_volume,
+        operations::extrude::extrude,
+        primitive_objects::{edges::circle::primitive_circle, volumes::cube::primitive_cube},
+        topology::{contour::Contour, face::Face},
+    };
+
+    #[test]
+    fn test_draft() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let sides: Vec<usize> = cube
+            .all_faces()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.normal(f.inner_point()).z.abs() < 0.5)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(sides.len(), 4);
+
+        // With the neutral plane at the bottom, the cube becomes a frustum with a smaller top.
+        let angle: f64 = 0.1;
+        let bottom = Plane::new(Point::new(0.0, 0.0, -1.0), Point::unit_x(), Point::unit_y());
+        let result = draft(&cube, &sides, Point::unit_z(), angle, &bottom).unwrap();
+        let top = 2.0 - 4.0 * angle.tan();
+        let expected = 2.0 / 3.0 * (4.0 + top * top + 2.0 * top);
+        assert!((measure_volume(&result) - expected).abs() < 1e-9);
+        for i in sides.iter() {
+            let face = &result.all_faces()[*i];
+            assert!((face.normal(face.inner_point()).z - angle.sin()).abs() < 1e-9);
+        }
+
+        // With the neutral plane in the middle, the volume stays the same.
+        let middle = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let result = draft(&cube, &sides, Point::unit_z(), angle, &middle).unwrap();
+        let (a, b) = (2.0 + 2.0 * angle.tan(), 2.0 - 2.0 * angle.tan());
+        let expected = 2.0 / 3.0 * (a * a + b * b + a * b);
+        assert!((measure_volume(&result) - expected).abs() < 1e-9);
+
+        // Volumes with curved faces are rejected, even if only their planar faces are selected.
+        let disk = Face::new(
+            Some(Contour::new(vec![primitive_circle(
+                Point::zero(),
+                -Point::unit_z(),
+                0.5,
+            )])),
+            vec![],
+            Rc::new(Surface::Plane(Plane::new(
+                Point::zero(),
+                Point::unit_y(),
+                Point::unit_x(),
+            ))),
+        );
+        let cylinder = extrude(disk, Point::new(0.0, 0.0, 2.0));
+        let planar: Vec<usize> = cylinder
+            .all_faces()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| matches!(*f.surface, Surface::Plane(_)))
+            .map(|(i, _)| i)
+            .collect();
+        assert!(matches!(
+            draft(&cylinder, &planar, Point::unit_z(), angle, &bottom),
+            Err(DraftError::CurvedFace(_))
+        ));
+    }
+}
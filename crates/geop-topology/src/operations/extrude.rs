@@ -1,131 +1,445 @@
-use std::rc::Rc;
+use std::{
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
 
 use geop_geometry::{
     curves::{curve::Curve, line::Line, CurveLike},
     points::point::Point,
-    surfaces::{cylinder::Cylinder, plane::Plane, surface::Surface},
-    transforms::Transform,
+    surfaces::{cylinder::Cylinder, plane::Plane, surface::Surface, SurfaceLike},
+    transforms::{rotation::Rotation, Transform},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    measure::mass::{contour_signed_area, measure_centroid},
+    primitive_objects::edges::line::primitive_line,
+    topology::{
+        contour::Contour, edge::Edge, face::Face, shell::Shell, volume::Volume, wire::Wire,
+    },
 };
 
-use crate::topology::{contour::Contour, edge::Edge, face::Face, shell::Shell, volume::Volume};
+use super::offset_wire::offset_wire;
 
 pub fn extrude(start_face: Face, direction: Point) -> Volume {
     let end_face = start_face
         .transform(Transform::from_translation(direction))
         .flip();
 
-    let mut faces = Vec::<Face>::new();
-    let all_edges = &start_face.all_edges();
-    let end_edges = &end_face.all_edges();
-    let n = all_edges.len();
-    for i in 0..n {
-        match &all_edges[i].curve {
-            Curve::Line(line) => {
-                let top = all_edges[i].flip();
-                let bottom = end_edges
-                    .iter()
-                    .find(|e| {
-                        **e == all_edges[i]
-                            .transform(Transform::from_translation(direction))
-                            .flip()
-                    })
-                    .unwrap()
-                    .flip();
-
-                let right = match (bottom.end, top.start) {
-                    (Some(start), Some(end)) => Some(Edge::new(
-                        Some(start),
-                        Some(end),
-                        Curve::Line(Line::new(start, end - start)),
-                    )),
-                    _ => None,
-                };
-                let left = match (top.end, bottom.start) {
-                    (Some(start), Some(end)) => Some(Edge::new(
-                        Some(start),
-                        Some(end),
-                        Curve::Line(Line::new(start, end - start)),
-                    )),
-                    _ => None,
-                };
-
-                let plane = Surface::Plane(Plane::new(line.basis, direction, line.direction));
-                let contour = Contour::new(
-                    vec![right, Some(top), left, Some(bottom)]
-                        .drain(..)
-                        .filter_map(|f| f)
-                        .collect(),
-                );
-
-                let face = Face::new(Some(contour), vec![], Rc::new(plane));
-                faces.push(face);
+    let mut faces = start_face
+        .all_edges()
+        .iter()
+        .map(|edge| extrude_edge(edge, direction))
+        .collect::<Vec<Face>>();
+    faces.push(start_face);
+    faces.push(end_face);
+
+    Volume::new(Shell::new(faces), vec![])
+}
+
+// Sweeps an edge along direction and returns the resulting side face. The edge becomes the reversed top boundary of the face, such that the face fits to the face the edge is taken from.
+pub fn extrude_edge(edge: &Edge, direction: Point) -> Face {
+    let top = edge.flip();
+    let bottom = edge.transform(Transform::from_translation(direction));
+
+    let right = match (bottom.end, top.start) {
+        (Some(start), Some(end)) => Some(Edge::new(
+            Some(start),
+            Some(end),
+            Curve::Line(Line::new(start, end - start)),
+        )),
+        _ => None,
+    };
+    let left = match (top.end, bottom.start) {
+        (Some(start), Some(end)) => Some(Edge::new(
+            Some(start),
+            Some(end),
+            Curve::Line(Line::new(start, end - start)),
+        )),
+        _ => None,
+    };
+
+    match &edge.curve {
+        Curve::Line(line) => {
+            let plane = Surface::Plane(Plane::new(line.basis, direction, line.direction));
+            let contour = Contour::new(
+                vec![right, Some(top), left, Some(bottom)]
+                    .drain(..)
+                    .filter_map(|f| f)
+                    .collect(),
+            );
+
+            Face::new(Some(contour), vec![], Rc::new(plane))
+        }
+        Curve::Circle(circle) => {
+            let midpoint = circle.get_midpoint(top.start, top.end);
+            let inwards_direction = direction.cross(circle.tangent(midpoint));
+            let normal_outwards = inwards_direction.dot(midpoint - circle.basis) > 0.0;
+
+            let cylinder = Surface::Cylinder(Cylinder::new(
+                circle.basis,
+                circle.normal,
+                circle.radius.norm(),
+                normal_outwards,
+            ));
+
+            match (left, right) {
+                (Some(left), Some(right)) => {
+                    let contour = Contour::new(vec![right, top, left, bottom]);
+                    Face::new(Some(contour), vec![], Rc::new(cylinder))
+                }
+                (None, None) => {
+                    let contour = Contour::new(vec![top]);
+                    Face::new(
+                        Some(contour),
+                        vec![Contour::new(vec![bottom])],
+                        Rc::new(cylinder),
+                    )
+                }
+                _ => todo!("Not implemented"),
             }
-            Curve::Circle(circle) => {
-                let top = all_edges[i].flip();
-                let bottom = end_edges
-                    .iter()
-                    .find(|e| {
-                        **e == all_edges[i]
-                            .transform(Transform::from_translation(direction))
-                            .flip()
-                    })
-                    .unwrap()
-                    .flip();
-
-                let right = match (bottom.end, top.start) {
-                    (Some(start), Some(end)) => Some(Edge::new(
-                        Some(start),
-                        Some(end),
-                        Curve::Line(Line::new(start, end - start)),
-                    )),
-                    _ => None,
-                };
-                let left = match (top.end, bottom.start) {
-                    (Some(start), Some(end)) => Some(Edge::new(
-                        Some(start),
-                        Some(end),
-                        Curve::Line(Line::new(start, end - start)),
-                    )),
-                    _ => None,
-                };
-
-                let midpoint = circle.get_midpoint(top.start, top.end);
-                let inwards_direction = direction.cross(circle.tangent(midpoint));
-                let normal_outwards = inwards_direction.dot(midpoint - circle.basis) > 0.0;
-
-                let cylinder = Surface::Cylinder(Cylinder::new(
-                    circle.basis,
-                    circle.normal,
-                    circle.radius.norm(),
-                    normal_outwards,
-                ));
-
-                match (left, right) {
-                    (Some(left), Some(right)) => {
-                        let contour = Contour::new(vec![right, top, left, bottom]);
-
-                        let face = Face::new(Some(contour), vec![], Rc::new(cylinder));
-                        faces.push(face);
-                    }
-                    (None, None) => {
-                        let contour = Contour::new(vec![top]);
-
-                        let face = Face::new(
-                            Some(contour),
-                            vec![Contour::new(vec![bottom])],
-                            Rc::new(cylinder),
-                        );
-                        faces.push(face);
+        }
+        Curve::Ellipse(_) => todo!("Implement this"),
+        Curve::Helix(_) => panic!("Cannot extrude helix"),
+        Curve::Bezier(_) => todo!("Extrusion of Bezier curves needs a general extrusion surface"),
+        Curve::Composite(_) => todo!("Split composite curves into edges before extruding"),
+    }
+}
+
+// Tapering and twisting of extrusions. The draft angle leans the side faces inwards like in draft, so positive angles make the
+// volume narrower towards the end face. The twist turns the end face by the angle around the normal of the start face through its
+// centroid. Twisted sides are not planar, so they are approximated by triangles in segments layers along the extrusion.
+#[derive(Debug, Clone, Copy)]
+pub struct ExtrudeOptions {
+    pub draft_angle: f64,
+    pub twist: f64,
+    pub segments: usize,
+}
+
+impl Default for ExtrudeOptions {
+    fn default() -> Self {
+        ExtrudeOptions {
+            draft_angle: 0.0,
+            twist: 0.0,
+            segments: 1,
+        }
+    }
+}
+
+impl ExtrudeOptions {
+    pub fn new() -> ExtrudeOptions {
+        ExtrudeOptions::default()
+    }
+
+    pub fn with_draft(mut self, angle: f64) -> ExtrudeOptions {
+        assert!(
+            angle.abs() < std::f64::consts::FRAC_PI_2,
+            "Draft angle has to be less than 90 degrees"
+        );
+        self.draft_angle = angle;
+        self
+    }
+
+    pub fn with_twist(mut self, angle: f64, segments: usize) -> ExtrudeOptions {
+        assert!(segments > 0, "Twist needs at least one segment");
+        self.twist = angle;
+        self.segments = segments;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ExtrudeError {
+    CurvedEdge(Point), // A curved edge, given by its midpoint. Drafted circles would need cones, and twisted ones general sweep surfaces
+}
+
+impl Display for ExtrudeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExtrudeError::CurvedEdge(p) => {
+                write!(
+                    f,
+                    "Curved edge through {:?} cannot be drafted or twisted",
+                    p
+                )
+            }
+        }
+    }
+}
+
+// The corners of the contour moved into the face by distance, such that the edges stay parallel to the original ones.
+fn offset_corners(corners: &[Point], normal: Point, distance: f64) -> Vec<Point> {
+    let n = corners.len();
+    (0..n)
+        .map(|i| {
+            let inwards = |a: Point, b: Point| normal.cross(b - a).normalize();
+            let before = inwards(corners[(i + n - 1) % n], corners[i]);
+            let after = inwards(corners[i], corners[(i + 1) % n]);
+            corners[i] + (before + after) * (distance / (1.0 + before.dot(after)))
+        })
+        .collect()
+}
+
+// The face through the corners in their order, with its normal given by the right hand rule. The slopes of the plane have to be
+// orthogonal for its normal to have unit length.
+fn planar_polygon(corners: &[Point]) -> Face {
+    let u = corners[1] - corners[0];
+    let w = corners[2] - corners[0];
+    let plane = Plane::new(corners[0], u, w - u * (w.dot(u) / u.dot(u)));
+    Face::polygon(&plane, corners)
+}
+
+fn contour_from_corners(corners: &[Point]) -> Contour {
+    let n = corners.len();
+    Contour::new(
+        (0..n)
+            .map(|i| primitive_line(corners[i], corners[(i + 1) % n]))
+            .collect(),
+    )
+}
+
+// Like extrude, with a draft angle and twist. Faces with curved edges can only be extruded straight. With a draft or twist they
+// return ExtrudeError::CurvedEdge, since there is no conical surface for drafted circles and no sweep surface for twisted ones.
+pub fn extrude_with(
+    start_face: Face,
+    direction: Point,
+    options: &ExtrudeOptions,
+) -> Result<Volume, ExtrudeError> {
+    if options.draft_angle == 0.0 && options.twist == 0.0 {
+        return Ok(extrude(start_face, direction));
+    }
+    let plane = match &*start_face.surface {
+        Surface::Plane(plane) => plane.clone(),
+        _ => panic!("Only planar faces can be extruded with draft or twist"),
+    };
+    let normal = start_face.surface.normal(plane.basis);
+    assert!(
+        direction.dot(normal).abs() > EQ_THRESHOLD,
+        "Direction must not lie in the plane of the face"
+    );
+    let contours = std::iter::once(start_face.boundary.as_ref())
+        .flatten()
+        .chain(start_face.holes.iter())
+        .map(|contour| {
+            contour
+                .edges
+                .iter()
+                .map(|edge| match (&edge.curve, edge.start) {
+                    (Curve::Line(_), Some(start)) => Ok(start),
+                    _ => Err(ExtrudeError::CurvedEdge(edge.get_midpoint())),
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<Point>>, ExtrudeError>>()?;
+    assert!(
+        start_face.boundary.is_some(),
+        "Face has to be bounded to be extruded"
+    );
+
+    // The corners of every contour in every layer. The layers are shared by neighboring faces, so that their edges match exactly.
+    let centroid = measure_centroid(&start_face);
+    let segments = match options.twist == 0.0 {
+        true => 1,
+        false => options.segments,
+    };
+    let layers: Vec<Vec<Vec<Point>>> = (0..=segments)
+        .map(|k| {
+            let t = k as f64 / segments as f64;
+            let transform = Transform::from_translation(centroid + direction * t)
+                * Rotation::from_axis_angle(normal, options.twist * t).to_transform()
+                * Transform::from_translation(-centroid);
+            let distance = direction.norm() * t * options.draft_angle.tan();
+            contours
+                .iter()
+                .map(|corners| {
+                    offset_corners(corners, normal, distance)
+                        .into_iter()
+                        .map(|p| transform * p)
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut faces = Vec::<Face>::new();
+    for k in 0..segments {
+        for (bottom, top) in layers[k].iter().zip(layers[k + 1].iter()) {
+            let n = bottom.len();
+            for i in 0..n {
+                let (a, b) = (bottom[i], bottom[(i + 1) % n]);
+                let (a1, b1) = (top[i], top[(i + 1) % n]);
+                match options.twist == 0.0 {
+                    true => faces.push(planar_polygon(&[b, a, a1, b1])),
+                    false => {
+                        faces.push(planar_polygon(&[b, a, a1]));
+                        faces.push(planar_polygon(&[b, a1, b1]));
                     }
-                    _ => todo!("Not implemented"),
                 }
             }
-            Curve::Ellipse(_) => todo!("Implement this"),
-            Curve::Helix(_) => panic!("Cannot extrude helix"),
         }
     }
+
+    let top = &layers[segments];
+    let end_transform = Transform::from_translation(centroid + direction)
+        * Rotation::from_axis_angle(normal, options.twist).to_transform()
+        * Transform::from_translation(-centroid);
+    let end_face = Face::new(
+        Some(contour_from_corners(&top[0])),
+        top[1..].iter().map(|c| contour_from_corners(c)).collect(),
+        Rc::new(start_face.surface.transform(end_transform)),
+    )
+    .flip();
     faces.push(start_face);
     faces.push(end_face);
+    Ok(Volume::new(Shell::new(faces), vec![]))
+}
 
-    Volume::new(Shell::new(faces), vec![])
+// A wall of the thickness along a planar wire, extruded by length along direction, like the sides of an enclosure or a rib. The wire
+// runs in the middle of the wall and has to lie in a plane perpendicular to direction. The outline is built from the offsets of the
+// wire to both sides, so convex corners are rounded on the outside. Open wires get flat ends, and closed wires make a ring.
+pub fn extrude_wire(wire: &Wire, direction: Point, length: f64, thickness: f64) -> Volume {
+    assert!(thickness > EQ_THRESHOLD, "Wall needs a thickness");
+    let normal = direction.normalize();
+    let basis = wire.start();
+    assert!(
+        wire.all_points()
+            .iter()
+            .all(|p| (*p - basis).dot(normal).abs() < EQ_THRESHOLD),
+        "Wire has to lie in a plane perpendicular to the direction"
+    );
+    let left = offset_wire(wire, thickness / 2.0, normal);
+    let right = offset_wire(wire, -thickness / 2.0, normal).flip();
+
+    // The profile faces against the direction, like the start face of extrude. Around that normal the left offset and the reversed
+    // right offset enclose the wall on their left.
+    let (boundary, holes) = match wire.is_closed() {
+        true => {
+            let (left, right) = (left.to_contour(), right.to_contour());
+            match contour_signed_area(&left, -normal) > 0.0 {
+                true => (left, vec![right]),
+                false => (right, vec![left]),
+            }
+        }
+        false => {
+            let mut edges = left.edges.clone();
+            edges.push(primitive_line(left.end(), right.start()));
+            edges.extend(right.edges.iter().cloned());
+            edges.push(primitive_line(right.end(), left.start()));
+            (Contour::new(edges), vec![])
+        }
+    };
+    let u = match Point::unit_x().cross(normal).norm_sq() > Point::unit_y().cross(normal).norm_sq()
+    {
+        true => Point::unit_x().cross(normal).normalize(),
+        false => Point::unit_y().cross(normal).normalize(),
+    };
+    let plane = Plane::new(basis, u, u.cross(normal));
+    let profile = Face::new(Some(boundary), holes, Rc::new(Surface::Plane(plane)));
+    extrude(profile, normal * length)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        contains::volume_point::{volume_point_contains, VolumePointContains},
+        measure::mass::measure_volume,
+        primitive_objects::edges::circle::primitive_circle,
+        topology::diagnostics::check_volume,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_extrude_wire() {
+        // A straight wall of 4 x 0.5 x 2 along an open wire.
+        let p = |x: f64, y: f64| Point::new(x, y, 0.0);
+        let wire = Wire::new(vec![primitive_line(p(0.0, 0.0), p(4.0, 0.0))]);
+        let wall = extrude_wire(&wire, Point::unit_z(), 2.0, 0.5);
+        assert!(check_volume(&wall.boundary, &wall.cavities).is_empty());
+        assert!((measure_volume(&wall) - 4.0).abs() < 1e-9);
+
+        // A closed square makes a ring with rounded outer corners, whichever way it runs.
+        for corners in [
+            [p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)],
+            [p(0.0, 0.0), p(0.0, 2.0), p(2.0, 2.0), p(2.0, 0.0)],
+        ] {
+            let wire = Wire::new(
+                (0..4)
+                    .map(|i| primitive_line(corners[i], corners[(i + 1) % 4]))
+                    .collect(),
+            );
+            let ring = extrude_wire(&wire, Point::unit_z(), 1.0, 0.2);
+            assert!(check_volume(&ring.boundary, &ring.cavities).is_empty());
+            assert_eq!(ring.all_faces().len(), 4 + 4 + 4 + 2);
+            assert!(matches!(
+                volume_point_contains(&ring, Point::new(1.0, 1.0, 0.5)),
+                VolumePointContains::Outside
+            ));
+            assert!(matches!(
+                volume_point_contains(&ring, Point::new(1.0, 2.05, 0.5)),
+                VolumePointContains::Inside
+            ));
+        }
+    }
+
+    #[test]
+    fn test_extrude_with() {
+        // A square with the normal pointing down, like the bottom of a volume.
+        let plane = Plane::new(Point::zero(), Point::unit_y(), Point::unit_x());
+        let square = Face::rectangle(&plane, 2.0, 2.0);
+
+        // A draft of 45 degrees over a height of 0.5 makes a frustum with a top of 1 x 1.
+        let options = ExtrudeOptions::new().with_draft(PI / 4.0);
+        let frustum = extrude_with(square.clone(), Point::unit_z() * 0.5, &options).unwrap();
+        assert!(check_volume(&frustum.boundary, &frustum.cavities).is_empty());
+        assert_eq!(frustum.all_faces().len(), 6);
+        assert!((measure_volume(&frustum) - 0.5 / 3.0 * (4.0 + 1.0 + 2.0)).abs() < 1e-9);
+
+        // A twist by 90 degrees in 8 layers of triangles.
+        let options = ExtrudeOptions::new().with_twist(PI / 2.0, 8);
+        let twisted = extrude_with(square, Point::unit_z(), &options).unwrap();
+        assert!(check_volume(&twisted.boundary, &twisted.cavities).is_empty());
+        assert_eq!(twisted.all_faces().len(), 2 + 4 * 8 * 2);
+        assert!(twisted.all_faces()[0]
+            .all_points()
+            .contains(&Point::new(-1.0, -1.0, 0.0)));
+        assert!(twisted
+            .all_faces()
+            .iter()
+            .any(|f| f.all_points().contains(&Point::new(-1.0, 1.0, 1.0))));
+        assert!(matches!(
+            volume_point_contains(&twisted, Point::new(0.9, 0.0, 0.5)),
+            VolumePointContains::Inside
+        ));
+        assert!(matches!(
+            volume_point_contains(&twisted, Point::new(0.9, 0.9, 0.5)),
+            VolumePointContains::Outside
+        ));
+
+        // A disk can only be extruded straight.
+        let disk = Face::new(
+            Some(Contour::new(vec![primitive_circle(
+                Point::zero(),
+                -Point::unit_z(),
+                0.5,
+            )])),
+            vec![],
+            Rc::new(Surface::Plane(plane)),
+        );
+        let cylinder = extrude_with(disk.clone(), Point::unit_z(), &ExtrudeOptions::new()).unwrap();
+        assert_eq!(cylinder.all_faces().len(), 3);
+        for options in [
+            ExtrudeOptions::new().with_draft(0.1),
+            ExtrudeOptions::new().with_twist(PI / 2.0, 8),
+        ] {
+            let result = extrude_with(disk.clone(), Point::unit_z(), &options);
+            assert!(
+                matches!(result, Err(ExtrudeError::CurvedEdge(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
 }
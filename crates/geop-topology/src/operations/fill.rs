@@ -0,0 +1,156 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    fitting::{fit_plane, fit_sphere},
+    points::point::Point,
+    surfaces::{plane::Plane, sphere::Sphere, surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::topology::{face::Face, wire::Wire};
+
+// Number of points per edge to which the plane or the sphere is fitted.
+const EDGE_SAMPLES: usize = 8;
+
+// Fills a closed boundary with a patch face, e.g. to repair holes in imported shells. The boundary keeps its direction and runs
+// counterclockwise around the normal of the patch, so for a hole in a shell it has to run against the open edges around the hole.
+// The patch is the best fitting plane or sphere through the boundary:
+// - A planar boundary gets a planar face with the boundary edges.
+// - A boundary on a sphere, like a loop of great or small circle arcs, gets a face on the sphere with the boundary edges.
+// - Any other boundary gets a polygon through its corners projected onto the plane or the sphere that fits better, with lines on
+//   the plane and great circle arcs on the sphere. This only approximates the hole, since the patch does not share the edges of
+//   the boundary.
+pub fn fill_hole(boundary: &Wire) -> Face {
+    assert!(
+        boundary.is_closed(),
+        "The boundary of a hole has to be closed"
+    );
+    let contour = boundary.to_contour();
+    let points: Vec<Point> = contour
+        .edges
+        .iter()
+        .flat_map(|e| (0..EDGE_SAMPLES).map(|i| e.interpolate(i as f64 / EDGE_SAMPLES as f64)))
+        .collect();
+    assert!(points.len() >= 3, "The boundary encloses no area");
+
+    // The patch points to the side around which the boundary runs counterclockwise.
+    let centroid = points.iter().fold(Point::zero(), |sum, p| sum + *p) / points.len() as f64;
+    let mut normal = Point::zero();
+    for i in 0..points.len() {
+        normal = normal + (points[i] - centroid).cross(points[(i + 1) % points.len()] - centroid);
+    }
+
+    let plane = fit_plane(&points);
+    let plane_fit = (plane.rms_residual, plane.max_residual);
+    let plane = match plane.shape.normal(plane.shape.basis).dot(normal) > 0.0 {
+        true => plane.shape,
+        false => Plane::new(plane.shape.basis, plane.shape.v_slope, plane.shape.u_slope),
+    };
+    if plane_fit.1 < EQ_THRESHOLD {
+        return Face::new(Some(contour), vec![], Rc::new(Surface::Plane(plane)));
+    }
+
+    let fit = fit_sphere(&points);
+    let sphere = Sphere::new(
+        fit.shape.basis,
+        fit.shape.radius,
+        normal.dot(centroid - fit.shape.basis) > 0.0,
+    );
+    if fit.max_residual < EQ_THRESHOLD {
+        return Face::new(Some(contour), vec![], Rc::new(Surface::Sphere(sphere)));
+    }
+
+    // Spherical polygons have to fit into a hemisphere, otherwise the plane is used.
+    let corners: Vec<Point> = boundary.edges.iter().map(|e| e.start.unwrap()).collect();
+    let mean = corners
+        .iter()
+        .fold(Point::zero(), |sum, p| sum + (*p - sphere.basis));
+    let in_hemisphere = corners
+        .iter()
+        .all(|p| (*p - sphere.basis).dot(mean) > EQ_THRESHOLD * sphere.radius);
+    match fit.rms_residual < plane_fit.0 && in_hemisphere {
+        true => {
+            let corners: Vec<Point> = corners.iter().map(|p| sphere.project(*p)).collect();
+            Face::spherical_polygon(&sphere, &corners)
+        }
+        false => {
+            let corners: Vec<Point> = corners.iter().map(|p| plane.project(*p)).collect();
+            Face::polygon(&plane, &corners)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        measure::mass::measure_area,
+        primitive_objects::edges::{
+            arc::ArcSide, line::primitive_line, sphere_arc::primitive_great_circle_arc,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_fill_hole() {
+        let wire = |points: &[Point]| {
+            Wire::new(
+                (0..points.len())
+                    .map(|i| primitive_line(points[i], points[(i + 1) % points.len()]))
+                    .collect(),
+            )
+        };
+
+        // A tilted square, clockwise seen from above, gets a planar patch pointing down.
+        let square = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.0, 1.0, 1.0),
+            Point::new(1.0, 1.0, 1.0),
+            Point::new(1.0, 0.0, 0.0),
+        ];
+        let patch = fill_hole(&wire(&square));
+        assert!(matches!(&*patch.surface, Surface::Plane(_)));
+        assert!((measure_area(&patch) - 2.0_f64.sqrt()).abs() < 1e-9);
+        assert!(patch.normal(patch.inner_point()).z < 0.0);
+
+        // An octant of the unit sphere keeps its arcs.
+        let sphere = Sphere::new(Point::zero(), 1.0, true);
+        let corners = [Point::unit_x(), Point::unit_y(), Point::unit_z()];
+        let arcs = (0..3)
+            .map(|i| {
+                primitive_great_circle_arc(
+                    &sphere,
+                    corners[i],
+                    corners[(i + 1) % 3],
+                    ArcSide::Minor,
+                )
+            })
+            .collect();
+        let patch = fill_hole(&Wire::new(arcs));
+        assert!(matches!(&*patch.surface, Surface::Sphere(_)));
+        assert_eq!(patch.all_edges().len(), 3);
+        let p = patch.inner_point();
+        assert!(p.x > 0.0 && p.y > 0.0 && p.z > 0.0);
+        assert!(patch.normal(p).dot(p) > 0.0);
+
+        // A skew quad gets a polygon through its corners moved onto the patch.
+        let skew = [
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.2),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 0.2),
+        ];
+        let patch = fill_hole(&wire(&skew));
+        let moved = patch.boundary.as_ref().unwrap().all_points();
+        assert_eq!(moved.len(), 4);
+        for (corner, p) in skew.iter().zip(moved) {
+            assert!(
+                (*corner - p).norm() < 0.2,
+                "Corner {:?} moved to {:?}",
+                corner,
+                p
+            );
+        }
+        assert!(patch.normal(patch.inner_point()).z > 0.0);
+    }
+}
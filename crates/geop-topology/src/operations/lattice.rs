@@ -0,0 +1,232 @@
+use std::f64::consts::PI;
+
+use geop_geometry::{
+    bounding_box::BoundingBox,
+    curve_surface_intersection::curve_surface::{
+        curve_surface_intersection, CurveSurfaceIntersection,
+    },
+    curves::{curve::Curve, line::Line},
+    points::point::Point,
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::{
+        face_point::{face_point_contains, FacePointContains},
+        volume_point::{volume_point_contains, VolumePointContains},
+    },
+    primitive_objects::edges::line::primitive_line,
+    topology::{edge::Edge, face::Face, volume::Volume},
+};
+
+// Unit cells of strut lattices, which are repeated along the axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LatticeCell {
+    // Struts along the edges of the cell.
+    Cubic,
+    // Like cubic, with additional struts from the center of the cell to its corners.
+    BodyCentered,
+}
+
+fn volume_bounding_box(volume: &Volume) -> BoundingBox {
+    let mut faces = volume
+        .boundary
+        .faces
+        .iter()
+        .map(|f| f.bounds().bounds.bounding_box.clone());
+    let mut bounding_box = faces.next().unwrap();
+    for other in faces {
+        bounding_box.add_point(other.min);
+        bounding_box.add_point(other.max);
+    }
+    bounding_box
+}
+
+// The parts of the segment from a to b inside of the volume.
+fn clip_segment(faces: &[Face], volume: &Volume, a: Point, b: Point) -> Vec<Edge> {
+    let direction = b - a;
+    let line = Curve::Line(Line::new(a, direction));
+    let mut params = vec![0.0, 1.0];
+    for face in faces.iter() {
+        if let CurveSurfaceIntersection::Points(points) =
+            curve_surface_intersection(&line, &face.surface)
+        {
+            for p in points.iter().map(|p| p.point) {
+                let t = (p - a).dot(direction) / direction.norm_sq();
+                if t > 0.0 && t < 1.0 && face_point_contains(face, p) != FacePointContains::Outside
+                {
+                    params.push(t);
+                }
+            }
+        }
+    }
+    params.sort_by(|x, y| x.total_cmp(y));
+
+    let mut segments = Vec::<Edge>::new();
+    for pair in params.windows(2) {
+        let (start, end) = (a + direction * pair[0], a + direction * pair[1]);
+        if (end - start).norm() < EQ_THRESHOLD {
+            continue;
+        }
+        let mid = a + direction * ((pair[0] + pair[1]) / 2.0);
+        if matches!(
+            volume_point_contains(volume, mid),
+            VolumePointContains::Inside
+        ) {
+            segments.push(primitive_line(start, end));
+        }
+    }
+    segments
+}
+
+// Fills the volume with a periodic lattice of struts with the given cell size, e.g. for lightweighting. The struts are returned as
+// their center lines, clipped to the volume. The lattice starts at the minimum corner of the bounding box of the volume.
+pub fn strut_lattice(volume: &Volume, cell: LatticeCell, size: f64) -> Vec<Edge> {
+    assert!(size > 0.0);
+    let faces = volume.all_faces();
+    let bounding_box = volume_bounding_box(volume);
+    let extent = bounding_box.max - bounding_box.min;
+    let counts = [extent.x, extent.y, extent.z].map(|e| ((e / size).ceil() as usize).max(1));
+    let node = |i: usize, j: usize, k: usize| {
+        bounding_box.min + Point::new(i as f64, j as f64, k as f64) * size
+    };
+
+    let mut struts = Vec::<Edge>::new();
+    for i in 0..=counts[0] {
+        for j in 0..=counts[1] {
+            for k in 0..=counts[2] {
+                let p = node(i, j, k);
+                if i < counts[0] {
+                    struts.extend(clip_segment(&faces, volume, p, node(i + 1, j, k)));
+                }
+                if j < counts[1] {
+                    struts.extend(clip_segment(&faces, volume, p, node(i, j + 1, k)));
+                }
+                if k < counts[2] {
+                    struts.extend(clip_segment(&faces, volume, p, node(i, j, k + 1)));
+                }
+                if cell == LatticeCell::BodyCentered
+                    && i < counts[0]
+                    && j < counts[1]
+                    && k < counts[2]
+                {
+                    let center = p + Point::new(0.5, 0.5, 0.5) * size;
+                    for corner in 0..8 {
+                        let q = node(i + (corner & 1), j + (corner >> 1 & 1), k + (corner >> 2));
+                        struts.extend(clip_segment(&faces, volume, center, q));
+                    }
+                }
+            }
+        }
+    }
+    struts
+}
+
+// An implicit gyroid infill. The gyroid is a triply periodic minimal surface, and the infill is the material within half the
+// thickness of it, which splits the volume into two interleaved channels.
+#[derive(Debug, Clone)]
+pub struct GyroidInfill {
+    pub volume: Volume,
+    pub period: f64,
+    pub thickness: f64,
+}
+
+impl GyroidInfill {
+    pub fn new(volume: Volume, period: f64, thickness: f64) -> GyroidInfill {
+        assert!(period > 0.0 && thickness > 0.0);
+        GyroidInfill {
+            volume,
+            period,
+            thickness,
+        }
+    }
+
+    // The approximation sin x cos y + sin y cos z + sin z cos x of the gyroid, which is 0 on the surface.
+    pub fn value(&self, p: Point) -> f64 {
+        let s = 2.0 * PI / self.period;
+        let (x, y, z) = (p.x * s, p.y * s, p.z * s);
+        x.sin() * y.cos() + y.sin() * z.cos() + z.sin() * x.cos()
+    }
+
+    // Whether the point is part of the infill. The distance to the surface is estimated from the value and its gradient.
+    pub fn contains(&self, p: Point) -> bool {
+        let s = 2.0 * PI / self.period;
+        let (x, y, z) = (p.x * s, p.y * s, p.z * s);
+        let gradient = Point::new(
+            x.cos() * y.cos() - z.sin() * x.sin(),
+            y.cos() * z.cos() - x.sin() * y.sin(),
+            z.cos() * x.cos() - y.sin() * z.sin(),
+        ) * s;
+        let distance = self.value(p).abs() / gradient.norm().max(EQ_THRESHOLD);
+        distance <= self.thickness / 2.0
+            && matches!(
+                volume_point_contains(&self.volume, p),
+                VolumePointContains::Inside
+            )
+    }
+
+    // The points of a grid with the given spacing that are part of the infill, e.g. for previews.
+    pub fn sample(&self, spacing: f64) -> Vec<Point> {
+        assert!(spacing > 0.0);
+        let bounding_box = volume_bounding_box(&self.volume);
+        let extent = bounding_box.max - bounding_box.min;
+        let counts = [extent.x, extent.y, extent.z].map(|e| (e / spacing).ceil() as usize);
+        let mut points = Vec::<Point>::new();
+        for i in 0..counts[0] {
+            for j in 0..counts[1] {
+                for k in 0..counts[2] {
+                    let p = bounding_box.min
+                        + Point::new(i as f64 + 0.5, j as f64 + 0.5, k as f64 + 0.5) * spacing;
+                    if self.contains(p) {
+                        points.push(p);
+                    }
+                }
+            }
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    #[test]
+    fn test_strut_lattice() {
+        // The cube from -1 to 1 with cells of size 1 has 3 x 3 nodes on each of 3 layers. Struts on the faces of the cube are
+        // not inside of it.
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let struts = strut_lattice(&cube, LatticeCell::Cubic, 1.0);
+        assert_eq!(struts.len(), 6);
+        for strut in struts.iter() {
+            assert!((strut.length().unwrap() - 1.0).abs() < 1e-9);
+        }
+
+        // Cells of size 1.5 overhang the cube, so the struts are clipped at its faces.
+        let struts = strut_lattice(&cube, LatticeCell::BodyCentered, 1.5);
+        assert!(!struts.is_empty());
+        for strut in struts.iter() {
+            for p in [strut.start.unwrap(), strut.end.unwrap()] {
+                assert!(
+                    p.x.abs() <= 1.0 + 1e-9 && p.y.abs() <= 1.0 + 1e-9 && p.z.abs() <= 1.0 + 1e-9
+                );
+            }
+        }
+        assert!(struts
+            .iter()
+            .any(|s| (s.length().unwrap() - 0.5).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_gyroid_infill() {
+        let infill = GyroidInfill::new(primitive_cube(2.0, 2.0, 2.0), 1.0, 0.2);
+        assert!(infill.value(Point::zero()).abs() < 1e-12);
+        assert!(infill.contains(Point::new(0.01, 0.0, 0.0)));
+        assert!(!infill.contains(Point::new(0.25, 0.0, 0.0)));
+        assert!(!infill.contains(Point::new(2.0, 0.0, 0.0)));
+        let points = infill.sample(0.1);
+        assert!(!points.is_empty() && points.len() < 8000);
+        assert!(points.iter().all(|p| infill.contains(*p)));
+    }
+}
@@ -1 +1,15 @@
+pub mod cap;
+pub mod draft;
 pub mod extrude;
+pub mod fill;
+pub mod lattice;
+pub mod offset_wire;
+pub mod pattern;
+pub mod replace_planes;
+pub mod seam;
+pub mod sew;
+pub mod shell;
+pub mod simplify;
+pub mod sweep;
+pub mod weld;
+pub mod wrap;
@@ -0,0 +1,144 @@
+use geop_geometry::{
+    curve_curve_intersection::curve_curve::{curve_curve_intersection, CurveCurveIntersection},
+    curves::{circle::Circle, curve::Curve, CurveLike},
+    points::point::Point,
+};
+
+use crate::topology::{
+    edge::Edge,
+    wire::{offset_edge, Wire},
+};
+
+// The arc around a convex corner of the wire, from the end of the offset edge before the corner to the start of the one after it.
+// It continues in the direction of the edge before the corner.
+fn corner_arc(corner: Point, end: Point, start: Point, tangent: Point, normal: Point) -> Edge {
+    let normal = match normal.cross(end - corner).dot(tangent) > 0.0 {
+        true => normal,
+        false => -normal,
+    };
+    let radius = (end - corner).norm();
+    Edge::new(
+        Some(end),
+        Some(start),
+        Curve::Circle(Circle::new(corner, normal, radius)),
+    )
+}
+
+// Where the offset edges before and after a concave corner cross. Of several crossings of arcs, the one closest to the corner is
+// taken.
+pub(crate) fn trim_point(before: &Edge, after: &Edge, corner: Point) -> Point {
+    let p = match curve_curve_intersection(&before.curve, &after.curve) {
+        CurveCurveIntersection::FinitePoints(points) => points
+            .iter()
+            .map(|p| p.point)
+            .min_by(|a, b| (*a - corner).norm().total_cmp(&(*b - corner).norm()))
+            .expect("Finite intersections have points"),
+        _ => panic!("Offset edges at a concave corner do not cross"),
+    };
+    assert!(
+        p != before.start.unwrap()
+            && p != after.end.unwrap()
+            && before.curve.between(p, before.start, before.end)
+            && after.curve.between(p, after.start, after.end),
+        "Offset is larger than an edge at a concave corner"
+    );
+    p
+}
+
+// Offsets a planar wire of lines and arcs by distance to the left, where left is normal x tangent, like Wire::offset. Instead of
+// bridging the gaps at convex corners with lines, they are rounded with arcs around the corner, and the edges at concave corners are
+// trimmed where they cross, which gives wall outlines and toolpaths at a constant distance from the wire. Closed wires stay closed.
+// Edges that vanish completely, because the offset is larger than they are, are not removed.
+pub fn offset_wire(wire: &Wire, distance: f64, normal: Point) -> Wire {
+    let normal = normal.normalize();
+    let mut edges: Vec<Edge> = wire
+        .edges
+        .iter()
+        .map(|e| offset_edge(e, distance, normal))
+        .collect();
+    let n = edges.len();
+    let corners = match wire.is_closed() {
+        true => n,
+        false => n - 1,
+    };
+    let mut arcs: Vec<Option<Edge>> = vec![None; n];
+    for i in 0..corners {
+        let j = (i + 1) % n;
+        let (end, start) = (edges[i].end.unwrap(), edges[j].start.unwrap());
+        if end == start {
+            continue;
+        }
+        let corner = wire.edges[i].end.unwrap();
+        let tangent = wire.edges[i].tangent(corner);
+        let turn = normal.dot(tangent.cross(wire.edges[j].tangent(corner)));
+        match turn * distance < 0.0 {
+            true => arcs[i] = Some(corner_arc(corner, end, start, tangent, normal)),
+            false => {
+                let p = trim_point(&edges[i], &edges[j], corner);
+                edges[i] = Edge::new(edges[i].start, Some(p), edges[i].curve.clone());
+                edges[j] = Edge::new(Some(p), edges[j].end, edges[j].curve.clone());
+            }
+        }
+    }
+    Wire::new(
+        edges
+            .into_iter()
+            .zip(arcs)
+            .flat_map(|(edge, arc)| std::iter::once(edge).chain(arc))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::EQ_THRESHOLD;
+
+    use crate::primitive_objects::edges::line::primitive_line;
+
+    use super::*;
+
+    #[test]
+    fn test_offset_wire() {
+        let p = |x: f64, y: f64| Point::new(x, y, 0.0);
+        let wire = Wire::new(vec![
+            primitive_line(p(0.0, 0.0), p(2.0, 0.0)),
+            primitive_line(p(2.0, 0.0), p(2.0, 1.0)),
+        ]);
+
+        // The wire turns left, so the corner is concave on the left and the edges are trimmed.
+        let inner = offset_wire(&wire, 0.5, Point::unit_z());
+        assert_eq!(inner.edges.len(), 2);
+        assert_eq!(
+            inner.all_points(),
+            vec![p(0.0, 0.5), p(1.5, 0.5), p(1.5, 1.0)]
+        );
+
+        // On the right, the corner is rounded.
+        let outer = offset_wire(&wire, -0.5, Point::unit_z());
+        assert_eq!(outer.edges.len(), 3);
+        assert_eq!(outer.edges[1].start, Some(p(2.0, -0.5)));
+        assert_eq!(outer.edges[1].end, Some(p(2.5, 0.0)));
+        let mid = outer.edges[1].interpolate(0.5);
+        assert!(((mid - p(2.0, 0.0)).norm() - 0.5).abs() < EQ_THRESHOLD);
+        assert!(mid.x > 2.0 && mid.y < 0.0);
+        assert!((outer.length() - (2.0 + 1.0 + std::f64::consts::PI / 4.0)).abs() < 1e-9);
+
+        // A counterclockwise square shrinks to the left and grows with rounded corners to the right.
+        let square = Wire::new(vec![
+            primitive_line(p(0.0, 0.0), p(2.0, 0.0)),
+            primitive_line(p(2.0, 0.0), p(2.0, 2.0)),
+            primitive_line(p(2.0, 2.0), p(0.0, 2.0)),
+            primitive_line(p(0.0, 2.0), p(0.0, 0.0)),
+        ]);
+        let shrunk = offset_wire(&square, 0.5, Point::unit_z());
+        assert!(shrunk.is_closed());
+        assert_eq!(
+            shrunk.all_points(),
+            vec![p(0.5, 0.5), p(1.5, 0.5), p(1.5, 1.5), p(0.5, 1.5)]
+        );
+        let grown = offset_wire(&square, -0.5, Point::unit_z());
+        assert!(grown.is_closed());
+        assert_eq!(grown.edges.len(), 8);
+        assert!((grown.length() - (8.0 + std::f64::consts::PI)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,77 @@
+use geop_geometry::transforms::Transform;
+
+use crate::topology::{volume::Volume, wire::Wire};
+
+// Places count copies of the volume along the wire, evenly spaced by length. The first copy is the volume itself, which sits at the start
+// of the wire. Open wires get a copy at both ends, closed wires spread the copies around the loop. With align_to_tangent, the copies turn
+// with the rotation minimizing frames of the wire, otherwise they are only moved. Overlapping copies are not merged, union_all does that.
+pub fn pattern_along_curve(
+    volume: &Volume,
+    wire: &Wire,
+    count: usize,
+    align_to_tangent: bool,
+) -> Vec<Volume> {
+    assert!(count >= 1);
+    let spacing = match (wire.is_closed(), count) {
+        (true, _) => wire.length() / count as f64,
+        (false, 1) => 0.0,
+        (false, _) => wire.length() / (count - 1) as f64,
+    };
+    let distances: Vec<f64> = (0..count).map(|i| i as f64 * spacing).collect();
+    let frames = wire.rmf_frames_at(&distances);
+    let to_first = frames[0].to_local_transform();
+    frames
+        .iter()
+        .map(|frame| {
+            let transform = match align_to_tangent {
+                true => frame.to_global_transform() * to_first,
+                false => Transform::from_translation(frame.origin - frames[0].origin),
+            };
+            volume.transform(transform)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::points::point::Point;
+
+    use super::*;
+    use crate::{
+        measure::mass::measure_volume,
+        primitive_objects::{
+            edges::{arc::primitive_arc, line::primitive_line},
+            volumes::cube::primitive_cube,
+        },
+    };
+
+    #[test]
+    fn test_pattern_along_curve() {
+        let cube = primitive_cube(1.0, 1.0, 1.0);
+        let line = Wire::new(vec![primitive_line(
+            Point::zero(),
+            Point::new(4.0, 0.0, 0.0),
+        )]);
+        let copies = pattern_along_curve(&cube, &line, 3, false);
+        assert_eq!(copies.len(), 3);
+        let points = |v: &Volume| -> Vec<Point> {
+            v.all_faces().iter().flat_map(|f| f.all_points()).collect()
+        };
+        assert!(points(&copies[2]).contains(&Point::new(4.5, 0.5, 0.5)));
+
+        // Along a quarter circle, an aligned copy at the end is turned by 90 degrees, so a bar that is long along the path stays long
+        // along it.
+        let bar = primitive_cube(0.2, 1.0, 0.2);
+        let start = Point::new(2.0, 0.0, 0.0);
+        let end = Point::new(0.0, 2.0, 0.0);
+        let arc = Wire::new(vec![primitive_arc(start, end, 2.0, Point::unit_z())]);
+        let moved = bar.transform(Transform::from_translation(start));
+        let copies = pattern_along_curve(&moved, &arc, 2, true);
+        let last = points(&copies[1]);
+        let (min_x, max_x) = last
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(a, b), p| (a.min(p.x), b.max(p.x)));
+        assert!((max_x - min_x - 1.0).abs() < 1e-6);
+        assert!((measure_volume(&copies[1]) - measure_volume(&moved)).abs() < 1e-9);
+    }
+}
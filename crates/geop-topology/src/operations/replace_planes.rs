@@ -0,0 +1,127 @@
+use std::{
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use geop_geometry::{
+    curves::{curve::Curve, line::Line},
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+    EQ_THRESHOLD,
+};
+
+use crate::topology::{contour::Contour, edge::Edge, face::Face};
+
+#[derive(Debug, Clone)]
+pub enum ReplacePlanesError {
+    UndefinedVertex(Point), // The new planes of the faces around the vertex do not meet in a single point
+    CollapsedEdge(usize), // An edge of the face shrinks to a point or turns around, i.e. the face passes through its neighbours
+}
+
+impl Display for ReplacePlanesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplacePlanesError::UndefinedVertex(p) => {
+                write!(
+                    f,
+                    "Planes of the faces around {:?} do not meet in a point",
+                    p
+                )
+            }
+            ReplacePlanesError::CollapsedEdge(i) => {
+                write!(f, "An edge of face {} collapses", i)
+            }
+        }
+    }
+}
+
+// The point closest to all planes in the least squares sense, if they meet in a single point. Planes are given by their unit
+// normal n and distance d, i.e. the points x with n * x = d.
+fn planes_intersection(planes: &[(Point, f64)]) -> Option<Point> {
+    let (mut a, mut b) = ([Point::zero(); 3], Point::zero());
+    for (n, d) in planes.iter() {
+        a[0] = a[0] + *n * n.x;
+        a[1] = a[1] + *n * n.y;
+        a[2] = a[2] + *n * n.z;
+        b = b + *n * *d;
+    }
+    // Cramer's rule for the symmetric normal equations.
+    let det = a[0].dot(a[1].cross(a[2]));
+    if det.abs() < EQ_THRESHOLD {
+        return None;
+    }
+    let p = Point::new(
+        b.dot(a[1].cross(a[2])),
+        a[0].dot(b.cross(a[2])),
+        a[0].dot(a[1].cross(b)),
+    ) / det;
+    match planes.iter().all(|(n, d)| (n.dot(p) - d).abs() < 1e-6) {
+        true => Some(p),
+        false => None,
+    }
+}
+
+// Moves the faces of a polyhedron onto new planes, one for every face, and re-trims them against each other. Every vertex moves
+// to where the new planes of its faces meet, so the faces keep their topology. The planes have to point to the same side as the
+// faces.
+pub fn replace_planes(faces: &[Face], planes: &[Plane]) -> Result<Vec<Face>, ReplacePlanesError> {
+    assert!(faces.len() == planes.len());
+    let equations: Vec<(Point, f64)> = planes
+        .iter()
+        .map(|plane| {
+            let normal = plane.u_slope.cross(plane.v_slope).normalize();
+            (normal, normal.dot(plane.basis))
+        })
+        .collect();
+    let mut vertices = Vec::<(Point, Point)>::new();
+    for face in faces.iter() {
+        for p in face.all_points() {
+            if vertices.iter().any(|(q, _)| *q == p) {
+                continue;
+            }
+            let adjacent: Vec<(Point, f64)> = (0..faces.len())
+                .filter(|i| faces[*i].all_points().contains(&p))
+                .map(|i| equations[i])
+                .collect();
+            let moved =
+                planes_intersection(&adjacent).ok_or(ReplacePlanesError::UndefinedVertex(p))?;
+            vertices.push((p, moved));
+        }
+    }
+    let moved = |p: Point| vertices.iter().find(|(q, _)| *q == p).unwrap().1;
+
+    let mut result = Vec::<Face>::new();
+    for (i, face) in faces.iter().enumerate() {
+        let move_contour = |contour: &Contour| -> Result<Contour, ReplacePlanesError> {
+            let mut edges = Vec::<Edge>::new();
+            for edge in contour.edges.iter() {
+                let (start, end) = match (&edge.curve, edge.start, edge.end) {
+                    (Curve::Line(_), Some(start), Some(end)) => (start, end),
+                    _ => todo!("Replacing planes of faces with curved edges"),
+                };
+                let (new_start, new_end) = (moved(start), moved(end));
+                if (new_end - new_start).dot(end - start) <= EQ_THRESHOLD {
+                    return Err(ReplacePlanesError::CollapsedEdge(i));
+                }
+                edges.push(Edge::new(
+                    Some(new_start),
+                    Some(new_end),
+                    Curve::Line(Line::new(new_start, new_end - new_start)),
+                ));
+            }
+            Ok(Contour::new(edges))
+        };
+        let boundary = face.boundary.as_ref().map(move_contour).transpose()?;
+        let holes = face
+            .holes
+            .iter()
+            .map(move_contour)
+            .collect::<Result<Vec<Contour>, ReplacePlanesError>>()?;
+        result.push(Face::new(
+            boundary,
+            holes,
+            Rc::new(Surface::Plane(planes[i].clone())),
+        ));
+    }
+    Ok(result)
+}
@@ -0,0 +1,146 @@
+use geop_geometry::{
+    curve_surface_intersection::curve_surface::{
+        curve_surface_intersection, CurveSurfaceIntersection,
+    },
+    curves::CurveLike,
+    periodic::seam_half_plane,
+    points::point::Point,
+    surfaces::surface::Surface,
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::edge_point::{edge_point_contains, EdgePointContains},
+    topology::{contour::Contour, edge::Edge, face::Face},
+};
+
+// Points where the edge crosses the seam of the surface, excluding its end points.
+fn seam_points(edge: &Edge, surface: &Surface) -> Vec<Point> {
+    let (plane, direction) = match seam_half_plane(surface) {
+        Some(seam) => seam,
+        None => return Vec::new(),
+    };
+    let points = match curve_surface_intersection(&edge.curve, &Surface::Plane(plane.clone())) {
+        CurveSurfaceIntersection::Points(points) => points.into_iter().map(|p| p.point),
+        // The edge runs along the seam, or does not touch it.
+        CurveSurfaceIntersection::Curve(_) | CurveSurfaceIntersection::None => return Vec::new(),
+    };
+    points
+        .into_iter()
+        .filter(|p| (*p - plane.basis).dot(direction) > EQ_THRESHOLD)
+        .filter(|p| edge_point_contains(edge, *p) == EdgePointContains::Inside)
+        .collect()
+}
+
+// Sorts the points in the order in which they appear on the edge, when starting at start.
+fn sort_along(edge: &Edge, start: Point, points: &mut [Point]) {
+    points.sort_by(|a, b| {
+        if a == b {
+            std::cmp::Ordering::Equal
+        } else if edge.curve.between(*a, Some(start), Some(*b)) {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Greater
+        }
+    });
+}
+
+// Splits an edge on a periodic surface where it crosses the seam, i.e. where the u parameter wraps around.
+// Each piece then has a continuous parameterization, which is needed to work with the edge in parameter space.
+// A closed edge that crosses the seam only once is returned as is, since it cannot be split into pieces with distinct end points.
+pub fn split_edge_at_seam(edge: &Edge, surface: &Surface) -> Vec<Edge> {
+    let mut points = seam_points(edge, surface);
+    if points.is_empty() {
+        return vec![edge.clone()];
+    }
+    match (edge.start, edge.end) {
+        (Some(start), Some(end)) => {
+            sort_along(edge, start, &mut points);
+            let mut vertices = vec![start];
+            vertices.extend(points);
+            vertices.push(end);
+            vertices
+                .windows(2)
+                .map(|w| Edge::new(Some(w[0]), Some(w[1]), edge.curve.clone()))
+                .collect()
+        }
+        (None, None) => {
+            if points.len() < 2 {
+                return vec![edge.clone()];
+            }
+            let first = points[0];
+            sort_along(edge, first, &mut points);
+            (0..points.len())
+                .map(|i| {
+                    Edge::new(
+                        Some(points[i]),
+                        Some(points[(i + 1) % points.len()]),
+                        edge.curve.clone(),
+                    )
+                })
+                .collect()
+        }
+        _ => todo!("Seam splitting of half infinite edges"),
+    }
+}
+
+fn split_contour_at_seam(contour: &Contour, surface: &Surface) -> Contour {
+    Contour::new(
+        contour
+            .edges
+            .iter()
+            .flat_map(|e| split_edge_at_seam(e, surface))
+            .collect(),
+    )
+}
+
+// Splits all edges of the face at the seam of its surface, so that the seam crossings become vertices of the contours.
+pub fn split_face_at_seam(face: &Face) -> Face {
+    Face::new(
+        face.boundary
+            .as_ref()
+            .map(|b| split_contour_at_seam(b, &face.surface)),
+        face.holes
+            .iter()
+            .map(|h| split_contour_at_seam(h, &face.surface))
+            .collect(),
+        face.surface.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use geop_geometry::{
+        curves::{circle::Circle, curve::Curve},
+        surfaces::{cylinder::Cylinder, SurfaceLike},
+    };
+
+    #[test]
+    fn test_split_edge_at_seam() {
+        let cylinder = Cylinder::new(Point::zero(), Point::unit_z(), 1.0, true);
+        let surface = Surface::Cylinder(cylinder.clone());
+        let circle = Circle::new(Point::new(0.0, 0.0, 1.0), Point::unit_z(), 1.0);
+        let seam = cylinder.radius + Point::new(0.0, 0.0, 1.0);
+
+        // An arc from -pi/4 to pi/4 crosses the seam at angle 0.
+        let start = surface.point_at(Point::new(-PI / 4.0, 1.0, 0.0));
+        let end = surface.point_at(Point::new(PI / 4.0, 1.0, 0.0));
+        let arc = Edge::new(Some(start), Some(end), Curve::Circle(circle.clone()));
+        let pieces = split_edge_at_seam(&arc, &surface);
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces[0].start, Some(start));
+        assert_eq!(pieces[0].end, Some(seam));
+        assert_eq!(pieces[1].end, Some(end));
+
+        // The arc in the other direction does not cross the seam.
+        let arc = Edge::new(Some(end), Some(start), Curve::Circle(circle));
+        assert_eq!(
+            edge_point_contains(&arc, surface.point_at(Point::new(PI, 1.0, 0.0))),
+            EdgePointContains::Inside
+        );
+        assert_eq!(split_edge_at_seam(&arc, &surface).len(), 1);
+    }
+}
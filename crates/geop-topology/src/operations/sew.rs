@@ -0,0 +1,299 @@
+use std::{collections::VecDeque, rc::Rc};
+
+use geop_geometry::{
+    curves::CurveLike,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD, HORIZON_DIST,
+};
+
+use crate::{
+    contains::volume_point::{volume_point_contains, VolumePointContains},
+    topology::{
+        contour::Contour,
+        edge::Edge,
+        face::{bounds::FaceBoundsCache, Face},
+        non_manifold_shell::NonManifoldShell,
+        shell::Shell,
+        volume::Volume,
+    },
+};
+
+use super::weld::{rebuild_edge, weld_points};
+
+// Maximum rounds of projecting a welded vertex onto the surfaces that meet at it.
+const PROJECTION_ITERATIONS: usize = 32;
+
+#[derive(Clone, Debug)]
+pub struct SewResult {
+    pub volumes: Vec<Volume>,
+    // Connected faces that do not close up, e.g. because a face is missing or an edge is shared by more than two faces.
+    pub open_shells: Vec<Shell>,
+}
+
+// A use of a shared edge by a contour of a face, and whether it runs along the shared edge or against it.
+#[derive(Clone, Copy)]
+struct EdgeUse {
+    face: usize,
+    forward: bool,
+}
+
+// Whether both edges run along the same curve within the tolerance, independent of their direction.
+fn coincident(a: &Edge, b: &Edge, tolerance: f64) -> bool {
+    let same_ends =
+        (a.start == b.start && a.end == b.end) || (a.start == b.end && a.end == b.start);
+    same_ends
+        && [0.25, 0.5, 0.75].iter().all(|t| {
+            let p = a.interpolate(*t);
+            b.curve.project(p).distance <= tolerance
+        })
+}
+
+// Whether the edge runs in the same direction as the shared edge it coincides with.
+fn same_direction(edge: &Edge, shared: &Edge) -> bool {
+    if edge.start.is_some() && edge.start != edge.end {
+        return edge.start == shared.start;
+    }
+    let p = shared.get_midpoint();
+    edge.curve.tangent(p).dot(shared.curve.tangent(p)) > 0.0
+}
+
+// Sews a bag of faces, e.g. the faces of an imported STEP file, into volumes. Boundary vertices closer than the tolerance are
+// welded onto the surfaces that meet at them, and edges that coincide within the tolerance are replaced by a single shared edge.
+// Faces are then flipped so that neighbouring faces run along their shared edges in opposite directions. Every connected set of
+// faces in which every edge is shared by exactly two faces becomes a closed shell, oriented with normals pointing outwards, and
+// closed shells inside of others become their cavities. All other connected sets of faces are returned as open shells.
+pub fn sew(faces: &[Face], tolerance: f64) -> SewResult {
+    let mut points = Vec::<Point>::new();
+    for face in faces.iter() {
+        for edge in face.all_edges() {
+            points.extend(edge.start);
+            points.extend(edge.end);
+        }
+    }
+    let (mut welded, mapping) = weld_points(&points, tolerance);
+
+    // Welded vertices are moved onto the surfaces of all faces that meet at them, by projecting onto the surfaces in turn.
+    let mut surfaces = vec![Vec::<Rc<Surface>>::new(); welded.len()];
+    let mut index = 0;
+    for face in faces.iter() {
+        for edge in face.all_edges() {
+            let count = edge.start.iter().count() + edge.end.iter().count();
+            for w in mapping[index..index + count].iter() {
+                if !surfaces[*w].iter().any(|s| Rc::ptr_eq(s, &face.surface)) {
+                    surfaces[*w].push(face.surface.clone());
+                }
+            }
+            index += count;
+        }
+    }
+    for (p, surfaces) in welded.iter_mut().zip(surfaces.iter()) {
+        for _ in 0..PROJECTION_ITERATIONS {
+            let last = *p;
+            for surface in surfaces.iter() {
+                *p = surface.project(*p);
+            }
+            if (*p - last).norm() < EQ_THRESHOLD * EQ_THRESHOLD {
+                break;
+            }
+        }
+    }
+
+    // Every contour of every face as indices into the shared edges. Edges that collapse to a point are dropped.
+    let mut mapping = mapping.into_iter();
+    let mut shared = Vec::<Edge>::new();
+    let mut uses = Vec::<Vec<EdgeUse>>::new();
+    let mut contours = Vec::<Vec<Vec<(usize, bool)>>>::new();
+    for (i, face) in faces.iter().enumerate() {
+        let mut face_contours = Vec::<Vec<(usize, bool)>>::new();
+        for contour in face.boundary.iter().chain(face.holes.iter()) {
+            let mut indices = Vec::<(usize, bool)>::new();
+            for edge in contour.edges.iter() {
+                let start = edge.start.map(|_| welded[mapping.next().unwrap()]);
+                let end = edge.end.map(|_| welded[mapping.next().unwrap()]);
+                let edge = match rebuild_edge(edge, start, end) {
+                    Some(edge) => edge,
+                    None => continue,
+                };
+                let index = match shared.iter().position(|e| coincident(&edge, e, tolerance)) {
+                    Some(index) => index,
+                    None => {
+                        shared.push(edge.clone());
+                        uses.push(Vec::new());
+                        shared.len() - 1
+                    }
+                };
+                let forward = same_direction(&edge, &shared[index]);
+                uses[index].push(EdgeUse { face: i, forward });
+                indices.push((index, forward));
+            }
+            face_contours.push(indices);
+        }
+        contours.push(face_contours);
+    }
+
+    // Walks over the connected faces and decides which ones have to be flipped to match the orientation of their neighbours.
+    let mut flipped = vec![None::<bool>; faces.len()];
+    let mut components = Vec::<(Vec<usize>, bool)>::new();
+    for seed in 0..faces.len() {
+        if flipped[seed].is_some() {
+            continue;
+        }
+        flipped[seed] = Some(false);
+        let mut component = Vec::<usize>::new();
+        let mut closed = true;
+        let mut queue = VecDeque::from([seed]);
+        while let Some(i) = queue.pop_front() {
+            component.push(i);
+            for &(index, forward) in contours[i].iter().flatten() {
+                let edge_uses = &uses[index];
+                if edge_uses.len() != 2 {
+                    closed = false;
+                }
+                for other in edge_uses.iter().filter(|u| u.face != i) {
+                    // Neighbours have to run along the shared edge in opposite directions.
+                    let flip = other.forward == (forward != flipped[i].unwrap());
+                    match flipped[other.face] {
+                        None => {
+                            flipped[other.face] = Some(flip);
+                            queue.push_back(other.face);
+                        }
+                        Some(f) if f != flip => closed = false,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        component.sort();
+        components.push((component, closed));
+    }
+
+    // Faces are rebuilt directly. Vertices lie on all adjacent surfaces, but a shared curve is taken from one of the faces and is
+    // only guaranteed to lie within the tolerance of the other surface.
+    let sewn: Vec<Face> = faces
+        .iter()
+        .enumerate()
+        .map(|(i, face)| {
+            let mut face_contours = contours[i].iter().map(|indices| {
+                Contour::new(
+                    indices
+                        .iter()
+                        .map(|&(index, forward)| match forward {
+                            true => shared[index].clone(),
+                            false => shared[index].flip(),
+                        })
+                        .collect(),
+                )
+            });
+            let face = Face {
+                boundary: face
+                    .boundary
+                    .as_ref()
+                    .map(|_| face_contours.next().unwrap()),
+                holes: face_contours.collect(),
+                surface: face.surface.clone(),
+                attributes: face.attributes.clone(),
+                bounds_cache: FaceBoundsCache::new(),
+            };
+            match flipped[i].unwrap() {
+                true => face.flip(),
+                false => face,
+            }
+        })
+        .collect();
+
+    let mut closed_shells = Vec::<Shell>::new();
+    let mut open_shells = Vec::<Shell>::new();
+    for (component, closed) in components {
+        let shell = Shell::new(component.iter().map(|i| sewn[*i].clone()).collect());
+        match closed {
+            true => closed_shells.push(shell),
+            false => open_shells.push(shell),
+        }
+    }
+
+    // Closed shells are turned outwards first. Shells inside of an odd number of other shells are cavities and point into the void.
+    let is_inside = |shell: &Shell, p: Point| {
+        matches!(
+            volume_point_contains(&Volume::new(shell.clone(), vec![]), p),
+            VolumePointContains::Inside
+        )
+    };
+    let far_away = Point::new(1.13, 1.27, 1.41) * HORIZON_DIST * 10.0;
+    let closed_shells: Vec<Shell> = closed_shells
+        .into_iter()
+        .map(|shell| match is_inside(&shell, far_away) {
+            true => shell.flip(),
+            false => shell,
+        })
+        .collect();
+    let mut faces = Vec::<Face>::new();
+    for (i, shell) in closed_shells.iter().enumerate() {
+        let p = shell.faces[0].inner_point();
+        let depth = closed_shells
+            .iter()
+            .enumerate()
+            .filter(|(j, other)| *j != i && is_inside(other, p))
+            .count();
+        match depth % 2 {
+            0 => faces.extend(shell.faces.iter().cloned()),
+            _ => faces.extend(shell.flip().faces),
+        }
+    }
+
+    SewResult {
+        volumes: NonManifoldShell::new(faces).to_volumes(),
+        open_shells,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::transforms::Transform;
+
+    use super::*;
+    use crate::{
+        measure::mass::measure_volume, primitive_objects::volumes::cube::primitive_cube,
+        topology::diagnostics::check_shell,
+    };
+
+    #[test]
+    fn test_sew() {
+        // The faces of a cube, with some of them flipped and shifted by less than the tolerance.
+        let faces: Vec<Face> = primitive_cube(2.0, 2.0, 2.0)
+            .all_faces()
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let f = f.transform(Transform::from_translation(Point::new(
+                    i as f64 * 1e-4,
+                    0.0,
+                    -(i as f64) * 1e-4,
+                )));
+                match i % 2 {
+                    0 => f.flip(),
+                    _ => f,
+                }
+            })
+            .collect();
+        let result = sew(&faces, 1e-2);
+        assert_eq!(result.volumes.len(), 1);
+        assert!(result.open_shells.is_empty());
+        assert!(check_shell(&result.volumes[0].boundary.faces).is_empty());
+        assert!((measure_volume(&result.volumes[0]) - 8.0).abs() < 1e-2);
+
+        // Without a face, the cube stays open.
+        let result = sew(&faces[1..], 1e-2);
+        assert!(result.volumes.is_empty());
+        assert_eq!(result.open_shells.len(), 1);
+        assert_eq!(result.open_shells[0].faces.len(), 5);
+
+        // A cube inside of a cube becomes its cavity.
+        let mut faces = primitive_cube(2.0, 2.0, 2.0).all_faces();
+        faces.extend(primitive_cube(1.0, 1.0, 1.0).all_faces());
+        let result = sew(&faces, 1e-6);
+        assert_eq!(result.volumes.len(), 1);
+        assert_eq!(result.volumes[0].cavities.len(), 1);
+        assert!((measure_volume(&result.volumes[0]) - 7.0).abs() < 1e-9);
+    }
+}
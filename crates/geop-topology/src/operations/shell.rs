@@ -0,0 +1,238 @@
+use std::fmt::{Display, Formatter};
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+};
+
+use crate::topology::{face::Face, shell::Shell, volume::Volume};
+
+use super::replace_planes::{replace_planes, ReplacePlanesError};
+
+// Hollows out a volume, or thickens it outwards, by offsetting its faces. Faces are indices into volume.all_faces().
+#[derive(Debug, Clone)]
+pub struct ShellOptions {
+    pub thickness: f64,
+    // Faces with a different thickness than the default one.
+    pub face_thickness: Vec<(usize, f64)>,
+    // Faces that are removed to open the shell, e.g. the top of a box.
+    pub open_faces: Vec<usize>,
+    // Adds the material outside of the volume instead of inside, so that the volume becomes the cavity.
+    pub outward: bool,
+}
+
+impl ShellOptions {
+    pub fn new(thickness: f64) -> ShellOptions {
+        ShellOptions {
+            thickness,
+            face_thickness: Vec::new(),
+            open_faces: Vec::new(),
+            outward: false,
+        }
+    }
+
+    pub fn with_face_thickness(mut self, face: usize, thickness: f64) -> ShellOptions {
+        self.face_thickness.push((face, thickness));
+        self
+    }
+
+    pub fn with_open_face(mut self, face: usize) -> ShellOptions {
+        self.open_faces.push(face);
+        self
+    }
+
+    pub fn outward(mut self) -> ShellOptions {
+        self.outward = true;
+        self
+    }
+
+    fn thickness_of(&self, face: usize) -> f64 {
+        if self.open_faces.contains(&face) {
+            return 0.0;
+        }
+        match self.face_thickness.iter().rev().find(|(f, _)| *f == face) {
+            Some((_, thickness)) => *thickness,
+            None => self.thickness,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ShellError {
+    UndefinedVertex(Point), // The offset faces around the vertex do not meet in a single point
+    SelfIntersection(usize), // The offset of the face collapses or turns inside out, because the wall is too thick
+    CurvedFace(usize),       // The face is not planar, and only planes can be offset
+    Cavities,                // The volume already has cavities, which would need walls of their own
+}
+
+impl Display for ShellError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShellError::UndefinedVertex(p) => {
+                write!(f, "Offset faces around {:?} do not meet in a point", p)
+            }
+            ShellError::SelfIntersection(i) => {
+                write!(f, "Offset of face {} intersects itself", i)
+            }
+            ShellError::CurvedFace(i) => write!(f, "Face {} is curved and cannot be offset", i),
+            ShellError::Cavities => write!(f, "Volumes with cavities cannot be shelled"),
+        }
+    }
+}
+
+// The plane of the face, moved by the offset along its normal.
+fn offset_plane(faces: &[Face], i: usize, offset: f64) -> Result<Plane, ShellError> {
+    match &*faces[i].surface {
+        Surface::Plane(plane) => Ok(Plane::new(
+            plane.basis + plane.u_slope.cross(plane.v_slope).normalize() * offset,
+            plane.u_slope,
+            plane.v_slope,
+        )),
+        _ => Err(ShellError::CurvedFace(i)),
+    }
+}
+
+// Turns a volume with planar faces into a shell with walls of the given thickness. Faces can have their own thickness, and open
+// faces are removed, leaving an opening that is bordered by a rim in the plane of the removed face. Without open faces, the
+// result is a volume with a cavity. The walls are checked for edges that collapse or turn around, which happens when the
+// offsets of the faces pass through each other. Volumes with curved faces or with cavities are rejected.
+pub fn shell(volume: &Volume, options: &ShellOptions) -> Result<Volume, ShellError> {
+    if !volume.cavities.is_empty() {
+        return Err(ShellError::Cavities);
+    }
+    let faces = volume.all_faces();
+    let offsets: Vec<f64> = (0..faces.len())
+        .map(|i| {
+            let thickness = options.thickness_of(i);
+            assert!(thickness > 0.0 || options.open_faces.contains(&i));
+            match options.outward {
+                true => thickness,
+                false => -thickness,
+            }
+        })
+        .collect();
+    let planes = offsets
+        .iter()
+        .enumerate()
+        .map(|(i, o)| offset_plane(&faces, i, *o))
+        .collect::<Result<Vec<Plane>, ShellError>>()?;
+    let offset = replace_planes(&faces, &planes).map_err(|e| match e {
+        ReplacePlanesError::UndefinedVertex(p) => ShellError::UndefinedVertex(p),
+        ReplacePlanesError::CollapsedEdge(i) => ShellError::SelfIntersection(i),
+    })?;
+    let (outer, inner) = match options.outward {
+        true => (offset, faces),
+        false => (faces, offset),
+    };
+
+    let is_open = |i: &usize| options.open_faces.contains(i);
+    let outer_faces = (0..outer.len())
+        .filter(|i| !is_open(i))
+        .map(|i| outer[i].clone());
+    let inner_faces = (0..inner.len())
+        .filter(|i| !is_open(i))
+        .map(|i| inner[i].flip());
+    if options.open_faces.is_empty() {
+        return Ok(Volume::new(
+            Shell::new(outer_faces.collect()),
+            vec![Shell::new(inner_faces.collect())],
+        ));
+    }
+
+    let rims = options.open_faces.iter().map(|i| {
+        assert!(outer[*i].holes.is_empty(), "Open faces cannot have holes");
+        Face::new(
+            outer[*i].boundary.clone(),
+            vec![inner[*i].boundary.as_ref().unwrap().flip()],
+            outer[*i].surface.clone(),
+        )
+    });
+    Ok(Volume::new(
+        Shell::new(outer_faces.chain(rims).chain(inner_faces).collect()),
+        vec![],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{
+        measure::mass::measure_volume,
+        operations::extrude::extrude,
+        primitive_objects::{edges::circle::primitive_circle, volumes::cube::primitive_cube},
+        topology::{contour::Contour, diagnostics::check_volume},
+    };
+
+    #[test]
+    fn test_shell() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let top = cube
+            .all_faces()
+            .iter()
+            .position(|f| f.normal(f.inner_point()).z > 0.5)
+            .unwrap();
+
+        let result = shell(&cube, &ShellOptions::new(0.25)).unwrap();
+        assert_eq!(result.cavities.len(), 1);
+        assert!((measure_volume(&result) - (8.0 - 1.5 * 1.5 * 1.5)).abs() < 1e-9);
+
+        let options = ShellOptions::new(0.25).with_face_thickness(top, 0.5);
+        let result = shell(&cube, &options).unwrap();
+        assert!((measure_volume(&result) - (8.0 - 1.5 * 1.5 * 1.25)).abs() < 1e-9);
+
+        // An open box, with a rim around the opening.
+        let result = shell(&cube, &ShellOptions::new(0.25).with_open_face(top)).unwrap();
+        assert!(result.cavities.is_empty());
+        assert_eq!(result.boundary.faces.len(), 11);
+        assert!(check_volume(&result.boundary, &result.cavities).is_empty());
+        assert!((measure_volume(&result) - (8.0 - 1.5 * 1.5 * 1.75)).abs() < 1e-9);
+
+        // Thickening outwards keeps the cube as the cavity.
+        let result = shell(&cube, &ShellOptions::new(0.25).outward()).unwrap();
+        assert!((measure_volume(&result) - (2.5 * 2.5 * 2.5 - 8.0)).abs() < 1e-9);
+
+        // Walls thicker than half the cube pass through each other.
+        assert!(matches!(
+            shell(&cube, &ShellOptions::new(1.5)),
+            Err(ShellError::SelfIntersection(_))
+        ));
+
+        // A hollow cube already has a cavity.
+        let hollow = shell(&cube, &ShellOptions::new(0.25)).unwrap();
+        assert!(matches!(
+            shell(&hollow, &ShellOptions::new(0.1)),
+            Err(ShellError::Cavities)
+        ));
+    }
+
+    #[test]
+    fn test_shell_curved() {
+        let disk = Face::new(
+            Some(Contour::new(vec![primitive_circle(
+                Point::zero(),
+                -Point::unit_z(),
+                0.5,
+            )])),
+            vec![],
+            Rc::new(Surface::Plane(Plane::new(
+                Point::zero(),
+                Point::unit_y(),
+                Point::unit_x(),
+            ))),
+        );
+        let cylinder = extrude(disk, Point::new(0.0, 0.0, 2.0));
+        let side = cylinder
+            .all_faces()
+            .iter()
+            .position(|f| matches!(*f.surface, Surface::Cylinder(_)))
+            .unwrap();
+        let result = shell(&cylinder, &ShellOptions::new(0.1));
+        assert!(
+            matches!(result, Err(ShellError::CurvedFace(i)) if i == side),
+            "{:?}",
+            result
+        );
+    }
+}
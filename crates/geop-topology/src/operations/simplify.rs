@@ -0,0 +1,191 @@
+use geop_geometry::{
+    curves::{circle::Circle, curve::Curve, line::Line},
+    points::point::Point,
+    EQ_THRESHOLD,
+};
+
+use crate::topology::edge::Edge;
+
+// Minimum number of polyline segments that are replaced by an arc. Shorter runs are cheaper as lines.
+const MIN_ARC_SEGMENTS: usize = 3;
+
+fn segment_point_distance(a: Point, b: Point, p: Point) -> f64 {
+    let d = b - a;
+    if d.norm_sq() < EQ_THRESHOLD * EQ_THRESHOLD {
+        return (p - a).norm();
+    }
+    let t = ((p - a).dot(d) / d.norm_sq()).clamp(0.0, 1.0);
+    (a + d * t - p).norm()
+}
+
+// Douglas-Peucker simplification. Returns the subset of points such that no removed point is further than tolerance from the simplified polyline.
+// The first and last point are always kept.
+pub fn douglas_peucker(points: &[Point], tolerance: f64) -> Vec<Point> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let mut max_dist = 0.0;
+        let mut max_index = start;
+        for i in start + 1..end {
+            let dist = segment_point_distance(points[start], points[end], points[i]);
+            if dist > max_dist {
+                max_dist = dist;
+                max_index = i;
+            }
+        }
+        if max_dist > tolerance {
+            keep[max_index] = true;
+            stack.push((start, max_index));
+            stack.push((max_index, end));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, k)| *k)
+        .map(|(p, _)| *p)
+        .collect()
+}
+
+// Circle through three points, or None if they are collinear.
+fn circle_through(a: Point, b: Point, c: Point) -> Option<(Point, Point, f64)> {
+    let ab = b - a;
+    let ac = c - a;
+    let normal = ab.cross(ac);
+    if normal.norm() < EQ_THRESHOLD * ab.norm() * ac.norm() {
+        return None;
+    }
+    let center = a
+        + (normal.cross(ab) * ac.norm_sq() + ac.cross(normal) * ab.norm_sq())
+            / (2.0 * normal.norm_sq());
+    Some((center, normal.normalize(), (a - center).norm()))
+}
+
+// Fits an arc from the first to the last point through the middle point and checks that all points are within tolerance of it.
+// The points have to run around the center monotonically and cover less than a full turn.
+fn fit_arc(points: &[Point], tolerance: f64) -> Option<Circle> {
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let (center, normal, radius) = circle_through(first, points[points.len() / 2], last)?;
+    let mut sweep = 0.0;
+    for i in 0..points.len() {
+        let p = points[i];
+        if ((p - center).norm() - radius).abs() > tolerance
+            || (p - center).dot(normal).abs() > tolerance
+        {
+            return None;
+        }
+        if i > 0 {
+            let angle = (points[i - 1] - center).angle2(p - center, normal);
+            if angle <= 0.0 {
+                return None;
+            }
+            sweep += angle;
+        }
+    }
+    if sweep >= 2.0 * std::f64::consts::PI - EQ_THRESHOLD {
+        return None;
+    }
+    Some(Circle::new(center, normal, radius))
+}
+
+fn push_lines(points: &[Point], tolerance: f64, edges: &mut Vec<Edge>) {
+    let simplified = douglas_peucker(points, tolerance);
+    for i in 0..simplified.len() - 1 {
+        let (a, b) = (simplified[i], simplified[i + 1]);
+        edges.push(Edge::new(
+            Some(a),
+            Some(b),
+            Curve::Line(Line::new(a, b - a)),
+        ));
+    }
+}
+
+// Turns a dense polyline, e.g. from an import or from marching along an intersection, into a minimal chain of edges that stays within tolerance of it.
+// Runs of points that lie on a common circle are replaced by arcs, the rest is simplified to lines with Douglas-Peucker.
+pub fn simplify_polyline(points: &[Point], tolerance: f64) -> Vec<Edge> {
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut edges = Vec::<Edge>::new();
+    let mut run_start = 0;
+    let mut i = 0;
+    while i < points.len() - 1 {
+        // Find the longest arc starting at i.
+        let mut arc = None;
+        let mut j = i + 2;
+        while j < points.len() {
+            match fit_arc(&points[i..=j], tolerance) {
+                Some(circle) => arc = Some((j, circle)),
+                None => break,
+            }
+            j += 1;
+        }
+        match arc {
+            Some((j, circle))
+                if j - i >= MIN_ARC_SEGMENTS
+                    && douglas_peucker(&points[i..=j], tolerance).len() > 2 =>
+            {
+                if run_start < i {
+                    push_lines(&points[run_start..=i], tolerance, &mut edges);
+                }
+                edges.push(Edge::new(
+                    Some(points[i]),
+                    Some(points[j]),
+                    Curve::Circle(circle),
+                ));
+                i = j;
+                run_start = j;
+            }
+            _ => i += 1,
+        }
+    }
+    if run_start < points.len() - 1 {
+        push_lines(&points[run_start..], tolerance, &mut edges);
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplify_polyline() {
+        // A straight line with noise, followed by a quarter circle and another straight line.
+        let mut points = Vec::<Point>::new();
+        for i in 0..20 {
+            let noise = if i % 2 == 0 { 1e-4 } else { -1e-4 };
+            points.push(Point::new(i as f64 * 0.5, noise, 0.0));
+        }
+        for i in 0..=30 {
+            let angle = i as f64 / 30.0 * std::f64::consts::FRAC_PI_2;
+            points.push(Point::new(10.0 + angle.sin(), 1.0 - angle.cos(), 0.0));
+        }
+        for i in 1..10 {
+            points.push(Point::new(11.0, 1.0 + i as f64 * 0.5, 0.0));
+        }
+
+        let edges = simplify_polyline(&points, 1e-3);
+        assert!(edges.len() <= 4, "Got {} edges", edges.len());
+        assert!(edges
+            .iter()
+            .any(|e| matches!(&e.curve, Curve::Circle(c) if (c.radius.norm() - 1.0).abs() < 1e-2)));
+        assert_eq!(edges[0].start, Some(points[0]));
+        assert_eq!(edges[edges.len() - 1].end, Some(points[points.len() - 1]));
+        for i in 0..edges.len() - 1 {
+            assert_eq!(edges[i].end, edges[i + 1].start);
+        }
+
+        let simplified = douglas_peucker(&points[0..20], 1e-3);
+        assert_eq!(simplified.len(), 2);
+    }
+}
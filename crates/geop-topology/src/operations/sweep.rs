@@ -0,0 +1,14 @@
+use geop_geometry::points::point::Point;
+
+use crate::topology::{face::Face, wire::Wire};
+
+use super::extrude::extrude_edge;
+
+// Sweeps a wire along a direction and returns the resulting faces, in the order of the edges of the wire.
+// The faces form an open sheet, unless the wire is closed, in which case they form a tube without caps.
+pub fn sweep_wire(wire: &Wire, direction: Point) -> Vec<Face> {
+    wire.edges
+        .iter()
+        .map(|edge| extrude_edge(edge, direction))
+        .collect()
+}
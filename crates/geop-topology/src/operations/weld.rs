@@ -0,0 +1,182 @@
+use geop_geometry::{
+    curves::{bezier::Bezier, circle::Circle, curve::Curve, line::Line, CurveLike},
+    points::{kd_tree::KdTree, point::Point},
+};
+
+use crate::topology::edge::Edge;
+
+// Merges points that are closer than tolerance to each other.
+// Returns the welded points and, for every input point, the index of the welded point it was merged into.
+// The first point of a cluster is used as its representative.
+pub fn weld_points(points: &[Point], tolerance: f64) -> (Vec<Point>, Vec<usize>) {
+    let mut welded = KdTree::new();
+    let mut mapping = Vec::with_capacity(points.len());
+    for p in points {
+        let index = match welded.nearest(*p) {
+            Some((index, dist)) if dist <= tolerance => index,
+            _ => welded.insert(*p),
+        };
+        mapping.push(index);
+    }
+    let welded = (0..welded.len()).map(|i| welded.get(i)).collect();
+    (welded, mapping)
+}
+
+// Snaps the end points of edges that are closer than tolerance to the same vertex, so that the edges can be joined into contours.
+// Lines, circles and Bezier curves are rebuilt through the welded vertices, so that edges that meet there share them exactly. Other
+// curves are kept and their end points are projected onto them. Lines that collapse to a single point are removed.
+pub fn weld_edges(edges: &[Edge], tolerance: f64) -> Vec<Edge> {
+    let mut points = Vec::<Point>::new();
+    for edge in edges {
+        points.extend(edge.start);
+        points.extend(edge.end);
+    }
+    let (welded, mapping) = weld_points(&points, tolerance);
+
+    let mut mapping = mapping.into_iter();
+    let mut result = Vec::<Edge>::new();
+    for edge in edges {
+        let start = edge.start.map(|_| welded[mapping.next().unwrap()]);
+        let end = edge.end.map(|_| welded[mapping.next().unwrap()]);
+        result.extend(rebuild_edge(edge, start, end));
+    }
+    result
+}
+
+// The circle with the same normal and about the same center that passes through the points exactly. A single point moves the
+// circle, and two points keep its center on their bisector as close to the old center as possible.
+fn circle_through(circle: &Circle, points: &[Point]) -> Circle {
+    match points {
+        [] => circle.clone(),
+        [p] => {
+            let offset = *p - circle.project(*p).point;
+            Circle::new(circle.basis + offset, circle.normal, circle.radius.norm())
+        }
+        [a, b, ..] => {
+            let chord = *b - *a;
+            let normal = circle.normal - chord * (circle.normal.dot(chord) / chord.norm_sq());
+            let middle = (*a + *b) / 2.0;
+            let towards_center = normal.cross(chord).normalize();
+            let center = middle + towards_center * (circle.basis - middle).dot(towards_center);
+            Circle::new(center, normal.normalize(), (*a - center).norm())
+        }
+    }
+}
+
+// Moves the end points of the edge to the welded vertices. Lines and circles are rebuilt through them, and Bezier curves that end
+// at the edge move their end control points along with their tangent handles. Other curves are kept and the vertices are projected
+// onto them. Returns None for lines that collapse to a single point.
+pub(crate) fn rebuild_edge(edge: &Edge, start: Option<Point>, end: Option<Point>) -> Option<Edge> {
+    match &edge.curve {
+        Curve::Line(line) => match (start, end) {
+            (Some(start), Some(end)) => {
+                if start == end {
+                    return None;
+                }
+                Some(Edge::new(
+                    Some(start),
+                    Some(end),
+                    Curve::Line(Line::new(start, end - start)),
+                ))
+            }
+            _ => {
+                let basis = start.or(end).unwrap_or(line.basis);
+                Some(Edge::new(
+                    start,
+                    end,
+                    Curve::Line(Line::new(basis, line.direction)),
+                ))
+            }
+        },
+        Curve::Circle(circle) => {
+            let mut points: Vec<Point> = start.into_iter().chain(end).collect();
+            points.dedup();
+            Some(Edge::new(
+                start,
+                end,
+                Curve::Circle(circle_through(circle, &points)),
+            ))
+        }
+        Curve::Bezier(bezier) if edge.start == Some(bezier.p0) && edge.end == Some(bezier.p3) => {
+            let (start, end) = (start.unwrap(), end.unwrap());
+            if start == end {
+                return None;
+            }
+            let (ds, de) = (start - bezier.p0, end - bezier.p3);
+            Some(Edge::new(
+                Some(start),
+                Some(end),
+                Curve::Bezier(Bezier::new(start, bezier.p1 + ds, bezier.p2 + de, end)),
+            ))
+        }
+        curve => Some(Edge::new(
+            start.map(|p| curve.project(p).point),
+            end.map(|p| curve.project(p).point),
+            curve.clone(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::edges::line::primitive_line;
+
+    use super::*;
+
+    #[test]
+    fn test_weld_edges() {
+        let (welded, mapping) = weld_points(
+            &[
+                Point::zero(),
+                Point::new(1.0, 0.0, 0.0),
+                Point::new(1e-4, 0.0, 0.0),
+            ],
+            1e-3,
+        );
+        assert_eq!(welded, vec![Point::zero(), Point::new(1.0, 0.0, 0.0)]);
+        assert_eq!(mapping, vec![0, 1, 0]);
+
+        // Lines with a small gap meet in one vertex, and a line shorter than the tolerance disappears.
+        let gap = Point::new(0.0, 1e-4, 0.0);
+        let lines = weld_edges(
+            &[
+                primitive_line(Point::zero(), Point::unit_x()),
+                primitive_line(Point::unit_x() + gap, Point::unit_y()),
+                primitive_line(Point::unit_y(), Point::unit_y() + gap),
+            ],
+            1e-3,
+        );
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].end, lines[1].start);
+
+        // Two arcs that nearly touch share the welded vertex exactly, and stay on circles of about the same size.
+        let arc = |center: Point, start: Point, end: Point| {
+            Edge::new(
+                Some(start),
+                Some(end),
+                Curve::Circle(Circle::new(center, Point::unit_z(), 1.0)),
+            )
+        };
+        let offset = Point::new(1e-4, 0.0, 0.0);
+        let arcs = weld_edges(
+            &[
+                arc(Point::zero(), Point::unit_x(), Point::unit_y()),
+                arc(
+                    Point::new(0.0, 2.0, 0.0) + offset,
+                    Point::unit_y() + offset,
+                    Point::new(1.0, 2.0, 0.0) + offset,
+                ),
+            ],
+            1e-3,
+        );
+        assert_eq!(arcs.len(), 2);
+        assert_eq!(arcs[0].end, Some(Point::unit_y()));
+        assert_eq!(arcs[1].start, Some(Point::unit_y()));
+        for edge in arcs.iter() {
+            match &edge.curve {
+                Curve::Circle(circle) => assert!((circle.radius.norm() - 1.0).abs() < 1e-3),
+                _ => panic!("Expected the arcs to stay circles"),
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    curves::{circle::Circle, curve::Curve, line::Line},
+    points::point::Point,
+    surfaces::{cylinder::Cylinder, plane::Plane, sphere::Sphere, surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    construction::work_plane::WorkPlane,
+    topology::{contour::Contour, edge::Edge, face::Face, shell::Shell, volume::Volume},
+};
+
+// Number of pieces that curved edges of the sketch are split into. Straight edges are only split where they turn by more than a
+// quarter around the surface, since the geodesics between the pieces take the shorter way around.
+const WRAP_SEGMENTS: usize = 16;
+
+// Maps the work plane onto a curved surface like a label wrapped around a bottle. The origin of the work plane goes to the anchor,
+// x runs around the axis of a cylinder or along the latitude of a sphere and y along the axis or the longitude.
+struct Wrapping<'a> {
+    work_plane: &'a WorkPlane,
+    surface: &'a Surface,
+    anchor: Point,
+    radius: f64,
+}
+
+impl Wrapping<'_> {
+    // Lengths along x and y are preserved on a cylinder. On a sphere they are preserved along the longitudes and the equator, and
+    // x is stretched more and more towards the poles.
+    fn map(&self, p: Point) -> Point {
+        let (x, y) = self.work_plane.to_local(p);
+        let u = self.anchor.x + x / self.radius;
+        match self.surface {
+            Surface::Cylinder(cylinder) => cylinder.point_at(Point::new(u, self.anchor.y + y, 0.0)),
+            Surface::Sphere(sphere) => {
+                let v = self.anchor.y - y / self.radius;
+                assert!(
+                    v > EQ_THRESHOLD && v < std::f64::consts::PI - EQ_THRESHOLD,
+                    "Sketch reaches over the pole of the sphere"
+                );
+                sphere.point_at(Point::new(u, v, 0.0))
+            }
+            Surface::Plane(_) => unreachable!(),
+        }
+    }
+
+    fn map_edge(&self, edge: &Edge) -> Vec<Edge> {
+        let pieces = match edge.curve {
+            Curve::Line(_) => {
+                let (start, _) = self.work_plane.to_local(edge.interpolate(0.0));
+                let (end, _) = self.work_plane.to_local(edge.interpolate(1.0));
+                let turn = (end - start).abs() / self.radius;
+                ((turn / std::f64::consts::FRAC_PI_2).ceil() as usize).max(1)
+            }
+            _ => WRAP_SEGMENTS,
+        };
+        (0..pieces)
+            .map(|i| {
+                let a = self.map(edge.interpolate(i as f64 / pieces as f64));
+                let b = self.map(edge.interpolate((i + 1) as f64 / pieces as f64));
+                Edge::new(Some(a), Some(b), self.surface.geodesic(a, b))
+            })
+            .collect()
+    }
+
+    fn map_contour(&self, contour: &Contour) -> Contour {
+        Contour::new(
+            contour
+                .edges
+                .iter()
+                .flat_map(|e| self.map_edge(e))
+                .collect(),
+        )
+    }
+}
+
+// Wraps a planar sketch onto the surface of a cylindrical or spherical face, with the origin of the work plane at the anchor. The
+// contour lies on the work plane and runs counterclockwise around its normal. The result is a face on the surface of the given face
+// that can be imprinted into it or embossed. Straight edges of the sketch become geodesics, i.e. lines, circles and helices on a
+// cylinder and great circles on a sphere. Curved edges are approximated by geodesic pieces. The sketch has to fit on the face,
+// which is not checked.
+pub fn wrap(contour: &Contour, work_plane: &WorkPlane, face: &Face, anchor: Point) -> Face {
+    let radius = match &*face.surface {
+        Surface::Cylinder(cylinder) => cylinder.radius.norm(),
+        Surface::Sphere(sphere) => sphere.radius,
+        Surface::Plane(_) => panic!("Sketches on planes do not need to be wrapped"),
+    };
+    let (x_min, x_max) = contour
+        .all_points()
+        .iter()
+        .map(|p| work_plane.to_local(*p).0)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), x| {
+            (a.min(x), b.max(x))
+        });
+    assert!(
+        x_max - x_min < 2.0 * std::f64::consts::PI * radius,
+        "Sketch is wider than the circumference of the surface"
+    );
+    let wrapping = Wrapping {
+        work_plane,
+        surface: &face.surface,
+        anchor: face.surface.parameters(anchor),
+        radius,
+    };
+
+    // The sketch keeps its orientation around the normal of the surface unless x and y swap sides, like on the inside of a tube.
+    let origin = wrapping.map(work_plane.origin);
+    let x = wrapping.map(work_plane.origin + work_plane.x_dir * 1e-3) - origin;
+    let y = wrapping.map(work_plane.origin + work_plane.y_dir * 1e-3) - origin;
+    let boundary = wrapping.map_contour(contour);
+    let boundary = match x.cross(y).dot(face.surface.normal(origin)) > 0.0 {
+        true => boundary,
+        false => boundary.flip(),
+    };
+    Face::new(Some(boundary), vec![], face.surface.clone())
+}
+
+// Moves an edge of a wrapped face by the height along the normal of the surface onto the offset surface.
+fn offset_edge(edge: &Edge, surface: &Surface, height: f64) -> Edge {
+    let (start, end) = (edge.start.unwrap(), edge.end.unwrap());
+    let offset = |p: Point| p + surface.normal(p) * height;
+    let curve = match &edge.curve {
+        Curve::Line(line) => Curve::Line(Line::new(offset(start), line.direction)),
+        Curve::Circle(circle) => Curve::Circle(Circle::new(
+            circle.basis,
+            circle.normal,
+            (offset(start) - circle.basis).norm(),
+        )),
+        Curve::Helix(_) => todo!("Side faces of embossed helices need a helicoid surface"),
+        _ => panic!("Wrapped faces only have geodesic edges"),
+    };
+    Edge::new(Some(offset(start)), Some(offset(end)), curve)
+}
+
+// Raises a wrapped face by the height along the normal of its surface into a volume, like extrude does for planar faces. The volume
+// can be united with the solid the face was wrapped onto, or cut from it to engrave the sketch. The side faces are planar, so edges
+// along helices, i.e. slanted lines of a sketch on a cylinder, are not supported.
+pub fn emboss(wrapped: &Face, height: f64) -> Volume {
+    assert!(height > EQ_THRESHOLD, "Emboss height must be positive");
+    let offset_surface = match &*wrapped.surface {
+        Surface::Cylinder(cylinder) => {
+            let radius = match cylinder.normal_outwards {
+                true => cylinder.radius.norm() + height,
+                false => cylinder.radius.norm() - height,
+            };
+            Surface::Cylinder(Cylinder::new(
+                cylinder.basis,
+                cylinder.extend_dir,
+                radius,
+                cylinder.normal_outwards,
+            ))
+        }
+        Surface::Sphere(sphere) => {
+            let radius = match sphere.normal_outwards {
+                true => sphere.radius + height,
+                false => sphere.radius - height,
+            };
+            Surface::Sphere(Sphere::new(sphere.basis, radius, sphere.normal_outwards))
+        }
+        Surface::Plane(_) => panic!("Use extrude for planar faces"),
+    };
+    let offset_contour = |c: &Contour| {
+        Contour::new(
+            c.edges
+                .iter()
+                .map(|e| offset_edge(e, &wrapped.surface, height))
+                .collect(),
+        )
+    };
+    let end_face = Face::new(
+        wrapped.boundary.as_ref().map(offset_contour),
+        wrapped.holes.iter().map(offset_contour).collect(),
+        Rc::new(offset_surface),
+    );
+
+    // Like in extrude, the side faces follow the edges of the flipped start face.
+    let start_face = wrapped.flip();
+    let mut faces = Vec::<Face>::new();
+    for edge in start_face.all_edges() {
+        let top = edge.flip();
+        let bottom = offset_edge(&edge, &wrapped.surface, height);
+        let line = |start: Point, end: Point| {
+            Edge::new(
+                Some(start),
+                Some(end),
+                Curve::Line(Line::new(start, end - start)),
+            )
+        };
+        let right = line(bottom.end.unwrap(), top.start.unwrap());
+        let left = line(top.end.unwrap(), bottom.start.unwrap());
+        let start = edge.start.unwrap();
+        let plane = Plane::new(start, wrapped.surface.normal(start), edge.tangent(start));
+        faces.push(Face::new(
+            Some(Contour::new(vec![right, top, left, bottom])),
+            vec![],
+            Rc::new(Surface::Plane(plane)),
+        ));
+    }
+    faces.push(start_face);
+    faces.push(end_face);
+    Volume::new(Shell::new(faces), vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::{
+        curves::rectangle::primitive_rectangle_curve,
+        edges::line::primitive_line,
+        faces::{cylinder::primitive_cylinder, sphere::primitive_sphere},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_wrap() {
+        // A label of 1 by 1 around a cylinder of radius 1 keeps its size.
+        let cylinder = primitive_cylinder(Point::zero(), Point::unit_z(), 1.0);
+        let work_plane = WorkPlane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let label =
+            primitive_rectangle_curve(Point::zero(), Point::unit_x() * 0.5, Point::unit_y() * 0.5);
+        let anchor = Point::new(1.0, 0.0, 0.0);
+        let wrapped = wrap(&label, &work_plane, &cylinder, anchor);
+        let points = wrapped.all_points();
+        assert_eq!(points.len(), 4);
+        for p in points.iter() {
+            assert!((Point::new(p.x, p.y, 0.0).norm() - 1.0).abs() < 1e-9);
+            assert!((p.z.abs() - 0.5).abs() < 1e-9);
+            assert!((p.y.atan2(p.x).abs() - 0.5).abs() < 1e-9);
+        }
+        let p = wrapped.inner_point();
+        assert!((wrapped.normal(p) - Point::new(p.x, p.y, 0.0)).norm() < 1e-9);
+
+        let embossed = emboss(&wrapped, 0.2);
+        let faces = embossed.all_faces();
+        assert_eq!(faces.len(), 6);
+        for p in faces.iter().flat_map(|f| f.all_points()) {
+            let r = Point::new(p.x, p.y, 0.0).norm();
+            assert!((r - 1.0).abs() < 1e-9 || (r - 1.2).abs() < 1e-9);
+        }
+
+        // A triangle on a sphere ends up with great circle edges.
+        let sphere = primitive_sphere(Point::zero(), 2.0);
+        let triangle = Contour::new(vec![
+            primitive_line(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)),
+            primitive_line(Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)),
+            primitive_line(Point::new(0.0, 1.0, 0.0), Point::new(0.0, 0.0, 0.0)),
+        ]);
+        let wrapped = wrap(&triangle, &work_plane, &sphere, Point::new(2.0, 0.0, 0.0));
+        assert_eq!(wrapped.all_edges().len(), 3);
+        assert!(wrapped
+            .all_edges()
+            .iter()
+            .all(|e| matches!(e.curve, Curve::Circle(_))));
+        let embossed = emboss(&wrapped, 0.5);
+        assert_eq!(embossed.all_faces().len(), 5);
+    }
+}
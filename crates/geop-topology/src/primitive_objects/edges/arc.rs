@@ -5,6 +5,13 @@ use geop_geometry::{
 
 use crate::topology::edge::Edge;
 
+// Which of the two arcs of a circle between two points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArcSide {
+    Minor,
+    Major,
+}
+
 pub fn primitive_arc(from: Point, to: Point, radius: f64, normal: Point) -> Edge {
     let midpoint = (from + to) / 2.0;
 
@@ -3,3 +3,4 @@ pub mod circle;
 pub mod ellipse;
 pub mod helix;
 pub mod line;
+pub mod sphere_arc;
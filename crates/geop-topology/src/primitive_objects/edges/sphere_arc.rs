@@ -0,0 +1,79 @@
+use geop_geometry::{
+    curves::curve::Curve,
+    points::point::Point,
+    surfaces::{sphere::Sphere, SurfaceLike},
+};
+
+use crate::topology::edge::Edge;
+
+use super::arc::ArcSide;
+
+// The arc of the great circle from one point on the sphere to another, the shorter or the longer way around.
+pub fn primitive_great_circle_arc(sphere: &Sphere, from: Point, to: Point, side: ArcSide) -> Edge {
+    assert!(sphere.on_surface(from) && sphere.on_surface(to));
+    let circle = sphere.great_circle(from, to);
+    let circle = match side {
+        ArcSide::Minor => circle,
+        ArcSide::Major => circle.neg(),
+    };
+    Edge::new(Some(from), Some(to), Curve::Circle(circle))
+}
+
+// The closed circle at the latitude relative to the axis, running counterclockwise around the axis. See Sphere::small_circle.
+pub fn primitive_small_circle(sphere: &Sphere, axis: Point, latitude: f64) -> Edge {
+    Edge::new(
+        None,
+        None,
+        Curve::Circle(sphere.small_circle(axis, latitude)),
+    )
+}
+
+// The arc of the small circle through both points, which have to be at the same latitude relative to the axis, counterclockwise
+// around the axis from one point to the other.
+pub fn primitive_small_circle_arc(sphere: &Sphere, axis: Point, from: Point, to: Point) -> Edge {
+    let latitude = sphere.latitude(axis, from);
+    assert!(
+        (sphere.latitude(axis, to) - latitude).abs() * sphere.radius < 1e-7,
+        "Points are not at the same latitude"
+    );
+    Edge::new(
+        Some(from),
+        Some(to),
+        Curve::Circle(sphere.small_circle(axis, latitude)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn test_sphere_arcs() {
+        let sphere = Sphere::new(Point::zero(), 2.0, true);
+        let (a, b) = (Point::new(2.0, 0.0, 0.0), Point::new(0.0, 2.0, 0.0));
+        let minor = primitive_great_circle_arc(&sphere, a, b, ArcSide::Minor);
+        let major = primitive_great_circle_arc(&sphere, a, b, ArcSide::Major);
+        assert!((minor.length().unwrap() - PI).abs() < 1e-9);
+        assert!((major.length().unwrap() - 3.0 * PI).abs() < 1e-9);
+        let mid = minor.interpolate(0.5);
+        assert!((mid - Point::new(2.0_f64.sqrt(), 2.0_f64.sqrt(), 0.0)).norm() < 1e-9);
+        assert!(major.interpolate(0.5).x < 0.0);
+
+        // The circle at 30 degrees north around z has half the radius of the sphere at height 1 and runs counterclockwise.
+        let circle = primitive_small_circle(&sphere, Point::unit_z(), PI / 6.0);
+        assert!(circle.length().is_none());
+        assert!(
+            matches!(&circle.curve, Curve::Circle(c) if (c.radius.norm() - 3.0_f64.sqrt()).abs() < 1e-9)
+        );
+        let p = Point::new(3.0_f64.sqrt(), 0.0, 1.0);
+        assert!(sphere.on_surface(p));
+        assert!(circle.tangent(p).dot(Point::unit_y()) > 0.99);
+
+        let q = Point::new(0.0, 3.0_f64.sqrt(), 1.0);
+        let arc = primitive_small_circle_arc(&sphere, Point::unit_z(), p, q);
+        assert!((arc.length().unwrap() - PI * 3.0_f64.sqrt() / 2.0).abs() < 1e-9);
+        assert!((sphere.latitude(Point::unit_z(), arc.interpolate(0.5)) - PI / 6.0).abs() < 1e-9);
+    }
+}
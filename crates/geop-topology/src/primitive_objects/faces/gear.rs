@@ -0,0 +1,225 @@
+use std::{f64::consts::PI, rc::Rc};
+
+use geop_geometry::{
+    curves::{bezier::Bezier, circle::Circle, curve::Curve, line::Line},
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+    transforms::Transform,
+};
+
+use crate::topology::{contour::Contour, edge::Edge, face::Face};
+
+// Number of pieces that approximate each involute flank. Arcs need more pieces, since they cannot follow the changing curvature.
+const BEZIER_FLANK_SEGMENTS: usize = 4;
+const ARC_FLANK_SEGMENTS: usize = 8;
+
+// How the involute flanks are approximated. Bezier flanks are closer to the involute, while arcs can be extruded into cylinders.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GearFlanks {
+    Bezier,
+    Arcs,
+}
+
+// Polar angle of the involute of the base circle at parameter t, where the radius is rb * sqrt(1 + t^2).
+fn involute_angle(t: f64) -> f64 {
+    t - t.atan()
+}
+
+fn polar(radius: f64, angle: f64) -> Point {
+    Point::new(radius * angle.cos(), radius * angle.sin(), 0.0)
+}
+
+fn line(start: Point, end: Point) -> Edge {
+    Edge::new(
+        Some(start),
+        Some(end),
+        Curve::Line(Line::new(start, end - start)),
+    )
+}
+
+// Counterclockwise arc around the center of the gear.
+fn arc(radius: f64, start_angle: f64, end_angle: f64) -> Edge {
+    Edge::new(
+        Some(polar(radius, start_angle)),
+        Some(polar(radius, end_angle)),
+        Curve::Circle(Circle::new(Point::zero(), Point::unit_z(), radius)),
+    )
+}
+
+// The involute of the base circle from parameter t0 to t1, rotated by offset and mirrored at the x axis if mirror is -1. Bezier
+// pieces have the derivatives of the involute at their ends, arcs go through the ends and the middle of their piece. Both leave the
+// involute by less than 1e-4 of the module for usual gears.
+fn flank(
+    flanks: GearFlanks,
+    base_radius: f64,
+    t0: f64,
+    t1: f64,
+    offset: f64,
+    mirror: f64,
+) -> Vec<Edge> {
+    let point = |t: f64| {
+        polar(
+            base_radius * (1.0 + t * t).sqrt(),
+            offset + mirror * involute_angle(t),
+        )
+    };
+    // The involute of the base circle at angle 0 has the derivative rb * t * (cos t, sin t), rotated like the point. It vanishes
+    // on the base circle, so there the first control point is moved out a little to keep the tangent of the curve defined.
+    let tangent = |t: f64| polar(base_radius * t, offset + mirror * t);
+    let segments = match flanks {
+        GearFlanks::Bezier => BEZIER_FLANK_SEGMENTS,
+        GearFlanks::Arcs => ARC_FLANK_SEGMENTS,
+    };
+    (0..segments)
+        .map(|i| {
+            let a = t0 + (t1 - t0) * i as f64 / segments as f64;
+            let b = t0 + (t1 - t0) * (i + 1) as f64 / segments as f64;
+            let (start, end) = (point(a), point(b));
+            let curve = match flanks {
+                GearFlanks::Bezier => {
+                    let step = (b - a) / 3.0;
+                    Curve::Bezier(Bezier::new(
+                        start,
+                        start + tangent(a.max((b - a) / 100.0)) * step,
+                        end - tangent(b) * step,
+                        end,
+                    ))
+                }
+                GearFlanks::Arcs => arc_through(start, point((a + b) / 2.0), end),
+            };
+            Edge::new(Some(start), Some(end), curve)
+        })
+        .collect()
+}
+
+// The circle on the xy plane through three points, oriented to run from a over b to c.
+fn arc_through(a: Point, b: Point, c: Point) -> Curve {
+    let (ab, bc) = (b - a, c - b);
+    let normal = match ab.cross(bc).z > 0.0 {
+        true => Point::unit_z(),
+        false => -Point::unit_z(),
+    };
+    // The center is on the bisector of ab, where it is as far from c as from a.
+    let bisector = Point::unit_z().cross(ab);
+    let mid = (a + b) / 2.0;
+    let s = ((c - mid).norm_sq() - (a - mid).norm_sq()) / (2.0 * (c - a).dot(bisector));
+    let center = mid + bisector * s;
+    Curve::Circle(Circle::new(center, normal, (a - center).norm()))
+}
+
+// The profile of an involute spur gear on the xy plane, centered at the origin, with the normal along z. The module is the pitch
+// diameter per tooth, and the pressure angle in radians is usually 20 degrees. The addendum is one module and the dedendum 1.25
+// modules, without a fillet at the root. Where the root circle is inside of the base circle, the flanks continue radially down
+// to the root. The tips and roots are arcs, and the teeth are copies of the first tooth rotated around the center. With arcs for
+// the flanks, the face can be extruded into a gear.
+pub fn primitive_spur_gear(
+    module: f64,
+    teeth: usize,
+    pressure_angle: f64,
+    flanks: GearFlanks,
+) -> Face {
+    assert!(module > 0.0, "The module has to be positive");
+    assert!(teeth >= 6, "Gears need at least 6 teeth");
+    assert!(
+        pressure_angle > 0.0 && pressure_angle < PI / 4.0,
+        "The pressure angle has to be between 0 and 45 degrees"
+    );
+    let pitch_radius = module * teeth as f64 / 2.0;
+    let base_radius = pitch_radius * pressure_angle.cos();
+    let tip_radius = pitch_radius + module;
+    let root_radius = pitch_radius - 1.25 * module;
+    let pitch = 2.0 * PI / teeth as f64;
+
+    // Half of the angle of a tooth on the base circle. On the pitch circle, teeth and gaps are equally wide.
+    let half_tooth = PI / (2.0 * teeth as f64) + involute_angle(pressure_angle.tan());
+    let t_tip = ((tip_radius / base_radius).powi(2) - 1.0).sqrt();
+    let t_root = ((root_radius.max(base_radius) / base_radius).powi(2) - 1.0).sqrt();
+    let tip_half_angle = half_tooth - involute_angle(t_tip);
+    assert!(tip_half_angle > 0.0, "The teeth are pointed");
+
+    // The first tooth is centered on the x axis.
+    let mut tooth = Vec::<Edge>::new();
+    if root_radius < base_radius {
+        tooth.push(line(
+            polar(root_radius, -half_tooth),
+            polar(base_radius, -half_tooth),
+        ));
+    }
+    tooth.extend(flank(flanks, base_radius, t_root, t_tip, -half_tooth, 1.0));
+    tooth.push(arc(tip_radius, -tip_half_angle, tip_half_angle));
+    tooth.extend(
+        flank(flanks, base_radius, t_root, t_tip, half_tooth, -1.0)
+            .iter()
+            .rev()
+            .map(|e| e.flip()),
+    );
+    if root_radius < base_radius {
+        tooth.push(line(
+            polar(base_radius, half_tooth),
+            polar(root_radius, half_tooth),
+        ));
+    }
+    tooth.push(arc(root_radius, half_tooth, pitch - half_tooth));
+
+    let edges = (0..teeth)
+        .flat_map(|i| {
+            let rotation = Transform::from_euler_angles(0.0, 0.0, i as f64 * pitch);
+            tooth.iter().map(move |e| e.transform(rotation))
+        })
+        .collect();
+    Face::new(
+        Some(Contour::new(edges)),
+        vec![],
+        Rc::new(Surface::Plane(Plane::new(
+            Point::zero(),
+            Point::unit_x(),
+            Point::unit_y(),
+        ))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operations::extrude::extrude;
+
+    use super::*;
+
+    #[test]
+    fn test_primitive_spur_gear() {
+        let (module, teeth, pressure_angle) = (2.0, 20, 20.0_f64.to_radians());
+        let base_radius = 20.0 * pressure_angle.cos();
+        let half_tooth = PI / 40.0 + involute_angle(pressure_angle.tan());
+        for (flanks, segments) in [
+            (GearFlanks::Bezier, BEZIER_FLANK_SEGMENTS),
+            (GearFlanks::Arcs, ARC_FLANK_SEGMENTS),
+        ] {
+            let gear = primitive_spur_gear(module, teeth, pressure_angle, flanks);
+            let edges = gear.all_edges();
+            // Two radial lines, two flanks, a tip and a root per tooth.
+            assert_eq!(edges.len(), teeth * (4 + 2 * segments));
+            let radii: Vec<f64> = gear.all_points().iter().map(|p| p.norm()).collect();
+            let max = radii.iter().cloned().fold(0.0, f64::max);
+            let min = radii.iter().cloned().fold(f64::INFINITY, f64::min);
+            assert!((max - 22.0).abs() < 1e-9);
+            assert!((min - 17.5).abs() < 1e-9);
+
+            // The rising flank of the first tooth stays on the involute.
+            for edge in edges[1..1 + segments].iter() {
+                for t in [0.25, 0.5, 0.75] {
+                    let p = edge.interpolate(t);
+                    let t_involute = ((p.norm() / base_radius).powi(2) - 1.0).sqrt();
+                    let angle = -half_tooth + involute_angle(t_involute);
+                    assert!((p.y.atan2(p.x) - angle).abs() * p.norm() < 1e-4 * module);
+                }
+            }
+        }
+
+        // With arcs, the gear can be extruded.
+        let gear = primitive_spur_gear(module, teeth, pressure_angle, GearFlanks::Arcs);
+        let volume = extrude(gear.flip(), Point::unit_z() * 5.0);
+        assert_eq!(
+            volume.all_faces().len(),
+            teeth * (4 + 2 * ARC_FLANK_SEGMENTS) + 2
+        );
+    }
+}
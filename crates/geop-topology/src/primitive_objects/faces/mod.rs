@@ -1,4 +1,5 @@
 pub mod cylinder;
+pub mod gear;
 pub mod plane;
 pub mod rectangle;
 pub mod sphere;
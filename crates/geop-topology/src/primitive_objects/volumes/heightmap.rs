@@ -0,0 +1,126 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface},
+};
+
+use crate::{
+    measure::mass::polygon_area_vector,
+    primitive_objects::edges::line::primitive_line,
+    topology::{
+        contour::Contour,
+        face::Face,
+        volume::{builder::VolumeBuilder, Volume},
+    },
+};
+
+// A planar face through the points. The points are reversed if needed, so that the face points to the side of the hint.
+fn planar_face(mut points: Vec<Point>, hint: Point) -> Face {
+    let mut normal = polygon_area_vector(&points).normalize();
+    if normal.dot(hint) < 0.0 {
+        points.reverse();
+        normal = -normal;
+    }
+    let u = points[1] - points[0];
+    let edges = (0..points.len())
+        .map(|i| primitive_line(points[i], points[(i + 1) % points.len()]))
+        .collect();
+    Face::new(
+        Some(Contour::new(edges)),
+        vec![],
+        Rc::new(Surface::Plane(Plane::new(points[0], u, normal.cross(u)))),
+    )
+}
+
+// A terrain from a grid of heights, with heights[row][column] at x = column * spacing and y = row * spacing. Every cell of the
+// grid is split into two planar triangles on top. The sides are vertical and the bottom is flat at the base height, which has to
+// be below all heights.
+pub fn primitive_heightmap(heights: &[Vec<f64>], spacing: f64, base: f64) -> Volume {
+    let rows = heights.len();
+    assert!(rows >= 2, "A heightmap needs at least two rows");
+    let columns = heights[0].len();
+    assert!(columns >= 2, "A heightmap needs at least two columns");
+    assert!(heights.iter().all(|row| row.len() == columns));
+    assert!(heights.iter().flatten().all(|h| *h > base));
+
+    let top = |row: usize, column: usize| {
+        Point::new(
+            column as f64 * spacing,
+            row as f64 * spacing,
+            heights[row][column],
+        )
+    };
+    let bottom =
+        |row: usize, column: usize| Point::new(column as f64 * spacing, row as f64 * spacing, base);
+
+    let mut builder = VolumeBuilder::new();
+    let mut add = |face: Face| {
+        builder
+            .add_face(face)
+            .unwrap_or_else(|v| panic!("Invalid heightmap: {}", v))
+    };
+    for row in 0..rows - 1 {
+        for column in 0..columns - 1 {
+            let (p00, p10) = (top(row, column), top(row, column + 1));
+            let (p01, p11) = (top(row + 1, column), top(row + 1, column + 1));
+            add(planar_face(vec![p00, p10, p11], Point::unit_z()));
+            add(planar_face(vec![p00, p11, p01], Point::unit_z()));
+        }
+    }
+
+    // Each side runs along the bottom between two corners and back along the border of the top.
+    let sides: [(Vec<(usize, usize)>, Point); 4] = [
+        ((0..columns).map(|c| (0, c)).collect(), -Point::unit_y()),
+        (
+            (0..columns).map(|c| (rows - 1, c)).collect(),
+            Point::unit_y(),
+        ),
+        ((0..rows).map(|r| (r, 0)).collect(), -Point::unit_x()),
+        (
+            (0..rows).map(|r| (r, columns - 1)).collect(),
+            Point::unit_x(),
+        ),
+    ];
+    for (border, outward) in sides {
+        let (first, last) = (border[0], border[border.len() - 1]);
+        let mut points = vec![bottom(first.0, first.1), bottom(last.0, last.1)];
+        points.extend(border.iter().rev().map(|(r, c)| top(*r, *c)));
+        add(planar_face(points, outward));
+    }
+    add(planar_face(
+        vec![
+            bottom(0, 0),
+            bottom(0, columns - 1),
+            bottom(rows - 1, columns - 1),
+            bottom(rows - 1, 0),
+        ],
+        -Point::unit_z(),
+    ));
+
+    builder
+        .build()
+        .unwrap_or_else(|v| panic!("Invalid heightmap: {}", v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{measure::mass::measure_volume, topology::diagnostics::check_shell};
+
+    #[test]
+    fn test_primitive_heightmap() {
+        let heights = vec![
+            vec![1.0, 1.0, 1.0],
+            vec![1.0, 2.0, 1.0],
+            vec![1.0, 1.0, 1.5],
+        ];
+        let volume = primitive_heightmap(&heights, 1.0, 0.0);
+        assert_eq!(volume.boundary.faces.len(), 8 + 4 + 1);
+        assert!(check_shell(&volume.boundary.faces).is_empty());
+
+        // Every triangle adds its area of 1 / 2 times the mean height of its corners above the 2 x 2 x 1 block.
+        let expected = 4.0 + 0.5 * (1.0 + 1.0 + 1.0 + 1.0 + 1.5 + 1.5) / 3.0;
+        assert!((measure_volume(&volume) - expected).abs() < 1e-9);
+    }
+}
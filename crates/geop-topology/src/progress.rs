@@ -0,0 +1,118 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+// A flag to abort a long running operation, e.g. from the UI thread while a boolean runs on a worker thread. Clones share the flag.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+// Returned by operations that were aborted through their cancellation token.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Operation was cancelled")
+    }
+}
+
+// Reports the progress of an operation as a fraction from 0 to 1 and checks whether it should be aborted. Operations report at
+// points where they can stop without leaving anything behind, and return Cancelled from there.
+pub struct Progress<'a> {
+    callback: Option<&'a dyn Fn(f64)>,
+    token: Option<&'a CancellationToken>,
+}
+
+impl<'a> Progress<'a> {
+    pub fn new(callback: &'a dyn Fn(f64), token: &'a CancellationToken) -> Progress<'a> {
+        Progress {
+            callback: Some(callback),
+            token: Some(token),
+        }
+    }
+
+    // Neither reports nor cancels, for the plain versions of the operations.
+    pub fn none() -> Progress<'a> {
+        Progress {
+            callback: None,
+            token: None,
+        }
+    }
+
+    pub fn with_callback(callback: &'a dyn Fn(f64)) -> Progress<'a> {
+        Progress {
+            callback: Some(callback),
+            token: None,
+        }
+    }
+
+    pub fn with_token(token: &'a CancellationToken) -> Progress<'a> {
+        Progress {
+            callback: None,
+            token: Some(token),
+        }
+    }
+
+    pub fn report(&self, fraction: f64) -> Result<(), Cancelled> {
+        if let Some(callback) = self.callback {
+            callback(fraction.clamp(0.0, 1.0));
+        }
+        match self.token.is_some_and(|t| t.is_cancelled()) {
+            true => Err(Cancelled),
+            false => Ok(()),
+        }
+    }
+
+    // Reports done out of total steps within the range from start to end, for operations with several stages.
+    pub fn report_step(
+        &self,
+        start: f64,
+        end: f64,
+        done: usize,
+        total: usize,
+    ) -> Result<(), Cancelled> {
+        let fraction = match total {
+            0 => 1.0,
+            _ => done as f64 / total as f64,
+        };
+        self.report(start + (end - start) * fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        let reported = RefCell::new(Vec::<f64>::new());
+        let callback = |f: f64| reported.borrow_mut().push(f);
+        let token = CancellationToken::new();
+        let progress = Progress::new(&callback, &token);
+        assert_eq!(progress.report(0.25), Ok(()));
+        assert_eq!(progress.report_step(0.5, 1.0, 1, 2), Ok(()));
+        token.clone().cancel();
+        assert_eq!(progress.report(2.0), Err(Cancelled));
+        assert_eq!(*reported.borrow(), vec![0.25, 0.75, 1.0]);
+        assert_eq!(Progress::none().report(0.5), Ok(()));
+    }
+}
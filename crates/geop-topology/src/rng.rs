@@ -0,0 +1,44 @@
+use geop_geometry::points::point::Point;
+
+// A small xorshift generator. Everything that samples randomly, like the estimates, the sample models and the checks of booleans,
+// uses it, so that results only depend on the seed and can be reproduced on every platform.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed.max(1) }
+    }
+
+    // Uniform in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+
+    // Uniform in min..=max.
+    pub fn range_usize(&mut self, min: usize, max: usize) -> usize {
+        min + ((max - min + 1) as f64 * self.next_f64()) as usize
+    }
+
+    // Uniform on the unit sphere, by rejecting points outside of the unit ball and too close to the origin.
+    pub fn unit_vector(&mut self) -> Point {
+        loop {
+            let p = Point::new(
+                self.range(-1.0, 1.0),
+                self.range(-1.0, 1.0),
+                self.range(-1.0, 1.0),
+            );
+            if p.norm() > 0.1 && p.norm() <= 1.0 {
+                return p.normalize();
+            }
+        }
+    }
+}
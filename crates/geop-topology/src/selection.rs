@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+
+use geop_geometry::surfaces::{surface::Surface, SurfaceLike};
+
+use crate::topology::{edge::Edge, face::Face};
+
+// Selections are lists of indices into a list of faces, e.g. into volume.all_faces(), since faces have no identity of their own.
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SurfaceType {
+    Plane,
+    Sphere,
+    Cylinder,
+}
+
+impl SurfaceType {
+    pub fn of(surface: &Surface) -> SurfaceType {
+        match surface {
+            Surface::Plane(_) => SurfaceType::Plane,
+            Surface::Sphere(_) => SurfaceType::Sphere,
+            Surface::Cylinder(_) => SurfaceType::Cylinder,
+        }
+    }
+}
+
+pub fn select_by_surface_type(faces: &[Face], surface_type: SurfaceType) -> Vec<usize> {
+    (0..faces.len())
+        .filter(|i| SurfaceType::of(&faces[*i].surface) == surface_type)
+        .collect()
+}
+
+// Faces do not store the feature that created them, so the caller provides the mapping, e.g. from the history of the operations.
+pub fn select_by_feature<F: Fn(&Face) -> Option<usize>>(
+    faces: &[Face],
+    feature_id: usize,
+    feature_of: F,
+) -> Vec<usize> {
+    (0..faces.len())
+        .filter(|i| feature_of(&faces[*i]) == Some(feature_id))
+        .collect()
+}
+
+// The faces that share an edge with the face, together with the shared edge.
+pub fn face_neighbors(faces: &[Face], index: usize) -> Vec<(usize, Edge)> {
+    let mut neighbors = Vec::<(usize, Edge)>::new();
+    for edge in faces[index].all_edges() {
+        for (i, face) in faces.iter().enumerate() {
+            if i != index && face.all_edges().contains(&edge) {
+                neighbors.push((i, edge.clone()));
+            }
+        }
+    }
+    neighbors
+}
+
+// Two faces meet tangentially (G1) at the edge, if their normals agree along it within the angle tolerance.
+// The normals are compared at the ends and in the middle of the edge.
+pub fn is_tangent_edge(face_a: &Face, face_b: &Face, edge: &Edge, angle_tolerance: f64) -> bool {
+    [0.0, 0.5, 1.0].iter().all(|t| {
+        let p = edge.interpolate(*t);
+        face_a.surface.normal(p).angle(face_b.surface.normal(p)) <= angle_tolerance
+    })
+}
+
+// Expands the selection to all faces that are connected to it through tangent edges, e.g. a fillet together with the faces it blends.
+pub fn expand_tangent(faces: &[Face], selection: &[usize], angle_tolerance: f64) -> Vec<usize> {
+    let mut selected = vec![false; faces.len()];
+    let mut queue = VecDeque::<usize>::new();
+    for i in selection {
+        if !selected[*i] {
+            selected[*i] = true;
+            queue.push_back(*i);
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        for (j, edge) in face_neighbors(faces, i) {
+            if !selected[j] && is_tangent_edge(&faces[i], &faces[j], &edge, angle_tolerance) {
+                selected[j] = true;
+                queue.push_back(j);
+            }
+        }
+    }
+    (0..faces.len()).filter(|i| selected[*i]).collect()
+}
+
+// The edges between neighboring faces of the selection that are tangent, e.g. to fillet all tangent edges of a region.
+pub fn tangent_edges(faces: &[Face], selection: &[usize], angle_tolerance: f64) -> Vec<Edge> {
+    let mut edges = Vec::<Edge>::new();
+    for i in selection {
+        for (j, edge) in face_neighbors(faces, *i) {
+            if *i < j
+                && selection.contains(&j)
+                && is_tangent_edge(&faces[*i], &faces[j], &edge, angle_tolerance)
+            {
+                edges.push(edge);
+            }
+        }
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use geop_geometry::{
+        curves::{circle::Circle, curve::Curve, line::Line},
+        points::point::Point,
+        surfaces::plane::Plane,
+        EQ_THRESHOLD,
+    };
+
+    use super::*;
+    use crate::{operations::extrude::extrude, topology::contour::Contour};
+
+    fn line(start: Point, end: Point) -> Edge {
+        Edge::new(
+            Some(start),
+            Some(end),
+            Curve::Line(Line::new(start, end - start)),
+        )
+    }
+
+    #[test]
+    fn test_selection() {
+        // A slot with a round end, whose long sides blend tangentially into the round end.
+        let p = |x: f64, y: f64| Point::new(x, y, 0.0);
+        let arc = Edge::new(
+            Some(p(2.0, 0.0)),
+            Some(p(2.0, 2.0)),
+            Curve::Circle(Circle::new(p(2.0, 1.0), Point::unit_z(), 1.0)),
+        );
+        let contour = Contour::new(vec![
+            line(p(0.0, 0.0), p(2.0, 0.0)),
+            arc,
+            line(p(2.0, 2.0), p(0.0, 2.0)),
+            line(p(0.0, 2.0), p(0.0, 0.0)),
+        ]);
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let face = Face::new(Some(contour), vec![], Rc::new(Surface::Plane(plane)));
+        let faces = extrude(face, Point::unit_z()).all_faces();
+
+        let cylinders = select_by_surface_type(&faces, SurfaceType::Cylinder);
+        assert_eq!(cylinders.len(), 1);
+        assert_eq!(select_by_surface_type(&faces, SurfaceType::Plane).len(), 5);
+        assert_eq!(face_neighbors(&faces, cylinders[0]).len(), 4);
+
+        let region = expand_tangent(&faces, &cylinders, EQ_THRESHOLD);
+        assert_eq!(region.len(), 3);
+        assert_eq!(tangent_edges(&faces, &region, EQ_THRESHOLD).len(), 2);
+
+        let feature = select_by_feature(&faces, 7, |f| match SurfaceType::of(&f.surface) {
+            SurfaceType::Cylinder => Some(7),
+            _ => None,
+        });
+        assert_eq!(feature, cylinders);
+    }
+}
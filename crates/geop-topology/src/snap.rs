@@ -0,0 +1,221 @@
+use geop_geometry::{
+    curve_surface_intersection::curve_surface::{
+        curve_surface_intersection, CurveSurfaceIntersection,
+    },
+    curves::{curve::Curve, line::Line},
+    points::point::Point,
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    measure::{distance::measure_distance, MeasureEntity},
+    topology::{edge::Edge, face::Face, scene::Scene},
+};
+
+// The kinds of snap targets, ordered by priority. If several candidates are within the tolerance, the one with the highest priority wins.
+#[derive(Clone, Debug)]
+pub enum SnapKind {
+    Vertex,
+    EdgeMidpoint(Edge),
+    Edge(Edge),
+    Face(Face),
+}
+
+impl SnapKind {
+    fn priority(&self) -> usize {
+        match self {
+            SnapKind::Vertex => 0,
+            SnapKind::EdgeMidpoint(_) => 1,
+            SnapKind::Edge(_) => 2,
+            SnapKind::Face(_) => 3,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SnapCandidate {
+    pub kind: SnapKind,
+    pub point: Point,        // The snapped point in world coordinates
+    pub depth: f64,          // Distance from the ray origin along the ray direction
+    pub pixel_distance: f64, // Distance from the cursor ray in pixels
+}
+
+// A cursor ray shot from the camera into the scene.
+// pixel_angle is the angle in radians that one pixel covers at the camera, which converts world distances to pixels.
+#[derive(Clone, Debug)]
+pub struct SnapRay {
+    pub origin: Point,
+    pub direction: Point,
+    pub pixel_angle: f64,
+}
+
+impl SnapRay {
+    pub fn new(origin: Point, direction: Point, pixel_angle: f64) -> SnapRay {
+        assert!(pixel_angle > 0.0);
+        SnapRay {
+            origin,
+            direction: direction.normalize(),
+            pixel_angle,
+        }
+    }
+
+    fn as_edge(&self) -> Edge {
+        Edge::new(
+            Some(self.origin),
+            None,
+            Curve::Line(Line::new(self.origin, self.direction)),
+        )
+    }
+
+    fn depth(&self, p: Point) -> f64 {
+        (p - self.origin).dot(self.direction)
+    }
+
+    // Returns the distance of p to the ray in pixels, or None if p is behind the camera.
+    fn pixel_distance(&self, p: Point) -> Option<f64> {
+        let depth = self.depth(p);
+        if depth < EQ_THRESHOLD {
+            return None;
+        }
+        let offset = (p - self.origin) - self.direction * depth;
+        Some(offset.norm() / (depth * self.pixel_angle))
+    }
+
+    fn candidate(&self, kind: SnapKind, point: Point) -> Option<SnapCandidate> {
+        let pixel_distance = self.pixel_distance(point)?;
+        Some(SnapCandidate {
+            kind,
+            point,
+            depth: self.depth(point),
+            pixel_distance,
+        })
+    }
+}
+
+fn scene_faces(scene: &Scene) -> Vec<Face> {
+    let mut faces = Vec::<Face>::new();
+    for (volume, _) in scene.volumes.iter() {
+        faces.extend(volume.all_faces());
+    }
+    for (face, _) in scene.faces.iter() {
+        faces.push(face.clone());
+    }
+    faces
+}
+
+fn scene_edges(scene: &Scene, faces: &[Face]) -> Vec<Edge> {
+    let mut edges = Vec::<Edge>::new();
+    for face in faces.iter() {
+        edges.extend(face.all_edges());
+    }
+    for (edge, _) in scene.edges.iter() {
+        edges.push(edge.clone());
+    }
+    edges
+}
+
+// Returns all snap candidates within pixel_tolerance of the ray, sorted by priority and then by depth.
+pub fn scene_snap_candidates(
+    scene: &Scene,
+    ray: &SnapRay,
+    pixel_tolerance: f64,
+) -> Vec<SnapCandidate> {
+    let faces = scene_faces(scene);
+    let edges = scene_edges(scene, &faces);
+
+    let mut candidates = Vec::<SnapCandidate>::new();
+
+    for (point, _) in scene.points.iter() {
+        candidates.extend(ray.candidate(SnapKind::Vertex, *point));
+    }
+    for edge in edges.iter() {
+        for point in [edge.start, edge.end].into_iter().flatten() {
+            candidates.extend(ray.candidate(SnapKind::Vertex, point));
+        }
+        if edge.start.is_some() && edge.end.is_some() {
+            candidates
+                .extend(ray.candidate(SnapKind::EdgeMidpoint(edge.clone()), edge.get_midpoint()));
+        }
+    }
+
+    let ray_edge = ray.as_edge();
+    for edge in edges.iter() {
+        let measurement =
+            measure_distance(&MeasureEntity::Edge(&ray_edge), &MeasureEntity::Edge(edge));
+        candidates.extend(ray.candidate(SnapKind::Edge(edge.clone()), measurement.witness_b));
+    }
+
+    for face in faces.iter() {
+        if let CurveSurfaceIntersection::Points(points) =
+            curve_surface_intersection(&ray_edge.curve, &face.surface)
+        {
+            for point in points.iter().map(|p| p.point) {
+                match face_point_contains(face, point) {
+                    FacePointContains::Outside | FacePointContains::NotOnSurface => {}
+                    _ => candidates.extend(ray.candidate(SnapKind::Face(face.clone()), point)),
+                }
+            }
+        }
+    }
+
+    candidates.retain(|c| c.pixel_distance <= pixel_tolerance);
+    candidates.sort_by(|a, b| {
+        a.kind
+            .priority()
+            .cmp(&b.kind.priority())
+            .then(a.depth.total_cmp(&b.depth))
+    });
+    candidates
+}
+
+// Returns the best snap target for a cursor ray: vertices first, then edge midpoints, edges and finally the first face that is hit.
+pub fn scene_snap(scene: &Scene, ray: &SnapRay, pixel_tolerance: f64) -> Option<SnapCandidate> {
+    scene_snap_candidates(scene, ray, pixel_tolerance)
+        .into_iter()
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{primitive_objects::volumes::cube::primitive_cube, topology::scene::Color};
+
+    use super::*;
+
+    #[test]
+    fn test_scene_snap() {
+        let scene = Scene::new(
+            vec![(primitive_cube(2.0, 2.0, 2.0), Color::white())],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let camera = Point::new(0.0, 0.0, 10.0);
+        let ray_to = |target: Point| SnapRay::new(camera, target - camera, 0.001);
+
+        // Close to a corner, the vertex wins over the edges and the face there.
+        let snap = scene_snap(&scene, &ray_to(Point::new(0.99, 0.99, 1.0)), 5.0).unwrap();
+        assert!(matches!(snap.kind, SnapKind::Vertex));
+        assert_eq!(snap.point, Point::new(1.0, 1.0, 1.0));
+
+        // Close to the middle of an edge, its midpoint, and further along it the edge itself.
+        let snap = scene_snap(&scene, &ray_to(Point::new(0.01, 0.99, 1.0)), 5.0).unwrap();
+        assert!(matches!(snap.kind, SnapKind::EdgeMidpoint(_)));
+        assert_eq!(snap.point, Point::new(0.0, 1.0, 1.0));
+        let snap = scene_snap(&scene, &ray_to(Point::new(0.5, 0.99, 1.0)), 5.0).unwrap();
+        assert!(matches!(snap.kind, SnapKind::Edge(_)));
+        assert!((snap.point - Point::new(0.5, 1.0, 1.0)).norm() < 0.01);
+
+        // In the middle of the top face, the face is hit in front of the bottom face.
+        let candidates = scene_snap_candidates(&scene, &ray_to(Point::new(0.3, 0.2, 1.0)), 5.0);
+        assert!(candidates
+            .iter()
+            .all(|c| matches!(c.kind, SnapKind::Face(_))));
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].point, Point::new(0.3, 0.2, 1.0));
+        assert!((candidates[0].depth - 9.0).abs() < 0.01);
+
+        // Away from the cube, nothing is hit.
+        assert!(scene_snap(&scene, &ray_to(Point::new(3.0, 0.0, 1.0)), 5.0).is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    any::{Any, TypeId},
+    fmt::{Debug, Formatter},
+    rc::Rc,
+};
+
+use super::{edge::Edge, face::Face, volume::Volume};
+
+// Data that applications attach to edges, faces and volumes, like boundary conditions or machining info. There is one value per
+// type, so applications define their own types for their tags instead of agreeing on string keys. The values are shared between
+// copies, so cloning, transforming and flipping an entity keeps its attributes without copying them. Operations that build new
+// entities, like splitting a face, start with empty attributes.
+// The values are `Rc<dyn Any>`, which makes Edge, Face and Volume neither Send nor UnwindSafe. Code that catches panics around them,
+// like the boolean recorder, has to wrap them in AssertUnwindSafe. That is sound, since values cannot be changed once inserted, so a
+// panic cannot leave them half updated.
+#[derive(Clone, Default)]
+pub struct Attributes {
+    entries: Vec<(TypeId, Rc<dyn Any>)>,
+}
+
+impl Attributes {
+    pub fn new() -> Attributes {
+        Attributes::default()
+    }
+
+    // Sets the value of its type, replacing the previous one.
+    pub fn insert<T: Any>(&mut self, value: T) {
+        self.remove::<T>();
+        self.entries.push((TypeId::of::<T>(), Rc::new(value)));
+    }
+
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.entries
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<T>())
+            .and_then(|(_, value)| value.downcast_ref::<T>())
+    }
+
+    // Returns whether there was a value of the type.
+    pub fn remove<T: Any>(&mut self) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|(id, _)| *id != TypeId::of::<T>());
+        self.entries.len() < len
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Adds the values of the other attributes whose types are not set yet, e.g. to pass the attributes of a face on to the pieces
+    // it is split into.
+    pub fn merge(&mut self, other: &Attributes) {
+        for (id, value) in other.entries.iter() {
+            if !self.entries.iter().any(|(i, _)| i == id) {
+                self.entries.push((*id, value.clone()));
+            }
+        }
+    }
+}
+
+impl Debug for Attributes {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Attributes({})", self.entries.len())
+    }
+}
+
+// Entities that carry attributes.
+pub trait Attributed: Sized {
+    fn attributes(&self) -> &Attributes;
+
+    fn attributes_mut(&mut self) -> &mut Attributes;
+
+    fn attribute<T: Any>(&self) -> Option<&T> {
+        self.attributes().get::<T>()
+    }
+
+    fn with_attribute<T: Any>(mut self, value: T) -> Self {
+        self.attributes_mut().insert(value);
+        self
+    }
+}
+
+impl Attributed for Edge {
+    fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    fn attributes_mut(&mut self) -> &mut Attributes {
+        &mut self.attributes
+    }
+}
+
+impl Attributed for Face {
+    fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    fn attributes_mut(&mut self) -> &mut Attributes {
+        &mut self.attributes
+    }
+}
+
+impl Attributed for Volume {
+    fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    fn attributes_mut(&mut self) -> &mut Attributes {
+        &mut self.attributes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, transforms::Transform};
+
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct BoundaryCondition(f64);
+
+    #[derive(Debug, PartialEq)]
+    struct Material(&'static str);
+
+    #[test]
+    fn test_attributes() {
+        let cube = primitive_cube(1.0, 1.0, 1.0).with_attribute(Material("steel"));
+        let mut faces = cube.all_faces();
+        faces[0] = faces[0].clone().with_attribute(BoundaryCondition(20.0));
+        faces[0].attributes_mut().insert(BoundaryCondition(30.0));
+        assert_eq!(faces[0].attributes().len(), 1);
+
+        let moved = faces[0].transform(Transform::from_translation(Point::unit_z()));
+        assert_eq!(moved.attribute(), Some(&BoundaryCondition(30.0)));
+        assert_eq!(moved.flip().attribute(), Some(&BoundaryCondition(30.0)));
+        assert_eq!(moved.attribute::<Material>(), None);
+
+        let moved = cube.transform(Transform::from_translation(Point::unit_z()));
+        assert_eq!(moved.attribute(), Some(&Material("steel")));
+
+        let mut merged = Attributes::new();
+        merged.insert(BoundaryCondition(0.0));
+        merged.merge(faces[0].attributes());
+        merged.merge(cube.attributes());
+        assert_eq!(merged.get(), Some(&BoundaryCondition(0.0)));
+        assert_eq!(merged.get(), Some(&Material("steel")));
+        assert!(merged.remove::<Material>());
+        assert!(!merged.remove::<Material>());
+    }
+}
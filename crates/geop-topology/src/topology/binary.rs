@@ -0,0 +1,586 @@
+use std::{
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+
+use geop_geometry::{
+    curves::{
+        bezier::Bezier,
+        circle::Circle,
+        composite::{CompositeCurve, CompositeSegment},
+        curve::Curve,
+        ellipse::Ellipse,
+        helix::Helix,
+        line::Line,
+        CurveLike,
+    },
+    points::point::Point,
+    surfaces::{cylinder::Cylinder, plane::Plane, sphere::Sphere, surface::Surface},
+};
+
+use super::{
+    contour::Contour,
+    diagnostics::describe,
+    edge::Edge,
+    face::Face,
+    scene::{Color, Scene},
+    shell::Shell,
+    volume::Volume,
+};
+
+// A compact, versioned binary format for topology and scenes, e.g. to cache results or to send them to a viewer.
+// All numbers are little endian. Every file starts with MAGIC, the VERSION and a kind byte. Curves and surfaces are stored by their
+// defining parameters, so decoding rebuilds exactly the same geometry. Surfaces shared by several faces are stored once per face.
+
+pub const MAGIC: [u8; 4] = *b"GEOP";
+// Increased whenever the layout changes. Older versions are rejected instead of being misread.
+pub const VERSION: u32 = 1;
+
+// The kind byte tells what follows the header. Other crates add their own kinds, e.g. tessellations in geop-rasterize use 2 and 3.
+pub const KIND_VOLUME: u8 = 0;
+pub const KIND_SCENE: u8 = 1;
+pub const KIND_FACE: u8 = 4;
+pub const HEADER_LEN: usize = 9;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    BadMagic,
+    UnsupportedVersion(u32),
+    WrongKind(u8),
+    UnexpectedEnd,
+    InvalidTag(u8),
+    Invalid(String), // The data decodes, but does not describe valid topology
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::BadMagic => write!(f, "Not a geop binary file"),
+            DecodeError::UnsupportedVersion(v) => {
+                write!(f, "Unsupported version {}, expected {}", v, VERSION)
+            }
+            DecodeError::WrongKind(k) => write!(f, "Unexpected kind {}", k),
+            DecodeError::UnexpectedEnd => write!(f, "Unexpected end of data"),
+            DecodeError::InvalidTag(t) => write!(f, "Invalid tag {}", t),
+            DecodeError::Invalid(message) => write!(f, "Invalid topology: {}", message),
+        }
+    }
+}
+
+pub fn header(kind: u8) -> Vec<u8> {
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend(VERSION.to_le_bytes());
+    bytes.push(kind);
+    bytes
+}
+
+pub fn check_header(bytes: &[u8], kind: u8) -> Result<(), DecodeError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+    if bytes[0..4] != MAGIC {
+        return Err(DecodeError::BadMagic);
+    }
+    let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+    if version != VERSION {
+        return Err(DecodeError::UnsupportedVersion(version));
+    }
+    match bytes[8] == kind {
+        true => Ok(()),
+        false => Err(DecodeError::WrongKind(bytes[8])),
+    }
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn new(kind: u8) -> Writer {
+        Writer {
+            bytes: header(kind),
+        }
+    }
+
+    fn u8(&mut self, v: u8) {
+        self.bytes.push(v);
+    }
+
+    fn bool(&mut self, v: bool) {
+        self.u8(v as u8);
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.bytes.extend(v.to_le_bytes());
+    }
+
+    fn len(&mut self, v: usize) {
+        self.u32(v as u32);
+    }
+
+    fn f32(&mut self, v: f32) {
+        self.bytes.extend(v.to_le_bytes());
+    }
+
+    fn f64(&mut self, v: f64) {
+        self.bytes.extend(v.to_le_bytes());
+    }
+
+    fn point(&mut self, p: Point) {
+        self.f64(p.x);
+        self.f64(p.y);
+        self.f64(p.z);
+    }
+
+    fn optional_point(&mut self, p: Option<Point>) {
+        self.bool(p.is_some());
+        if let Some(p) = p {
+            self.point(p);
+        }
+    }
+
+    fn color(&mut self, c: &Color) {
+        for v in [c.r, c.g, c.b, c.a] {
+            self.f32(v);
+        }
+    }
+
+    fn curve(&mut self, curve: &Curve) {
+        match curve {
+            Curve::Line(line) => {
+                self.u8(0);
+                self.point(line.basis);
+                self.point(line.direction);
+            }
+            Curve::Circle(circle) => {
+                self.u8(1);
+                self.point(circle.basis);
+                self.point(circle.normal);
+                self.f64(circle.radius.norm());
+            }
+            Curve::Ellipse(ellipse) => {
+                self.u8(2);
+                self.point(ellipse.basis);
+                self.point(ellipse.normal);
+                self.point(ellipse.major_radius);
+                self.point(ellipse.minor_radius);
+            }
+            Curve::Helix(helix) => {
+                self.u8(3);
+                self.point(helix.basis);
+                self.point(helix.pitch);
+                self.point(helix.radius);
+                self.bool(helix.is_right_winding());
+            }
+            Curve::Bezier(bezier) => {
+                self.u8(4);
+                for p in [bezier.p0, bezier.p1, bezier.p2, bezier.p3] {
+                    self.point(p);
+                }
+            }
+            Curve::Composite(composite) => {
+                self.u8(5);
+                self.len(composite.segments.len());
+                for segment in composite.segments.iter() {
+                    self.curve(&segment.curve);
+                    self.point(segment.start);
+                    self.point(segment.end);
+                }
+            }
+        }
+    }
+
+    fn surface(&mut self, surface: &Surface) {
+        match surface {
+            Surface::Plane(plane) => {
+                self.u8(0);
+                self.point(plane.basis);
+                self.point(plane.u_slope);
+                self.point(plane.v_slope);
+            }
+            Surface::Sphere(sphere) => {
+                self.u8(1);
+                self.point(sphere.basis);
+                self.f64(sphere.radius);
+                self.bool(sphere.normal_outwards);
+            }
+            Surface::Cylinder(cylinder) => {
+                self.u8(2);
+                self.point(cylinder.basis);
+                self.point(cylinder.extend_dir);
+                self.f64(cylinder.radius.norm());
+                self.bool(cylinder.normal_outwards);
+            }
+        }
+    }
+
+    fn edge(&mut self, edge: &Edge) {
+        self.optional_point(edge.start);
+        self.optional_point(edge.end);
+        self.curve(&edge.curve);
+    }
+
+    fn contour(&mut self, contour: &Contour) {
+        self.len(contour.edges.len());
+        for edge in contour.edges.iter() {
+            self.edge(edge);
+        }
+    }
+
+    fn face(&mut self, face: &Face) {
+        self.surface(&face.surface);
+        self.bool(face.boundary.is_some());
+        if let Some(boundary) = &face.boundary {
+            self.contour(boundary);
+        }
+        self.len(face.holes.len());
+        for hole in face.holes.iter() {
+            self.contour(hole);
+        }
+    }
+
+    fn shell(&mut self, shell: &Shell) {
+        self.len(shell.faces.len());
+        for face in shell.faces.iter() {
+            self.face(face);
+        }
+    }
+
+    fn volume(&mut self, volume: &Volume) {
+        self.shell(&volume.boundary);
+        self.len(volume.cavities.len());
+        for cavity in volume.cavities.iter() {
+            self.shell(cavity);
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8], kind: u8) -> Result<Reader<'a>, DecodeError> {
+        check_header(bytes, kind)?;
+        Ok(Reader {
+            bytes,
+            position: HEADER_LEN,
+        })
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self.position + n;
+        if end > self.bytes.len() {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn bool(&mut self) -> Result<bool, DecodeError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            t => Err(DecodeError::InvalidTag(t)),
+        }
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    // A length, which is checked against the remaining data, so that corrupt lengths do not allocate huge vectors.
+    fn len(&mut self) -> Result<usize, DecodeError> {
+        let len = self.u32()? as usize;
+        match len <= self.bytes.len() - self.position {
+            true => Ok(len),
+            false => Err(DecodeError::UnexpectedEnd),
+        }
+    }
+
+    fn f32(&mut self) -> Result<f32, DecodeError> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn f64(&mut self) -> Result<f64, DecodeError> {
+        Ok(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn point(&mut self) -> Result<Point, DecodeError> {
+        Ok(Point::new(self.f64()?, self.f64()?, self.f64()?))
+    }
+
+    fn optional_point(&mut self) -> Result<Option<Point>, DecodeError> {
+        match self.bool()? {
+            true => Ok(Some(self.point()?)),
+            false => Ok(None),
+        }
+    }
+
+    fn color(&mut self) -> Result<Color, DecodeError> {
+        Ok(Color::new(
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+        ))
+    }
+
+    fn curve(&mut self) -> Result<Curve, DecodeError> {
+        Ok(match self.u8()? {
+            0 => Curve::Line(Line::new(self.point()?, self.point()?)),
+            1 => Curve::Circle(Circle::new(self.point()?, self.point()?, self.f64()?)),
+            2 => Curve::Ellipse(Ellipse::new(
+                self.point()?,
+                self.point()?,
+                self.point()?,
+                self.point()?,
+            )),
+            3 => Curve::Helix(Helix::new(
+                self.point()?,
+                self.point()?,
+                self.point()?,
+                self.bool()?,
+            )),
+            4 => Curve::Bezier(Bezier::new(
+                self.point()?,
+                self.point()?,
+                self.point()?,
+                self.point()?,
+            )),
+            5 => {
+                let mut segments = Vec::<CompositeSegment>::new();
+                for _ in 0..self.len()? {
+                    let curve = self.curve()?;
+                    let (start, end) = (self.point()?, self.point()?);
+                    if !curve.on_curve(start) || !curve.on_curve(end) {
+                        return Err(DecodeError::Invalid(
+                            "Composite segment ends are not on its curve".to_string(),
+                        ));
+                    }
+                    segments.push(CompositeSegment::new(curve, start, end));
+                }
+                Curve::Composite(CompositeCurve::new(segments))
+            }
+            t => return Err(DecodeError::InvalidTag(t)),
+        })
+    }
+
+    fn surface(&mut self) -> Result<Surface, DecodeError> {
+        Ok(match self.u8()? {
+            0 => Surface::Plane(Plane::new(self.point()?, self.point()?, self.point()?)),
+            1 => Surface::Sphere(Sphere::new(self.point()?, self.f64()?, self.bool()?)),
+            2 => Surface::Cylinder(Cylinder::new(
+                self.point()?,
+                self.point()?,
+                self.f64()?,
+                self.bool()?,
+            )),
+            t => return Err(DecodeError::InvalidTag(t)),
+        })
+    }
+
+    fn edge(&mut self) -> Result<Edge, DecodeError> {
+        let start = self.optional_point()?;
+        let end = self.optional_point()?;
+        let curve = self.curve()?;
+        if [start, end]
+            .into_iter()
+            .flatten()
+            .any(|p| !curve.on_curve(p))
+        {
+            return Err(DecodeError::Invalid(
+                "Edge vertex is not on its curve".to_string(),
+            ));
+        }
+        Ok(Edge::new(start, end, curve))
+    }
+
+    fn contour(&mut self) -> Result<Contour, DecodeError> {
+        let mut edges = Vec::<Edge>::new();
+        for _ in 0..self.len()? {
+            edges.push(self.edge()?);
+        }
+        Contour::try_new(edges).map_err(|v| DecodeError::Invalid(describe(&v)))
+    }
+
+    fn face(&mut self) -> Result<Face, DecodeError> {
+        let surface = Rc::new(self.surface()?);
+        let boundary = match self.bool()? {
+            true => Some(self.contour()?),
+            false => None,
+        };
+        let mut holes = Vec::<Contour>::new();
+        for _ in 0..self.len()? {
+            holes.push(self.contour()?);
+        }
+        Face::try_new(boundary, holes, surface).map_err(|v| DecodeError::Invalid(describe(&v)))
+    }
+
+    fn shell(&mut self) -> Result<Shell, DecodeError> {
+        let mut faces = Vec::<Face>::new();
+        for _ in 0..self.len()? {
+            faces.push(self.face()?);
+        }
+        match faces.is_empty() {
+            true => Err(DecodeError::Invalid("Shell has no faces".to_string())),
+            false => Ok(Shell::new(faces)),
+        }
+    }
+
+    fn volume(&mut self) -> Result<Volume, DecodeError> {
+        let boundary = self.shell()?;
+        let mut cavities = Vec::<Shell>::new();
+        for _ in 0..self.len()? {
+            cavities.push(self.shell()?);
+        }
+        Ok(Volume::new(boundary, cavities))
+    }
+
+    fn finish<T>(self, value: T) -> Result<T, DecodeError> {
+        match self.position == self.bytes.len() {
+            true => Ok(value),
+            false => Err(DecodeError::Invalid("Trailing data".to_string())),
+        }
+    }
+}
+
+pub fn volume_to_bytes(volume: &Volume) -> Vec<u8> {
+    let mut writer = Writer::new(KIND_VOLUME);
+    writer.volume(volume);
+    writer.bytes
+}
+
+pub fn volume_from_bytes(bytes: &[u8]) -> Result<Volume, DecodeError> {
+    let mut reader = Reader::new(bytes, KIND_VOLUME)?;
+    let volume = reader.volume()?;
+    reader.finish(volume)
+}
+
+pub fn face_to_bytes(face: &Face) -> Vec<u8> {
+    let mut writer = Writer::new(KIND_FACE);
+    writer.face(face);
+    writer.bytes
+}
+
+pub fn face_from_bytes(bytes: &[u8]) -> Result<Face, DecodeError> {
+    let mut reader = Reader::new(bytes, KIND_FACE)?;
+    let face = reader.face()?;
+    reader.finish(face)
+}
+
+pub fn scene_to_bytes(scene: &Scene) -> Vec<u8> {
+    let mut writer = Writer::new(KIND_SCENE);
+    writer.len(scene.volumes.len());
+    for (volume, color) in scene.volumes.iter() {
+        writer.volume(volume);
+        writer.color(color);
+    }
+    writer.len(scene.faces.len());
+    for (face, color) in scene.faces.iter() {
+        writer.face(face);
+        writer.color(color);
+    }
+    writer.len(scene.edges.len());
+    for (edge, color) in scene.edges.iter() {
+        writer.edge(edge);
+        writer.color(color);
+    }
+    writer.len(scene.points.len());
+    for (point, color) in scene.points.iter() {
+        writer.point(*point);
+        writer.color(color);
+    }
+    writer.bytes
+}
+
+pub fn scene_from_bytes(bytes: &[u8]) -> Result<Scene, DecodeError> {
+    let mut reader = Reader::new(bytes, KIND_SCENE)?;
+    let mut volumes = Vec::<(Volume, Color)>::new();
+    for _ in 0..reader.len()? {
+        volumes.push((reader.volume()?, reader.color()?));
+    }
+    let mut faces = Vec::<(Face, Color)>::new();
+    for _ in 0..reader.len()? {
+        faces.push((reader.face()?, reader.color()?));
+    }
+    let mut edges = Vec::<(Edge, Color)>::new();
+    for _ in 0..reader.len()? {
+        edges.push((reader.edge()?, reader.color()?));
+    }
+    let mut points = Vec::<(Point, Color)>::new();
+    for _ in 0..reader.len()? {
+        points.push((reader.point()?, reader.color()?));
+    }
+    reader.finish(Scene::new(volumes, faces, edges, points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive_objects::{
+            edges::{circle::primitive_circle, helix::primitive_helix},
+            faces::sphere::primitive_sphere,
+            volumes::cube::primitive_cube,
+        },
+        topology::dump::{dump_face, dump_volume},
+    };
+
+    #[test]
+    fn test_binary_round_trip() {
+        let volume = primitive_cube(2.0, 2.0, 2.0).with_cavity(&primitive_cube(1.0, 1.0, 1.0));
+        let bytes = volume_to_bytes(&volume);
+        assert_eq!(bytes[0..4], MAGIC);
+        let decoded = volume_from_bytes(&bytes).unwrap();
+        assert_eq!(dump_volume(&decoded), dump_volume(&volume));
+        assert_eq!(decoded.cavities.len(), 1);
+
+        let scene = Scene::new(
+            vec![(volume, Color::red())],
+            vec![(primitive_sphere(Point::zero(), 1.0), Color::blue())],
+            vec![
+                (
+                    primitive_circle(Point::zero(), Point::unit_z(), 2.0),
+                    Color::black(),
+                ),
+                (
+                    primitive_helix(Point::zero(), Point::unit_z(), Point::unit_x(), false),
+                    Color::black(),
+                ),
+            ],
+            vec![(Point::new(1.0, 2.0, 3.0), Color::white())],
+        );
+        let bytes = scene_to_bytes(&scene);
+        let decoded = scene_from_bytes(&bytes).unwrap();
+        assert_eq!(dump_face(&decoded.faces[0].0), dump_face(&scene.faces[0].0));
+        assert_eq!(decoded.edges[1].0, scene.edges[1].0);
+        assert_eq!(decoded.points[0].0, scene.points[0].0);
+        assert_eq!(scene_to_bytes(&decoded), bytes);
+
+        let face = scene.faces[0].0.clone();
+        let decoded = face_from_bytes(&face_to_bytes(&face)).unwrap();
+        assert_eq!(dump_face(&decoded), dump_face(&face));
+
+        assert!(matches!(
+            volume_from_bytes(&bytes),
+            Err(DecodeError::WrongKind(KIND_SCENE))
+        ));
+        assert!(matches!(
+            scene_from_bytes(&bytes[..bytes.len() - 1]),
+            Err(DecodeError::UnexpectedEnd)
+        ));
+        let mut newer = bytes.clone();
+        newer[4] = 2;
+        assert!(matches!(
+            scene_from_bytes(&newer),
+            Err(DecodeError::UnsupportedVersion(2))
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+use geop_geometry::{
+    bounding_box::BoundingBox, bounding_sphere::BoundingSphere, curves::curve::Curve, kdop::Kdop,
+    points::point::Point,
+};
+
+use super::{
+    contour::Contour,
+    edge::Edge,
+    face::{bounds::face_sample_points, Face},
+    shell::Shell,
+    volume::Volume,
+    wire::Wire,
+};
+
+// Number of samples on curved edges.
+const EDGE_SAMPLES: usize = 64;
+
+// Bounds of an entity at several resolutions. The sphere is the cheapest to test and the k-DOP the tightest, so pairs of entities
+// that are far apart are rejected by the spheres, and the k-DOP only has to be tested for pairs that are close.
+#[derive(Debug, Clone)]
+pub struct Bounds {
+    pub bounding_sphere: BoundingSphere,
+    pub bounding_box: BoundingBox,
+    pub kdop: Option<Kdop>,
+}
+
+impl Bounds {
+    pub fn from_points(points: &[Point], with_kdop: bool) -> Bounds {
+        assert!(!points.is_empty(), "Nothing to bound");
+        Bounds {
+            bounding_sphere: BoundingSphere::from_points(points),
+            bounding_box: BoundingBox::from_points(points),
+            kdop: match with_kdop {
+                true => Some(Kdop::from_points(points)),
+                false => None,
+            },
+        }
+    }
+
+    // Tests the bounds from the cheapest to the tightest. The k-DOPs are only compared if both bounds have one.
+    pub fn may_intersect(&self, other: &Bounds, tolerance: f64) -> bool {
+        self.bounding_sphere
+            .intersects(&other.bounding_sphere, tolerance)
+            && self.bounding_box.intersects(&other.bounding_box, tolerance)
+            && match (&self.kdop, &other.kdop) {
+                (Some(a), Some(b)) => a.intersects(b, tolerance),
+                _ => true,
+            }
+    }
+}
+
+// Entities with a finite extent. The bounds are computed from sample points, which are exact for straight edges and planar faces,
+// and approximate curved ones.
+pub trait Bounded {
+    // Points on or inside of the entity that approximate its extent.
+    fn bound_points(&self) -> Vec<Point>;
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(&self.bound_points())
+    }
+
+    fn bounding_sphere(&self) -> BoundingSphere {
+        BoundingSphere::from_points(&self.bound_points())
+    }
+
+    fn kdop(&self) -> Kdop {
+        Kdop::from_points(&self.bound_points())
+    }
+
+    // The sphere and the box, which are enough to cull most pairs.
+    fn bounds(&self) -> Bounds {
+        Bounds::from_points(&self.bound_points(), false)
+    }
+
+    // Like bounds, with a k-DOP for the pairs that are close.
+    fn tight_bounds(&self) -> Bounds {
+        Bounds::from_points(&self.bound_points(), true)
+    }
+}
+
+impl Bounded for Edge {
+    fn bound_points(&self) -> Vec<Point> {
+        match (&self.curve, self.start, self.end) {
+            (Curve::Line(_), Some(start), Some(end)) => vec![start, end],
+            (Curve::Line(_), _, _) => panic!("Infinite lines have no bounds"),
+            _ => (0..=EDGE_SAMPLES)
+                .map(|i| self.interpolate(i as f64 / EDGE_SAMPLES as f64))
+                .collect(),
+        }
+    }
+}
+
+impl Bounded for Contour {
+    fn bound_points(&self) -> Vec<Point> {
+        self.edges.iter().flat_map(|e| e.bound_points()).collect()
+    }
+}
+
+impl Bounded for Wire {
+    fn bound_points(&self) -> Vec<Point> {
+        self.edges.iter().flat_map(|e| e.bound_points()).collect()
+    }
+}
+
+impl Bounded for Face {
+    fn bound_points(&self) -> Vec<Point> {
+        face_sample_points(self)
+    }
+}
+
+impl Bounded for Shell {
+    fn bound_points(&self) -> Vec<Point> {
+        self.faces.iter().flat_map(|f| f.bound_points()).collect()
+    }
+}
+
+// Cavities are inside of the boundary, so only the boundary is sampled.
+impl Bounded for Volume {
+    fn bound_points(&self) -> Vec<Point> {
+        self.boundary.bound_points()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::{
+        edges::{arc::primitive_arc, line::primitive_line},
+        volumes::cube::primitive_cube,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_bounded() {
+        let cube = primitive_cube(2.0, 2.0, 2.0);
+        let bounds = cube.tight_bounds();
+        assert_eq!(bounds.bounding_box.min, Point::new(-1.0, -1.0, -1.0));
+        assert_eq!(bounds.bounding_box.max, Point::new(1.0, 1.0, 1.0));
+        assert!((bounds.bounding_sphere.radius - 3.0_f64.sqrt()).abs() < 1e-9);
+
+        // An edge next to the middle of the diagonal of a cube is inside of the box and sphere of the diagonal, but outside of its
+        // k-DOP.
+        let diagonal = primitive_line(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        let edge = primitive_line(Point::new(1.0, -1.0, 0.0), Point::new(0.8, -1.0, 0.0));
+        let (a, b) = (diagonal.tight_bounds(), edge.tight_bounds());
+        assert!(a.bounding_sphere.intersects(&b.bounding_sphere, 0.0));
+        assert!(a.bounding_box.intersects(&b.bounding_box, 0.0));
+        assert!(!a.may_intersect(&b, 0.0));
+        assert!(a.may_intersect(&edge.bounds(), 0.0));
+
+        // Curved edges are sampled, so their box contains the bulge of the arc.
+        let arc = primitive_arc(
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            2.0,
+            Point::unit_z(),
+        );
+        assert!(arc.bounding_box().max.y > 0.2);
+    }
+}
@@ -1,13 +1,20 @@
 use std::fmt::Display;
 
-use geop_geometry::{curves::CurveLike, points::point::Point, transforms::Transform};
+use geop_geometry::{
+    curves::{ClosestPoint, CurveLike},
+    points::point::Point,
+    transforms::Transform,
+};
 
 use crate::contains::{
     contour_point::contour_point_contains,
     edge_point::{edge_point_contains, EdgePointContains},
 };
 
-use super::edge::Edge;
+use super::{
+    diagnostics::{check_contour, describe, Violation},
+    edge::Edge,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum EdgeIndex {
@@ -61,16 +68,24 @@ pub struct Contour {
 // Keep in mind that the contour is still closed, but the points are "next to" the edges, not "part of" the edges, because otherwise two neighbouring edges would overlap at the point, making things a lot more complicated.
 impl Contour {
     pub fn new(edges: Vec<Edge>) -> Contour {
-        assert!(edges.len() > 0);
-        for i in 0..edges.len() {
-            let edge = edges[i].clone();
-            let next_edge = edges[(i + 1) % edges.len()].clone();
-            assert!(edge.end == next_edge.start);
-        }
-        assert!(edges[0].start == edges[edges.len() - 1].end);
+        let violations = check_contour(&edges);
+        assert!(
+            violations.is_empty(),
+            "Invalid contour:\n{}",
+            describe(&violations)
+        );
         Contour { edges }
     }
 
+    // Like new, but returns the reasons why the edges do not form a contour instead of panicking.
+    pub fn try_new(edges: Vec<Edge>) -> Result<Contour, Vec<Violation>> {
+        let violations = check_contour(&edges);
+        match violations.is_empty() {
+            true => Ok(Contour { edges }),
+            false => Err(violations),
+        }
+    }
+
     pub fn all_points(&self) -> Vec<Point> {
         let mut points = Vec::<Point>::new();
         for edge in self.edges.iter() {
@@ -100,6 +115,16 @@ impl Contour {
         Contour::new(edges)
     }
 
+    // Splits the edges at the points that are inside of them, so that the contour gets these points as vertices.
+    pub fn split_at_points(&self, points: &[Point]) -> Contour {
+        let edges = self
+            .edges
+            .iter()
+            .flat_map(|e| e.split_at_points(points))
+            .collect::<Vec<Edge>>();
+        Contour::new(edges)
+    }
+
     // Returns an edge that contains the point, or None if the point is not on the contour.
     // It can also be the start or the end point of an edge, hence, if this function is used, take special care of the case where this case.
     fn get_edge_index(&self, point: Point) -> EdgeIndex {
@@ -134,6 +159,16 @@ impl Contour {
         }
     }
 
+    // Returns the index of the edge that is closest to p, and the closest point on it.
+    pub fn project(&self, p: Point) -> (usize, ClosestPoint) {
+        self.edges
+            .iter()
+            .map(|e| e.project(p))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance))
+            .expect("Contour has edges")
+    }
+
     // Gets the subcurve between these two points. It is guaranteed that there will be no zero length edges.
     pub fn get_subcurve(&self, start: Point, end: Point) -> Vec<Edge> {
         assert!(start != end);
@@ -232,3 +267,39 @@ impl Display for Contour {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::edges::line::primitive_line;
+
+    use super::*;
+
+    #[test]
+    fn test_contour_project() {
+        let corners = [
+            Point::zero(),
+            Point::unit_x(),
+            Point::new(1.0, 1.0, 0.0),
+            Point::unit_y(),
+        ];
+        let contour = Contour::new(
+            (0..4)
+                .map(|i| primitive_line(corners[i], corners[(i + 1) % 4]))
+                .collect(),
+        );
+
+        // The closest edge is returned with the point on it.
+        let (i, closest) = contour.project(Point::new(0.5, -1.0, 0.0));
+        assert_eq!(i, 0);
+        assert_eq!(closest.point, Point::new(0.5, 0.0, 0.0));
+        assert!(closest.inside_bounds);
+        let (i, closest) = contour.project(Point::new(1.5, 0.5, 2.0));
+        assert_eq!(i, 1);
+        assert!((closest.distance - 4.25_f64.sqrt()).abs() < 1e-9);
+
+        // Next to a corner both edges clamp to it.
+        let (_, closest) = contour.project(Point::new(2.0, 2.0, 0.0));
+        assert_eq!(closest.point, Point::new(1.0, 1.0, 0.0));
+        assert!(!closest.inside_bounds);
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::{Display, Formatter};
+
+use geop_geometry::{
+    curve_surface_intersection::curve_surface::curve_surface_intersection,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+use super::{contour::Contour, edge::Edge, face::Face, shell::Shell};
+
+// Number of samples along an edge to locate where it leaves a surface.
+const EDGE_SAMPLES: usize = 16;
+
+// A reason why entities do not form valid topology, together with the offending entities.
+#[derive(Clone, Debug)]
+pub enum Violation {
+    EmptyContour,
+    ContourNotClosed(usize, Option<Point>, Option<Point>), // Index of the edge, its end and the start of the next edge
+    EdgeNotOnSurface(Edge, f64, Point), // Interpolation parameter and point of the edge that is furthest away from the surface
+    EmptyShell,
+    OpenEdge(Edge),                // Edge of a shell that belongs to only one face
+    NonManifoldEdge(Edge, usize),  // Edge of a shell with the number of faces it belongs to
+    InconsistentOrientation(Edge), // Both faces of the edge run along it in the same direction
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Violation::EmptyContour => write!(f, "Contour has no edges"),
+            Violation::ContourNotClosed(i, end, next_start) => write!(
+                f,
+                "Contour is not closed between edge {} ending at {:?} and the next edge starting at {:?}",
+                i, end, next_start
+            ),
+            Violation::EdgeNotOnSurface(edge, t, p) => write!(
+                f,
+                "Edge {} is not on the surface, e.g. at t = {} at {:?}",
+                edge, t, p
+            ),
+            Violation::EmptyShell => write!(f, "Shell has no faces"),
+            Violation::OpenEdge(edge) => write!(f, "Edge {} belongs to only one face", edge),
+            Violation::NonManifoldEdge(edge, n) => {
+                write!(f, "Edge {} belongs to {} faces instead of two", edge, n)
+            }
+            Violation::InconsistentOrientation(edge) => write!(
+                f,
+                "Both faces of edge {} run along it in the same direction",
+                edge
+            ),
+        }
+    }
+}
+
+// Formats the violations one per line, for panic messages.
+pub fn describe(violations: &[Violation]) -> String {
+    violations
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn check_contour(edges: &[Edge]) -> Vec<Violation> {
+    if edges.is_empty() {
+        return vec![Violation::EmptyContour];
+    }
+    let mut violations = Vec::<Violation>::new();
+    for i in 0..edges.len() {
+        let next = &edges[(i + 1) % edges.len()];
+        if edges[i].end != next.start {
+            violations.push(Violation::ContourNotClosed(i, edges[i].end, next.start));
+        }
+    }
+    violations
+}
+
+fn check_edge_on_surface(edge: &Edge, surface: &Surface) -> Option<Violation> {
+    if curve_surface_intersection(&edge.curve, surface).is_curve() {
+        return None;
+    }
+    let (t, p) = (0..=EDGE_SAMPLES)
+        .map(|i| {
+            let t = i as f64 / EDGE_SAMPLES as f64;
+            (t, edge.interpolate(t))
+        })
+        .max_by(|(_, a), (_, b)| {
+            let da = (*a - surface.project(*a)).norm();
+            let db = (*b - surface.project(*b)).norm();
+            da.total_cmp(&db)
+        })
+        .expect("There are always samples");
+    Some(Violation::EdgeNotOnSurface(edge.clone(), t, p))
+}
+
+// The checks of Face::new: all edges of the contours have to lie on the surface.
+pub fn check_face(
+    boundary: &Option<Contour>,
+    holes: &[Contour],
+    surface: &Surface,
+) -> Vec<Violation> {
+    boundary
+        .iter()
+        .chain(holes.iter())
+        .flat_map(|contour| contour.edges.iter())
+        .filter_map(|edge| check_edge_on_surface(edge, surface))
+        .collect()
+}
+
+// A shell is closed and consistently oriented if every edge belongs to exactly two faces, which run along it in opposite directions.
+pub fn check_shell(faces: &[Face]) -> Vec<Violation> {
+    if faces.is_empty() {
+        return vec![Violation::EmptyShell];
+    }
+    let mut edges = Vec::<(Edge, Vec<Edge>)>::new();
+    for face in faces {
+        for edge in face.all_edges() {
+            match edges.iter_mut().find(|(e, _)| *e == edge) {
+                Some((_, uses)) => uses.push(edge),
+                None => edges.push((edge.clone(), vec![edge])),
+            }
+        }
+    }
+    let mut violations = Vec::<Violation>::new();
+    for (edge, uses) in edges {
+        match uses.len() {
+            1 => violations.push(Violation::OpenEdge(edge)),
+            2 => {
+                if uses[0].start.is_some() && uses[0].start == uses[1].start {
+                    violations.push(Violation::InconsistentOrientation(edge));
+                }
+            }
+            n => violations.push(Violation::NonManifoldEdge(edge, n)),
+        }
+    }
+    violations
+}
+
+pub fn check_volume(boundary: &Shell, cavities: &[Shell]) -> Vec<Violation> {
+    let mut violations = check_shell(&boundary.faces);
+    for cavity in cavities {
+        violations.extend(check_shell(&cavity.faces));
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use geop_geometry::surfaces::plane::Plane;
+
+    use super::*;
+    use crate::primitive_objects::{edges::line::primitive_line, volumes::cube::primitive_cube};
+
+    #[test]
+    fn test_diagnostics() {
+        let cube = primitive_cube(1.0, 1.0, 1.0);
+        assert!(check_volume(&cube.boundary, &[]).is_empty());
+
+        let mut faces = cube.all_faces();
+        faces.pop();
+        assert_eq!(check_shell(&faces).len(), 4);
+        faces.push(cube.all_faces()[5].flip());
+        let violations = check_shell(&faces);
+        assert_eq!(violations.len(), 4);
+        assert!(matches!(
+            violations[0],
+            Violation::InconsistentOrientation(_)
+        ));
+
+        let p = |x: f64, y: f64, z: f64| Point::new(x, y, z);
+        let edges = vec![
+            primitive_line(p(0.0, 0.0, 0.0), p(1.0, 0.0, 0.0)),
+            primitive_line(p(1.0, 0.0, 0.0), p(1.0, 1.0, 1.0)),
+            primitive_line(p(1.0, 1.0, 0.0), p(0.0, 0.0, 0.0)),
+        ];
+        let violations = check_contour(&edges);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            Violation::ContourNotClosed(1, _, _)
+        ));
+        assert!(Contour::try_new(edges.clone()).is_err());
+
+        let plane = Surface::Plane(Plane::new(Point::zero(), Point::unit_x(), Point::unit_y()));
+        let violations = check_face(&Some(Contour { edges }), &[], &plane);
+        assert_eq!(violations.len(), 1);
+        match &violations[0] {
+            Violation::EdgeNotOnSurface(_, t, point) => {
+                assert_eq!(*t, 1.0);
+                assert_eq!(*point, p(1.0, 1.0, 1.0));
+            }
+            _ => panic!("Expected the edge to be off the surface"),
+        }
+        assert!(violations[0].to_string().contains("not on the surface"));
+        assert!(Face::try_new(None, vec![], Rc::new(plane)).is_ok());
+    }
+}
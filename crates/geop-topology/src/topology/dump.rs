@@ -0,0 +1,202 @@
+use std::fmt::Write;
+
+use geop_geometry::{curves::curve::Curve, points::point::Point, surfaces::surface::Surface};
+
+use super::{contour::Contour, edge::Edge, face::Face, shell::Shell, volume::Volume};
+
+// Human readable dumps of the topology, e.g. to inspect the result of a boolean operation.
+
+fn surface_description(surface: &Surface) -> String {
+    match surface {
+        Surface::Plane(p) => format!(
+            "Plane at {:?} with normal {:?}",
+            p.basis,
+            p.u_slope.cross(p.v_slope)
+        ),
+        Surface::Sphere(s) => format!(
+            "Sphere at {:?} with radius {:?}, normal outwards = {}",
+            s.basis, s.radius, s.normal_outwards
+        ),
+        Surface::Cylinder(c) => format!(
+            "Cylinder at {:?} with extend_dir {:?} and radius {:?}, normal outwards = {}",
+            c.basis, c.extend_dir, c.radius, c.normal_outwards
+        ),
+    }
+}
+
+fn curve_name(curve: &Curve) -> &'static str {
+    match curve {
+        Curve::Line(_) => "Line",
+        Curve::Circle(_) => "Circle",
+        Curve::Ellipse(_) => "Ellipse",
+        Curve::Helix(_) => "Helix",
+        Curve::Bezier(_) => "Bezier",
+        Curve::Composite(_) => "Composite",
+    }
+}
+
+fn surface_name(surface: &Surface) -> &'static str {
+    match surface {
+        Surface::Plane(_) => "Plane",
+        Surface::Sphere(_) => "Sphere",
+        Surface::Cylinder(_) => "Cylinder",
+    }
+}
+
+fn dump_contour(out: &mut String, name: &str, contour: &Contour, indent: usize) {
+    let pad = "  ".repeat(indent);
+    writeln!(out, "{}{} ({} edges)", pad, name, contour.edges.len()).unwrap();
+    for edge in contour.edges.iter() {
+        writeln!(out, "{}  Edge {}", pad, edge).unwrap();
+        for (name, vertex) in [("Start", edge.start), ("End", edge.end)] {
+            if let Some(vertex) = vertex {
+                writeln!(out, "{}    {} vertex {:?}", pad, name, vertex).unwrap();
+            }
+        }
+    }
+}
+
+fn dump_face_at(out: &mut String, index: usize, face: &Face, indent: usize) {
+    let pad = "  ".repeat(indent);
+    writeln!(
+        out,
+        "{}Face {}: {}",
+        pad,
+        index,
+        surface_description(&face.surface)
+    )
+    .unwrap();
+    if let Some(boundary) = &face.boundary {
+        dump_contour(out, "Boundary", boundary, indent + 1);
+    }
+    for hole in face.holes.iter() {
+        dump_contour(out, "Hole", hole, indent + 1);
+    }
+}
+
+fn dump_shell_at(out: &mut String, name: &str, shell: &Shell, indent: usize) {
+    let pad = "  ".repeat(indent);
+    writeln!(out, "{}{} ({} faces)", pad, name, shell.faces.len()).unwrap();
+    for (i, face) in shell.faces.iter().enumerate() {
+        dump_face_at(out, i, face, indent + 1);
+    }
+}
+
+pub fn dump_face(face: &Face) -> String {
+    let mut out = String::new();
+    dump_face_at(&mut out, 0, face, 0);
+    out
+}
+
+pub fn dump_shell(shell: &Shell) -> String {
+    let mut out = String::new();
+    dump_shell_at(&mut out, "Shell", shell, 0);
+    out
+}
+
+// Prints the whole tree of the volume, from the shells down to the faces, their contours, edges and vertices.
+pub fn dump_volume(volume: &Volume) -> String {
+    let mut out = String::new();
+    writeln!(out, "Volume").unwrap();
+    dump_shell_at(&mut out, "Boundary shell", &volume.boundary, 1);
+    for cavity in volume.cavities.iter() {
+        dump_shell_at(&mut out, "Cavity shell", cavity, 1);
+    }
+    out
+}
+
+// Exports the incidence graph of shells, faces, edges and vertices in the Graphviz DOT format. Edges and vertices shared by
+// several faces are a single node, so the graph shows how the faces are connected, e.g. with `dot -Tsvg topology.dot`.
+pub fn volume_to_dot(volume: &Volume) -> String {
+    let mut edges = Vec::<Edge>::new();
+    let mut vertices = Vec::<Point>::new();
+    let mut out = String::new();
+    writeln!(out, "graph topology {{").unwrap();
+    writeln!(out, "  node [shape=box];").unwrap();
+
+    let mut face_index = 0;
+    for (s, shell) in volume.all_shells().iter().enumerate() {
+        let name = match s {
+            0 => "Boundary shell",
+            _ => "Cavity shell",
+        };
+        writeln!(out, "  s{} [label=\"{} {}\", shape=folder];", s, name, s).unwrap();
+        for face in shell.faces.iter() {
+            writeln!(
+                out,
+                "  f{} [label=\"Face {}\\n{}\"];",
+                face_index,
+                face_index,
+                surface_name(&face.surface)
+            )
+            .unwrap();
+            writeln!(out, "  s{} -- f{};", s, face_index).unwrap();
+            for edge in face.all_edges() {
+                let e = match edges.iter().position(|other| *other == edge) {
+                    Some(e) => e,
+                    None => {
+                        edges.push(edge.clone());
+                        let e = edges.len() - 1;
+                        writeln!(
+                            out,
+                            "  e{} [label=\"Edge {}\\n{}\", shape=ellipse];",
+                            e,
+                            e,
+                            curve_name(&edge.curve)
+                        )
+                        .unwrap();
+                        for vertex in [edge.start, edge.end].into_iter().flatten() {
+                            let v = match vertices.iter().position(|other| *other == vertex) {
+                                Some(v) => v,
+                                None => {
+                                    vertices.push(vertex);
+                                    let v = vertices.len() - 1;
+                                    writeln!(
+                                        out,
+                                        "  v{} [label=\"({:.3}, {:.3}, {:.3})\", shape=plaintext];",
+                                        v, vertex.x, vertex.y, vertex.z
+                                    )
+                                    .unwrap();
+                                    v
+                                }
+                            };
+                            writeln!(out, "  e{} -- v{};", e, v).unwrap();
+                        }
+                        e
+                    }
+                };
+                writeln!(out, "  f{} -- e{};", face_index, e).unwrap();
+            }
+            face_index += 1;
+        }
+    }
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    #[test]
+    fn test_dump() {
+        let cube = primitive_cube(1.0, 1.0, 1.0);
+        let dump = dump_volume(&cube);
+        assert!(dump.starts_with("Volume\n  Boundary shell (6 faces)\n"));
+        assert_eq!(dump.matches("Face ").count(), 6);
+        assert_eq!(dump.matches("Start vertex").count(), 24);
+
+        let dot = volume_to_dot(&cube);
+        let count = |prefix: &str| {
+            dot.lines()
+                .filter(|l| l.starts_with(prefix) && l.contains('['))
+                .count()
+        };
+        assert_eq!(count("  f"), 6);
+        assert_eq!(count("  e"), 12);
+        assert_eq!(count("  v"), 8);
+        assert_eq!(dot.matches(" -- e").count(), 24);
+        assert!(dot.ends_with("}\n"));
+    }
+}
@@ -0,0 +1,106 @@
+use geop_geometry::{
+    curves::{circle::Circle, curve::Curve},
+    points::point::Point,
+    EQ_THRESHOLD,
+};
+
+use crate::primitive_objects::edges::{arc::ArcSide, line::primitive_line};
+
+use super::Edge;
+
+// Arcs given by their end points, like in sketches and DXF files, instead of by the circle they lie on. The normal is the one of the
+// sketch plane, and the arcs run counterclockwise around it, like circles do.
+impl Edge {
+    // The arc with the radius from start counterclockwise to end, the shorter or the longer way around. The radius has to be at least
+    // half the distance between the points, where both ways are half circles.
+    pub fn arc(start: Point, end: Point, normal: Point, radius: f64, side: ArcSide) -> Edge {
+        let normal = normal.normalize();
+        let chord = end - start;
+        assert!(
+            chord.dot(normal).abs() < EQ_THRESHOLD,
+            "End points are not in a plane with the normal"
+        );
+        let half = chord.norm() / 2.0;
+        assert!(half > EQ_THRESHOLD, "End points are equal");
+        assert!(
+            radius > half - EQ_THRESHOLD,
+            "Radius is too small to reach from start to end"
+        );
+
+        // The center is on the left of the chord for the shorter way around, and on the right for the longer one.
+        let h = (radius * radius - half * half).max(0.0).sqrt();
+        let left = normal.cross(chord).normalize();
+        let center = match side {
+            ArcSide::Minor => (start + end) / 2.0 + left * h,
+            ArcSide::Major => (start + end) / 2.0 - left * h,
+        };
+        let radius = (start - center).norm();
+        Edge::new(
+            Some(start),
+            Some(end),
+            Curve::Circle(Circle::new(center, normal, radius)),
+        )
+    }
+
+    // The arc with the bulge of DXF polylines, which is the tangent of a quarter of the angle the arc spans. A positive bulge runs
+    // counterclockwise around the normal, a negative one clockwise, and a bulge of 0 is a line. The absolute value is 1 for half
+    // circles, and above 1 for the longer way around.
+    pub fn arc_bulge(start: Point, end: Point, normal: Point, bulge: f64) -> Edge {
+        if bulge.abs() < EQ_THRESHOLD {
+            return primitive_line(start, end);
+        }
+        let radius = (end - start).norm() * (1.0 + bulge * bulge) / (4.0 * bulge.abs());
+        let side = match bulge.abs() <= 1.0 {
+            true => ArcSide::Minor,
+            false => ArcSide::Major,
+        };
+        let normal = match bulge > 0.0 {
+            true => normal,
+            false => -normal,
+        };
+        Edge::arc(start, end, normal, radius, side)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use geop_geometry::curves::CurveLike;
+
+    use super::*;
+
+    #[test]
+    fn test_arcs() {
+        let (a, b) = (Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+        let quarter = Edge::arc(a, b, Point::unit_z(), 1.0, ArcSide::Minor);
+        assert!((quarter.length().unwrap() - PI / 2.0).abs() < 1e-9);
+        assert!(quarter.get_midpoint().x > 0.0);
+        let three_quarters = Edge::arc(a, b, Point::unit_z(), 1.0, ArcSide::Major);
+        assert!((three_quarters.length().unwrap() - 3.0 * PI / 2.0).abs() < 1e-9);
+        assert_eq!(
+            three_quarters.get_midpoint(),
+            Point::new(1.0, 1.0, 0.0) * (1.0 + 0.5_f64.sqrt())
+        );
+
+        // A half circle from the bulge 1, which bulges to the right of the chord when it runs counterclockwise.
+        let (a, b) = (Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0));
+        let half = Edge::arc_bulge(a, b, Point::unit_z(), 1.0);
+        assert!((half.length().unwrap() - PI).abs() < 1e-9);
+        assert_eq!(half.get_midpoint(), Point::new(0.0, -1.0, 0.0));
+        let half = Edge::arc_bulge(a, b, Point::unit_z(), -1.0);
+        assert_eq!(half.get_midpoint(), Point::new(0.0, 1.0, 0.0));
+
+        // The bulge is the tangent of a quarter of the spanned angle.
+        let third = Edge::arc_bulge(a, b, Point::unit_z(), (PI / 6.0).tan());
+        let Curve::Circle(circle) = &third.curve else {
+            panic!("Expected a circle");
+        };
+        assert!((circle.radius.norm() - 2.0 / 3.0_f64.sqrt()).abs() < 1e-9);
+        assert!(circle.on_curve(a) && circle.on_curve(b));
+        assert!(matches!(
+            Edge::arc_bulge(a, b, Point::unit_z(), 0.0).curve,
+            Curve::Line(_)
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+use geop_geometry::{points::point::Point, transforms::frame::Frame, EQ_THRESHOLD};
+
+use super::Edge;
+
+// Step in the edge parameter between the points that are used to estimate the normal.
+const FRAME_STEP: f64 = 1e-3;
+
+// Frames along an edge, with x along the tangent. They place profiles for sweeps and copies for patterns along paths.
+impl Edge {
+    // The Frenet frame at t, which goes from 0 at the start to 1 at the end of the edge. x is the tangent, y the normal towards the center of
+    // curvature and z the binormal. The normal is undefined where the edge is straight, so there is no frame there.
+    pub fn frenet_frame(&self, t: f64) -> Option<Frame> {
+        let point = self.interpolate(t);
+        let tangent = self.tangent(point);
+        let s = t.clamp(FRAME_STEP, 1.0 - FRAME_STEP);
+        let (a, b, c) = (
+            self.interpolate(s - FRAME_STEP),
+            self.interpolate(s),
+            self.interpolate(s + FRAME_STEP),
+        );
+        if (b - a).norm() < EQ_THRESHOLD || (c - b).norm() < EQ_THRESHOLD {
+            return None;
+        }
+        // The direction of the chords turns towards the center of curvature.
+        let turn = (c - b).normalize() - (b - a).normalize();
+        if 2.0 * turn.norm() / (c - a).norm() < EQ_THRESHOLD {
+            return None;
+        }
+        let normal = turn / turn.norm();
+        Some(Frame::new(point, tangent, normal))
+    }
+
+    // Rotation minimizing frames at count evenly spaced parameters from the start to the end of the edge. Unlike Frenet frames, they
+    // are defined along straight parts and do not flip at inflections, since every frame turns as little as possible around the tangent
+    // relative to the previous one. The first frame is the Frenet frame, or has an arbitrary normal if the edge starts straight.
+    pub fn rmf_frames(&self, count: usize) -> Vec<Frame> {
+        assert!(count >= 2);
+        let ts: Vec<f64> = (0..count).map(|i| i as f64 / (count - 1) as f64).collect();
+        self.rmf_frames_at(&ts, None)
+    }
+
+    // Rotation minimizing frames at increasing parameters, starting with the given normal, e.g. to continue the frames of the previous
+    // edge of a wire. The frames are only accurate if the parameters are close enough to follow the turns of the edge.
+    pub fn rmf_frames_at(&self, ts: &[f64], normal: Option<Point>) -> Vec<Frame> {
+        assert!(!ts.is_empty());
+        let points: Vec<Point> = ts.iter().map(|t| self.interpolate(*t)).collect();
+        let tangents: Vec<Point> = points.iter().map(|p| self.tangent(*p)).collect();
+        let first = match (normal, self.frenet_frame(ts[0])) {
+            (Some(normal), _) => normal,
+            (None, Some(frame)) => frame.y_dir,
+            (None, None) => match tangents[0].cross(Point::unit_x()).norm() > 0.1 {
+                true => tangents[0].cross(Point::unit_x()),
+                false => tangents[0].cross(Point::unit_y()),
+            },
+        };
+
+        // The double reflection method of Wang et al.: reflect the previous frame in the bisecting plane of the two points, then in the
+        // plane that maps the reflected tangent onto the next tangent.
+        let mut normals = vec![first];
+        for i in 0..ts.len() - 1 {
+            normals.push(double_reflection(
+                normals[i],
+                tangents[i],
+                points[i + 1] - points[i],
+                tangents[i + 1],
+            ));
+        }
+        points
+            .iter()
+            .zip(tangents.iter().zip(normals.iter()))
+            .map(|(p, (t, n))| Frame::new(*p, *t, *n))
+            .collect()
+    }
+}
+
+// Carries the normal of a frame with the given tangent along the step to the next tangent.
+pub(crate) fn double_reflection(
+    normal: Point,
+    tangent: Point,
+    step: Point,
+    next_tangent: Point,
+) -> Point {
+    let reflect = |v: Point, n: Point| v - n * (2.0 * v.dot(n) / n.norm_sq());
+    let (mut normal, mut tangent) = (normal, tangent);
+    if step.norm() > EQ_THRESHOLD {
+        normal = reflect(normal, step);
+        tangent = reflect(tangent, step);
+    }
+    let turn = next_tangent - tangent;
+    if turn.norm() > EQ_THRESHOLD {
+        normal = reflect(normal, turn);
+    }
+    normal
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::edges::{arc::primitive_arc, line::primitive_line};
+
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        // The normal of an arc points towards its center.
+        let arc = primitive_arc(
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+            2.0,
+            Point::unit_z(),
+        );
+        let center = Point::new(0.0, -3.0_f64.sqrt(), 0.0);
+        for t in [0.0, 0.3, 1.0] {
+            let frame = arc.frenet_frame(t).unwrap();
+            assert!((frame.origin + frame.y_dir * 2.0 - center).norm() < 1e-6);
+            assert!((frame.z_dir - Point::unit_z()).norm() < 1e-6);
+        }
+        // For a planar curve, the rotation minimizing frames are the Frenet frames.
+        for (i, frame) in arc.rmf_frames(9).iter().enumerate() {
+            let frenet = arc.frenet_frame(i as f64 / 8.0).unwrap();
+            assert!((frame.y_dir - frenet.y_dir).norm() < 1e-6);
+        }
+
+        // A line has no Frenet frames, and its rotation minimizing frames do not turn.
+        let line = primitive_line(Point::zero(), Point::new(1.0, 2.0, 3.0));
+        assert!(line.frenet_frame(0.5).is_none());
+        let frames = line.rmf_frames(4);
+        assert_eq!(frames.len(), 4);
+        assert!(frames
+            .iter()
+            .all(|f| (f.y_dir - frames[0].y_dir).norm() < 1e-9));
+        assert!(frames[0].x_dir.dot(frames[0].y_dir).abs() < 1e-9);
+    }
+}
@@ -1,18 +1,25 @@
 use std::fmt::{Display, Formatter};
 
 use geop_geometry::{
-    curves::{curve::Curve, CurveLike},
+    curves::{curve::Curve, ClosestPoint, CurveLike},
     points::point::Point,
     transforms::Transform,
 };
 
 use crate::contains::edge_point::{edge_point_contains, EdgePointContains};
 
+use super::attributes::Attributes;
+
+pub mod arcs;
+pub mod frames;
+pub mod split;
+
 #[derive(Clone, Debug)]
 pub struct Edge {
     pub start: Option<Point>,
     pub end: Option<Point>,
     pub curve: Curve,
+    pub attributes: Attributes,
 }
 // Represents an Edge, defined by a curve, and a start and end point.
 // It is important to know that the start and end point are not considered a part of the edge.
@@ -21,10 +28,14 @@ impl Edge {
     pub fn new(start: Option<Point>, end: Option<Point>, curve: Curve) -> Edge {
         assert!(start != end || start.is_none());
         if let Some(start) = start {
-            assert!(curve.on_curve(start));
+            assert!(
+                curve.on_curve(start),
+                "Start {:?} is not on the curve",
+                start
+            );
         }
         if let Some(end) = end {
-            assert!(curve.on_curve(end));
+            assert!(curve.on_curve(end), "End {:?} is not on the curve", end);
         }
         match start {
             Some(start) => match end {
@@ -36,11 +47,13 @@ impl Edge {
                             start: None,
                             end: None,
                             curve,
+                            attributes: Attributes::new(),
                         },
                         false => Edge {
                             start: Some(start),
                             end: Some(end),
                             curve,
+                            attributes: Attributes::new(),
                         },
                     }
                 }
@@ -48,6 +61,7 @@ impl Edge {
                     start: Some(start),
                     end: None,
                     curve,
+                    attributes: Attributes::new(),
                 },
             },
             None => match end {
@@ -55,30 +69,41 @@ impl Edge {
                     start: None,
                     end: Some(end),
                     curve,
+                    attributes: Attributes::new(),
                 },
                 None => Edge {
                     start: None,
                     end: None,
                     curve,
+                    attributes: Attributes::new(),
                 },
             },
         }
     }
 
     pub fn neg(&self) -> Edge {
-        Edge::new(self.end.clone(), self.start.clone(), self.curve.clone())
+        Edge {
+            attributes: self.attributes.clone(),
+            ..Edge::new(self.end.clone(), self.start.clone(), self.curve.clone())
+        }
     }
 
     pub fn flip(&self) -> Edge {
-        Edge::new(self.end.clone(), self.start.clone(), self.curve.neg())
+        Edge {
+            attributes: self.attributes.clone(),
+            ..Edge::new(self.end.clone(), self.start.clone(), self.curve.neg())
+        }
     }
 
     pub fn transform(&self, transform: Transform) -> Edge {
-        Edge::new(
-            transform * self.start,
-            transform * self.end,
-            self.curve.transform(transform),
-        )
+        Edge {
+            attributes: self.attributes.clone(),
+            ..Edge::new(
+                transform * self.start,
+                transform * self.end,
+                self.curve.transform(transform),
+            )
+        }
     }
 
     pub fn get_midpoint(&self) -> Point {
@@ -95,6 +120,35 @@ impl Edge {
         self.curve.interpolate(self.start, self.end, t)
     }
 
+    // Finds the closest point on the edge. If the foot of the perpendicular is outside of the edge, the closer end point is returned.
+    pub fn project(&self, p: Point) -> ClosestPoint {
+        // Points on the axis of a circle are equally far away from every point of the circle.
+        if let Curve::Circle(circle) = &self.curve {
+            if (p - circle.basis).is_parallel(circle.normal) {
+                let point = match (self.start, self.end) {
+                    (Some(start), _) => start,
+                    (None, Some(end)) => end,
+                    (None, None) => circle.basis + circle.radius,
+                };
+                return ClosestPoint::new(circle.angle(point), point, p);
+            }
+        }
+
+        let projected = self.curve.project(p);
+        if edge_point_contains(self, projected.point) != EdgePointContains::Outside {
+            return projected;
+        }
+        let end_point = [self.start, self.end]
+            .into_iter()
+            .flatten()
+            .min_by(|a, b| (*a - p).norm().total_cmp(&(*b - p).norm()))
+            .expect("Projected point has to be inside of an edge without end points");
+        ClosestPoint {
+            inside_bounds: false,
+            ..ClosestPoint::new(self.curve.project(end_point).parameter, end_point, p)
+        }
+    }
+
     pub fn length(&self) -> Option<f64> {
         match (self.start, self.end) {
             (Some(start), Some(end)) => Some(self.curve.distance(start, end)),
@@ -105,8 +159,9 @@ impl Edge {
 
 impl PartialEq for Edge {
     fn eq(&self, other: &Edge) -> bool {
-        if self.start == other.start && self.end == other.end {
-            return self.curve == other.curve;
+        // Closed edges have no start and end, so they can match in both directions.
+        if self.start == other.start && self.end == other.end && self.curve == other.curve {
+            return true;
         }
         if self.start == other.end && self.end == other.start {
             return self.curve == other.curve.neg();
@@ -126,6 +181,59 @@ impl Display for Edge {
             ),
             Curve::Ellipse(_) => write!(f, "Ellipse {:?} - {:?}", self.start, self.end),
             Curve::Helix(_) => write!(f, "Helix {:?} - {:?}", self.start, self.end),
+            Curve::Bezier(_) => write!(f, "Bezier {:?} - {:?}", self.start, self.end),
+            Curve::Composite(composite) => write!(
+                f,
+                "Composite ({} segments) {:?} - {:?}",
+                composite.segments.len(),
+                self.start,
+                self.end
+            ),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::edges::{arc::ArcSide, line::primitive_line};
+
+    use super::*;
+
+    #[test]
+    fn test_edge_project() {
+        // The foot of the perpendicular inside of the edge, and the end point when it is outside.
+        let line = primitive_line(Point::zero(), Point::unit_x() * 2.0);
+        let closest = line.project(Point::new(1.0, 1.0, 0.0));
+        assert_eq!(closest.point, Point::unit_x());
+        assert!((closest.distance - 1.0).abs() < 1e-9);
+        assert!(closest.inside_bounds);
+        let closest = line.project(Point::new(3.0, 1.0, 0.0));
+        assert_eq!(closest.point, Point::unit_x() * 2.0);
+        assert!((closest.distance - 2.0_f64.sqrt()).abs() < 1e-9);
+        assert!(!closest.inside_bounds, "The end point is a bound");
+        assert_eq!(
+            closest.parameter,
+            line.curve.project(closest.point).parameter
+        );
+
+        // Points on the axis of an arc are equally far away from all of it, so they project onto its start.
+        let arc = Edge::arc(
+            Point::unit_x(),
+            Point::unit_y(),
+            Point::unit_z(),
+            1.0,
+            ArcSide::Minor,
+        );
+        let closest = arc.project(Point::unit_z() * 5.0);
+        assert_eq!(closest.point, Point::unit_x());
+        assert!((closest.distance - 26.0_f64.sqrt()).abs() < 1e-9);
+
+        // Behind the arc, the closer end point is clamped to.
+        let closest = arc.project(Point::new(-1.0, -0.1, 0.0));
+        assert_eq!(closest.point, Point::unit_y());
+        assert!(!closest.inside_bounds);
+        let closest = arc.project(Point::new(2.0, 2.0, 0.0));
+        assert_eq!(closest.point, Point::new(1.0, 1.0, 0.0).normalize());
+        assert!(closest.inside_bounds);
+    }
+}
@@ -0,0 +1,95 @@
+use geop_geometry::{curves::CurveLike, points::point::Point};
+
+use crate::contains::edge_point::{edge_point_contains, EdgePointContains};
+
+use super::Edge;
+
+// Splitting an edge into consecutive pieces on the same curve. Neighboring pieces share the very same split point, so that later
+// comparisons of their end points do not depend on rounding. The pieces are new edges without the attributes of the edge.
+impl Edge {
+    // Splits the edge at the points inside of it, which may come in any order. Points that are not inside the edge, like its end
+    // points, are ignored. A closed edge runs from the first point around to the same point again, so it needs two points to be
+    // split.
+    pub fn split_at_points(&self, points: &[Point]) -> Vec<Edge> {
+        let mut inside = Vec::<Point>::new();
+        for p in points {
+            if edge_point_contains(self, *p) == EdgePointContains::Inside && !inside.contains(p) {
+                inside.push(*p);
+            }
+        }
+        let anchor = match self.start {
+            Some(start) => start,
+            None => match inside.first() {
+                Some(first) => *first,
+                None => return vec![self.clone()],
+            },
+        };
+        inside.sort_by(|a, b| {
+            self.curve
+                .distance(anchor, *a)
+                .total_cmp(&self.curve.distance(anchor, *b))
+        });
+
+        let mut corners: Vec<Option<Point>> = Vec::new();
+        match self.start {
+            Some(_) => {
+                corners.push(self.start);
+                corners.extend(inside.into_iter().map(Some));
+                corners.push(self.end);
+            }
+            None => {
+                if inside.len() < 2 {
+                    return vec![self.clone()];
+                }
+                corners.extend(inside.into_iter().map(Some));
+                corners.push(Some(anchor));
+            }
+        }
+        corners
+            .windows(2)
+            .map(|w| Edge::new(w[0], w[1], self.curve.clone()))
+            .collect()
+    }
+
+    // Splits the edge at the relative positions between 0 at the start and 1 at the end, as for interpolate.
+    pub fn split_at_params(&self, ts: &[f64]) -> Vec<Edge> {
+        let points: Vec<Point> = ts.iter().map(|t| self.interpolate(*t)).collect();
+        self.split_at_points(&points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        primitive_objects::edges::{circle::primitive_circle, line::primitive_line},
+        topology::contour::Contour,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_split_at_points() {
+        let line = primitive_line(Point::zero(), Point::unit_x() * 4.0);
+        let pieces = line.split_at_params(&[0.75, 0.25, 0.0, 0.5, 0.5]);
+        assert_eq!(pieces.len(), 4);
+        for (i, piece) in pieces.iter().enumerate() {
+            assert_eq!(piece.start, Some(Point::unit_x() * i as f64));
+            assert_eq!(piece.end, Some(Point::unit_x() * (i + 1) as f64));
+        }
+        assert_eq!(line.split_at_points(&[Point::unit_y()]), vec![line.clone()]);
+
+        // A closed circle needs two points, and its pieces cover it once.
+        let circle = primitive_circle(Point::zero(), Point::unit_z(), 1.0);
+        assert_eq!(circle.split_at_points(&[Point::unit_x()]).len(), 1);
+        let pieces = circle.split_at_points(&[Point::unit_x(), -Point::unit_x(), Point::unit_y()]);
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(pieces[0].start, Some(Point::unit_x()));
+        assert_eq!(pieces[0].end, Some(Point::unit_y()));
+        assert_eq!(pieces[2].end, Some(Point::unit_x()));
+
+        let contour = Contour::new(vec![circle]);
+        let split = contour.split_at_points(&[Point::unit_y(), -Point::unit_y()]);
+        assert_eq!(split.edges.len(), 2);
+        assert_eq!(split.all_points(), vec![Point::unit_y(), -Point::unit_y()]);
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    cell::OnceCell,
+    f64::consts::PI,
+    fmt::{Debug, Formatter},
+};
+
+use geop_geometry::{
+    normal_cone::NormalCone,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+};
+
+use crate::{
+    contains::face_point::{face_point_contains, FacePointContains},
+    topology::bounded::{Bounded, Bounds},
+};
+
+use super::Face;
+
+// Density of the surface point grid used to sample the inside of curved faces.
+const GRID_DENSITY: f64 = 2.0;
+
+// Bounds and normal cone of a face. They are computed once, so that queries like silhouette detection, draft analysis
+// or finding parallel faces can reject most faces without touching their geometry.
+#[derive(Debug, Clone)]
+pub struct FaceBounds {
+    pub bounds: Bounds,
+    pub normal_cone: NormalCone,
+}
+
+// Points on the edges of the face and, for curved faces, inside of it, which approximate the extent of the face.
+pub fn face_sample_points(face: &Face) -> Vec<Point> {
+    let mut points: Vec<Point> = face
+        .all_edges()
+        .iter()
+        .flat_map(|e| e.bound_points())
+        .collect();
+    if !matches!(&*face.surface, Surface::Plane(_)) {
+        points.extend(
+            face.surface
+                .grid(GRID_DENSITY)
+                .points
+                .iter()
+                .cloned()
+                .filter(|p| face_point_contains(face, *p) == FacePointContains::Inside),
+        );
+    }
+    points
+}
+
+impl FaceBounds {
+    // For curved faces both bounds are computed from samples on the edges and inside of the face. The normal cone is widened by the
+    // angular spacing of the samples, so that it also contains the normals between them.
+    pub fn new(face: &Face) -> FaceBounds {
+        let points = face_sample_points(face);
+        let normal_cone = match &*face.surface {
+            Surface::Plane(plane) => NormalCone::new(face.surface.normal(plane.basis), 0.0),
+            Surface::Sphere(_) if face.boundary.is_none() => NormalCone::full(),
+            Surface::Cylinder(cylinder) if face.boundary.is_none() => {
+                NormalCone::new(cylinder.extend_dir, PI / 2.0)
+            }
+            Surface::Sphere(_) | Surface::Cylinder(_) => {
+                let normals: Vec<Point> = points.iter().map(|p| face.surface.normal(*p)).collect();
+                let cone = NormalCone::from_normals(&normals);
+                let spacing = 2.0 * PI / (16.0 * GRID_DENSITY);
+                NormalCone::new(cone.axis, cone.half_angle + spacing)
+            }
+        };
+        assert!(!points.is_empty(), "Face has no samples");
+        FaceBounds {
+            bounds: Bounds::from_points(&points, true),
+            normal_cone,
+        }
+    }
+}
+
+// The bounds of a face, computed the first time they are asked for. Copies of a face get their own copy of the cache, and faces built
+// from new contours start with an empty cache. Like the grid cache of a surface, the contours of a face must not be changed after its
+// bounds were computed, or the cache has to be cleared.
+#[derive(Clone, Default)]
+pub struct FaceBoundsCache {
+    bounds: OnceCell<FaceBounds>,
+}
+
+impl FaceBoundsCache {
+    pub fn new() -> FaceBoundsCache {
+        FaceBoundsCache::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bounds.get().is_none()
+    }
+
+    pub fn clear(&mut self) {
+        self.bounds.take();
+    }
+}
+
+impl Debug for FaceBoundsCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.is_empty() {
+            true => write!(f, "FaceBoundsCache(empty)"),
+            false => write!(f, "FaceBoundsCache(computed)"),
+        }
+    }
+}
+
+impl Face {
+    // The bounds and normal cone of the face, computed once and cached with the face.
+    pub fn bounds(&self) -> &FaceBounds {
+        self.bounds_cache
+            .bounds
+            .get_or_init(|| FaceBounds::new(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+    use geop_geometry::transforms::Transform;
+
+    #[test]
+    fn test_face_bounds() {
+        let cube = primitive_cube(1.0, 2.0, 3.0);
+        for face in cube.all_faces() {
+            let bounds = FaceBounds::new(&face);
+            assert_eq!(bounds.normal_cone.half_angle, 0.0);
+            assert!(bounds.normal_cone.contains(face.normal(face.inner_point())));
+            assert!(bounds.bounds.bounding_box.max_size() <= 3.0 + 1e-9);
+            // A planar face has a silhouette only when viewed edge on.
+            let normal = bounds.normal_cone.axis;
+            assert!(!bounds.normal_cone.may_be_perpendicular_to(normal));
+        }
+
+        // The bounds are computed once and travel with copies of the face, but not with transformed faces.
+        let face = cube.all_faces().remove(0);
+        assert!(face.bounds_cache.is_empty());
+        let axis = face.bounds().normal_cone.axis;
+        assert!(
+            !face.bounds_cache.is_empty(),
+            "Expected the bounds to be cached"
+        );
+        let copy = face.clone();
+        assert!(
+            !copy.bounds_cache.is_empty(),
+            "Expected copies to keep the bounds"
+        );
+        assert_eq!(copy.bounds().normal_cone.axis, axis);
+        let moved = face.transform(Transform::from_translation(Point::new(5.0, 0.0, 0.0)));
+        assert!(
+            moved.bounds_cache.is_empty(),
+            "Expected transformed faces to start empty"
+        );
+        assert!(moved.bounds().bounds.bounding_box.min.x > 4.0);
+        let mut cleared = copy;
+        cleared.bounds_cache.clear();
+        assert!(cleared.bounds_cache.is_empty());
+    }
+}
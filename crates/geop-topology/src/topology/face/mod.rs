@@ -1,7 +1,6 @@
 use std::rc::Rc;
 
 use geop_geometry::{
-    curve_surface_intersection::curve_surface::curve_surface_intersection,
     points::point::Point,
     surfaces::{surface::Surface, SurfaceLike},
     transforms::Transform,
@@ -13,16 +12,27 @@ use crate::contains::{
     face_point::{face_point_contains, FacePointContains},
 };
 
+use bounds::FaceBoundsCache;
+
 use super::{
+    attributes::Attributes,
     contour::ContourTangent,
+    diagnostics::{check_face, describe, Violation},
     {contour::Contour, edge::Edge},
 };
 
+pub mod bounds;
+pub mod planar;
+pub mod ruled;
+pub mod spherical_polygon;
+
 #[derive(Clone, Debug)]
 pub struct Face {
     pub boundary: Option<Contour>, // Coutner-clockwise
     pub holes: Vec<Contour>,       // Clockwise
     pub surface: Rc<Surface>,
+    pub attributes: Attributes,
+    pub bounds_cache: FaceBoundsCache,
 }
 
 // Implements a Face. A Face is bounded by the outer_loop and might have holes in inner_loops.
@@ -31,35 +41,55 @@ pub struct Face {
 // The contours are not allowed to intersect in any way. Keep in mind that a point is not considered an intersection, hence it is allowed that the contours touch each other at points.
 impl Face {
     pub fn new(boundary: Option<Contour>, holes: Vec<Contour>, surface: Rc<Surface>) -> Face {
-        if let Some(boundary) = &boundary {
-            for edge in boundary.edges.iter() {
-                assert!(curve_surface_intersection(&edge.curve, &*surface).is_curve());
-            }
-        }
-        for contour in holes.iter() {
-            for edge in contour.edges.iter() {
-                assert!(curve_surface_intersection(&edge.curve, &*surface).is_curve());
-            }
-        }
+        let violations = check_face(&boundary, &holes, &surface);
+        assert!(
+            violations.is_empty(),
+            "Invalid face:\n{}",
+            describe(&violations)
+        );
         Face {
             boundary,
             holes,
             surface,
+            attributes: Attributes::new(),
+            bounds_cache: FaceBoundsCache::new(),
+        }
+    }
+
+    // Like new, but returns the reasons why the contours do not form a face instead of panicking.
+    pub fn try_new(
+        boundary: Option<Contour>,
+        holes: Vec<Contour>,
+        surface: Rc<Surface>,
+    ) -> Result<Face, Vec<Violation>> {
+        let violations = check_face(&boundary, &holes, &surface);
+        match violations.is_empty() {
+            true => Ok(Face {
+                boundary,
+                holes,
+                surface,
+                attributes: Attributes::new(),
+                bounds_cache: FaceBoundsCache::new(),
+            }),
+            false => Err(violations),
         }
     }
 
     pub fn transform(&self, transform: Transform) -> Face {
-        Face::new(
-            match &self.boundary {
-                Some(boundary) => Some(boundary.transform(transform)),
-                None => None,
-            },
-            self.holes
-                .iter()
-                .map(|contour| contour.transform(transform))
-                .collect(),
-            Rc::new(self.surface.transform(transform)),
-        )
+        Face {
+            attributes: self.attributes.clone(),
+            ..Face::new(
+                match &self.boundary {
+                    Some(boundary) => Some(boundary.transform(transform)),
+                    None => None,
+                },
+                self.holes
+                    .iter()
+                    .map(|contour| contour.transform(transform))
+                    .collect(),
+                Rc::new(self.surface.transform(transform)),
+            )
+        }
     }
 
     pub fn all_points(&self) -> Vec<Point> {
@@ -90,8 +120,29 @@ impl Face {
         return edges;
     }
 
+    // Returns an inner point where normal vector is well defined.
+    // It is found by stepping from the midpoint of a boundary edge towards the inside of the face, halving the step until the point is inside.
     pub fn inner_point(&self) -> Point {
-        todo!("Returns an inner point where normal vector is well defined.");
+        let q = match self.get_boundary_point() {
+            Some(q) => q,
+            None => return self.surface.point_grid(1.0)[0],
+        };
+        let tangent = *self.boundary_tangent(q).expect_on_edge();
+        let inwards = self.surface.normal(q).cross(tangent).normalize();
+
+        let edge = match &self.boundary {
+            Some(boundary) => &boundary.edges[0],
+            None => &self.holes[0].edges[0],
+        };
+        let mut step = edge.length().unwrap_or(1.0) / 4.0;
+        for _ in 0..64 {
+            let p = self.surface.project(q + inwards * step);
+            if face_point_contains(self, p) == FacePointContains::Inside {
+                return p;
+            }
+            step /= 2.0;
+        }
+        panic!("Could not find an inner point of the face");
     }
 
     pub fn edge_from_to(&self, from: Point, to: Point) -> Edge {
@@ -143,6 +194,8 @@ impl Face {
             },
             holes: self.holes.iter().rev().map(|l| l.flip()).collect(),
             surface: self.surface.clone(),
+            attributes: self.attributes.clone(),
+            bounds_cache: FaceBoundsCache::new(),
         }
     }
 
@@ -154,6 +207,8 @@ impl Face {
             },
             holes: self.holes.iter().map(|l| l.flip()).collect(),
             surface: Rc::new(self.surface.neg()),
+            attributes: self.attributes.clone(),
+            bounds_cache: FaceBoundsCache::new(),
         }
     }
 }
@@ -182,8 +237,24 @@ impl std::fmt::Display for Face {
                     }
                 }
             }
-            Surface::Sphere(_s) => {
-                writeln!(f, "sphere is still todo")?;
+            Surface::Sphere(s) => {
+                writeln!(
+                    f,
+                    "Sphere at basis = {:?} with radius = {:?} and normal direction = {:?}",
+                    s.basis, s.radius, s.normal_outwards
+                )?;
+                if let Some(boundary) = &self.boundary {
+                    writeln!(f, "Boundary:")?;
+                    for edge in boundary.edges.iter() {
+                        writeln!(f, "  {}", edge)?;
+                    }
+                }
+                for contour in self.holes.iter() {
+                    writeln!(f, "Hole:")?;
+                    for edge in contour.edges.iter() {
+                        writeln!(f, "  {}", edge)?;
+                    }
+                }
             }
             Surface::Cylinder(c) => {
                 writeln!(f, "Cylinder at bases = {:?} with extend_dir = {:?}, radius = {:?} and normal direction = {:?}", c.basis, c.extend_dir, c.radius, c.normal_outwards)?;
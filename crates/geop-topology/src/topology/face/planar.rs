@@ -0,0 +1,133 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{plane::Plane, surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    primitive_objects::edges::{circle::primitive_circle, line::primitive_line},
+    topology::contour::Contour,
+};
+
+use super::Face;
+
+// Faces on planes, without assembling the edges and contours by hand. The boundaries run counterclockwise around the normal of the
+// plane, whatever order the corners are given in, and holes are cut out of them with with_hole.
+impl Face {
+    // The polygon whose corners are connected by lines, in the given order.
+    pub fn polygon(plane: &Plane, points: &[Point]) -> Face {
+        assert!(points.len() >= 3, "A polygon needs three corners");
+        assert!(
+            points.iter().all(|p| plane.on_surface(*p)),
+            "Corners are not on the plane"
+        );
+        let normal = plane.normal(plane.basis);
+        let n = points.len();
+        let area: f64 = (0..n)
+            .map(|i| normal.dot((points[i] - points[0]).cross(points[(i + 1) % n] - points[0])))
+            .sum();
+        assert!(area.abs() > EQ_THRESHOLD, "Corners enclose no area");
+        let mut corners = points.to_vec();
+        if area < 0.0 {
+            corners.reverse();
+        }
+        let edges = (0..n)
+            .map(|i| primitive_line(corners[i], corners[(i + 1) % n]))
+            .collect();
+        Face::new(
+            Some(Contour::new(edges)),
+            vec![],
+            Rc::new(Surface::Plane(plane.clone())),
+        )
+    }
+
+    // The rectangle around the basis of the plane, with the width along u_slope and the height along v_slope.
+    pub fn rectangle(plane: &Plane, width: f64, height: f64) -> Face {
+        assert!(
+            width > EQ_THRESHOLD && height > EQ_THRESHOLD,
+            "Rectangle has no area"
+        );
+        let u = plane.u_slope * (width / 2.0);
+        let v = plane.v_slope * (height / 2.0);
+        let b = plane.basis;
+        Face::polygon(plane, &[b - u - v, b + u - v, b + u + v, b - u + v])
+    }
+
+    pub fn disk(plane: &Plane, center: Point, radius: f64) -> Face {
+        assert!(radius > EQ_THRESHOLD, "Disk has no area");
+        assert!(plane.on_surface(center), "Center is not on the plane");
+        let circle = primitive_circle(center, plane.normal(center), radius);
+        Face::new(
+            Some(Contour::new(vec![circle])),
+            vec![],
+            Rc::new(Surface::Plane(plane.clone())),
+        )
+    }
+
+    // Cuts the other face out of this one. The other face is usually built by one of the constructors above on the same plane, and
+    // its boundary becomes a hole that runs the other way around.
+    pub fn with_hole(self, hole: Face) -> Face {
+        let boundary = hole.boundary.expect("Hole has no boundary");
+        let mut holes = self.holes;
+        holes.push(boundary.flip());
+        let face = Face::new(self.boundary, holes, self.surface);
+        Face {
+            attributes: self.attributes,
+            ..face
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contains::face_point::{face_point_contains, FacePointContains};
+
+    use super::*;
+
+    #[test]
+    fn test_planar_faces() {
+        let plane = Plane::new(Point::unit_z(), Point::unit_x(), Point::unit_y());
+        let p = |x: f64, y: f64| Point::new(x, y, 1.0);
+
+        // The order of the corners does not matter.
+        let triangle = Face::polygon(&plane, &[p(0.0, 0.0), p(1.0, 0.0), p(0.0, 1.0)]);
+        let clockwise = Face::polygon(&plane, &[p(0.0, 1.0), p(1.0, 0.0), p(0.0, 0.0)]);
+        assert_eq!(triangle.all_edges(), clockwise.all_edges());
+        assert_eq!(
+            triangle.all_edges()[0],
+            primitive_line(p(0.0, 0.0), p(1.0, 0.0))
+        );
+
+        let square = Face::rectangle(&plane, 4.0, 2.0);
+        assert_eq!(square.all_points().len(), 4);
+        assert!(square.all_points().contains(&p(2.0, 1.0)));
+        assert_eq!(
+            square.all_edges()[0],
+            primitive_line(p(-2.0, -1.0), p(2.0, -1.0))
+        );
+
+        let annulus =
+            Face::disk(&plane, p(0.0, 0.0), 2.0).with_hole(Face::disk(&plane, p(0.0, 0.0), 1.0));
+        assert_eq!(annulus.holes.len(), 1);
+        assert_eq!(
+            face_point_contains(&annulus, p(1.5, 0.0)),
+            FacePointContains::Inside
+        );
+        assert_eq!(
+            face_point_contains(&annulus, p(0.5, 0.0)),
+            FacePointContains::Outside
+        );
+
+        let frame = square.with_hole(triangle);
+        assert_eq!(
+            face_point_contains(&frame, p(0.2, 0.2)),
+            FacePointContains::Outside
+        );
+        assert_eq!(
+            face_point_contains(&frame, p(-1.0, 0.0)),
+            FacePointContains::Inside
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    curves::{circle::Circle, curve::Curve, CurveLike},
+    surfaces::{cylinder::Cylinder, plane::Plane, surface::Surface},
+    EQ_THRESHOLD,
+};
+
+use crate::{primitive_objects::edges::line::primitive_line, topology::contour::Contour};
+
+use super::{super::edge::Edge, Face};
+
+// The contour along a, over to the end of b, back along b and over to the start of a. Rulings of zero length, where the edges meet,
+// are left out.
+fn ruled_contour(a: &Edge, b: &Edge) -> Contour {
+    let (a_start, a_end) = (a.start.unwrap(), a.end.unwrap());
+    let (b_start, b_end) = (b.start.unwrap(), b.end.unwrap());
+    let mut edges = vec![a.clone()];
+    if a_end != b_end {
+        edges.push(primitive_line(a_end, b_end));
+    }
+    edges.push(b.flip());
+    if b_start != a_start {
+        edges.push(primitive_line(b_start, a_start));
+    }
+    Contour::new(edges)
+}
+
+// The closed circle b running the other way around than the closed circle a.
+fn opposite(a: &Circle, b: &Edge, circle_b: &Circle) -> Edge {
+    match a.normal.dot(circle_b.normal) > 0.0 {
+        true => b.flip(),
+        false => b.clone(),
+    }
+}
+
+impl Face {
+    // The face swept by the straight lines from the points of a to the corresponding points of b, where the starts and the ends of
+    // the edges correspond. The boundary runs along a, and back along b, and the normal follows from this orientation. Closed circles
+    // have no start, so b is turned to run the other way around than a. Supported are:
+    // - Lines in a plane, which give planar quads and triangles.
+    // - Arcs or circles of the same radius on a common axis, which give a cylinder.
+    // - Concentric circles in a plane, which give an annulus.
+    // Other pairs need cones or general ruled surfaces.
+    pub fn ruled(a: &Edge, b: &Edge) -> Face {
+        assert!(
+            a.start.is_some() == b.start.is_some(),
+            "Edges have to be both closed or both bounded"
+        );
+        match (&a.curve, &b.curve) {
+            (Curve::Line(line_a), Curve::Line(_)) => {
+                let a_start = a.start.expect("Lines have to be bounded");
+                let (b_start, b_end) = (b.start.unwrap(), b.end.unwrap());
+                let u = line_a.direction.normalize();
+                let across = match b_start == a_start {
+                    true => b_end - a_start,
+                    false => b_start - a_start,
+                };
+                let v = across - u * across.dot(u);
+                assert!(v.norm() > EQ_THRESHOLD, "Edges are on the same line");
+                let normal = u.cross(v).normalize();
+                assert!(
+                    (b_end - a_start).dot(normal).abs() < EQ_THRESHOLD,
+                    "Ruled faces between skew lines need a general ruled surface"
+                );
+                Face::new(
+                    Some(ruled_contour(a, b)),
+                    vec![],
+                    Rc::new(Surface::Plane(Plane::new(a_start, u, v))),
+                )
+            }
+            (Curve::Circle(circle_a), Curve::Circle(circle_b)) => {
+                assert!(
+                    circle_a.normal.is_parallel(circle_b.normal),
+                    "Ruled faces between circles with different axes need a general ruled surface"
+                );
+                let offset = circle_b.basis - circle_a.basis;
+                let (radius_a, radius_b) = (circle_a.radius.norm(), circle_b.radius.norm());
+                if offset.dot(circle_a.normal).abs() < EQ_THRESHOLD {
+                    assert!(
+                        offset.norm() < EQ_THRESHOLD && a.start.is_none(),
+                        "Ruled faces between circles in a plane need concentric closed circles"
+                    );
+                    // a is the outer boundary if it is the larger circle, and the hole otherwise.
+                    let normal = match radius_a > radius_b {
+                        true => circle_a.normal,
+                        false => -circle_a.normal,
+                    };
+                    let u = circle_a.radius.normalize();
+                    let plane = Plane::new(circle_a.basis, u, normal.cross(u));
+                    let (boundary, hole) = match radius_a > radius_b {
+                        true => (a.clone(), opposite(circle_a, b, circle_b)),
+                        false => (opposite(circle_a, b, circle_b), a.clone()),
+                    };
+                    return Face::new(
+                        Some(Contour::new(vec![boundary])),
+                        vec![Contour::new(vec![hole])],
+                        Rc::new(Surface::Plane(plane)),
+                    );
+                }
+                assert!(
+                    offset.is_parallel(circle_a.normal)
+                        && (radius_a - radius_b).abs() < EQ_THRESHOLD,
+                    "Ruled faces between circles of different radii or axes need a cone"
+                );
+                let p = a.start.unwrap_or(circle_a.basis + circle_a.radius);
+                let normal = circle_a.tangent(p).cross(offset);
+                let cylinder = Surface::Cylinder(Cylinder::new(
+                    circle_a.basis,
+                    circle_a.normal,
+                    radius_a,
+                    normal.dot(p - circle_a.basis) > 0.0,
+                ));
+                match a.start {
+                    Some(_) => {
+                        assert!(
+                            circle_a.normal.dot(circle_b.normal) > 0.0
+                                && (b.start.unwrap() - a.start.unwrap()).is_parallel(offset),
+                            "Arcs have to run along each other"
+                        );
+                        Face::new(Some(ruled_contour(a, b)), vec![], Rc::new(cylinder))
+                    }
+                    None => Face::new(
+                        Some(Contour::new(vec![a.clone()])),
+                        vec![Contour::new(vec![opposite(circle_a, b, circle_b)])],
+                        Rc::new(cylinder),
+                    ),
+                }
+            }
+            _ => todo!("Ruled faces between these curves"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geop_geometry::{points::point::Point, surfaces::SurfaceLike};
+
+    use crate::{
+        measure::mass::measure_area,
+        primitive_objects::edges::{arc::ArcSide, circle::primitive_circle},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_ruled() {
+        let p = |x: f64, y: f64, z: f64| Point::new(x, y, z);
+
+        // A trapezoid between two parallel lines, and a triangle where they meet.
+        let a = primitive_line(p(0.0, 0.0, 0.0), p(2.0, 0.0, 0.0));
+        let quad = Face::ruled(&a, &primitive_line(p(0.0, 1.0, 0.0), p(1.0, 1.0, 0.0)));
+        assert!((measure_area(&quad) - 1.5).abs() < 1e-9);
+        assert_eq!(quad.normal(p(0.5, 0.5, 0.0)), Point::unit_z());
+        let triangle = Face::ruled(&a, &primitive_line(p(0.0, 0.0, 0.0), p(0.0, 0.0, 1.0)));
+        assert_eq!(triangle.all_edges().len(), 3);
+
+        // A half cylinder between two arcs, with the normal pointing outwards.
+        let arc = Edge::arc(
+            p(1.0, 0.0, 0.0),
+            p(-1.0, 0.0, 0.0),
+            Point::unit_z(),
+            1.0,
+            ArcSide::Minor,
+        );
+        let top = Edge::arc(
+            p(1.0, 0.0, 2.0),
+            p(-1.0, 0.0, 2.0),
+            Point::unit_z(),
+            1.0,
+            ArcSide::Minor,
+        );
+        let half = Face::ruled(&arc, &top);
+        let q = half.inner_point();
+        assert!(matches!(&*half.surface, Surface::Cylinder(_)));
+        assert!(half.surface.normal(q).dot(p(q.x, q.y, 0.0)) > 0.0);
+
+        // A full cylinder and an annulus between closed circles.
+        let circle = primitive_circle(Point::zero(), Point::unit_z(), 1.0);
+        let tube = Face::ruled(
+            &circle,
+            &primitive_circle(Point::unit_z(), Point::unit_z(), 1.0),
+        );
+        assert_eq!(tube.holes.len(), 1);
+        let annulus = Face::ruled(
+            &circle,
+            &primitive_circle(Point::zero(), Point::unit_z(), 2.0),
+        );
+        assert!((measure_area(&annulus) - 3.0 * std::f64::consts::PI).abs() < 1e-3);
+        assert_eq!(annulus.normal(p(1.5, 0.0, 0.0)), -Point::unit_z());
+    }
+}
@@ -0,0 +1,91 @@
+use std::rc::Rc;
+
+use geop_geometry::{
+    points::point::Point,
+    surfaces::{sphere::Sphere, surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    primitive_objects::edges::{arc::ArcSide, sphere_arc::primitive_great_circle_arc},
+    topology::contour::Contour,
+};
+
+use super::Face;
+
+impl Face {
+    // The polygon on the sphere whose corners are connected by the shorter great circle arcs, in the given order. A closed loop on a
+    // sphere bounds two regions, so the face is the one that fits into the hemisphere around the mean direction of the corners,
+    // independent of whether the corners run clockwise or counterclockwise. Corners that do not fit into an open hemisphere leave
+    // this ambiguous and are rejected, as are consecutive corners that are equal or antipodal. The face points to the side of the
+    // normal of the sphere.
+    pub fn spherical_polygon(sphere: &Sphere, points: &[Point]) -> Face {
+        assert!(points.len() >= 3, "A spherical polygon needs three corners");
+        assert!(
+            points.iter().all(|p| sphere.on_surface(*p)),
+            "Corners are not on the sphere"
+        );
+        let directions: Vec<Point> = points.iter().map(|p| *p - sphere.basis).collect();
+        let mean = directions.iter().fold(Point::zero(), |a, d| a + *d);
+        assert!(
+            mean.norm() > EQ_THRESHOLD
+                && directions
+                    .iter()
+                    .all(|d| d.dot(mean) > EQ_THRESHOLD * sphere.radius),
+            "Corners do not fit into a hemisphere, so the inside of the polygon is ambiguous"
+        );
+
+        // Counterclockwise around the mean direction, the polygon is on the left of its edges when seen from outside.
+        let n = points.len();
+        let winding: f64 = (0..n)
+            .map(|i| mean.dot(directions[i].cross(directions[(i + 1) % n])))
+            .sum();
+        assert!(winding.abs() > EQ_THRESHOLD, "Corners enclose no area");
+        let mut corners = points.to_vec();
+        if (winding > 0.0) != sphere.normal_outwards {
+            corners.reverse();
+        }
+        let edges = (0..n)
+            .map(|i| {
+                primitive_great_circle_arc(sphere, corners[i], corners[(i + 1) % n], ArcSide::Minor)
+            })
+            .collect();
+        Face::new(
+            Some(Contour::new(edges)),
+            vec![],
+            Rc::new(Surface::Sphere(sphere.clone())),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spherical_polygon() {
+        let sphere = Sphere::new(Point::zero(), 2.0, true);
+        let corners = [
+            Point::unit_x() * 2.0,
+            Point::unit_y() * 2.0,
+            Point::unit_z() * 2.0,
+        ];
+        let octant = Face::spherical_polygon(&sphere, &corners);
+        let p = octant.inner_point();
+        assert!(p.x > 0.0 && p.y > 0.0 && p.z > 0.0);
+        assert!((octant.normal(p) - p.normalize()).norm() < 1e-9);
+
+        // The order of the corners does not matter.
+        let mut reversed = corners.to_vec();
+        reversed.reverse();
+        let same = Face::spherical_polygon(&sphere, &reversed);
+        assert_eq!(same.all_edges(), octant.all_edges());
+
+        // On a sphere with inwards normals the polygon runs the other way around.
+        let inside = Sphere::new(Point::zero(), 2.0, false);
+        let inner = Face::spherical_polygon(&inside, &corners);
+        assert_eq!(inner.all_edges()[0], octant.all_edges()[1].flip());
+        let p = inner.inner_point();
+        assert!((inner.normal(p) + p.normalize()).norm() < 1e-9);
+    }
+}
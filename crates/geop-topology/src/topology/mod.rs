@@ -1,6 +1,15 @@
+pub mod attributes;
+pub mod binary;
+pub mod bounded;
 pub mod contour;
+pub mod diagnostics;
+pub mod dump;
 pub mod edge;
 pub mod face;
+pub mod non_manifold_shell;
+pub mod parameter_svg;
 pub mod scene;
 pub mod shell;
+pub mod stats;
 pub mod volume;
+pub mod wire;
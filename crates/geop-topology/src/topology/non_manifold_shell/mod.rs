@@ -0,0 +1,188 @@
+use geop_geometry::{points::point::Point, transforms::Transform, HORIZON_DIST};
+
+use crate::contains::volume_point::{volume_point_contains, VolumePointContains};
+
+use super::{edge::Edge, face::Face, shell::Shell, volume::Volume};
+
+// A set of faces that is not required to be manifold, e.g. edges may be shared by any number of faces and faces may be dangling.
+// This is the natural state during booleans and imprint-and-merge workflows. Use to_volumes to go back to manifold Volumes.
+#[derive(Clone, Debug)]
+pub struct NonManifoldShell {
+    pub faces: Vec<Face>,
+}
+
+// An edge together with the indices of all faces that use it.
+#[derive(Clone, Debug)]
+pub struct EdgeUse {
+    pub edge: Edge,
+    pub faces: Vec<usize>,
+}
+
+impl NonManifoldShell {
+    pub fn new(faces: Vec<Face>) -> NonManifoldShell {
+        NonManifoldShell { faces }
+    }
+
+    pub fn empty() -> NonManifoldShell {
+        NonManifoldShell { faces: Vec::new() }
+    }
+
+    pub fn from_volume(volume: &Volume) -> NonManifoldShell {
+        NonManifoldShell::new(volume.all_faces())
+    }
+
+    pub fn transform(&self, transform: Transform) -> NonManifoldShell {
+        NonManifoldShell {
+            faces: self.faces.iter().map(|f| f.transform(transform)).collect(),
+        }
+    }
+
+    pub fn add_face(&mut self, face: Face) {
+        self.faces.push(face);
+    }
+
+    // Merges the faces of both shells. Faces are not split, so both shells have to be imprinted onto each other beforehand.
+    pub fn merge(&self, other: &NonManifoldShell) -> NonManifoldShell {
+        let mut faces = self.faces.clone();
+        faces.extend(other.faces.iter().cloned());
+        NonManifoldShell { faces }
+    }
+
+    // Returns all distinct edges and the faces that use them. Edges are compared independent of their direction.
+    pub fn edge_uses(&self) -> Vec<EdgeUse> {
+        let mut uses = Vec::<EdgeUse>::new();
+        for (i, face) in self.faces.iter().enumerate() {
+            for edge in face.all_edges() {
+                match uses.iter_mut().find(|u| u.edge == edge) {
+                    Some(edge_use) => edge_use.faces.push(i),
+                    None => uses.push(EdgeUse {
+                        edge,
+                        faces: vec![i],
+                    }),
+                }
+            }
+        }
+        uses
+    }
+
+    // Returns all edges that are not shared by exactly two faces.
+    pub fn non_manifold_edges(&self) -> Vec<EdgeUse> {
+        self.edge_uses()
+            .into_iter()
+            .filter(|u| u.faces.len() != 2)
+            .collect()
+    }
+
+    pub fn is_manifold(&self) -> bool {
+        self.non_manifold_edges().is_empty()
+    }
+
+    // Groups the faces into sets that are connected by shared edges.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let uses = self.edge_uses();
+        let mut component = (0..self.faces.len()).collect::<Vec<usize>>();
+        fn find(component: &mut Vec<usize>, i: usize) -> usize {
+            if component[i] != i {
+                let root = find(component, component[i]);
+                component[i] = root;
+            }
+            component[i]
+        }
+        for edge_use in uses.iter() {
+            for window in edge_use.faces.windows(2) {
+                let a = find(&mut component, window[0]);
+                let b = find(&mut component, window[1]);
+                component[a] = b;
+            }
+        }
+
+        let mut groups = Vec::<(usize, Vec<usize>)>::new();
+        for i in 0..self.faces.len() {
+            let root = find(&mut component, i);
+            match groups.iter_mut().find(|(r, _)| *r == root) {
+                Some((_, group)) => group.push(i),
+                None => groups.push((root, vec![i])),
+            }
+        }
+        groups.into_iter().map(|(_, group)| group).collect()
+    }
+
+    // Converts the shell back to manifold volumes. Every connected component has to be manifold and forms a closed shell.
+    // Shells that are inside of another shell become its cavities, so they already have to be oriented with normals pointing into the void.
+    pub fn to_volumes(&self) -> Vec<Volume> {
+        let shells = self
+            .connected_components()
+            .into_iter()
+            .map(|group| {
+                let shell =
+                    NonManifoldShell::new(group.iter().map(|i| self.faces[*i].clone()).collect());
+                assert!(
+                    shell.is_manifold(),
+                    "Component is not a closed manifold shell"
+                );
+                Shell::new(shell.faces)
+            })
+            .collect::<Vec<Shell>>();
+
+        // Containment is checked independent of the orientation of the shells. Shells of cavities contain everything except the void.
+        let is_inside = |shell: &Shell, p: Point| {
+            matches!(
+                volume_point_contains(&Volume::new(shell.clone(), vec![]), p),
+                VolumePointContains::Inside
+            )
+        };
+        let far_away = Point::new(1.13, 1.27, 1.41) * HORIZON_DIST * 10.0;
+        let inverted = shells
+            .iter()
+            .map(|s| is_inside(s, far_away))
+            .collect::<Vec<bool>>();
+        let test_points = shells
+            .iter()
+            .map(|s| s.faces[0].inner_point())
+            .collect::<Vec<_>>();
+        let contains = |outer: usize, inner: usize| {
+            outer != inner && is_inside(&shells[outer], test_points[inner]) != inverted[outer]
+        };
+
+        let mut volumes = Vec::<(usize, Vec<Shell>)>::new();
+        let mut cavities = Vec::<usize>::new();
+        for i in 0..shells.len() {
+            match (0..shells.len()).find(|j| contains(*j, i)) {
+                Some(_) => cavities.push(i),
+                None => volumes.push((i, Vec::new())),
+            }
+        }
+        for i in cavities {
+            let (_, volume_cavities) = volumes
+                .iter_mut()
+                .find(|(j, _)| contains(*j, i))
+                .expect("Nested cavities with islands are not supported");
+            volume_cavities.push(shells[i].clone());
+        }
+
+        volumes
+            .into_iter()
+            .map(|(i, cavities)| Volume::new(shells[i].clone(), cavities))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    use super::*;
+
+    #[test]
+    fn test_to_volumes_with_cavity() {
+        let volume = primitive_cube(2.0, 2.0, 2.0).with_cavity(&primitive_cube(1.0, 1.0, 1.0));
+        let shell = NonManifoldShell::from_volume(&volume);
+        assert!(shell.is_manifold());
+        assert_eq!(shell.connected_components().len(), 2);
+
+        let volumes = shell.to_volumes();
+        assert_eq!(volumes.len(), 1);
+        assert_eq!(volumes[0].boundary.faces.len(), 6);
+        assert_eq!(volumes[0].cavities.len(), 1);
+    }
+}
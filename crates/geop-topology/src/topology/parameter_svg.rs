@@ -0,0 +1,232 @@
+use std::{f64::consts::PI, fmt::Write};
+
+use geop_geometry::{
+    curves::curve::Curve,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    EQ_THRESHOLD,
+};
+
+use crate::contains::face_point::{face_point_contains, FacePointContains};
+
+use super::{contour::Contour, face::Face};
+
+// Renders the parameter space of a face to SVG, to debug trimming, seams and poles. The contours are drawn as curves in (u, v),
+// with a dot at the start of every edge, and a grid of samples is colored by whether the face contains their point on the surface.
+// Seams of periodic surfaces and the poles of spheres are drawn as dashed lines. u goes to the right and v goes up.
+
+// Number of samples on curved edges.
+const EDGE_SAMPLES: usize = 64;
+// Size of the drawing area in pixels.
+const SIZE: f64 = 512.0;
+const MARGIN: f64 = 16.0;
+
+// The parameters of a polyline along the contour. On periodic surfaces u is unwrapped, so that a contour crossing the seam stays connected.
+fn contour_parameters(contour: &Contour, surface: &Surface) -> Vec<Vec<Point>> {
+    let mut edges = Vec::<Vec<Point>>::new();
+    let mut last: Option<Point> = None;
+    for edge in contour.edges.iter() {
+        let samples = match (&edge.curve, edge.start, edge.end) {
+            (Curve::Line(_), Some(start), Some(end)) => vec![start, end],
+            _ => (0..=EDGE_SAMPLES)
+                .map(|i| edge.interpolate(i as f64 / EDGE_SAMPLES as f64))
+                .collect(),
+        };
+        let mut uvs = Vec::<Point>::new();
+        for p in samples {
+            let mut uv = surface.parameters(p);
+            if let (Some(period), Some(last)) = (surface.u_period(), last) {
+                uv.x += ((last.x - uv.x) / period).round() * period;
+            }
+            uvs.push(uv);
+            last = Some(uv);
+        }
+        edges.push(uvs);
+    }
+    edges
+}
+
+fn polyline(
+    out: &mut String,
+    points: &[Point],
+    to_svg: &impl Fn(Point) -> (f64, f64),
+    style: &str,
+) {
+    let coordinates: Vec<String> = points
+        .iter()
+        .map(|p| {
+            let (x, y) = to_svg(*p);
+            format!("{:.2},{:.2}", x, y)
+        })
+        .collect();
+    writeln!(
+        out,
+        "  <polyline points=\"{}\" fill=\"none\" {}/>",
+        coordinates.join(" "),
+        style
+    )
+    .unwrap();
+}
+
+// Draws the face with a classification grid of grid x grid samples.
+pub fn face_parameter_svg(face: &Face, grid: usize) -> String {
+    let contours: Vec<(Vec<Vec<Point>>, &str)> = face
+        .boundary
+        .iter()
+        .map(|c| (contour_parameters(c, &face.surface), "blue"))
+        .chain(
+            face.holes
+                .iter()
+                .map(|c| (contour_parameters(c, &face.surface), "darkorange")),
+        )
+        .collect();
+
+    // The drawn range covers the contours, the whole period of periodic surfaces and both poles of spheres.
+    let mut extent: Vec<Point> = contours
+        .iter()
+        .flat_map(|(c, _)| c.iter().flatten())
+        .cloned()
+        .collect();
+    let v = extent.first().map_or(0.0, |p| p.y);
+    if let Some(period) = face.surface.u_period() {
+        extent.push(Point::new(0.0, v, 0.0));
+        extent.push(Point::new(period, v, 0.0));
+    }
+    if let Surface::Sphere(_) = &*face.surface {
+        extent.push(Point::new(0.0, 0.0, 0.0));
+        extent.push(Point::new(0.0, PI, 0.0));
+    }
+    let range = |coordinate: fn(&Point) -> f64| {
+        let min = extent.iter().map(coordinate).fold(f64::INFINITY, f64::min);
+        let max = extent
+            .iter()
+            .map(coordinate)
+            .fold(f64::NEG_INFINITY, f64::max);
+        match max - min > EQ_THRESHOLD {
+            true => (min, max),
+            // Unbounded directions, like the height of a cylinder without boundary.
+            false => (min.min(0.0) - 1.0, max.max(0.0) + 1.0),
+        }
+    };
+    let (min_u, max_u) = range(|p| p.x);
+    let (min_v, max_v) = range(|p| p.y);
+    let min = Point::new(min_u, min_v, 0.0);
+    let max = Point::new(max_u, max_v, 0.0);
+
+    let scale = (SIZE - 2.0 * MARGIN) / (max.x - min.x).max(max.y - min.y);
+    let to_svg = |p: Point| {
+        (
+            MARGIN + (p.x - min.x) * scale,
+            SIZE - MARGIN - (p.y - min.y) * scale,
+        )
+    };
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">",
+        SIZE, SIZE
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "  <rect width=\"100%\" height=\"100%\" fill=\"white\"/>"
+    )
+    .unwrap();
+
+    let dashed = "stroke=\"gray\" stroke-dasharray=\"4 4\"";
+    if let Some(period) = face.surface.u_period() {
+        let mut u = (min.x / period).ceil() * period;
+        while u <= max.x {
+            polyline(
+                &mut out,
+                &[Point::new(u, min.y, 0.0), Point::new(u, max.y, 0.0)],
+                &to_svg,
+                dashed,
+            );
+            u += period;
+        }
+    }
+    if let Surface::Sphere(_) = &*face.surface {
+        for v in [0.0, PI] {
+            polyline(
+                &mut out,
+                &[Point::new(min.x, v, 0.0), Point::new(max.x, v, 0.0)],
+                &to_svg,
+                dashed,
+            );
+        }
+    }
+
+    for i in 0..grid {
+        for j in 0..grid {
+            let uv = Point::new(
+                min.x + (i as f64 + 0.5) / grid as f64 * (max.x - min.x),
+                min.y + (j as f64 + 0.5) / grid as f64 * (max.y - min.y),
+                0.0,
+            );
+            let color = match face_point_contains(face, face.surface.point_at(uv)) {
+                FacePointContains::Inside => "limegreen",
+                FacePointContains::Outside => "lightgray",
+                FacePointContains::OnEdge(_) | FacePointContains::OnPoint(_) => "red",
+                FacePointContains::NotOnSurface => "black",
+            };
+            let (x, y) = to_svg(uv);
+            writeln!(
+                out,
+                "  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"2\" fill=\"{}\"/>",
+                x, y, color
+            )
+            .unwrap();
+        }
+    }
+
+    for (edges, color) in contours.iter() {
+        for edge in edges.iter() {
+            polyline(&mut out, edge, &to_svg, &format!("stroke=\"{}\"", color));
+            let (x, y) = to_svg(edge[0]);
+            writeln!(
+                out,
+                "  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"4\" fill=\"{}\"/>",
+                x, y, color
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "</svg>").unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive_objects::{
+        faces::{cylinder::primitive_cylinder, rectangle::primitive_rectangle},
+        volumes::cube::primitive_cube,
+    };
+
+    #[test]
+    fn test_face_parameter_svg() {
+        let face = primitive_cube(1.0, 1.0, 1.0).all_faces()[0].clone();
+        let svg = face_parameter_svg(&face, 10);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<polyline").count(), 4);
+        assert_eq!(svg.matches("r=\"2\"").count(), 100);
+        // The grid is exactly the parameter range of the face, so every sample is inside.
+        assert_eq!(svg.matches("limegreen").count(), 100);
+
+        let rectangle = primitive_rectangle(Point::zero(), Point::unit_x(), Point::unit_y());
+        assert_eq!(
+            face_parameter_svg(&rectangle, 4)
+                .matches("limegreen")
+                .count(),
+            16
+        );
+
+        // The unbounded cylinder has a seam at both ends of its period.
+        let cylinder = primitive_cylinder(Point::zero(), Point::unit_z(), 1.0);
+        let svg = face_parameter_svg(&cylinder, 4);
+        assert_eq!(svg.matches("stroke-dasharray").count(), 2);
+    }
+}
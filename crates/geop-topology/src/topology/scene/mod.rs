@@ -156,12 +156,25 @@ impl Mul<Color> for Color {
     }
 }
 
+// An entry of a scene, by its kind and its index in the list of that kind.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SceneEntry {
+    Volume(usize),
+    Face(usize),
+    Edge(usize),
+    Point(usize),
+}
+
+// Entries can be tagged, e.g. with "model", "construction", "dimensions" or "analysis", and tags can be hidden to toggle these groups
+// in a viewer. An entry is visible unless one of its tags is hidden, so untagged entries are always visible.
 #[derive(Clone, Debug)]
 pub struct Scene {
     pub volumes: Vec<(Volume, Color)>,
     pub faces: Vec<(Face, Color)>,
     pub edges: Vec<(Edge, Color)>,
     pub points: Vec<(Point, Color)>,
+    pub tags: Vec<(SceneEntry, String)>,
+    pub hidden: Vec<String>,
 }
 
 impl Scene {
@@ -176,15 +189,143 @@ impl Scene {
             faces,
             edges,
             points,
+            tags: Vec::new(),
+            hidden: Vec::new(),
         }
     }
 
     pub fn empty() -> Scene {
-        Scene {
-            volumes: Vec::new(),
-            faces: Vec::new(),
-            edges: Vec::new(),
-            points: Vec::new(),
+        Scene::new(Vec::new(), Vec::new(), Vec::new(), Vec::new())
+    }
+
+    pub fn add_volume(&mut self, volume: Volume, color: Color, tags: &[&str]) -> SceneEntry {
+        self.volumes.push((volume, color));
+        self.tag_all(SceneEntry::Volume(self.volumes.len() - 1), tags)
+    }
+
+    pub fn add_face(&mut self, face: Face, color: Color, tags: &[&str]) -> SceneEntry {
+        self.faces.push((face, color));
+        self.tag_all(SceneEntry::Face(self.faces.len() - 1), tags)
+    }
+
+    pub fn add_edge(&mut self, edge: Edge, color: Color, tags: &[&str]) -> SceneEntry {
+        self.edges.push((edge, color));
+        self.tag_all(SceneEntry::Edge(self.edges.len() - 1), tags)
+    }
+
+    pub fn add_point(&mut self, point: Point, color: Color, tags: &[&str]) -> SceneEntry {
+        self.points.push((point, color));
+        self.tag_all(SceneEntry::Point(self.points.len() - 1), tags)
+    }
+
+    fn tag_all(&mut self, entry: SceneEntry, tags: &[&str]) -> SceneEntry {
+        for tag in tags {
+            self.tag(entry, tag);
+        }
+        entry
+    }
+
+    pub fn tag(&mut self, entry: SceneEntry, tag: &str) {
+        if !self.entry_tags(entry).contains(&tag) {
+            self.tags.push((entry, tag.to_string()));
+        }
+    }
+
+    pub fn entry_tags(&self, entry: SceneEntry) -> Vec<&str> {
+        self.tags
+            .iter()
+            .filter(|(e, _)| *e == entry)
+            .map(|(_, t)| t.as_str())
+            .collect()
+    }
+
+    pub fn hide(&mut self, tag: &str) {
+        if !self.is_hidden(tag) {
+            self.hidden.push(tag.to_string());
+        }
+    }
+
+    pub fn show(&mut self, tag: &str) {
+        self.hidden.retain(|t| t != tag);
+    }
+
+    pub fn is_hidden(&self, tag: &str) -> bool {
+        self.hidden.iter().any(|t| t == tag)
+    }
+
+    pub fn is_visible(&self, entry: SceneEntry) -> bool {
+        !self.entry_tags(entry).iter().any(|tag| self.is_hidden(tag))
+    }
+
+    // The visible entries with their tags, for viewers and exporters that draw everything they are given.
+    pub fn visible(&self) -> Scene {
+        let mut scene = Scene::empty();
+        let tags = |entry: SceneEntry| self.entry_tags(entry);
+        for (i, (volume, color)) in self.volumes.iter().enumerate() {
+            let entry = SceneEntry::Volume(i);
+            if self.is_visible(entry) {
+                scene.add_volume(volume.clone(), *color, &tags(entry));
+            }
         }
+        for (i, (face, color)) in self.faces.iter().enumerate() {
+            let entry = SceneEntry::Face(i);
+            if self.is_visible(entry) {
+                scene.add_face(face.clone(), *color, &tags(entry));
+            }
+        }
+        for (i, (edge, color)) in self.edges.iter().enumerate() {
+            let entry = SceneEntry::Edge(i);
+            if self.is_visible(entry) {
+                scene.add_edge(edge.clone(), *color, &tags(entry));
+            }
+        }
+        for (i, (point, color)) in self.points.iter().enumerate() {
+            let entry = SceneEntry::Point(i);
+            if self.is_visible(entry) {
+                scene.add_point(*point, *color, &tags(entry));
+            }
+        }
+        scene
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::{edges::line::primitive_line, volumes::cube::primitive_cube};
+
+    use super::*;
+
+    #[test]
+    fn test_scene_tags() {
+        let mut scene = Scene::empty();
+        scene.add_volume(primitive_cube(1.0, 1.0, 1.0), Color::gray(), &["model"]);
+        let axis = scene.add_edge(
+            primitive_line(Point::zero(), Point::unit_z()),
+            Color::red(),
+            &["construction"],
+        );
+        scene.add_point(Point::zero(), Color::black(), &[]);
+        scene.tag(axis, "dimensions");
+        assert_eq!(scene.entry_tags(axis), vec!["construction", "dimensions"]);
+
+        scene.hide("construction");
+        assert!(!scene.is_visible(axis));
+        let visible = scene.visible();
+        assert_eq!(
+            (
+                visible.volumes.len(),
+                visible.edges.len(),
+                visible.points.len()
+            ),
+            (1, 0, 1)
+        );
+        assert_eq!(visible.entry_tags(SceneEntry::Volume(0)), vec!["model"]);
+
+        // Showing one tag of an entry is not enough while another one is hidden.
+        scene.hide("dimensions");
+        scene.show("construction");
+        assert!(!scene.is_visible(axis));
+        scene.show("dimensions");
+        assert_eq!(scene.visible().edges.len(), 1);
     }
 }
@@ -59,6 +59,13 @@ impl Shell {
         }
     }
 
+    // Flips the orientation of all faces, e.g. to turn the outer shell of a solid into the shell of a cavity.
+    pub fn flip(&self) -> Shell {
+        Shell {
+            faces: self.faces.iter().map(|f| f.flip()).collect(),
+        }
+    }
+
     pub fn normal(&self, point: Point) -> ShellNormal {
         let mut relevant_faces = Vec::<&Face>::new();
         for face in self.faces.iter() {
@@ -0,0 +1,241 @@
+use std::{
+    fmt::{Display, Formatter},
+    mem::size_of,
+};
+
+use geop_geometry::{curves::curve::Curve, points::point::Point, surfaces::surface::Surface};
+
+use super::{contour::Contour, edge::Edge, face::Face, scene::Scene, shell::Shell, volume::Volume};
+
+// Number of faces per type of surface.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SurfaceHistogram {
+    pub planes: usize,
+    pub cylinders: usize,
+    pub spheres: usize,
+}
+
+// Number of edges per type of curve.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveHistogram {
+    pub lines: usize,
+    pub circles: usize,
+    pub ellipses: usize,
+    pub helices: usize,
+    pub beziers: usize,
+    pub composites: usize,
+}
+
+// How complex a model is, to budget the cost of booleans and rendering before running them. Edges and vertices that are shared
+// between faces are counted once.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelStats {
+    pub volumes: usize,
+    pub shells: usize,
+    pub faces: usize,
+    pub contours: usize,
+    pub edges: usize,
+    pub vertices: usize,
+    pub surfaces: SurfaceHistogram,
+    pub curves: CurveHistogram,
+    // Triangles of the tessellation, which geop-rasterize fills in for a given tolerance.
+    pub triangles: Option<usize>,
+    // Estimate of the memory held by the topology, from the sizes of the structs and the vectors they own. Surfaces that are
+    // shared between faces are counted for every face.
+    pub memory_bytes: usize,
+}
+
+fn curve_bytes(curve: &Curve) -> usize {
+    match curve {
+        Curve::Composite(composite) => {
+            size_of::<Curve>()
+                + composite
+                    .segments
+                    .iter()
+                    .map(|s| size_of::<Point>() * 2 + curve_bytes(&s.curve) + size_of::<f64>())
+                    .sum::<usize>()
+        }
+        _ => size_of::<Curve>(),
+    }
+}
+
+impl ModelStats {
+    pub fn new() -> ModelStats {
+        ModelStats::default()
+    }
+
+    // Adds the counts of the other stats, e.g. of another volume of a scene. Entities shared between both are counted twice.
+    pub fn merge(&mut self, other: &ModelStats) {
+        self.volumes += other.volumes;
+        self.shells += other.shells;
+        self.faces += other.faces;
+        self.contours += other.contours;
+        self.edges += other.edges;
+        self.vertices += other.vertices;
+        self.surfaces.planes += other.surfaces.planes;
+        self.surfaces.cylinders += other.surfaces.cylinders;
+        self.surfaces.spheres += other.surfaces.spheres;
+        self.curves.lines += other.curves.lines;
+        self.curves.circles += other.curves.circles;
+        self.curves.ellipses += other.curves.ellipses;
+        self.curves.helices += other.curves.helices;
+        self.curves.beziers += other.curves.beziers;
+        self.curves.composites += other.curves.composites;
+        self.triangles = match (self.triangles, other.triangles) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        self.memory_bytes += other.memory_bytes;
+    }
+
+    fn add_edges(&mut self, edges: &[Edge]) {
+        let mut unique = Vec::<&Edge>::new();
+        let mut vertices = Vec::<Point>::new();
+        for edge in edges.iter() {
+            for p in [edge.start, edge.end].into_iter().flatten() {
+                if !vertices.contains(&p) {
+                    vertices.push(p);
+                }
+            }
+            if unique.contains(&edge) {
+                continue;
+            }
+            unique.push(edge);
+            match edge.curve {
+                Curve::Line(_) => self.curves.lines += 1,
+                Curve::Circle(_) => self.curves.circles += 1,
+                Curve::Ellipse(_) => self.curves.ellipses += 1,
+                Curve::Helix(_) => self.curves.helices += 1,
+                Curve::Bezier(_) => self.curves.beziers += 1,
+                Curve::Composite(_) => self.curves.composites += 1,
+            }
+        }
+        self.edges += unique.len();
+        self.vertices += vertices.len();
+    }
+
+    fn add_face(&mut self, face: &Face) {
+        self.faces += 1;
+        match &*face.surface {
+            Surface::Plane(_) => self.surfaces.planes += 1,
+            Surface::Cylinder(_) => self.surfaces.cylinders += 1,
+            Surface::Sphere(_) => self.surfaces.spheres += 1,
+        }
+        let contours: Vec<&Contour> = face.boundary.iter().chain(face.holes.iter()).collect();
+        self.contours += contours.len();
+        self.memory_bytes += size_of::<Face>() + size_of::<Surface>();
+        for contour in contours {
+            self.memory_bytes += size_of::<Contour>() + contour.edges.len() * size_of::<Edge>();
+            for edge in contour.edges.iter() {
+                self.memory_bytes += curve_bytes(&edge.curve) - size_of::<Curve>();
+            }
+        }
+    }
+
+    fn add_shells(&mut self, shells: &[Shell]) {
+        self.shells += shells.len();
+        let mut edges = Vec::<Edge>::new();
+        for shell in shells.iter() {
+            self.memory_bytes += size_of::<Shell>();
+            for face in shell.faces.iter() {
+                self.add_face(face);
+                edges.extend(face.all_edges());
+            }
+        }
+        self.add_edges(&edges);
+    }
+}
+
+impl Display for ModelStats {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} volumes, {} shells, {} faces, {} contours, {} edges, {} vertices",
+            self.volumes, self.shells, self.faces, self.contours, self.edges, self.vertices
+        )?;
+        writeln!(
+            f,
+            "Surfaces: {} planes, {} cylinders, {} spheres",
+            self.surfaces.planes, self.surfaces.cylinders, self.surfaces.spheres
+        )?;
+        writeln!(
+            f,
+            "Curves: {} lines, {} circles, {} ellipses, {} helices, {} beziers, {} composites",
+            self.curves.lines,
+            self.curves.circles,
+            self.curves.ellipses,
+            self.curves.helices,
+            self.curves.beziers,
+            self.curves.composites
+        )?;
+        if let Some(triangles) = self.triangles {
+            writeln!(f, "Triangles: {}", triangles)?;
+        }
+        write!(f, "Memory: about {} kB", self.memory_bytes.div_ceil(1024))
+    }
+}
+
+impl Volume {
+    pub fn stats(&self) -> ModelStats {
+        let mut stats = ModelStats::new();
+        stats.volumes = 1;
+        stats.memory_bytes += size_of::<Volume>();
+        stats.add_shells(&self.all_shells());
+        stats
+    }
+}
+
+impl Scene {
+    // The stats of the volumes plus the loose faces, edges and points of the scene. Loose points count as vertices.
+    pub fn stats(&self) -> ModelStats {
+        let mut stats = ModelStats::new();
+        for (volume, _) in self.volumes.iter() {
+            stats.merge(&volume.stats());
+        }
+        let mut edges = Vec::<Edge>::new();
+        for (face, _) in self.faces.iter() {
+            stats.add_face(face);
+            edges.extend(face.all_edges());
+        }
+        edges.extend(self.edges.iter().map(|(e, _)| e.clone()));
+        stats.add_edges(&edges);
+        stats.memory_bytes += self.edges.len() * size_of::<Edge>();
+        stats.vertices += self.points.len();
+        stats.memory_bytes += self.points.len() * size_of::<Point>();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{primitive_objects::volumes::cube::primitive_cube, topology::scene::Color};
+
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let cube = primitive_cube(1.0, 2.0, 3.0);
+        let stats = cube.stats();
+        assert_eq!(
+            (stats.volumes, stats.shells, stats.faces, stats.contours),
+            (1, 1, 6, 6)
+        );
+        assert_eq!((stats.edges, stats.vertices), (12, 8));
+        assert_eq!(stats.surfaces.planes, 6);
+        assert_eq!(stats.curves.lines, 12);
+        assert_eq!(stats.triangles, None);
+        assert!(stats.memory_bytes > 24 * size_of::<Edge>());
+
+        let scene = Scene::new(
+            vec![(cube.clone(), Color::gray()), (cube.clone(), Color::gray())],
+            vec![],
+            vec![],
+            vec![(Point::zero(), Color::black())],
+        );
+        let scene_stats = scene.stats();
+        assert_eq!(scene_stats.volumes, 2);
+        assert_eq!(scene_stats.edges, 24);
+        assert_eq!(scene_stats.vertices, 17);
+        assert!(scene_stats.to_string().contains("12 faces"));
+    }
+}
@@ -0,0 +1,185 @@
+use super::Volume;
+use crate::topology::{
+    diagnostics::{check_face, Violation},
+    edge::Edge,
+    face::Face,
+    shell::Shell,
+};
+
+// The faces of a shell, and every edge with the copies of it that the faces run along.
+#[derive(Debug, Clone, Default)]
+struct ShellState {
+    faces: Vec<Face>,
+    edges: Vec<(Edge, Vec<Edge>)>,
+}
+
+// Assembles a volume face by face, e.g. in importers and generators. Every face is checked against the faces before it when it is
+// added, so the first inconsistency is reported together with the face that caused it, instead of a list of violations of the
+// whole shell at the end. Rejected faces are not added, so the caller can skip them and go on. The edge table is kept while
+// building, so that the closedness check of build does not have to match all edges again. Violations are boxed, since they
+// carry the offending edge.
+#[derive(Debug, Clone)]
+pub struct VolumeBuilder {
+    shells: Vec<ShellState>, // The boundary, followed by the cavities
+}
+
+impl Default for VolumeBuilder {
+    fn default() -> Self {
+        VolumeBuilder::new()
+    }
+}
+
+impl VolumeBuilder {
+    // Starts with the boundary, which gets all faces until the first cavity is started.
+    pub fn new() -> VolumeBuilder {
+        VolumeBuilder {
+            shells: vec![ShellState::default()],
+        }
+    }
+
+    // Faces added after this go to a new cavity, with normals pointing into the void.
+    pub fn begin_cavity(&mut self) {
+        self.shells.push(ShellState::default());
+    }
+
+    // Adds the face to the current shell. The edges have to lie on the surface, and every edge can be shared with one other face of
+    // the shell, which has to run along it in the opposite direction.
+    pub fn add_face(&mut self, face: Face) -> Result<(), Box<Violation>> {
+        if let Some(violation) = check_face(&face.boundary, &face.holes, &face.surface)
+            .into_iter()
+            .next()
+        {
+            return Err(Box::new(violation));
+        }
+        let shell = self.shells.last_mut().expect("There is always a shell");
+
+        // The uses are added one by one, so that edges that the face runs along twice, like seams, are checked against each other.
+        // If one of them fails, the uses added so far are removed again.
+        let known = shell.edges.len();
+        let mut extended = Vec::<usize>::new();
+        let mut result = Ok(());
+        for edge in face.all_edges() {
+            match shell.edges.iter().position(|(e, _)| *e == edge) {
+                None => shell.edges.push((edge.clone(), vec![edge])),
+                Some(i) => {
+                    let (shared, uses) = &shell.edges[i];
+                    if uses.len() >= 2 {
+                        result = Err(Box::new(Violation::NonManifoldEdge(
+                            shared.clone(),
+                            uses.len() + 1,
+                        )));
+                        break;
+                    }
+                    if edge.start.is_some() && uses[0].start == edge.start {
+                        result = Err(Box::new(Violation::InconsistentOrientation(shared.clone())));
+                        break;
+                    }
+                    shell.edges[i].1.push(edge);
+                    if i < known {
+                        extended.push(i);
+                    }
+                }
+            }
+        }
+        if result.is_err() {
+            shell.edges.truncate(known);
+            for i in extended {
+                shell.edges[i].1.pop();
+            }
+            return result;
+        }
+        shell.faces.push(face);
+        Ok(())
+    }
+
+    pub fn add_faces(
+        &mut self,
+        faces: impl IntoIterator<Item = Face>,
+    ) -> Result<(), Box<Violation>> {
+        for face in faces {
+            self.add_face(face)?;
+        }
+        Ok(())
+    }
+
+    pub fn with_face(mut self, face: Face) -> Result<VolumeBuilder, Box<Violation>> {
+        self.add_face(face)?;
+        Ok(self)
+    }
+
+    // Edges of the current shell that belong to only one face so far, i.e. where faces are still missing.
+    pub fn open_edges(&self) -> Vec<Edge> {
+        let shell = self.shells.last().expect("There is always a shell");
+        shell
+            .edges
+            .iter()
+            .filter(|(_, uses)| uses.len() == 1)
+            .map(|(e, _)| e.clone())
+            .collect()
+    }
+
+    pub fn face_count(&self) -> usize {
+        self.shells.iter().map(|s| s.faces.len()).sum()
+    }
+
+    // Finishes the volume, once all shells are closed.
+    pub fn build(self) -> Result<Volume, Box<Violation>> {
+        let mut shells = Vec::<Shell>::new();
+        for shell in self.shells {
+            if shell.faces.is_empty() {
+                return Err(Box::new(Violation::EmptyShell));
+            }
+            if let Some((edge, _)) = shell.edges.iter().find(|(_, uses)| uses.len() == 1) {
+                return Err(Box::new(Violation::OpenEdge(edge.clone())));
+            }
+            shells.push(Shell::new(shell.faces));
+        }
+        let boundary = shells.remove(0);
+        Ok(Volume::new(boundary, shells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::volumes::cube::primitive_cube;
+
+    use super::*;
+
+    #[test]
+    fn test_volume_builder() {
+        let cube = primitive_cube(1.0, 1.0, 1.0);
+        let faces = cube.all_faces();
+        let mut builder = VolumeBuilder::new();
+        builder.add_faces(faces[..5].iter().cloned()).unwrap();
+        assert_eq!(builder.open_edges().len(), 4);
+        assert!(matches!(
+            builder.clone().build().map_err(|v| *v),
+            Err(Violation::OpenEdge(_))
+        ));
+
+        // A flipped face is rejected and leaves the builder as it was.
+        assert!(matches!(
+            builder.add_face(faces[5].flip()).map_err(|v| *v),
+            Err(Violation::InconsistentOrientation(_))
+        ));
+        assert_eq!(builder.open_edges().len(), 4);
+        assert!(matches!(
+            builder.add_face(faces[4].clone()).map_err(|v| *v),
+            Err(Violation::NonManifoldEdge(_, 3))
+        ));
+        builder.add_face(faces[5].clone()).unwrap();
+
+        // A smaller cube as cavity.
+        let inner = primitive_cube(0.5, 0.5, 0.5).boundary.flip();
+        builder.begin_cavity();
+        builder.add_faces(inner.faces).unwrap();
+        assert_eq!(builder.face_count(), 12);
+        let volume = builder.build().unwrap();
+        assert_eq!(volume.cavities.len(), 1);
+
+        assert!(matches!(
+            VolumeBuilder::new().build().map_err(|v| *v),
+            Err(Violation::EmptyShell)
+        ));
+    }
+}
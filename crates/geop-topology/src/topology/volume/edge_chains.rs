@@ -0,0 +1,185 @@
+use geop_geometry::points::point::Point;
+
+use crate::topology::edge::Edge;
+
+use super::Volume;
+
+// A sequence of edges that continue each other smoothly. The edges are oriented along the chain, such that each edge starts where the previous one ends.
+#[derive(Debug, Clone)]
+pub struct EdgeChain {
+    pub edges: Vec<Edge>,
+    pub closed: bool, // The last edge ends where the first one starts
+}
+
+// Edges meeting at a vertex, with the direction in which each edge leaves the vertex.
+struct Vertex {
+    point: Point,
+    leaving: Vec<(usize, Point)>,
+}
+
+// Two edges continue each other smoothly at a vertex if they leave it in opposite directions.
+fn is_smooth(a: Point, b: Point) -> bool {
+    a.is_parallel(b) && a.dot(b) < 0.0
+}
+
+// The edge that continues the edge smoothly at the vertex. There is none if the continuation is ambiguous,
+// e.g. where two tangent chains touch each other.
+fn smooth_partner(vertex: &Vertex, edge: usize) -> Option<usize> {
+    let (_, dir) = vertex.leaving.iter().find(|(i, _)| *i == edge)?;
+    let candidates: Vec<usize> = vertex
+        .leaving
+        .iter()
+        .filter(|(i, d)| *i != edge && is_smooth(*dir, *d))
+        .map(|(i, _)| *i)
+        .collect();
+    match candidates.len() {
+        1 => Some(candidates[0]),
+        _ => None,
+    }
+}
+
+// Groups the edges of the volume into chains of edges with equal tangents at their shared vertices, e.g. the edges along a fillet
+// or around a slot, such that fillets and chamfers can be applied to whole chains. Every edge is part of exactly one chain, edges
+// without smooth neighbors form chains on their own, and closed edges like full circles are closed chains.
+pub fn find_tangent_edge_chains(volume: &Volume) -> Vec<EdgeChain> {
+    let mut edges = Vec::<Edge>::new();
+    for face in volume.all_faces() {
+        for edge in face.all_edges() {
+            if !edges.contains(&edge) {
+                edges.push(edge);
+            }
+        }
+    }
+
+    let mut vertices = Vec::<Vertex>::new();
+    for (i, edge) in edges.iter().enumerate() {
+        let ends = [
+            edge.start.map(|p| (p, edge.tangent(p))),
+            edge.end.map(|p| (p, -edge.tangent(p))),
+        ];
+        for (point, dir) in ends.into_iter().flatten() {
+            match vertices.iter_mut().find(|v| v.point == point) {
+                Some(vertex) => vertex.leaving.push((i, dir)),
+                None => vertices.push(Vertex {
+                    point,
+                    leaving: vec![(i, dir)],
+                }),
+            }
+        }
+    }
+    let partner = |index: usize, at: Option<Point>| -> Option<usize> {
+        let vertex = vertices.iter().find(|v| Some(v.point) == at)?;
+        smooth_partner(vertex, index)
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut chains = Vec::<EdgeChain>::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut chain = vec![edges[first].clone()];
+        let mut closed = edges[first].start.is_none();
+
+        // Walk forwards from the end of the edge, orienting each following edge to start where the chain ends.
+        let mut current = first;
+        while let Some(next) = partner(current, chain[chain.len() - 1].end) {
+            if next == first {
+                closed = true;
+                break;
+            }
+            if used[next] {
+                break;
+            }
+            used[next] = true;
+            let end = chain[chain.len() - 1].end;
+            chain.push(match edges[next].start == end {
+                true => edges[next].clone(),
+                false => edges[next].flip(),
+            });
+            current = next;
+        }
+
+        // Walk backwards from the start of the first edge, if the chain is not closed.
+        let mut current = first;
+        while let Some(next) = partner(current, chain[0].start).filter(|n| !closed && !used[*n]) {
+            used[next] = true;
+            let start = chain[0].start;
+            chain.insert(
+                0,
+                match edges[next].end == start {
+                    true => edges[next].clone(),
+                    false => edges[next].flip(),
+                },
+            );
+            current = next;
+        }
+
+        chains.push(EdgeChain {
+            edges: chain,
+            closed,
+        });
+    }
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use geop_geometry::{
+        curves::{circle::Circle, curve::Curve, line::Line},
+        surfaces::{plane::Plane, surface::Surface},
+    };
+
+    use super::*;
+    use crate::{
+        operations::extrude::extrude,
+        primitive_objects::volumes::cube::primitive_cube,
+        topology::{contour::Contour, face::Face},
+    };
+
+    #[test]
+    fn test_find_tangent_edge_chains() {
+        // A stadium, whose straight sides blend tangentially into the round ends.
+        let p = |x: f64, y: f64| Point::new(x, y, 0.0);
+        let line = |start: Point, end: Point| {
+            Edge::new(
+                Some(start),
+                Some(end),
+                Curve::Line(Line::new(start, end - start)),
+            )
+        };
+        let arc = |start: Point, end: Point, center: Point| {
+            Edge::new(
+                Some(start),
+                Some(end),
+                Curve::Circle(Circle::new(center, Point::unit_z(), 1.0)),
+            )
+        };
+        let contour = Contour::new(vec![
+            line(p(0.0, 0.0), p(2.0, 0.0)),
+            arc(p(2.0, 0.0), p(2.0, 2.0), p(2.0, 1.0)),
+            line(p(2.0, 2.0), p(0.0, 2.0)),
+            arc(p(0.0, 2.0), p(0.0, 0.0), p(0.0, 1.0)),
+        ]);
+        let plane = Plane::new(Point::zero(), Point::unit_x(), Point::unit_y());
+        let face = Face::new(Some(contour), vec![], Rc::new(Surface::Plane(plane)));
+        let chains = find_tangent_edge_chains(&extrude(face, Point::unit_z()));
+        assert_eq!(chains.len(), 6);
+        let loops: Vec<&EdgeChain> = chains.iter().filter(|c| c.closed).collect();
+        assert_eq!(loops.len(), 2);
+        for chain in loops {
+            assert_eq!(chain.edges.len(), 4);
+            for (a, b) in chain.edges.iter().zip(chain.edges.iter().cycle().skip(1)) {
+                assert_eq!(a.end, b.start);
+            }
+        }
+
+        // A cube has no smooth edges.
+        let chains = find_tangent_edge_chains(&primitive_cube(1.0, 1.0, 1.0));
+        assert_eq!(chains.len(), 12);
+        assert!(chains.iter().all(|c| c.edges.len() == 1 && !c.closed));
+    }
+}
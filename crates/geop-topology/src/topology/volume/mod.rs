@@ -1,21 +1,46 @@
-use geop_geometry::{points::point::Point, transforms::Transform};
+use geop_geometry::{
+    curves::curve::Curve,
+    oriented_bounding_box::OrientedBoundingBox,
+    points::point::Point,
+    surfaces::{surface::Surface, SurfaceLike},
+    transforms::{frame::Frame, Transform},
+};
 
 use crate::contains::{face_point::FacePointContains, shell_point::shell_point_contains};
 
 use super::{
-    face::Face,
+    attributes::Attributes,
+    diagnostics::{check_volume, Violation},
+    face::{bounds::face_sample_points, Face},
     shell::{Shell, ShellNormal},
 };
 
+pub mod builder;
+pub mod edge_chains;
+
 #[derive(Clone, Debug)]
 pub struct Volume {
     pub boundary: Shell,      // Normal pointing outwards
     pub cavities: Vec<Shell>, // Normal pointing inwards
+    pub attributes: Attributes,
 }
 
 impl Volume {
     pub fn new(boundary: Shell, cavities: Vec<Shell>) -> Volume {
-        Volume { boundary, cavities }
+        Volume {
+            boundary,
+            cavities,
+            attributes: Attributes::new(),
+        }
+    }
+
+    // Like new, but checks that all shells are closed and consistently oriented, and returns the reasons if they are not.
+    pub fn try_new(boundary: Shell, cavities: Vec<Shell>) -> Result<Volume, Vec<Violation>> {
+        let violations = check_volume(&boundary, &cavities);
+        match violations.is_empty() {
+            true => Ok(Volume::new(boundary, cavities)),
+            false => Err(violations),
+        }
     }
 
     pub fn transform(&self, transform: Transform) -> Volume {
@@ -26,9 +51,31 @@ impl Volume {
                 .iter()
                 .map(|h| h.transform(transform))
                 .collect(),
+            attributes: self.attributes.clone(),
         }
     }
 
+    // Adds an internal void to the volume. The cavity is given as a solid with outwards pointing normals, which is flipped such that the normals point into the void.
+    // The cavity has to be strictly inside of the boundary and must not intersect other cavities.
+    pub fn with_cavity(&self, cavity: &Volume) -> Volume {
+        assert!(
+            cavity.cavities.is_empty(),
+            "Cavities with islands inside are not supported"
+        );
+        let mut cavities = self.cavities.clone();
+        cavities.push(cavity.boundary.flip());
+        Volume {
+            attributes: self.attributes.clone(),
+            ..Volume::new(self.boundary.clone(), cavities)
+        }
+    }
+
+    pub fn all_shells(&self) -> Vec<Shell> {
+        let mut shells = vec![self.boundary.clone()];
+        shells.extend(self.cavities.iter().cloned());
+        shells
+    }
+
     pub fn all_faces(&self) -> Vec<Face> {
         let mut faces = Vec::<Face>::new();
 
@@ -39,6 +86,35 @@ impl Volume {
         return faces;
     }
 
+    // Tight box around the outer boundary. Besides the principal axes of the volume, the frames spanned by planar faces and their straight edges
+    // are tried, since the principal axes are ambiguous for symmetric parts like cubes. The box with the smallest volume is returned.
+    pub fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        let points: Vec<Point> = self
+            .boundary
+            .faces
+            .iter()
+            .flat_map(face_sample_points)
+            .collect();
+        let mut best = OrientedBoundingBox::from_points(&points);
+        for face in self.boundary.faces.iter() {
+            let normal = match &*face.surface {
+                Surface::Plane(plane) => face.surface.normal(plane.basis),
+                _ => continue,
+            };
+            for edge in face.all_edges() {
+                if let Curve::Line(line) = &edge.curve {
+                    let frame =
+                        Frame::new(Point::zero(), line.direction, normal.cross(line.direction));
+                    let candidate = OrientedBoundingBox::from_points_in_frame(&points, &frame);
+                    if candidate.volume() < best.volume() {
+                        best = candidate;
+                    }
+                }
+            }
+        }
+        best
+    }
+
     pub fn boundary_normal(&self, p: Point) -> ShellNormal {
         if shell_point_contains(&self.boundary, p) != FacePointContains::Outside {
             return self.boundary.normal(p);
@@ -0,0 +1,305 @@
+use std::fmt::Display;
+
+use geop_geometry::{
+    curves::{circle::Circle, curve::Curve, line::Line, ClosestPoint, CurveLike},
+    points::point::Point,
+    transforms::{frame::Frame, Transform},
+    EQ_THRESHOLD,
+};
+
+use crate::{
+    contains::edge_point::{edge_point_contains, EdgePointContains},
+    operations::offset_wire::trim_point,
+};
+
+use super::{
+    contour::Contour,
+    edge::{frames::double_reflection, Edge},
+};
+
+// Number of steps per edge along which rotation minimizing frames are carried.
+const FRAME_SAMPLES: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct Wire {
+    pub edges: Vec<Edge>,
+}
+
+// A Wire is an ordered chain of bounded edges, where each edge starts where the previous one ends.
+// In contrast to a Contour it does not have to be closed, which makes it the right type for paths, e.g. for sweeps, toolpaths or measurement paths.
+// A Wire may be closed, in which case it can be converted to a Contour.
+impl Wire {
+    pub fn new(edges: Vec<Edge>) -> Wire {
+        assert!(!edges.is_empty());
+        for edge in edges.iter() {
+            assert!(
+                edge.start.is_some() && edge.end.is_some(),
+                "Wire edges must be bounded"
+            );
+        }
+        for i in 0..edges.len() - 1 {
+            assert!(edges[i].end == edges[i + 1].start);
+        }
+        Wire { edges }
+    }
+
+    pub fn from_contour(contour: &Contour) -> Wire {
+        Wire::new(contour.edges.clone())
+    }
+
+    pub fn to_contour(&self) -> Contour {
+        assert!(self.is_closed());
+        Contour::new(self.edges.clone())
+    }
+
+    pub fn start(&self) -> Point {
+        self.edges[0].start.unwrap()
+    }
+
+    pub fn end(&self) -> Point {
+        self.edges[self.edges.len() - 1].end.unwrap()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.start() == self.end()
+    }
+
+    pub fn all_points(&self) -> Vec<Point> {
+        let mut points = self
+            .edges
+            .iter()
+            .map(|e| e.start.unwrap())
+            .collect::<Vec<Point>>();
+        if !self.is_closed() {
+            points.push(self.end());
+        }
+        points
+    }
+
+    pub fn flip(&self) -> Wire {
+        Wire::new(self.edges.iter().rev().map(|e| e.flip()).collect())
+    }
+
+    pub fn transform(&self, transform: Transform) -> Wire {
+        Wire::new(self.edges.iter().map(|e| e.transform(transform)).collect())
+    }
+
+    pub fn length(&self) -> f64 {
+        self.edges.iter().map(|e| e.length().unwrap()).sum()
+    }
+
+    // Returns the point at the given distance from the start of the wire, measured along the wire.
+    // The distance is clamped to the length of the wire.
+    pub fn point_at(&self, distance: f64) -> Point {
+        let mut remaining = distance.max(0.0);
+        for edge in self.edges.iter() {
+            let length = edge.length().unwrap();
+            if remaining <= length {
+                return edge.interpolate(remaining / length);
+            }
+            remaining -= length;
+        }
+        self.end()
+    }
+
+    // Returns the unit tangent at a point on the wire. At corners, the tangent of the outgoing edge is returned.
+    pub fn tangent(&self, p: Point) -> Point {
+        for edge in self.edges.iter() {
+            if edge.start == Some(p) || edge_point_contains(edge, p) == EdgePointContains::Inside {
+                return edge.tangent(p);
+            }
+        }
+        if p == self.end() {
+            return self.edges[self.edges.len() - 1].tangent(p);
+        }
+        panic!("Point is not on wire");
+    }
+
+    // Returns the point on the wire that is closest to p. The parameter is the distance from the start of the wire, like for point_at.
+    pub fn project(&self, p: Point) -> ClosestPoint {
+        let mut closest = ClosestPoint::new(0.0, self.start(), p);
+        let mut offset = 0.0;
+        for edge in self.edges.iter() {
+            let candidate = edge.project(p);
+            if candidate.distance < closest.distance {
+                let start = edge.start.unwrap();
+                closest = ClosestPoint::new(
+                    offset + edge.curve.distance(start, candidate.point),
+                    candidate.point,
+                    p,
+                );
+            }
+            offset += edge.length().unwrap();
+        }
+        closest
+    }
+
+    // Rotation minimizing frames at increasing distances from the start of the wire, like for point_at. The frames are carried across
+    // the corners between the edges, so that they only turn around the bend of the corner.
+    pub fn rmf_frames_at(&self, distances: &[f64]) -> Vec<Frame> {
+        assert!(distances.windows(2).all(|d| d[0] <= d[1]));
+        let mut frames = Vec::<Frame>::with_capacity(distances.len());
+        let mut previous: Option<Frame> = None;
+        let (mut offset, mut next) = (0.0, 0);
+        for (i, edge) in self.edges.iter().enumerate() {
+            let length = edge.length().unwrap();
+            let last = i + 1 == self.edges.len();
+            // The parameters of the requested frames on this edge, between samples that let the frames follow the turns of the edge.
+            let mut ts: Vec<(f64, bool)> = (0..=FRAME_SAMPLES)
+                .map(|k| (k as f64 / FRAME_SAMPLES as f64, false))
+                .collect();
+            while next < distances.len() && (distances[next] <= offset + length || last) {
+                ts.push((((distances[next] - offset) / length).clamp(0.0, 1.0), true));
+                next += 1;
+            }
+            ts.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let normal = previous.map(|frame| {
+                let tangent = edge.tangent(edge.start.unwrap());
+                double_reflection(frame.y_dir, frame.x_dir, Point::zero(), tangent)
+            });
+            let params: Vec<f64> = ts.iter().map(|(t, _)| *t).collect();
+            let edge_frames = edge.rmf_frames_at(&params, normal);
+            for ((_, requested), frame) in ts.iter().zip(edge_frames.iter()) {
+                if *requested {
+                    frames.push(*frame);
+                }
+            }
+            previous = edge_frames.last().copied();
+            offset += length;
+        }
+        frames
+    }
+
+    // Offsets a planar wire by distance to the left, where left is normal x tangent.
+    // The gaps at convex corners are closed with straight lines, and the edges at concave corners are trimmed where they cross.
+    pub fn offset(&self, distance: f64, normal: Point) -> Wire {
+        let normal = normal.normalize();
+        let mut offset_edges = self
+            .edges
+            .iter()
+            .map(|e| offset_edge(e, distance, normal))
+            .collect::<Vec<Edge>>();
+
+        let n = offset_edges.len();
+        let corners = match self.is_closed() {
+            true => n,
+            false => n - 1,
+        };
+        let mut bridges: Vec<Option<Edge>> = vec![None; n];
+        for i in 0..corners {
+            let j = (i + 1) % n;
+            let (end, start) = (offset_edges[i].end.unwrap(), offset_edges[j].start.unwrap());
+            if end == start {
+                continue;
+            }
+            let corner = self.edges[i].end.unwrap();
+            let tangent = self.edges[i].tangent(corner);
+            let turn = normal.dot(tangent.cross(self.edges[j].tangent(corner)));
+            match turn * distance < 0.0 {
+                true => {
+                    bridges[i] = Some(Edge::new(
+                        Some(end),
+                        Some(start),
+                        Curve::Line(Line::new(end, start - end)),
+                    ))
+                }
+                false => {
+                    let p = trim_point(&offset_edges[i], &offset_edges[j], corner);
+                    let (before, after) = (offset_edges[i].clone(), offset_edges[j].clone());
+                    offset_edges[i] = Edge::new(before.start, Some(p), before.curve);
+                    offset_edges[j] = Edge::new(Some(p), after.end, after.curve);
+                }
+            }
+        }
+        Wire::new(
+            offset_edges
+                .into_iter()
+                .zip(bridges)
+                .flat_map(|(edge, bridge)| std::iter::once(edge).chain(bridge))
+                .collect(),
+        )
+    }
+}
+
+pub(crate) fn offset_edge(edge: &Edge, distance: f64, normal: Point) -> Edge {
+    let start = edge.start.unwrap();
+    let end = edge.end.unwrap();
+    match &edge.curve {
+        Curve::Line(line) => {
+            assert!(line.direction.is_perpendicular(normal));
+            let shift = normal.cross(line.direction).normalize() * distance;
+            Edge::new(
+                Some(start + shift),
+                Some(end + shift),
+                Curve::Line(Line::new(start + shift, line.direction)),
+            )
+        }
+        Curve::Circle(circle) => {
+            assert!(circle.normal.is_parallel(normal));
+            let radius = circle.radius.norm();
+            let left = normal.cross(circle.tangent(start));
+            let new_radius = match left.dot(start - circle.basis) > 0.0 {
+                true => radius + distance,
+                false => radius - distance,
+            };
+            assert!(new_radius > EQ_THRESHOLD, "Offset collapses the arc");
+            let scale = |p: Point| circle.basis + (p - circle.basis) * (new_radius / radius);
+            Edge::new(
+                Some(scale(start)),
+                Some(scale(end)),
+                Curve::Circle(Circle::new(circle.basis, circle.normal, new_radius)),
+            )
+        }
+        Curve::Ellipse(_) => todo!("Offset of ellipses is not an ellipse"),
+        Curve::Helix(_) => panic!("Helix is not planar"),
+        Curve::Bezier(_) => todo!("Offset of Bezier curves is not a Bezier curve"),
+        Curve::Composite(_) => todo!("Split composite curves into edges before offsetting"),
+    }
+}
+
+impl Display for Wire {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Wire: ")?;
+        for p in self.all_points() {
+            write!(f, "{:?} ", p)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primitive_objects::edges::line::primitive_line;
+
+    use super::*;
+
+    #[test]
+    fn test_wire() {
+        let p1 = Point::new(0.0, 0.0, 0.0);
+        let p2 = Point::new(2.0, 0.0, 0.0);
+        let p3 = Point::new(2.0, 1.0, 0.0);
+        let wire = Wire::new(vec![primitive_line(p1, p2), primitive_line(p2, p3)]);
+        assert!(!wire.is_closed());
+        assert!((wire.length() - 3.0).abs() < EQ_THRESHOLD);
+        assert_eq!(wire.point_at(2.5), Point::new(2.0, 0.5, 0.0));
+        assert_eq!(
+            wire.project(Point::new(3.0, 0.5, 0.0)).point,
+            Point::new(2.0, 0.5, 0.0)
+        );
+        assert!((wire.project(Point::new(3.0, 0.5, 0.0)).parameter - 2.5).abs() < EQ_THRESHOLD);
+        assert_eq!(wire.tangent(p2), Point::unit_y());
+
+        // The wire turns left, so the edges are trimmed at the concave corner on the left, and bridged at the convex one on the right.
+        let offset = wire.offset(0.5, Point::unit_z());
+        assert_eq!(offset.start(), Point::new(0.0, 0.5, 0.0));
+        assert_eq!(offset.end(), Point::new(1.5, 1.0, 0.0));
+        assert_eq!(offset.edges.len(), 2);
+        assert_eq!(offset.edges[0].end, Some(Point::new(1.5, 0.5, 0.0)));
+        let offset = wire.offset(-0.5, Point::unit_z());
+        assert_eq!(offset.edges.len(), 3);
+        assert_eq!(offset.edges[1].start, Some(Point::new(2.0, -0.5, 0.0)));
+        assert_eq!(offset.edges[1].end, Some(Point::new(2.5, 0.0, 0.0)));
+        assert_eq!(offset.end(), Point::new(2.5, 1.0, 0.0));
+    }
+}
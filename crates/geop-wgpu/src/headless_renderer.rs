@@ -14,6 +14,7 @@ use geop_rasterize::{
     },
 };
 use geop_topology::topology::scene::{Color, Scene};
+use image::RgbaImage;
 use winit::dpi::PhysicalSize;
 
 use crate::pipeline_manager::PipelineManager;
@@ -116,6 +117,23 @@ impl HeadlessRenderer {
         camera_pos: Point,
         file_path: &std::path::Path,
     ) {
+        let image = self
+            .render_to_image(scene, dark_mode, wireframe_mode, camera_pos)
+            .await;
+        // Create folder if it doesn't exist
+        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        image.save(file_path).unwrap();
+    }
+
+    // Renders the scene into an image of the size of the texture, e.g. to compare it with a reference image.
+    pub async fn render_to_image(
+        &mut self,
+        scene: &Scene,
+        dark_mode: bool,
+        wireframe_mode: bool,
+        camera_pos: Point,
+    ) -> RgbaImage {
+        let scene = &scene.visible();
         let (background_color, face_color, edge_color, point_color) =
             Color::standard_pallet(dark_mode);
         let u32_size = std::mem::size_of::<u32>() as u32;
@@ -247,15 +265,9 @@ impl HeadlessRenderer {
         self.device.poll(wgpu::Maintain::Wait);
         rx.receive().await.unwrap().unwrap();
 
-        let data = buffer_slice.get_mapped_range();
-
-        use image::{ImageBuffer, Rgba};
-        let buffer =
-            ImageBuffer::<Rgba<u8>, _>::from_raw(self.texture_size, self.texture_size, data)
-                .unwrap();
-        // Create folder if it doesn't exist
-        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
-        buffer.save(file_path).unwrap();
+        let data = buffer_slice.get_mapped_range().to_vec();
+        self.output_buffer.unmap();
+        RgbaImage::from_raw(self.texture_size, self.texture_size, data).unwrap()
     }
 }
 
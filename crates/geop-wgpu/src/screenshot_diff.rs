@@ -0,0 +1,196 @@
+use std::path::{Path, PathBuf};
+
+use geop_geometry::points::point::Point;
+use geop_topology::topology::scene::Scene;
+use image::{Rgba, RgbaImage};
+
+use crate::headless_renderer::HeadlessRenderer;
+
+// Visual regression tests. A scene is rendered headless and compared to a checked in reference image. Set GEOP_UPDATE_SCREENSHOTS=1
+// to write the references instead, e.g. after an intended change of the tessellation or the shaders. Pixels are compared by their
+// perceived color difference in the YIQ color space like in pixelmatch, so that small shifts in brightness from other GPUs and
+// drivers are accepted, while missing faces or edges are not.
+
+// The largest possible difference in YIQ between two pixels with channels from 0 to 255.
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScreenshotTolerance {
+    // Perceived difference from 0 to 1 above which two pixels differ. 0.1 hides anti aliasing and driver noise.
+    pub pixel_threshold: f64,
+    // Fraction of pixels that may differ, for the edges of anti aliased lines.
+    pub max_differing_fraction: f64,
+}
+
+impl Default for ScreenshotTolerance {
+    fn default() -> Self {
+        ScreenshotTolerance {
+            pixel_threshold: 0.1,
+            max_differing_fraction: 0.001,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageDiff {
+    pub differing_pixels: usize,
+    pub total_pixels: usize,
+    // The largest perceived difference from 0 to 1.
+    pub max_difference: f64,
+    // The actual image faded to gray, with the differing pixels in red.
+    pub diff_image: RgbaImage,
+}
+
+impl ImageDiff {
+    pub fn differing_fraction(&self) -> f64 {
+        self.differing_pixels as f64 / self.total_pixels as f64
+    }
+}
+
+// The pixel blended onto a white background, since transparent pixels look like the background.
+fn blend(pixel: &Rgba<u8>) -> [f64; 3] {
+    let alpha = pixel[3] as f64 / 255.0;
+    [0, 1, 2].map(|i| 255.0 + (pixel[i] as f64 - 255.0) * alpha)
+}
+
+fn yiq(rgb: [f64; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb;
+    [
+        r * 0.29889531 + g * 0.58662247 + b * 0.11448223,
+        r * 0.59597799 - g * 0.27417610 - b * 0.32180189,
+        r * 0.21147017 - g * 0.52261711 + b * 0.31114694,
+    ]
+}
+
+// Perceived difference of two pixels from 0 to 1.
+pub fn pixel_difference(a: &Rgba<u8>, b: &Rgba<u8>) -> f64 {
+    let (a, b) = (yiq(blend(a)), yiq(blend(b)));
+    let (y, i, q) = (a[0] - b[0], a[1] - b[1], a[2] - b[2]);
+    (0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q) / MAX_YIQ_DELTA
+}
+
+// Compares two images of the same size. The threshold is compared to the square root of the perceived difference, which spreads
+// the small differences that matter over the range of the threshold.
+pub fn compare_images(expected: &RgbaImage, actual: &RgbaImage, pixel_threshold: f64) -> ImageDiff {
+    assert_eq!(
+        expected.dimensions(),
+        actual.dimensions(),
+        "Images of different sizes cannot be compared"
+    );
+    let mut diff_image = RgbaImage::new(actual.width(), actual.height());
+    let mut differing_pixels = 0;
+    let mut max_difference: f64 = 0.0;
+    for (x, y, pixel) in actual.enumerate_pixels() {
+        let difference = pixel_difference(expected.get_pixel(x, y), pixel);
+        max_difference = max_difference.max(difference);
+        let out = match difference.sqrt() > pixel_threshold {
+            true => {
+                differing_pixels += 1;
+                Rgba([255, 0, 0, 255])
+            }
+            false => {
+                let gray = 255.0 - (255.0 - yiq(blend(pixel))[0]) * 0.1;
+                Rgba([gray as u8, gray as u8, gray as u8, 255])
+            }
+        };
+        diff_image.put_pixel(x, y, out);
+    }
+    ImageDiff {
+        differing_pixels,
+        total_pixels: (actual.width() * actual.height()) as usize,
+        max_difference,
+        diff_image,
+    }
+}
+
+// The image next to the reference where the actual image or the diff is written when they do not match, e.g. cube.actual.png.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
+
+// Compares the image with the reference image at the path. The reference is written if it does not exist yet or if
+// GEOP_UPDATE_SCREENSHOTS is set. If too many pixels differ, the actual image and the diff are written next to the reference
+// for inspection.
+pub fn check_screenshot(
+    path: &Path,
+    actual: &RgbaImage,
+    tolerance: &ScreenshotTolerance,
+) -> Result<(), String> {
+    if std::env::var("GEOP_UPDATE_SCREENSHOTS").is_ok() || !path.exists() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        return actual.save(path).map_err(|e| e.to_string());
+    }
+    let expected = image::open(path).map_err(|e| e.to_string())?.to_rgba8();
+    if expected.dimensions() != actual.dimensions() {
+        return Err(format!(
+            "{} has the size {:?} instead of {:?}",
+            path.display(),
+            expected.dimensions(),
+            actual.dimensions()
+        ));
+    }
+    let diff = compare_images(&expected, actual, tolerance.pixel_threshold);
+    if diff.differing_fraction() <= tolerance.max_differing_fraction {
+        return Ok(());
+    }
+    let (actual_path, diff_path) = (sibling(path, "actual"), sibling(path, "diff"));
+    actual.save(&actual_path).map_err(|e| e.to_string())?;
+    diff.diff_image
+        .save(&diff_path)
+        .map_err(|e| e.to_string())?;
+    Err(format!(
+        "{} differs in {} of {} pixels, see {} and {}",
+        path.display(),
+        diff.differing_pixels,
+        diff.total_pixels,
+        actual_path.display(),
+        diff_path.display()
+    ))
+}
+
+// Renders the scene in light mode from the camera position and compares it with the reference image.
+pub async fn check_scene_screenshot(
+    renderer: &mut HeadlessRenderer,
+    scene: &Scene,
+    camera_pos: Point,
+    path: &Path,
+    tolerance: &ScreenshotTolerance,
+) -> Result<(), String> {
+    let actual = renderer
+        .render_to_image(scene, false, false, camera_pos)
+        .await;
+    check_screenshot(path, &actual, tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_images() {
+        let expected = RgbaImage::from_pixel(10, 10, Rgba([200, 200, 200, 255]));
+        let mut actual = expected.clone();
+        // A slightly darker pixel is noise, a black one is a difference.
+        actual.put_pixel(1, 1, Rgba([198, 198, 198, 255]));
+        actual.put_pixel(2, 2, Rgba([0, 0, 0, 255]));
+        let diff = compare_images(&expected, &actual, 0.1);
+        assert_eq!(diff.differing_pixels, 1);
+        assert_eq!(diff.diff_image.get_pixel(2, 2), &Rgba([255, 0, 0, 255]));
+        assert!((diff.differing_fraction() - 0.01).abs() < 1e-12);
+        assert!(diff.max_difference < 1.0);
+        // Transparent pixels look like the white background.
+        assert!(pixel_difference(&Rgba([0, 0, 0, 0]), &Rgba([255, 255, 255, 255])) < 1e-12);
+
+        let path = std::env::temp_dir().join("geop_screenshot_diff_test.png");
+        let _ = std::fs::remove_file(&path);
+        let tolerance = ScreenshotTolerance::default();
+        assert!(check_screenshot(&path, &expected, &tolerance).is_ok());
+        assert!(check_screenshot(&path, &expected, &tolerance).is_ok());
+        let error = check_screenshot(&path, &actual, &tolerance).unwrap_err();
+        assert!(error.contains("1 of 100 pixels"));
+        assert!(sibling(&path, "diff").exists());
+    }
+}
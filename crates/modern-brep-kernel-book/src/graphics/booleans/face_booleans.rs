@@ -146,7 +146,7 @@ mod tests {
             })
             .collect::<Vec<FaceSplit>>();
 
-        let contours = face_remesh(edges);
+        let contours = face_remesh(edges, &face1.surface);
         for contour in contours {
             let contour =
                 contour.transform(Transform::from_translation(Point::new(0.0, 0.001, 0.0)));